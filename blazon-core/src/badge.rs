@@ -1,85 +1,561 @@
 //! SVG badge generation for Rust project metrics
 
-use std::process::Command;
+mod widths;
 
-/// Generate an SVG badge
+use crate::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+/// The visual style of a badge, named as shields.io names them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BadgeStyle {
+    /// Rounded corners with a subtle gradient, the shields.io default
+    #[default]
+    Flat,
+    /// Square corners without a gradient
+    FlatSquare,
+    /// Shorter, with a glossy gradient and embossed text
+    Plastic,
+    /// Taller and all caps, with a bold value
+    ForTheBadge,
+    /// GitHub-style button with a speech-bubble count
+    Social,
+}
+
+impl BadgeStyle {
+    /// Every style, in the order shields.io documents them
+    pub const ALL: [BadgeStyle; 5] = [
+        BadgeStyle::Flat,
+        BadgeStyle::FlatSquare,
+        BadgeStyle::Plastic,
+        BadgeStyle::ForTheBadge,
+        BadgeStyle::Social,
+    ];
+
+    /// The name shields.io uses for this style in the `?style=` query
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BadgeStyle::Flat => "flat",
+            BadgeStyle::FlatSquare => "flat-square",
+            BadgeStyle::Plastic => "plastic",
+            BadgeStyle::ForTheBadge => "for-the-badge",
+            BadgeStyle::Social => "social",
+        }
+    }
+}
+
+impl fmt::Display for BadgeStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for BadgeStyle {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BadgeStyle::ALL
+            .into_iter()
+            .find(|style| style.as_str() == s)
+            .ok_or_else(|| ParseError::Unknown {
+                kind: "badge style",
+                name: s.to_string(),
+                expected: BadgeStyle::ALL.iter().map(|s| s.as_str()).collect(),
+            })
+    }
+}
+
+/// A single badge, independent of how it is rendered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Badge {
+    /// Short identifier for the metric, used as the file stem of its SVG
+    pub id: String,
+    /// Alt text for the badge image
+    pub alt: String,
+    /// Text on the left of the badge
+    pub label: String,
+    /// Text on the right of the badge
+    pub value: String,
+    /// Colour of the value side, as a shields.io colour name or hex code
+    pub color: String,
+    /// Visual style, shared by the shields.io URL and the local SVG
+    pub style: BadgeStyle,
+}
+
+impl Badge {
+    /// The img.shields.io static badge URL for this badge
+    ///
+    /// The style is only added as a `?style=` query when it is not the default flat style.
+    pub fn shields_url(&self) -> String {
+        let mut url = format!(
+            "https://img.shields.io/badge/{}-{}-{}",
+            shields_escape(&self.label),
+            shields_escape(&self.value),
+            shields_escape(self.color.trim_start_matches('#'))
+        );
+        if self.style != BadgeStyle::Flat {
+            url.push_str("?style=");
+            url.push_str(self.style.as_str());
+        }
+        url
+    }
+
+    /// Render this badge as a self-contained SVG
+    pub fn to_svg(&self) -> String {
+        create_styled_badge(&self.label, &self.value, &self.color, self.style)
+    }
+
+    /// Markdown for this badge showing `image` and linking to `link`
+    pub fn markdown(&self, image: &str, link: &str) -> String {
+        let mut alt = String::with_capacity(self.alt.len());
+        for c in self.alt.chars() {
+            if matches!(c, '[' | ']' | '\\') {
+                alt.push('\\');
+            }
+            alt.push(c);
+        }
+        format!("[![{}]({})]({})", alt, image, link)
+    }
+}
+
+/// Encode text for one `-`-separated field of a shields.io static badge path
+///
+/// Shields reads `--` as `-`, `__` as `_` and a single `_` as a space, so literal dashes and
+/// underscores are doubled and spaces written as `%20`. Anything else outside the URL
+/// unreserved set is percent-encoded as UTF-8.
+pub fn shields_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '-' => escaped.push_str("--"),
+            '_' => escaped.push_str("__"),
+            c if c.is_ascii_alphanumeric() || c == '.' || c == '~' => escaped.push(c),
+            c => {
+                let mut buf = [0; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    escaped.push_str(&format!("%{:02X}", byte));
+                }
+            }
+        }
+    }
+    escaped
+}
+
+/// Escape text for use in SVG character data or a double-quoted attribute
+///
+/// Tabs and line breaks are written as character references, so parsers keep them rather than
+/// normalising them to spaces or `\n`. Characters XML does not allow at all, the other C0
+/// controls and U+FFFE and U+FFFF, are dropped.
+pub fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push_str(&format!("&#{};", c as u32)),
+            '\0'..='\x1f' | '\u{fffe}' | '\u{ffff}' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Resolve a shields.io colour name to the hex code shields renders it as
+///
+/// Anything else (hex codes, CSS colour names) is passed through, with a `#` added to bare 3 or
+/// 6 digit hex. An empty colour is lightgrey, so the badge still has a fill.
+pub fn svg_color(color: &str) -> String {
+    let hex = match color {
+        "" => "#9f9f9f",
+        "brightgreen" | "success" => "#4c1",
+        "green" => "#97ca00",
+        "yellowgreen" => "#a4a61d",
+        "yellow" => "#dfb317",
+        "orange" | "important" => "#fe7d37",
+        "red" | "critical" => "#e05d44",
+        "blue" => "#007ec6",
+        "lightgrey" | "lightgray" | "inactive" => "#9f9f9f",
+        "grey" | "gray" => "#555",
+        "blueviolet" => "#8a2be2",
+        _ if matches!(color.len(), 3 | 6) && color.chars().all(|c| c.is_ascii_hexdigit()) => {
+            return format!("#{}", color);
+        }
+        _ => color,
+    };
+    hex.to_string()
+}
+
+/// Horizontal padding either side of each text, in pixels
+const HORIZONTAL_PADDING: u32 = 5;
+
+/// The fonts shields.io measures badge text in
+#[derive(Debug, Clone, Copy)]
+enum Font {
+    Verdana11,
+    Verdana10,
+    VerdanaBold10,
+    HelveticaBold11,
+}
+
+impl Font {
+    fn table(self) -> &'static [(u32, u32, f64)] {
+        match self {
+            Font::Verdana11 => widths::VERDANA_11PX,
+            Font::Verdana10 => widths::VERDANA_10PX,
+            Font::VerdanaBold10 => widths::VERDANA_10PX_BOLD,
+            Font::HelveticaBold11 => widths::HELVETICA_11PX_BOLD,
+        }
+    }
+
+    fn text_width(self, text: &str) -> f64 {
+        text.chars().map(|c| self.char_width(c)).sum()
+    }
+
+    fn char_width(self, c: char) -> f64 {
+        let code = c as u32;
+        if code <= 31 || code == 127 {
+            return 0.0;
+        }
+
+        let table = self.table();
+        let index = table.partition_point(|&(lower, _, _)| lower <= code);
+        match index.checked_sub(1).map(|i| table[i]) {
+            Some((_, upper, width)) if code <= upper => width,
+            _ => self.char_width('m'),
+        }
+    }
+
+    /// Width truncated to whole pixels, then rounded up to odd
+    ///
+    /// An odd width puts the text's centre on a half pixel, which with the 5px padding lands the
+    /// text on the pixel grid.
+    fn preferred_width(self, text: &str) -> u32 {
+        let width = self.text_width(text) as u32;
+        if width.is_multiple_of(2) {
+            width + 1
+        } else {
+            width
+        }
+    }
+}
+
+/// Width of text in 11px Verdana, the font shields.io lays flat badges out with
+///
+/// Control characters are zero-width, and characters missing from the width table are assumed
+/// to be as wide as `m`, as shields.io does. Renderers falling back to DejaVu Sans or another
+/// font still match this layout, because the SVG fixes each text's `textLength`.
+pub fn text_width(text: &str) -> f64 {
+    Font::Verdana11.text_width(text)
+}
+
+/// The width shields.io gives text in a flat badge: truncated to whole pixels, then rounded up
+/// to odd
+pub fn preferred_width(text: &str) -> u32 {
+    Font::Verdana11.preferred_width(text)
+}
+
+/// Text and shadow colours that stay legible on a background colour
+///
+/// Mirrors shields.io: light backgrounds (by W3C perceived brightness) get dark text.
+fn text_colors(background: &str) -> (&'static str, &'static str) {
+    let hex = background.trim_start_matches('#');
+    let channel = |i: usize, len: usize| {
+        let digits = hex.get(i * len..(i + 1) * len).unwrap_or("0");
+        let value = u8::from_str_radix(digits, 16).unwrap_or(0);
+        if len == 1 { value * 17 } else { value }
+    };
+    let (r, g, b) = match hex.len() {
+        3 => (channel(0, 1), channel(1, 1), channel(2, 1)),
+        6 => (channel(0, 2), channel(1, 2), channel(2, 2)),
+        _ => return ("#fff", "#010101"),
+    };
+
+    let brightness = (0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64) / 255.0;
+    if brightness <= 0.69 {
+        ("#fff", "#010101")
+    } else {
+        ("#333", "#ccc")
+    }
+}
+
+/// Generate an SVG badge in the flat style
+///
+/// The layout follows shields.io, so a badge rendered here is the same size as the one
+/// img.shields.io serves for the same label, value and colour.
 pub fn create_badge(label: &str, value: &str, color: &str) -> String {
-    let label_width = label.len() * 7 + 10;
-    let value_width = value.len() * 7 + 10;
-    let total_width = label_width + value_width;
+    create_styled_badge(label, value, color, BadgeStyle::Flat)
+}
+
+/// Generate an SVG badge in the given style, with the geometry shields.io gives that style
+pub fn create_styled_badge(label: &str, value: &str, color: &str, style: BadgeStyle) -> String {
+    let color = svg_color(color);
+    match style {
+        BadgeStyle::Flat => flat_badge(label, value, &color),
+        BadgeStyle::FlatSquare => flat_square_badge(label, value, &color),
+        BadgeStyle::Plastic => plastic_badge(label, value, &color),
+        BadgeStyle::ForTheBadge => for_the_badge(label, value, &color),
+        BadgeStyle::Social => social_badge(label, value),
+    }
+}
+
+/// Geometry shared by the flat, flat-square and plastic styles, which differ only in chrome
+struct FlatLayout {
+    left_width: u32,
+    right_width: u32,
+    total_width: u32,
+    label_x: u32,
+    value_x: u32,
+    label_length: u32,
+    value_length: u32,
+}
+
+impl FlatLayout {
+    fn new(label: &str, value: &str) -> Self {
+        let label_width = preferred_width(label);
+        let value_width = preferred_width(value);
+
+        let left_width = label_width + 2 * HORIZONTAL_PADDING;
+        let right_width = value_width + 2 * HORIZONTAL_PADDING;
+
+        // Text is drawn at 10x scale (font-size 110 inside scale(.1)) for sub-pixel positioning
+        FlatLayout {
+            left_width,
+            right_width,
+            total_width: left_width + right_width,
+            label_x: 10 * (1 + HORIZONTAL_PADDING) + 5 * label_width,
+            value_x: 10 * (left_width - 1 + HORIZONTAL_PADDING) + 5 * value_width,
+            label_length: 10 * label_width,
+            value_length: 10 * value_width,
+        }
+    }
+}
+
+fn flat_badge(label: &str, value: &str, color: &str) -> String {
+    let FlatLayout {
+        left_width,
+        right_width,
+        total_width,
+        label_x,
+        value_x,
+        label_length,
+        value_length,
+    } = FlatLayout::new(label, value);
+
+    let (label_fill, label_shadow) = text_colors("#555");
+    let (value_fill, value_shadow) = text_colors(color);
+
+    // Widths are measured on the raw text, the markup gets the escaped text
+    let label = xml_escape(label);
+    let value = xml_escape(value);
+    let color = xml_escape(color);
 
     format!(
-        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{total_width}" height="20">
-  <linearGradient id="b" x2="0" y2="100%">
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{total_width}" height="20" role="img" aria-label="{label}: {value}">
+  <title>{label}: {value}</title>
+  <linearGradient id="s" x2="0" y2="100%">
     <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
     <stop offset="1" stop-opacity=".1"/>
   </linearGradient>
-  <rect rx="3" width="{total_width}" height="20" fill="#555"/>
-  <rect rx="3" x="{label_width}" width="{value_width}" height="20" fill="{color}"/>
-  <rect rx="3" width="{total_width}" height="20" fill="url(#b)"/>
-  <g fill="#fff" text-anchor="middle" font-family="DejaVu Sans,Verdana,Geneva,sans-serif" font-size="11">
-    <text x="{}" y="15" fill="#010101" fill-opacity=".3">{label}</text>
-    <text x="{}" y="14">{label}</text>
-    <text x="{}" y="15" fill="#010101" fill-opacity=".3">{value}</text>
-    <text x="{}" y="14">{value}</text>
+  <clipPath id="r">
+    <rect width="{total_width}" height="20" rx="3" fill="#fff"/>
+  </clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{left_width}" height="20" fill="#555"/>
+    <rect x="{left_width}" width="{right_width}" height="20" fill="{color}"/>
+    <rect width="{total_width}" height="20" fill="url(#s)"/>
   </g>
-</svg>"#,
-        label_width / 2,
-        label_width / 2,
-        label_width + value_width / 2,
-        label_width + value_width / 2
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" text-rendering="geometricPrecision" font-size="110">
+    <text aria-hidden="true" x="{label_x}" y="150" fill="{label_shadow}" fill-opacity=".3" transform="scale(.1)" textLength="{label_length}">{label}</text>
+    <text x="{label_x}" y="140" transform="scale(.1)" fill="{label_fill}" textLength="{label_length}">{label}</text>
+    <text aria-hidden="true" x="{value_x}" y="150" fill="{value_shadow}" fill-opacity=".3" transform="scale(.1)" textLength="{value_length}">{value}</text>
+    <text x="{value_x}" y="140" transform="scale(.1)" fill="{value_fill}" textLength="{value_length}">{value}</text>
+  </g>
+</svg>"##
     )
 }
 
-/// Count dependencies using cargo tree
-pub fn count_dependencies() -> Result<usize, String> {
-    let output = Command::new("cargo")
-        .args(["tree", "--edges", "normal", "--prefix", "none"])
-        .output()
-        .map_err(|e| format!("Failed to run cargo tree: {}", e))?;
+fn flat_square_badge(label: &str, value: &str, color: &str) -> String {
+    let FlatLayout {
+        left_width,
+        right_width,
+        total_width,
+        label_x,
+        value_x,
+        label_length,
+        value_length,
+    } = FlatLayout::new(label, value);
 
-    if !output.status.success() {
-        return Err(format!(
-            "cargo tree failed: {}",
-            String::from_utf8_lossy(&output.stderr)
-        ));
-    }
+    let (label_fill, _) = text_colors("#555");
+    let (value_fill, _) = text_colors(color);
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut unique_deps: std::collections::HashSet<&str> = std::collections::HashSet::new();
+    let label = xml_escape(label);
+    let value = xml_escape(value);
+    let color = xml_escape(color);
 
-    for line in stdout.lines() {
-        let dep = line.trim().split_whitespace().next().unwrap_or("");
-        if !dep.is_empty() {
-            unique_deps.insert(dep);
-        }
-    }
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{total_width}" height="20" role="img" aria-label="{label}: {value}">
+  <title>{label}: {value}</title>
+  <g shape-rendering="crispEdges">
+    <rect width="{left_width}" height="20" fill="#555"/>
+    <rect x="{left_width}" width="{right_width}" height="20" fill="{color}"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" text-rendering="geometricPrecision" font-size="110">
+    <text x="{label_x}" y="140" transform="scale(.1)" fill="{label_fill}" textLength="{label_length}">{label}</text>
+    <text x="{value_x}" y="140" transform="scale(.1)" fill="{value_fill}" textLength="{value_length}">{value}</text>
+  </g>
+</svg>"##
+    )
+}
+
+fn plastic_badge(label: &str, value: &str, color: &str) -> String {
+    let FlatLayout {
+        left_width,
+        right_width,
+        total_width,
+        label_x,
+        value_x,
+        label_length,
+        value_length,
+    } = FlatLayout::new(label, value);
 
-    Ok(unique_deps.len())
+    let (label_fill, label_shadow) = text_colors("#555");
+    let (value_fill, value_shadow) = text_colors(color);
+
+    let label = xml_escape(label);
+    let value = xml_escape(value);
+    let color = xml_escape(color);
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{total_width}" height="18" role="img" aria-label="{label}: {value}">
+  <title>{label}: {value}</title>
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#fff" stop-opacity=".7"/>
+    <stop offset=".1" stop-color="#aaa" stop-opacity=".1"/>
+    <stop offset=".9" stop-color="#000" stop-opacity=".3"/>
+    <stop offset="1" stop-color="#000" stop-opacity=".5"/>
+  </linearGradient>
+  <clipPath id="r">
+    <rect width="{total_width}" height="18" rx="4" fill="#fff"/>
+  </clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{left_width}" height="18" fill="#555"/>
+    <rect x="{left_width}" width="{right_width}" height="18" fill="{color}"/>
+    <rect width="{total_width}" height="18" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" text-rendering="geometricPrecision" font-size="110">
+    <text aria-hidden="true" x="{label_x}" y="140" fill="{label_shadow}" fill-opacity=".3" transform="scale(.1)" textLength="{label_length}">{label}</text>
+    <text x="{label_x}" y="130" transform="scale(.1)" fill="{label_fill}" textLength="{label_length}">{label}</text>
+    <text aria-hidden="true" x="{value_x}" y="140" fill="{value_shadow}" fill-opacity=".3" transform="scale(.1)" textLength="{value_length}">{value}</text>
+    <text x="{value_x}" y="130" transform="scale(.1)" fill="{value_fill}" textLength="{value_length}">{value}</text>
+  </g>
+</svg>"##
+    )
 }
 
-/// Get binary size in bytes
-pub fn get_binary_size(binary_name: &str) -> Result<u64, String> {
-    let path = format!("target/release/{}", binary_name);
-    std::fs::metadata(&path)
-        .map(|m| m.len())
-        .map_err(|e| format!("Failed to get binary size for {}: {}", path, e))
+fn for_the_badge(label: &str, value: &str, color: &str) -> String {
+    // Letter spacing and margins are fractional in this style, so widths stay as f64
+    const LETTER_SPACING: f64 = 1.25;
+    const TEXT_MARGIN: f64 = 12.0;
+
+    // Measured after uppercasing, with spacing added per UTF-16 unit as shields.io does
+    let label = label.to_uppercase();
+    let value = value.to_uppercase();
+    let spaced_width = |text: &str, font: Font| {
+        font.text_width(text).trunc() + LETTER_SPACING * text.encode_utf16().count() as f64
+    };
+    let label_width = spaced_width(&label, Font::Verdana10);
+    let value_width = spaced_width(&value, Font::VerdanaBold10);
+
+    let left_width = label_width + 2.0 * TEXT_MARGIN;
+    let right_width = value_width + 2.0 * TEXT_MARGIN;
+    let total_width = left_width + right_width;
+
+    let label_x = 10.0 * (TEXT_MARGIN + label_width / 2.0);
+    let value_x = 10.0 * (left_width + TEXT_MARGIN + value_width / 2.0);
+    let label_length = 10.0 * label_width;
+    let value_length = 10.0 * value_width;
+
+    let (label_fill, _) = text_colors("#555");
+    let (value_fill, _) = text_colors(color);
+
+    let label = xml_escape(&label);
+    let value = xml_escape(&value);
+    let color = xml_escape(color);
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{total_width}" height="28" role="img" aria-label="{label}: {value}">
+  <title>{label}: {value}</title>
+  <g shape-rendering="crispEdges">
+    <rect width="{left_width}" height="28" fill="#555"/>
+    <rect x="{left_width}" width="{right_width}" height="28" fill="{color}"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" text-rendering="geometricPrecision" font-size="100">
+    <text transform="scale(.1)" x="{label_x}" y="175" fill="{label_fill}" textLength="{label_length}">{label}</text>
+    <text transform="scale(.1)" x="{value_x}" y="175" fill="{value_fill}" textLength="{value_length}" font-weight="bold">{value}</text>
+  </g>
+</svg>"##
+    )
 }
 
-/// Format bytes as human-readable string
-pub fn format_size(bytes: u64) -> String {
-    if bytes < 1024 {
-        format!("{}B", bytes)
-    } else if bytes < 1024 * 1024 {
-        format!("{:.1}K", bytes as f64 / 1024.0)
-    } else {
-        format!("{:.1}M", bytes as f64 / (1024.0 * 1024.0))
-    }
+fn social_badge(label: &str, value: &str) -> String {
+    // The social style ignores the colour: it always draws a grey button and white bubble
+    const LABEL_PADDING: u32 = 5;
+    const VALUE_PADDING: u32 = 4;
+    const GUTTER: u32 = 6;
+
+    let mut chars = label.chars();
+    let label: String = match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    };
+    let label_width = Font::HelveticaBold11.preferred_width(&label);
+    let value_width = Font::HelveticaBold11.preferred_width(value);
+
+    let label_rect_width = label_width + 2 * LABEL_PADDING;
+    let value_rect_width = value_width + 2 * VALUE_PADDING;
+    let total_width = label_rect_width + 1 + GUTTER + value_rect_width;
+
+    let notch_x = label_rect_width + GUTTER;
+    let bubble_x = notch_x as f64 + 0.5;
+    let label_x = 10.0 * (label_width as f64 / 2.0 + LABEL_PADDING as f64);
+    let value_x = 10.0 * (notch_x as f64 + value_rect_width as f64 / 2.0);
+    let label_length = 10 * label_width;
+    let value_length = 10 * value_width;
+
+    let label = xml_escape(&label);
+    let value = xml_escape(value);
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{total_width}" height="20" role="img" aria-label="{label}: {value}">
+  <title>{label}: {value}</title>
+  <linearGradient id="a" x2="0" y2="100%">
+    <stop offset="0" stop-color="#fcfcfc" stop-opacity="0"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <g stroke="#d5d5d5">
+    <rect stroke="none" fill="#fcfcfc" x=".5" y=".5" width="{label_rect_width}" height="19" rx="2"/>
+    <rect x="{bubble_x}" y=".5" width="{value_rect_width}" height="19" rx="2" fill="#fafafa"/>
+    <rect x="{notch_x}" y="7.5" width=".5" height="5" stroke="#fafafa"/>
+    <path d="M{bubble_x} 6.5 l-3 3v1 l3 3" fill="#fafafa"/>
+  </g>
+  <g aria-hidden="true" fill="#333" text-anchor="middle" font-family="Helvetica Neue,Helvetica,Arial,sans-serif" text-rendering="geometricPrecision" font-weight="700" font-size="110px" line-height="14px">
+    <rect stroke="#d5d5d5" fill="url(#a)" x=".5" y=".5" width="{label_rect_width}" height="19" rx="2"/>
+    <text aria-hidden="true" x="{label_x}" y="150" fill="#fff" transform="scale(.1)" textLength="{label_length}">{label}</text>
+    <text x="{label_x}" y="140" transform="scale(.1)" textLength="{label_length}">{label}</text>
+    <text aria-hidden="true" x="{value_x}" y="150" fill="#fff" transform="scale(.1)" textLength="{value_length}">{value}</text>
+    <text x="{value_x}" y="140" transform="scale(.1)" textLength="{value_length}">{value}</text>
+  </g>
+</svg>"##
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::format_size;
 
     #[test]
     fn test_create_badge() {
@@ -87,6 +563,33 @@ mod tests {
         assert!(badge.contains("<svg"));
         assert!(badge.contains("test"));
         assert!(badge.contains("123"));
+        assert!(badge.contains(r##"fill="#007ec6""##));
+    }
+
+    #[test]
+    fn test_text_width() {
+        assert_eq!(preferred_width("build"), 27);
+        assert_eq!(preferred_width("passing"), 41);
+        // Measured per character, not per UTF-8 byte
+        assert_eq!(preferred_width("größe"), 31);
+        assert_eq!(text_width("\u{7}"), 0.0);
+        assert_eq!(text_width("🦀"), text_width("m"));
+    }
+
+    #[test]
+    fn test_text_colors() {
+        assert_eq!(text_colors("#007ec6"), ("#fff", "#010101"));
+        assert_eq!(text_colors("#dfb317"), ("#fff", "#010101"));
+        assert_eq!(text_colors("#fff"), ("#333", "#ccc"));
+    }
+
+    #[test]
+    fn test_svg_color() {
+        assert_eq!(svg_color("green"), "#97ca00");
+        assert_eq!(svg_color("ff69b4"), "#ff69b4");
+        assert_eq!(svg_color("#abc"), "#abc");
+        assert_eq!(svg_color("hotpink"), "hotpink");
+        assert_eq!(svg_color(""), "#9f9f9f");
     }
 
     #[test]
@@ -0,0 +1,3007 @@
+//! Advance widths of the fonts shields.io lays badges out with
+//!
+//! Generated by `scripts/widths.py` from the `assets/fonts/*.json` tables of the `shields`
+//! crate 1.6.0 on crates.io (github.com/Jannchie/shields.rs, commit
+//! 8fb1a2e8b0aba18472a4118fd61178ac21ad750a), which are those of the anafanafo package
+//! shields.io measures badge text with. Only code points below U+20000 are kept. Each entry is
+//! an inclusive code point range and the width in pixels of every character in it, sorted and
+//! non-overlapping.
+
+/// 11px Verdana, the font of the flat, flat-square and plastic styles
+// Measured widths that happen to be close to 2π are not meant as TAU
+#[allow(clippy::approx_constant)]
+#[rustfmt::skip]
+pub(super) static VERDANA_11PX: &[(u32, u32, f64)] = &[
+    (0x20, 0x20, 3.87), (0x21, 0x21, 4.33), (0x22, 0x22, 5.05), (0x23, 0x23, 9.0),
+    (0x24, 0x24, 6.99), (0x25, 0x25, 11.84), (0x26, 0x26, 7.99), (0x27, 0x27, 2.95),
+    (0x28, 0x29, 5.0), (0x2a, 0x2a, 6.99), (0x2b, 0x2b, 9.0), (0x2c, 0x2c, 4.0), (0x2d, 0x2d, 5.0),
+    (0x2e, 0x2e, 4.0), (0x2f, 0x2f, 5.0), (0x30, 0x39, 6.99), (0x3a, 0x3b, 5.0), (0x3c, 0x3e, 9.0),
+    (0x3f, 0x3f, 6.0), (0x40, 0x40, 11.0), (0x41, 0x41, 7.52), (0x42, 0x42, 7.54),
+    (0x43, 0x43, 7.68), (0x44, 0x44, 8.48), (0x45, 0x45, 6.96), (0x46, 0x46, 6.32),
+    (0x47, 0x47, 8.53), (0x48, 0x48, 8.27), (0x49, 0x49, 4.63), (0x4a, 0x4a, 5.0),
+    (0x4b, 0x4b, 7.62), (0x4c, 0x4c, 6.12), (0x4d, 0x4d, 9.27), (0x4e, 0x4e, 8.23),
+    (0x4f, 0x4f, 8.66), (0x50, 0x50, 6.63), (0x51, 0x51, 8.66), (0x52, 0x52, 7.65),
+    (0x53, 0x53, 7.52), (0x54, 0x54, 6.78), (0x55, 0x55, 8.05), (0x56, 0x56, 7.52),
+    (0x57, 0x57, 10.88), (0x58, 0x58, 7.54), (0x59, 0x59, 6.77), (0x5a, 0x5a, 7.54),
+    (0x5b, 0x5d, 5.0), (0x5e, 0x5e, 9.0), (0x5f, 0x60, 6.99), (0x61, 0x61, 6.61),
+    (0x62, 0x62, 6.85), (0x63, 0x63, 5.73), (0x64, 0x64, 6.85), (0x65, 0x65, 6.55),
+    (0x66, 0x66, 3.87), (0x67, 0x67, 6.85), (0x68, 0x68, 6.96), (0x69, 0x69, 3.02),
+    (0x6a, 0x6a, 3.79), (0x6b, 0x6b, 6.51), (0x6c, 0x6c, 3.02), (0x6d, 0x6d, 10.7),
+    (0x6e, 0x6e, 6.96), (0x6f, 0x6f, 6.68), (0x70, 0x71, 6.85), (0x72, 0x72, 4.69),
+    (0x73, 0x73, 5.73), (0x74, 0x74, 4.33), (0x75, 0x75, 6.96), (0x76, 0x76, 6.51),
+    (0x77, 0x77, 9.0), (0x78, 0x79, 6.51), (0x7a, 0x7a, 5.78), (0x7b, 0x7b, 6.98),
+    (0x7c, 0x7c, 5.0), (0x7d, 0x7d, 6.98), (0x7e, 0x7e, 9.0), (0xa0, 0xa0, 3.87),
+    (0xa1, 0xa1, 4.33), (0xa2, 0xa5, 6.99), (0xa6, 0xa6, 5.0), (0xa7, 0xa8, 6.99),
+    (0xa9, 0xa9, 11.0), (0xaa, 0xaa, 6.0), (0xab, 0xab, 7.09), (0xac, 0xac, 9.0), (0xad, 0xad, 0.0),
+    (0xae, 0xae, 11.0), (0xaf, 0xaf, 6.99), (0xb0, 0xb0, 5.96), (0xb1, 0xb1, 9.0),
+    (0xb2, 0xb3, 5.96), (0xb4, 0xb4, 6.99), (0xb5, 0xb5, 7.06), (0xb6, 0xb6, 6.99),
+    (0xb7, 0xb7, 4.0), (0xb8, 0xb8, 6.99), (0xb9, 0xb9, 5.96), (0xba, 0xba, 6.0),
+    (0xbb, 0xbb, 7.09), (0xbc, 0xbe, 11.0), (0xbf, 0xbf, 6.0), (0xc0, 0xc5, 7.52),
+    (0xc6, 0xc6, 10.83), (0xc7, 0xc7, 7.68), (0xc8, 0xcb, 6.96), (0xcc, 0xcf, 4.63),
+    (0xd0, 0xd0, 8.53), (0xd1, 0xd1, 8.23), (0xd2, 0xd6, 8.66), (0xd7, 0xd7, 9.0),
+    (0xd8, 0xd8, 8.66), (0xd9, 0xdc, 8.05), (0xdd, 0xdd, 6.77), (0xde, 0xde, 6.66),
+    (0xdf, 0xdf, 6.82), (0xe0, 0xe5, 6.61), (0xe6, 0xe6, 10.51), (0xe7, 0xe7, 5.73),
+    (0xe8, 0xeb, 6.55), (0xec, 0xef, 3.02), (0xf0, 0xf0, 6.73), (0xf1, 0xf1, 6.96),
+    (0xf2, 0xf6, 6.68), (0xf7, 0xf7, 9.0), (0xf8, 0xf8, 6.68), (0xf9, 0xfc, 6.96),
+    (0xfd, 0xfd, 6.51), (0xfe, 0xfe, 6.85), (0x100, 0x100, 7.52), (0x101, 0x101, 6.61),
+    (0x102, 0x102, 7.52), (0x103, 0x103, 6.61), (0x104, 0x104, 7.52), (0x105, 0x105, 6.61),
+    (0x106, 0x106, 7.68), (0x107, 0x107, 5.73), (0x108, 0x108, 7.68), (0x109, 0x109, 5.73),
+    (0x10a, 0x10a, 7.68), (0x10b, 0x10b, 5.73), (0x10c, 0x10c, 7.68), (0x10d, 0x10d, 5.73),
+    (0x10e, 0x10e, 8.48), (0x10f, 0x10f, 7.12), (0x110, 0x110, 8.53), (0x111, 0x111, 6.85),
+    (0x112, 0x112, 6.96), (0x113, 0x113, 6.55), (0x114, 0x114, 6.96), (0x115, 0x115, 6.55),
+    (0x116, 0x116, 6.96), (0x117, 0x117, 6.55), (0x118, 0x118, 6.96), (0x119, 0x119, 6.55),
+    (0x11a, 0x11a, 6.96), (0x11b, 0x11b, 6.55), (0x11c, 0x11c, 8.53), (0x11d, 0x11d, 6.85),
+    (0x11e, 0x11e, 8.53), (0x11f, 0x11f, 6.85), (0x120, 0x120, 8.53), (0x121, 0x121, 6.85),
+    (0x122, 0x122, 8.53), (0x123, 0x123, 6.85), (0x124, 0x124, 8.27), (0x125, 0x125, 6.96),
+    (0x126, 0x126, 8.27), (0x127, 0x127, 6.96), (0x128, 0x128, 4.63), (0x129, 0x129, 3.02),
+    (0x12a, 0x12a, 4.63), (0x12b, 0x12b, 3.02), (0x12c, 0x12c, 4.63), (0x12d, 0x12d, 3.02),
+    (0x12e, 0x12e, 4.63), (0x12f, 0x12f, 3.02), (0x130, 0x130, 4.63), (0x131, 0x131, 3.02),
+    (0x132, 0x132, 9.58), (0x133, 0x133, 6.75), (0x134, 0x134, 5.0), (0x135, 0x135, 3.79),
+    (0x136, 0x136, 7.62), (0x137, 0x138, 6.51), (0x139, 0x139, 6.12), (0x13a, 0x13a, 3.02),
+    (0x13b, 0x13b, 6.12), (0x13c, 0x13c, 3.02), (0x13d, 0x13d, 6.12), (0x13e, 0x13e, 3.25),
+    (0x13f, 0x13f, 6.12), (0x140, 0x140, 5.04), (0x141, 0x141, 6.18), (0x142, 0x142, 3.13),
+    (0x143, 0x143, 8.23), (0x144, 0x144, 6.96), (0x145, 0x145, 8.23), (0x146, 0x146, 6.96),
+    (0x147, 0x147, 8.23), (0x148, 0x148, 6.96), (0x149, 0x149, 8.04), (0x14a, 0x14a, 8.23),
+    (0x14b, 0x14b, 6.96), (0x14c, 0x14c, 8.66), (0x14d, 0x14d, 6.68), (0x14e, 0x14e, 8.66),
+    (0x14f, 0x14f, 6.68), (0x150, 0x150, 8.66), (0x151, 0x151, 6.68), (0x152, 0x152, 11.77),
+    (0x153, 0x153, 10.8), (0x154, 0x154, 7.65), (0x155, 0x155, 4.69), (0x156, 0x156, 7.65),
+    (0x157, 0x157, 4.69), (0x158, 0x158, 7.65), (0x159, 0x159, 4.69), (0x15a, 0x15a, 7.52),
+    (0x15b, 0x15b, 5.73), (0x15c, 0x15c, 7.52), (0x15d, 0x15d, 5.73), (0x15e, 0x15e, 7.52),
+    (0x15f, 0x15f, 5.73), (0x160, 0x160, 7.52), (0x161, 0x161, 5.73), (0x162, 0x162, 6.78),
+    (0x163, 0x163, 4.33), (0x164, 0x164, 6.78), (0x165, 0x165, 4.33), (0x166, 0x166, 6.78),
+    (0x167, 0x167, 4.33), (0x168, 0x168, 8.05), (0x169, 0x169, 6.96), (0x16a, 0x16a, 8.05),
+    (0x16b, 0x16b, 6.96), (0x16c, 0x16c, 8.05), (0x16d, 0x16d, 6.93), (0x16e, 0x16e, 8.05),
+    (0x16f, 0x16f, 6.96), (0x170, 0x170, 8.05), (0x171, 0x171, 6.96), (0x172, 0x172, 8.05),
+    (0x173, 0x173, 6.93), (0x174, 0x174, 10.88), (0x175, 0x175, 9.0), (0x176, 0x176, 6.77),
+    (0x177, 0x177, 6.51), (0x178, 0x178, 6.77), (0x179, 0x179, 7.54), (0x17a, 0x17a, 5.78),
+    (0x17b, 0x17b, 7.54), (0x17c, 0x17c, 5.78), (0x17d, 0x17d, 7.54), (0x17e, 0x17e, 5.78),
+    (0x180, 0x180, 5.5), (0x181, 0x181, 8.36), (0x182, 0x182, 6.32), (0x183, 0x183, 5.5),
+    (0x184, 0x184, 6.32), (0x185, 0x185, 5.5), (0x186, 0x187, 7.34), (0x188, 0x188, 4.88),
+    (0x189, 0x189, 7.94), (0x18a, 0x18a, 8.96), (0x18b, 0x18b, 6.32), (0x18c, 0x18c, 5.5),
+    (0x18d, 0x18d, 5.18), (0x18e, 0x18e, 6.72), (0x18f, 0x18f, 8.3), (0x190, 0x190, 5.51),
+    (0x191, 0x191, 6.12), (0x192, 0x192, 6.99), (0x193, 0x194, 7.94), (0x195, 0x195, 8.47),
+    (0x196, 0x196, 2.79), (0x197, 0x197, 3.66), (0x198, 0x198, 7.94), (0x199, 0x199, 5.5),
+    (0x19a, 0x19a, 3.06), (0x19b, 0x19b, 5.33), (0x19c, 0x19c, 8.98), (0x19d, 0x19d, 7.94),
+    (0x19e, 0x19e, 5.5), (0x19f, 0x19f, 7.94), (0x1a0, 0x1a0, 8.87), (0x1a1, 0x1a1, 6.68),
+    (0x1a2, 0x1a2, 10.17), (0x1a3, 0x1a3, 7.61), (0x1a4, 0x1a4, 7.15), (0x1a5, 0x1a5, 5.5),
+    (0x1a6, 0x1a7, 6.12), (0x1a8, 0x1a8, 4.28), (0x1a9, 0x1a9, 6.4), (0x1aa, 0x1aa, 3.78),
+    (0x1ab, 0x1ab, 3.06), (0x1ac, 0x1ac, 6.72), (0x1ad, 0x1ad, 3.06), (0x1ae, 0x1ae, 6.72),
+    (0x1af, 0x1af, 8.32), (0x1b0, 0x1b0, 7.26), (0x1b1, 0x1b1, 8.17), (0x1b2, 0x1b2, 7.94),
+    (0x1b3, 0x1b3, 8.59), (0x1b4, 0x1b4, 5.5), (0x1b5, 0x1b5, 6.72), (0x1b6, 0x1b6, 4.88),
+    (0x1b7, 0x1b8, 5.93), (0x1b9, 0x1ba, 4.88), (0x1bb, 0x1bc, 5.5), (0x1bd, 0x1bd, 4.88),
+    (0x1be, 0x1be, 4.64), (0x1bf, 0x1bf, 5.5), (0x1c0, 0x1c0, 2.2), (0x1c1, 0x1c1, 3.09),
+    (0x1c2, 0x1c2, 2.74), (0x1c3, 0x1c3, 3.66), (0x1c4, 0x1c4, 14.66), (0x1c5, 0x1c5, 12.83),
+    (0x1c6, 0x1c6, 10.38), (0x1c7, 0x1c7, 11.0), (0x1c8, 0x1c8, 9.78), (0x1c9, 0x1c9, 6.11),
+    (0x1ca, 0x1ca, 12.22), (0x1cb, 0x1cb, 11.0), (0x1cc, 0x1cc, 8.56), (0x1cd, 0x1cd, 7.94),
+    (0x1ce, 0x1ce, 4.88), (0x1cf, 0x1cf, 3.66), (0x1d0, 0x1d0, 3.06), (0x1d1, 0x1d1, 7.94),
+    (0x1d2, 0x1d2, 5.5), (0x1d3, 0x1d3, 7.94), (0x1d4, 0x1d4, 5.5), (0x1d5, 0x1d5, 7.94),
+    (0x1d6, 0x1d6, 5.5), (0x1d7, 0x1d7, 8.05), (0x1d8, 0x1d8, 6.96), (0x1d9, 0x1d9, 7.94),
+    (0x1da, 0x1da, 5.5), (0x1db, 0x1db, 8.05), (0x1dc, 0x1dc, 6.96), (0x1dd, 0x1dd, 4.88),
+    (0x1de, 0x1de, 7.94), (0x1df, 0x1df, 4.88), (0x1e0, 0x1e0, 7.94), (0x1e1, 0x1e1, 4.88),
+    (0x1e2, 0x1e2, 9.78), (0x1e3, 0x1e3, 7.34), (0x1e4, 0x1e4, 7.94), (0x1e5, 0x1e5, 5.5),
+    (0x1e6, 0x1e6, 7.94), (0x1e7, 0x1e7, 5.5), (0x1e8, 0x1e8, 7.94), (0x1e9, 0x1e9, 5.5),
+    (0x1ea, 0x1ea, 7.94), (0x1eb, 0x1eb, 5.5), (0x1ec, 0x1ec, 7.94), (0x1ed, 0x1ed, 5.5),
+    (0x1ee, 0x1ee, 5.93), (0x1ef, 0x1ef, 4.88), (0x1f0, 0x1f0, 3.06), (0x1f1, 0x1f1, 14.66),
+    (0x1f2, 0x1f2, 12.83), (0x1f3, 0x1f3, 10.38), (0x1f4, 0x1f4, 8.53), (0x1f5, 0x1f5, 6.85),
+    (0x1f6, 0x1f6, 10.45), (0x1f7, 0x1f7, 6.16), (0x1f8, 0x1f8, 8.23), (0x1f9, 0x1f9, 6.96),
+    (0x1fa, 0x1fa, 7.52), (0x1fb, 0x1fb, 6.61), (0x1fc, 0x1fc, 10.83), (0x1fd, 0x1fd, 10.51),
+    (0x1fe, 0x1fe, 8.66), (0x1ff, 0x1ff, 6.68), (0x200, 0x200, 7.94), (0x201, 0x201, 4.88),
+    (0x202, 0x202, 7.94), (0x203, 0x203, 4.88), (0x204, 0x204, 6.72), (0x205, 0x205, 4.88),
+    (0x206, 0x206, 6.72), (0x207, 0x207, 4.88), (0x208, 0x208, 3.66), (0x209, 0x209, 3.06),
+    (0x20a, 0x20a, 3.66), (0x20b, 0x20b, 3.06), (0x20c, 0x20c, 7.94), (0x20d, 0x20d, 5.5),
+    (0x20e, 0x20e, 7.94), (0x20f, 0x20f, 5.5), (0x210, 0x210, 7.34), (0x211, 0x211, 3.66),
+    (0x212, 0x212, 7.34), (0x213, 0x213, 3.66), (0x214, 0x214, 7.94), (0x215, 0x215, 5.5),
+    (0x216, 0x216, 7.94), (0x217, 0x217, 5.5), (0x218, 0x218, 7.52), (0x219, 0x219, 5.73),
+    (0x21a, 0x21a, 6.78), (0x21b, 0x21b, 4.33), (0x21c, 0x21c, 6.19), (0x21d, 0x21d, 4.35),
+    (0x21e, 0x21e, 7.94), (0x21f, 0x21f, 5.5), (0x220, 0x220, 7.15), (0x221, 0x221, 5.5),
+    (0x222, 0x222, 6.64), (0x223, 0x223, 5.5), (0x224, 0x224, 6.72), (0x225, 0x225, 4.88),
+    (0x226, 0x226, 7.94), (0x227, 0x227, 4.88), (0x228, 0x228, 6.72), (0x229, 0x229, 4.88),
+    (0x22a, 0x22a, 7.94), (0x22b, 0x22b, 5.5), (0x22c, 0x22c, 7.94), (0x22d, 0x22d, 5.5),
+    (0x22e, 0x22e, 7.94), (0x22f, 0x22f, 5.5), (0x230, 0x230, 7.94), (0x231, 0x231, 5.5),
+    (0x232, 0x232, 7.94), (0x233, 0x233, 5.5), (0x234, 0x234, 3.06), (0x235, 0x235, 5.5),
+    (0x236, 0x236, 3.49), (0x237, 0x237, 3.06), (0x238, 0x238, 8.51), (0x239, 0x239, 8.5),
+    (0x23a, 0x23a, 7.94), (0x23b, 0x23b, 7.34), (0x23c, 0x23c, 5.5), (0x23d, 0x23e, 6.72),
+    (0x23f, 0x23f, 4.28), (0x240, 0x240, 4.88), (0x241, 0x241, 5.87), (0x242, 0x242, 4.37),
+    (0x243, 0x243, 7.34), (0x244, 0x244, 7.94), (0x245, 0x245, 7.98), (0x246, 0x246, 6.72),
+    (0x247, 0x247, 4.88), (0x248, 0x248, 4.28), (0x249, 0x249, 3.06), (0x24a, 0x24a, 7.72),
+    (0x24b, 0x24b, 5.5), (0x24c, 0x24c, 7.34), (0x24d, 0x24d, 3.66), (0x24e, 0x24e, 7.94),
+    (0x370, 0x370, 4.61), (0x371, 0x371, 3.65), (0x372, 0x372, 6.46), (0x373, 0x373, 5.14),
+    (0x374, 0x375, 3.66), (0x376, 0x376, 7.93), (0x377, 0x377, 6.38), (0x378, 0x379, 11.0),
+    (0x37a, 0x37a, 3.66), (0x37b, 0x37d, 4.88), (0x37e, 0x37e, 5.0), (0x37f, 0x37f, 3.66),
+    (0x380, 0x383, 11.0), (0x384, 0x385, 6.99), (0x386, 0x386, 7.52), (0x387, 0x387, 5.0),
+    (0x388, 0x388, 8.26), (0x389, 0x389, 9.57), (0x38a, 0x38a, 5.94), (0x38b, 0x38b, 11.0),
+    (0x38c, 0x38c, 9.69), (0x38d, 0x38d, 11.0), (0x38e, 0x38e, 8.29), (0x38f, 0x38f, 9.98),
+    (0x390, 0x390, 3.02), (0x391, 0x391, 7.52), (0x392, 0x392, 7.54), (0x393, 0x393, 6.23),
+    (0x394, 0x394, 7.73), (0x395, 0x395, 6.96), (0x396, 0x396, 7.54), (0x397, 0x397, 8.27),
+    (0x398, 0x398, 8.66), (0x399, 0x399, 4.63), (0x39a, 0x39a, 7.62), (0x39b, 0x39b, 7.54),
+    (0x39c, 0x39c, 9.27), (0x39d, 0x39d, 8.23), (0x39e, 0x39e, 7.14), (0x39f, 0x39f, 8.66),
+    (0x3a0, 0x3a0, 8.27), (0x3a1, 0x3a1, 6.63), (0x3a2, 0x3a2, 11.0), (0x3a3, 0x3a3, 7.4),
+    (0x3a4, 0x3a4, 6.78), (0x3a5, 0x3a5, 6.77), (0x3a6, 0x3a6, 9.01), (0x3a7, 0x3a7, 7.54),
+    (0x3a8, 0x3a8, 9.58), (0x3a9, 0x3a9, 9.0), (0x3aa, 0x3aa, 4.63), (0x3ab, 0x3ab, 6.77),
+    (0x3ac, 0x3ac, 6.85), (0x3ad, 0x3ad, 5.64), (0x3ae, 0x3ae, 6.96), (0x3af, 0x3af, 3.02),
+    (0x3b0, 0x3b0, 6.94), (0x3b1, 0x3b1, 6.85), (0x3b2, 0x3b2, 6.82), (0x3b3, 0x3b3, 6.51),
+    (0x3b4, 0x3b4, 6.69), (0x3b5, 0x3b5, 5.64), (0x3b6, 0x3b6, 5.03), (0x3b7, 0x3b7, 6.96),
+    (0x3b8, 0x3b8, 6.86), (0x3b9, 0x3b9, 3.02), (0x3ba, 0x3bb, 6.51), (0x3bc, 0x3bc, 7.04),
+    (0x3bd, 0x3bd, 6.51), (0x3be, 0x3be, 5.53), (0x3bf, 0x3bf, 6.68), (0x3c0, 0x3c0, 7.01),
+    (0x3c1, 0x3c1, 6.88), (0x3c2, 0x3c2, 5.59), (0x3c3, 0x3c3, 6.93), (0x3c4, 0x3c4, 5.46),
+    (0x3c5, 0x3c5, 6.94), (0x3c6, 0x3c6, 8.69), (0x3c7, 0x3c7, 6.49), (0x3c8, 0x3c8, 9.04),
+    (0x3c9, 0x3c9, 8.95), (0x3ca, 0x3ca, 3.02), (0x3cb, 0x3cb, 6.94), (0x3cc, 0x3cc, 6.68),
+    (0x3cd, 0x3cd, 6.94), (0x3ce, 0x3ce, 8.95), (0x3cf, 0x3cf, 6.23), (0x3d0, 0x3d0, 5.6),
+    (0x3d1, 0x3d1, 5.45), (0x3d2, 0x3d2, 7.94), (0x3d3, 0x3d3, 9.79), (0x3d4, 0x3d4, 7.94),
+    (0x3d5, 0x3d5, 5.79), (0x3d6, 0x3d6, 7.24), (0x3d7, 0x3d7, 6.16), (0x3d8, 0x3d8, 7.94),
+    (0x3d9, 0x3d9, 5.5), (0x3da, 0x3da, 7.34), (0x3db, 0x3db, 4.62), (0x3dc, 0x3dc, 6.12),
+    (0x3dd, 0x3dd, 4.91), (0x3de, 0x3de, 6.33), (0x3df, 0x3df, 4.96), (0x3e0, 0x3e0, 8.05),
+    (0x3e1, 0x3e1, 6.09), (0x3e2, 0x3e2, 9.11), (0x3e3, 0x3e3, 8.56), (0x3e4, 0x3e4, 6.77),
+    (0x3e5, 0x3e5, 5.73), (0x3e6, 0x3e6, 7.27), (0x3e7, 0x3e7, 4.88), (0x3e8, 0x3e9, 5.96),
+    (0x3ea, 0x3ea, 7.22), (0x3eb, 0x3eb, 6.39), (0x3ec, 0x3ec, 7.69), (0x3ed, 0x3ed, 5.6),
+    (0x3ee, 0x3ee, 5.23), (0x3ef, 0x3ef, 4.24), (0x3f0, 0x3f0, 6.16), (0x3f1, 0x3f1, 5.6),
+    (0x3f2, 0x3f2, 4.88), (0x3f3, 0x3f3, 3.06), (0x3f4, 0x3f4, 7.94), (0x3f5, 0x3f6, 4.45),
+    (0x3f7, 0x3f7, 6.12), (0x3f8, 0x3f8, 5.5), (0x3f9, 0x3f9, 7.34), (0x3fa, 0x3fa, 9.78),
+    (0x3fb, 0x3fb, 6.96), (0x3fc, 0x3fc, 5.49), (0x3fd, 0x3fe, 7.34), (0x400, 0x401, 6.96),
+    (0x402, 0x402, 8.72), (0x403, 0x403, 6.23), (0x404, 0x404, 7.71), (0x405, 0x405, 7.52),
+    (0x406, 0x407, 4.63), (0x408, 0x408, 5.0), (0x409, 0x409, 12.3), (0x40a, 0x40a, 12.13),
+    (0x40b, 0x40b, 9.0), (0x40c, 0x40c, 7.62), (0x40d, 0x40d, 8.26), (0x40e, 0x40e, 6.77),
+    (0x40f, 0x40f, 8.27), (0x410, 0x410, 7.52), (0x411, 0x412, 7.54), (0x413, 0x413, 6.23),
+    (0x414, 0x414, 8.2), (0x415, 0x415, 6.96), (0x416, 0x416, 10.7), (0x417, 0x417, 6.77),
+    (0x418, 0x419, 8.26), (0x41a, 0x41a, 7.62), (0x41b, 0x41b, 8.08), (0x41c, 0x41c, 9.27),
+    (0x41d, 0x41d, 8.27), (0x41e, 0x41e, 8.66), (0x41f, 0x41f, 8.27), (0x420, 0x420, 6.63),
+    (0x421, 0x421, 7.68), (0x422, 0x422, 6.78), (0x423, 0x423, 6.77), (0x424, 0x424, 9.01),
+    (0x425, 0x425, 7.54), (0x426, 0x426, 8.37), (0x427, 0x427, 7.83), (0x428, 0x428, 11.33),
+    (0x429, 0x429, 11.49), (0x42a, 0x42a, 8.62), (0x42b, 0x42b, 10.12), (0x42c, 0x42c, 7.49),
+    (0x42d, 0x42d, 7.71), (0x42e, 0x42e, 11.38), (0x42f, 0x42f, 7.77), (0x430, 0x430, 6.61),
+    (0x431, 0x431, 6.76), (0x432, 0x432, 6.54), (0x433, 0x433, 5.18), (0x434, 0x434, 6.84),
+    (0x435, 0x435, 6.55), (0x436, 0x436, 8.78), (0x437, 0x437, 5.77), (0x438, 0x439, 7.04),
+    (0x43a, 0x43a, 6.51), (0x43b, 0x43b, 6.83), (0x43c, 0x43c, 7.66), (0x43d, 0x43d, 7.01),
+    (0x43e, 0x43e, 6.68), (0x43f, 0x43f, 7.01), (0x440, 0x440, 6.85), (0x441, 0x441, 5.88),
+    (0x442, 0x442, 5.46), (0x443, 0x443, 6.51), (0x444, 0x444, 9.25), (0x445, 0x445, 6.51),
+    (0x446, 0x446, 7.09), (0x447, 0x447, 6.66), (0x448, 0x448, 9.64), (0x449, 0x449, 9.76),
+    (0x44a, 0x44a, 7.05), (0x44b, 0x44b, 8.74), (0x44c, 0x44c, 6.28), (0x44d, 0x44d, 6.02),
+    (0x44e, 0x44e, 9.23), (0x44f, 0x44f, 6.6), (0x450, 0x451, 6.55), (0x452, 0x452, 6.96),
+    (0x453, 0x453, 5.18), (0x454, 0x454, 6.02), (0x455, 0x455, 5.73), (0x456, 0x457, 3.02),
+    (0x458, 0x458, 3.79), (0x459, 0x45a, 10.05), (0x45b, 0x45b, 6.96), (0x45c, 0x45c, 6.51),
+    (0x45d, 0x45d, 7.04), (0x45e, 0x45e, 6.51), (0x45f, 0x45f, 7.01), (0x460, 0x460, 12.9),
+    (0x461, 0x461, 6.97), (0x462, 0x462, 7.38), (0x463, 0x463, 5.97), (0x464, 0x464, 10.65),
+    (0x465, 0x465, 7.45), (0x466, 0x466, 7.94), (0x467, 0x467, 6.49), (0x468, 0x468, 11.36),
+    (0x469, 0x469, 9.17), (0x46a, 0x46a, 9.86), (0x46b, 0x46b, 7.6), (0x46c, 0x46c, 13.29),
+    (0x46d, 0x46d, 10.29), (0x46e, 0x46e, 5.51), (0x46f, 0x46f, 4.35), (0x470, 0x470, 8.12),
+    (0x471, 0x471, 6.89), (0x472, 0x472, 7.94), (0x473, 0x473, 5.5), (0x474, 0x474, 8.95),
+    (0x475, 0x475, 6.48), (0x476, 0x476, 8.95), (0x477, 0x477, 6.48), (0x478, 0x478, 13.06),
+    (0x479, 0x479, 11.44), (0x47a, 0x47a, 8.39), (0x47b, 0x47b, 6.23), (0x47c, 0x47c, 10.82),
+    (0x47d, 0x47d, 8.95), (0x47e, 0x47e, 12.9), (0x47f, 0x47f, 6.97), (0x480, 0x480, 7.34),
+    (0x481, 0x481, 4.88), (0x482, 0x482, 3.67), (0x483, 0x486, 0.0), (0x487, 0x487, 6.99),
+    (0x488, 0x489, 0.0), (0x48a, 0x48a, 7.94), (0x48b, 0x48b, 5.89), (0x48c, 0x48c, 6.32),
+    (0x48d, 0x48d, 5.17), (0x48e, 0x48e, 6.12), (0x48f, 0x48f, 5.5), (0x490, 0x490, 6.23),
+    (0x491, 0x491, 5.18), (0x492, 0x492, 6.23), (0x493, 0x493, 5.18), (0x494, 0x494, 6.94),
+    (0x495, 0x495, 5.67), (0x496, 0x496, 10.7), (0x497, 0x497, 8.78), (0x498, 0x498, 5.51),
+    (0x499, 0x499, 4.35), (0x49a, 0x49a, 7.62), (0x49b, 0x49b, 6.51), (0x49c, 0x49c, 7.62),
+    (0x49d, 0x49d, 6.51), (0x49e, 0x49e, 7.34), (0x49f, 0x49f, 5.34), (0x4a0, 0x4a0, 8.64),
+    (0x4a1, 0x4a1, 6.31), (0x4a2, 0x4a2, 8.27), (0x4a3, 0x4a3, 7.01), (0x4a4, 0x4a4, 9.36),
+    (0x4a5, 0x4a5, 6.85), (0x4a6, 0x4a6, 11.35), (0x4a7, 0x4a7, 8.68), (0x4a8, 0x4a8, 8.66),
+    (0x4a9, 0x4a9, 6.78), (0x4aa, 0x4aa, 7.34), (0x4ab, 0x4ab, 4.88), (0x4ac, 0x4ac, 6.72),
+    (0x4ad, 0x4ad, 4.81), (0x4ae, 0x4ae, 6.77), (0x4af, 0x4af, 6.51), (0x4b0, 0x4b0, 6.77),
+    (0x4b1, 0x4b1, 6.51), (0x4b2, 0x4b2, 7.54), (0x4b3, 0x4b3, 6.51), (0x4b4, 0x4b4, 8.77),
+    (0x4b5, 0x4b5, 6.63), (0x4b6, 0x4b6, 7.15), (0x4b7, 0x4b7, 5.53), (0x4b8, 0x4b8, 7.83),
+    (0x4b9, 0x4b9, 6.66), (0x4ba, 0x4ba, 7.83), (0x4bb, 0x4bb, 6.96), (0x4bc, 0x4bc, 9.66),
+    (0x4bd, 0x4bd, 7.58), (0x4be, 0x4be, 9.66), (0x4bf, 0x4bf, 7.58), (0x4c0, 0x4c0, 3.66),
+    (0x4c1, 0x4c1, 9.86), (0x4c2, 0x4c2, 7.6), (0x4c3, 0x4c3, 7.34), (0x4c4, 0x4c4, 5.67),
+    (0x4c5, 0x4c5, 7.46), (0x4c6, 0x4c6, 5.49), (0x4c7, 0x4c7, 7.94), (0x4c8, 0x4c8, 5.89),
+    (0x4c9, 0x4c9, 7.94), (0x4ca, 0x4ca, 5.89), (0x4cb, 0x4cb, 7.15), (0x4cc, 0x4cc, 5.53),
+    (0x4cd, 0x4cd, 9.78), (0x4ce, 0x4ce, 6.96), (0x4cf, 0x4cf, 3.06), (0x4d0, 0x4d0, 7.94),
+    (0x4d1, 0x4d1, 4.88), (0x4d2, 0x4d2, 7.94), (0x4d3, 0x4d3, 4.88), (0x4d4, 0x4d4, 9.78),
+    (0x4d5, 0x4d5, 7.34), (0x4d6, 0x4d6, 6.72), (0x4d7, 0x4d7, 4.88), (0x4d8, 0x4d8, 8.3),
+    (0x4d9, 0x4d9, 6.55), (0x4da, 0x4da, 7.94), (0x4db, 0x4db, 4.88), (0x4dc, 0x4dc, 9.86),
+    (0x4dd, 0x4dd, 7.6), (0x4de, 0x4de, 5.51), (0x4df, 0x4df, 4.35), (0x4e0, 0x4e0, 5.51),
+    (0x4e1, 0x4e1, 4.88), (0x4e2, 0x4e2, 7.94), (0x4e3, 0x4e3, 5.89), (0x4e4, 0x4e4, 7.94),
+    (0x4e5, 0x4e5, 5.89), (0x4e6, 0x4e6, 7.94), (0x4e7, 0x4e7, 5.5), (0x4e8, 0x4e8, 8.66),
+    (0x4e9, 0x4e9, 6.68), (0x4ea, 0x4ea, 7.94), (0x4eb, 0x4eb, 5.5), (0x4ec, 0x4ec, 7.26),
+    (0x4ed, 0x4ed, 4.72), (0x4ee, 0x4ee, 7.79), (0x4ef, 0x4ef, 5.5), (0x4f0, 0x4f0, 7.79),
+    (0x4f1, 0x4f1, 5.5), (0x4f2, 0x4f2, 7.79), (0x4f3, 0x4f3, 5.5), (0x4f4, 0x4f4, 7.15),
+    (0x4f5, 0x4f5, 5.53), (0x4f6, 0x4f6, 6.36), (0x4f7, 0x4f7, 4.51), (0x4f8, 0x4f8, 9.59),
+    (0x4f9, 0x4f9, 7.39), (0x4fa, 0x4fa, 6.36), (0x4fb, 0x4fb, 4.51), (0x4fc, 0x4fc, 7.94),
+    (0x4fd, 0x4fd, 5.5), (0x4fe, 0x4fe, 7.94), (0x500, 0x500, 6.32), (0x501, 0x501, 5.5),
+    (0x502, 0x502, 8.84), (0x503, 0x503, 8.48), (0x504, 0x504, 8.82), (0x505, 0x505, 7.76),
+    (0x506, 0x506, 6.31), (0x507, 0x507, 4.98), (0x508, 0x508, 9.97), (0x509, 0x509, 8.27),
+    (0x50a, 0x50a, 10.45), (0x50b, 0x50b, 8.66), (0x50c, 0x50c, 7.94), (0x50d, 0x50d, 4.98),
+    (0x50e, 0x50e, 7.65), (0x50f, 0x50f, 6.62), (0x510, 0x510, 5.51), (0x511, 0x511, 4.35),
+    (0x512, 0x512, 7.46), (0x513, 0x513, 5.49), (0x514, 0x514, 8.38), (0x515, 0x515, 7.62),
+    (0x516, 0x516, 9.13), (0x517, 0x517, 7.49), (0x518, 0x518, 11.34), (0x519, 0x519, 8.11),
+    (0x51a, 0x51a, 8.66), (0x51b, 0x51b, 6.85), (0x51c, 0x51c, 10.88), (0x51d, 0x51d, 9.0),
+    (0x51e, 0x51e, 7.53), (0x51f, 0x51f, 6.94), (0x520, 0x520, 10.79), (0x521, 0x521, 8.76),
+    (0x522, 0x522, 12.22), (0x523, 0x523, 9.99), (0x524, 0x524, 7.77), (0x525, 0x525, 6.78),
+    (0x526, 0x526, 7.56), (0x527, 0x527, 6.92), (0x528, 0x528, 4.88), (0x529, 0x529, 4.43),
+    (0x52a, 0x52a, 9.8), (0x52b, 0x52b, 8.94), (0x52c, 0x52c, 6.18), (0x52d, 0x52d, 5.76),
+    (0x52e, 0x52e, 7.49), (0x1d00, 0x1d00, 5.53), (0x1d01, 0x1d01, 7.1), (0x1d02, 0x1d02, 7.34),
+    (0x1d03, 0x1d03, 5.11), (0x1d04, 0x1d04, 5.26), (0x1d05, 0x1d06, 5.77), (0x1d07, 0x1d07, 4.89),
+    (0x1d08, 0x1d08, 4.35), (0x1d09, 0x1d09, 3.06), (0x1d0a, 0x1d0a, 3.26), (0x1d0b, 0x1d0b, 5.94),
+    (0x1d0c, 0x1d0c, 4.89), (0x1d0d, 0x1d0d, 6.96), (0x1d0e, 0x1d0e, 5.89), (0x1d0f, 0x1d0f, 5.5),
+    (0x1d10, 0x1d10, 5.26), (0x1d11, 0x1d11, 5.96), (0x1d12, 0x1d12, 5.91), (0x1d13, 0x1d13, 5.96),
+    (0x1d14, 0x1d14, 7.94), (0x1d15, 0x1d15, 4.8), (0x1d16, 0x1d17, 5.5), (0x1d18, 0x1d18, 5.02),
+    (0x1d19, 0x1d1a, 5.06), (0x1d1b, 0x1d1b, 4.81), (0x1d1c, 0x1d1c, 5.91), (0x1d1d, 0x1d1d, 5.55),
+    (0x1d1e, 0x1d1e, 7.2), (0x1d1f, 0x1d1f, 5.55), (0x1d20, 0x1d20, 5.5), (0x1d21, 0x1d21, 7.94),
+    (0x1d22, 0x1d22, 4.88), (0x1d23, 0x1d23, 4.35), (0x1d24, 0x1d24, 4.37), (0x1d25, 0x1d25, 5.92),
+    (0x1d26, 0x1d26, 4.51), (0x1d27, 0x1d27, 5.5), (0x1d28, 0x1d28, 5.89), (0x1d29, 0x1d29, 5.02),
+    (0x1d2a, 0x1d2a, 5.81), (0x1d2b, 0x1d2b, 5.49), (0x1d2c, 0x1d2c, 4.64), (0x1d2d, 0x1d2d, 5.92),
+    (0x1d2e, 0x1d2f, 4.25), (0x1d30, 0x1d30, 4.73), (0x1d31, 0x1d32, 4.07), (0x1d33, 0x1d33, 4.76),
+    (0x1d34, 0x1d34, 4.84), (0x1d35, 0x1d35, 2.2), (0x1d36, 0x1d36, 2.71), (0x1d37, 0x1d37, 5.04),
+    (0x1d38, 0x1d38, 4.08), (0x1d39, 0x1d39, 5.94), (0x1d3a, 0x1d3a, 5.09), (0x1d3b, 0x1d3b, 4.85),
+    (0x1d3c, 0x1d3c, 4.6), (0x1d3d, 0x1d3d, 3.7), (0x1d3e, 0x1d3e, 3.65), (0x1d3f, 0x1d3f, 4.51),
+    (0x1d40, 0x1d40, 3.99), (0x1d41, 0x1d41, 4.98), (0x1d42, 0x1d42, 6.09), (0x1d43, 0x1d44, 3.01),
+    (0x1d45, 0x1d45, 3.45), (0x1d46, 0x1d46, 4.29), (0x1d47, 0x1d47, 3.41), (0x1d48, 0x1d48, 3.42),
+    (0x1d49, 0x1d4a, 2.82), (0x1d4b, 0x1d4c, 2.66), (0x1d4d, 0x1d4d, 3.31), (0x1d4e, 0x1d4e, 1.8),
+    (0x1d4f, 0x1d4f, 3.47), (0x1d50, 0x1d50, 5.38), (0x1d51, 0x1d51, 3.1), (0x1d52, 0x1d52, 3.17),
+    (0x1d53, 0x1d53, 2.81), (0x1d54, 0x1d55, 3.17), (0x1d56, 0x1d56, 3.42), (0x1d57, 0x1d57, 2.11),
+    (0x1d58, 0x1d58, 3.6), (0x1d59, 0x1d59, 3.36), (0x1d5a, 0x1d5a, 5.38), (0x1d5b, 0x1d5b, 3.23),
+    (0x1d5c, 0x1d5c, 3.51), (0x1d5d, 0x1d5d, 3.1), (0x1d5e, 0x1d5e, 2.92), (0x1d5f, 0x1d5f, 2.98),
+    (0x1d60, 0x1d60, 3.68), (0x1d61, 0x1d61, 3.1), (0x1d62, 0x1d62, 1.8), (0x1d63, 0x1d63, 2.52),
+    (0x1d64, 0x1d64, 3.6), (0x1d65, 0x1d65, 3.23), (0x1d66, 0x1d66, 3.1), (0x1d67, 0x1d67, 2.92),
+    (0x1d68, 0x1d68, 3.25), (0x1d69, 0x1d69, 3.68), (0x1d6a, 0x1d6a, 3.1), (0x1d6b, 0x1d6b, 8.37),
+    (0x1d6c, 0x1d6d, 5.5), (0x1d6e, 0x1d6e, 3.66), (0x1d6f, 0x1d6f, 8.56), (0x1d70, 0x1d71, 5.5),
+    (0x1d72, 0x1d73, 3.66), (0x1d74, 0x1d74, 4.28), (0x1d75, 0x1d75, 3.06), (0x1d76, 0x1d76, 4.88),
+    (0x1d77, 0x1d77, 5.5), (0x1d78, 0x1d78, 3.56), (0x1d79, 0x1d79, 5.18), (0x1d7a, 0x1d7a, 8.43),
+    (0x1d7b, 0x1d7b, 3.06), (0x1d7c, 0x1d7c, 2.96), (0x1d7d, 0x1d7d, 5.5), (0x1d7e, 0x1d7e, 5.91),
+    (0x1e00, 0x1e00, 7.94), (0x1e01, 0x1e01, 4.88), (0x1e02, 0x1e02, 7.34), (0x1e03, 0x1e03, 5.5),
+    (0x1e04, 0x1e04, 7.54), (0x1e05, 0x1e05, 6.85), (0x1e06, 0x1e06, 7.34), (0x1e07, 0x1e07, 5.5),
+    (0x1e08, 0x1e08, 7.68), (0x1e09, 0x1e09, 5.73), (0x1e0a, 0x1e0a, 7.94), (0x1e0b, 0x1e0b, 5.5),
+    (0x1e0c, 0x1e0c, 8.48), (0x1e0d, 0x1e0d, 6.85), (0x1e0e, 0x1e0e, 7.94), (0x1e0f, 0x1e0f, 5.5),
+    (0x1e10, 0x1e10, 7.94), (0x1e11, 0x1e11, 5.5), (0x1e12, 0x1e12, 7.94), (0x1e13, 0x1e13, 5.5),
+    (0x1e14, 0x1e14, 6.96), (0x1e15, 0x1e15, 6.55), (0x1e16, 0x1e16, 6.96), (0x1e17, 0x1e17, 6.55),
+    (0x1e18, 0x1e18, 6.72), (0x1e19, 0x1e19, 4.88), (0x1e1a, 0x1e1a, 6.72), (0x1e1b, 0x1e1b, 4.88),
+    (0x1e1c, 0x1e1c, 6.72), (0x1e1d, 0x1e1d, 4.88), (0x1e1e, 0x1e1e, 6.12), (0x1e1f, 0x1e1f, 3.66),
+    (0x1e20, 0x1e20, 7.94), (0x1e21, 0x1e21, 5.5), (0x1e22, 0x1e22, 7.94), (0x1e23, 0x1e23, 5.5),
+    (0x1e24, 0x1e24, 8.27), (0x1e25, 0x1e25, 6.96), (0x1e26, 0x1e26, 7.94), (0x1e27, 0x1e27, 5.5),
+    (0x1e28, 0x1e28, 7.94), (0x1e29, 0x1e29, 5.5), (0x1e2a, 0x1e2a, 7.94), (0x1e2b, 0x1e2b, 5.5),
+    (0x1e2c, 0x1e2c, 3.66), (0x1e2d, 0x1e2d, 3.06), (0x1e2e, 0x1e2e, 4.63), (0x1e2f, 0x1e2f, 3.02),
+    (0x1e30, 0x1e30, 7.62), (0x1e31, 0x1e31, 6.51), (0x1e32, 0x1e32, 7.62), (0x1e33, 0x1e33, 6.51),
+    (0x1e34, 0x1e34, 7.94), (0x1e35, 0x1e35, 5.5), (0x1e36, 0x1e36, 6.12), (0x1e37, 0x1e37, 3.02),
+    (0x1e38, 0x1e38, 6.72), (0x1e39, 0x1e39, 3.06), (0x1e3a, 0x1e3a, 6.72), (0x1e3b, 0x1e3b, 3.06),
+    (0x1e3c, 0x1e3c, 6.72), (0x1e3d, 0x1e3d, 3.06), (0x1e3e, 0x1e3e, 9.27), (0x1e3f, 0x1e3f, 10.7),
+    (0x1e40, 0x1e40, 9.78), (0x1e41, 0x1e41, 8.56), (0x1e42, 0x1e42, 9.27), (0x1e43, 0x1e43, 10.7),
+    (0x1e44, 0x1e44, 7.94), (0x1e45, 0x1e45, 5.5), (0x1e46, 0x1e46, 8.23), (0x1e47, 0x1e47, 6.96),
+    (0x1e48, 0x1e48, 7.94), (0x1e49, 0x1e49, 5.5), (0x1e4a, 0x1e4a, 7.94), (0x1e4b, 0x1e4b, 5.5),
+    (0x1e4c, 0x1e4c, 8.66), (0x1e4d, 0x1e4d, 6.68), (0x1e4e, 0x1e4e, 7.94), (0x1e4f, 0x1e4f, 5.5),
+    (0x1e50, 0x1e50, 8.66), (0x1e51, 0x1e51, 6.68), (0x1e52, 0x1e52, 8.66), (0x1e53, 0x1e53, 6.68),
+    (0x1e54, 0x1e54, 6.63), (0x1e55, 0x1e55, 6.85), (0x1e56, 0x1e56, 6.12), (0x1e57, 0x1e57, 5.5),
+    (0x1e58, 0x1e58, 7.34), (0x1e59, 0x1e59, 3.66), (0x1e5a, 0x1e5a, 7.65), (0x1e5b, 0x1e5b, 4.69),
+    (0x1e5c, 0x1e5c, 7.34), (0x1e5d, 0x1e5d, 3.66), (0x1e5e, 0x1e5e, 7.34), (0x1e5f, 0x1e5f, 3.66),
+    (0x1e60, 0x1e60, 6.12), (0x1e61, 0x1e61, 4.28), (0x1e62, 0x1e62, 7.52), (0x1e63, 0x1e63, 5.73),
+    (0x1e64, 0x1e64, 6.12), (0x1e65, 0x1e65, 4.28), (0x1e66, 0x1e66, 6.12), (0x1e67, 0x1e67, 4.28),
+    (0x1e68, 0x1e68, 6.12), (0x1e69, 0x1e69, 4.28), (0x1e6a, 0x1e6a, 6.72), (0x1e6b, 0x1e6b, 3.06),
+    (0x1e6c, 0x1e6c, 6.78), (0x1e6d, 0x1e6d, 4.33), (0x1e6e, 0x1e6e, 6.72), (0x1e6f, 0x1e6f, 3.06),
+    (0x1e70, 0x1e70, 6.72), (0x1e71, 0x1e71, 3.06), (0x1e72, 0x1e72, 7.94), (0x1e73, 0x1e73, 5.5),
+    (0x1e74, 0x1e74, 7.94), (0x1e75, 0x1e75, 5.5), (0x1e76, 0x1e76, 7.94), (0x1e77, 0x1e77, 5.5),
+    (0x1e78, 0x1e78, 8.05), (0x1e79, 0x1e79, 6.96), (0x1e7a, 0x1e7a, 7.94), (0x1e7b, 0x1e7b, 5.5),
+    (0x1e7c, 0x1e7c, 7.52), (0x1e7d, 0x1e7d, 6.51), (0x1e7e, 0x1e7e, 7.52), (0x1e7f, 0x1e7f, 6.51),
+    (0x1e80, 0x1e80, 10.88), (0x1e81, 0x1e81, 9.0), (0x1e82, 0x1e82, 10.88), (0x1e83, 0x1e83, 9.0),
+    (0x1e84, 0x1e84, 10.88), (0x1e85, 0x1e85, 9.0), (0x1e86, 0x1e86, 10.38), (0x1e87, 0x1e87, 7.94),
+    (0x1e88, 0x1e88, 10.88), (0x1e89, 0x1e89, 9.0), (0x1e8a, 0x1e8a, 7.94), (0x1e8b, 0x1e8b, 5.5),
+    (0x1e8c, 0x1e8c, 7.94), (0x1e8d, 0x1e8d, 5.5), (0x1e8e, 0x1e8e, 7.94), (0x1e8f, 0x1e8f, 5.5),
+    (0x1e90, 0x1e90, 6.72), (0x1e91, 0x1e91, 4.88), (0x1e92, 0x1e92, 7.54), (0x1e93, 0x1e93, 5.78),
+    (0x1e94, 0x1e94, 6.72), (0x1e95, 0x1e95, 4.88), (0x1e96, 0x1e96, 5.5), (0x1e97, 0x1e97, 3.06),
+    (0x1e98, 0x1e98, 7.94), (0x1e99, 0x1e99, 5.5), (0x1e9a, 0x1e9a, 4.88), (0x1e9b, 0x1e9b, 3.06),
+    (0x1e9c, 0x1e9c, 4.66), (0x1e9d, 0x1e9d, 4.65), (0x1e9e, 0x1e9e, 7.46), (0x1e9f, 0x1e9f, 6.62),
+    (0x1ea0, 0x1ea0, 7.52), (0x1ea1, 0x1ea1, 6.61), (0x1ea2, 0x1ea2, 7.52), (0x1ea3, 0x1ea3, 6.61),
+    (0x1ea4, 0x1ea4, 7.52), (0x1ea5, 0x1ea5, 6.61), (0x1ea6, 0x1ea6, 7.52), (0x1ea7, 0x1ea7, 6.61),
+    (0x1ea8, 0x1ea8, 7.52), (0x1ea9, 0x1ea9, 6.61), (0x1eaa, 0x1eaa, 7.52), (0x1eab, 0x1eab, 6.61),
+    (0x1eac, 0x1eac, 7.52), (0x1ead, 0x1ead, 6.61), (0x1eae, 0x1eae, 7.52), (0x1eaf, 0x1eaf, 6.61),
+    (0x1eb0, 0x1eb0, 7.52), (0x1eb1, 0x1eb1, 6.61), (0x1eb2, 0x1eb2, 7.52), (0x1eb3, 0x1eb3, 6.61),
+    (0x1eb4, 0x1eb4, 7.52), (0x1eb5, 0x1eb5, 6.61), (0x1eb6, 0x1eb6, 7.52), (0x1eb7, 0x1eb7, 6.61),
+    (0x1eb8, 0x1eb8, 6.96), (0x1eb9, 0x1eb9, 6.55), (0x1eba, 0x1eba, 6.96), (0x1ebb, 0x1ebb, 6.55),
+    (0x1ebc, 0x1ebc, 6.96), (0x1ebd, 0x1ebd, 6.55), (0x1ebe, 0x1ebe, 6.96), (0x1ebf, 0x1ebf, 6.55),
+    (0x1ec0, 0x1ec0, 6.96), (0x1ec1, 0x1ec1, 6.55), (0x1ec2, 0x1ec2, 6.96), (0x1ec3, 0x1ec3, 6.55),
+    (0x1ec4, 0x1ec4, 6.96), (0x1ec5, 0x1ec5, 6.55), (0x1ec6, 0x1ec6, 6.96), (0x1ec7, 0x1ec7, 6.55),
+    (0x1ec8, 0x1ec8, 4.63), (0x1ec9, 0x1ec9, 3.02), (0x1eca, 0x1eca, 4.63), (0x1ecb, 0x1ecb, 3.02),
+    (0x1ecc, 0x1ecc, 8.66), (0x1ecd, 0x1ecd, 6.68), (0x1ece, 0x1ece, 8.66), (0x1ecf, 0x1ecf, 6.68),
+    (0x1ed0, 0x1ed0, 8.66), (0x1ed1, 0x1ed1, 6.68), (0x1ed2, 0x1ed2, 8.66), (0x1ed3, 0x1ed3, 6.68),
+    (0x1ed4, 0x1ed4, 8.66), (0x1ed5, 0x1ed5, 6.68), (0x1ed6, 0x1ed6, 8.66), (0x1ed7, 0x1ed7, 6.68),
+    (0x1ed8, 0x1ed8, 8.66), (0x1ed9, 0x1ed9, 6.68), (0x1eda, 0x1eda, 8.87), (0x1edb, 0x1edb, 6.68),
+    (0x1edc, 0x1edc, 8.87), (0x1edd, 0x1edd, 6.68), (0x1ede, 0x1ede, 8.87), (0x1edf, 0x1edf, 6.68),
+    (0x1ee0, 0x1ee0, 8.87), (0x1ee1, 0x1ee1, 6.68), (0x1ee2, 0x1ee2, 8.87), (0x1ee3, 0x1ee3, 6.68),
+    (0x1ee4, 0x1ee4, 8.05), (0x1ee5, 0x1ee5, 6.96), (0x1ee6, 0x1ee6, 8.05), (0x1ee7, 0x1ee7, 6.96),
+    (0x1ee8, 0x1ee8, 8.32), (0x1ee9, 0x1ee9, 7.26), (0x1eea, 0x1eea, 8.32), (0x1eeb, 0x1eeb, 7.26),
+    (0x1eec, 0x1eec, 8.32), (0x1eed, 0x1eed, 7.26), (0x1eee, 0x1eee, 8.32), (0x1eef, 0x1eef, 7.26),
+    (0x1ef0, 0x1ef0, 8.32), (0x1ef1, 0x1ef1, 7.26), (0x1ef2, 0x1ef2, 6.77), (0x1ef3, 0x1ef3, 6.51),
+    (0x1ef4, 0x1ef4, 6.77), (0x1ef5, 0x1ef5, 6.51), (0x1ef6, 0x1ef6, 6.77), (0x1ef7, 0x1ef7, 6.51),
+    (0x1ef8, 0x1ef8, 6.77), (0x1ef9, 0x1ef9, 6.51), (0x1efa, 0x1efa, 10.3), (0x1efb, 0x1efb, 6.48),
+    (0x1efc, 0x1efc, 5.57), (0x1efd, 0x1efd, 4.89), (0x1efe, 0x1efe, 5.04), (0x1f00, 0x1f07, 5.76),
+    (0x1f08, 0x1f09, 7.94), (0x1f0a, 0x1f0f, 8.7), (0x1f10, 0x1f15, 4.62), (0x1f16, 0x1f17, 11.0),
+    (0x1f18, 0x1f19, 8.23), (0x1f1a, 0x1f1d, 9.95), (0x1f1e, 0x1f1f, 11.0), (0x1f20, 0x1f27, 5.75),
+    (0x1f28, 0x1f29, 9.45), (0x1f2a, 0x1f2f, 11.17), (0x1f30, 0x1f37, 2.96), (0x1f38, 0x1f39, 5.17),
+    (0x1f3a, 0x1f3f, 6.89), (0x1f40, 0x1f45, 5.5), (0x1f46, 0x1f47, 11.0), (0x1f48, 0x1f49, 8.7),
+    (0x1f4a, 0x1f4b, 10.96), (0x1f4c, 0x1f4d, 9.88), (0x1f4e, 0x1f4f, 11.0), (0x1f50, 0x1f57, 5.45),
+    (0x1f58, 0x1f58, 11.0), (0x1f59, 0x1f59, 9.45), (0x1f5a, 0x1f5a, 11.0), (0x1f5b, 0x1f5b, 11.23),
+    (0x1f5c, 0x1f5c, 11.0), (0x1f5d, 0x1f5d, 11.23), (0x1f5e, 0x1f5e, 11.0),
+    (0x1f5f, 0x1f5f, 11.23), (0x1f60, 0x1f67, 7.24), (0x1f68, 0x1f69, 8.93),
+    (0x1f6a, 0x1f6b, 10.97), (0x1f6c, 0x1f6f, 10.22), (0x1f70, 0x1f71, 6.85),
+    (0x1f72, 0x1f73, 5.64), (0x1f74, 0x1f75, 6.96), (0x1f76, 0x1f77, 3.02), (0x1f78, 0x1f79, 6.68),
+    (0x1f7a, 0x1f7b, 6.94), (0x1f7c, 0x1f7d, 8.95), (0x1f7e, 0x1f7f, 11.0), (0x1f80, 0x1f87, 5.76),
+    (0x1f88, 0x1f89, 7.94), (0x1f8a, 0x1f8f, 8.7), (0x1f90, 0x1f97, 5.75), (0x1f98, 0x1f99, 9.45),
+    (0x1f9a, 0x1f9f, 11.17), (0x1fa0, 0x1fa7, 7.24), (0x1fa8, 0x1fa9, 8.93),
+    (0x1faa, 0x1fab, 10.97), (0x1fac, 0x1faf, 10.22), (0x1fb0, 0x1fb4, 5.76),
+    (0x1fb5, 0x1fb5, 11.0), (0x1fb6, 0x1fb7, 5.76), (0x1fb8, 0x1fb9, 7.94), (0x1fba, 0x1fbb, 7.52),
+    (0x1fbc, 0x1fbc, 7.94), (0x1fbd, 0x1fbd, 3.66), (0x1fbe, 0x1fbe, 3.02), (0x1fbf, 0x1fc1, 3.66),
+    (0x1fc2, 0x1fc4, 5.75), (0x1fc5, 0x1fc5, 11.0), (0x1fc6, 0x1fc7, 5.75), (0x1fc8, 0x1fc8, 6.96),
+    (0x1fc9, 0x1fc9, 8.26), (0x1fca, 0x1fca, 8.27), (0x1fcb, 0x1fcb, 9.57), (0x1fcc, 0x1fcc, 7.94),
+    (0x1fcd, 0x1fcf, 3.66), (0x1fd0, 0x1fd1, 2.96), (0x1fd2, 0x1fd3, 3.02), (0x1fd4, 0x1fd5, 11.0),
+    (0x1fd6, 0x1fd7, 2.96), (0x1fd8, 0x1fd9, 3.66), (0x1fda, 0x1fda, 4.63), (0x1fdb, 0x1fdb, 5.94),
+    (0x1fdc, 0x1fdc, 11.0), (0x1fdd, 0x1fdf, 3.66), (0x1fe0, 0x1fe1, 5.45), (0x1fe2, 0x1fe3, 6.94),
+    (0x1fe4, 0x1fe5, 5.49), (0x1fe6, 0x1fe7, 5.45), (0x1fe8, 0x1fe9, 7.94), (0x1fea, 0x1fea, 6.77),
+    (0x1feb, 0x1feb, 8.29), (0x1fec, 0x1fec, 7.63), (0x1fed, 0x1fef, 6.99), (0x1ff0, 0x1ff1, 11.0),
+    (0x1ff2, 0x1ff4, 7.24), (0x1ff5, 0x1ff5, 11.0), (0x1ff6, 0x1ff7, 7.24), (0x1ff8, 0x1ff8, 8.66),
+    (0x1ff9, 0x1ff9, 9.69), (0x1ffa, 0x1ffa, 9.0), (0x1ffb, 0x1ffb, 9.98), (0x1ffc, 0x1ffc, 8.17),
+    (0x1ffd, 0x1ffd, 6.99), (0x1ffe, 0x1ffe, 3.66), (0x2000, 0x2000, 5.5), (0x2001, 0x2001, 11.0),
+    (0x2002, 0x2002, 5.5), (0x2003, 0x2003, 11.0), (0x2004, 0x2004, 3.67), (0x2005, 0x2005, 2.75),
+    (0x2006, 0x2006, 1.83), (0x2007, 0x2007, 6.99), (0x2008, 0x2008, 4.0), (0x2009, 0x2009, 2.2),
+    (0x200a, 0x200a, 0.69), (0x200b, 0x200f, 0.0), (0x2010, 0x2011, 6.36), (0x2012, 0x2013, 6.99),
+    (0x2014, 0x2015, 11.0), (0x2016, 0x2016, 3.09), (0x2017, 0x2017, 6.99), (0x2018, 0x201b, 2.95),
+    (0x201c, 0x201f, 5.05), (0x2020, 0x2021, 6.99), (0x2022, 0x2022, 6.0), (0x2023, 0x2023, 5.17),
+    (0x2024, 0x2024, 3.67), (0x2025, 0x2025, 7.33), (0x2026, 0x2026, 9.0), (0x2027, 0x2027, 3.48),
+    (0x2028, 0x202e, 0.0), (0x202f, 0x202f, 1.93), (0x2030, 0x2030, 16.74), (0x2031, 0x2031, 14.91),
+    (0x2032, 0x2032, 3.97), (0x2033, 0x2034, 6.13), (0x2035, 0x2035, 3.58), (0x2036, 0x2036, 5.96),
+    (0x2037, 0x2037, 8.35), (0x2038, 0x2038, 3.88), (0x2039, 0x203a, 5.0), (0x203b, 0x203b, 7.95),
+    (0x203c, 0x203c, 6.87), (0x203d, 0x203d, 4.64), (0x203e, 0x203e, 6.99), (0x203f, 0x2040, 5.5),
+    (0x2041, 0x2041, 5.11), (0x2042, 0x2042, 10.02), (0x2043, 0x2043, 3.58), (0x2044, 0x2044, 3.97),
+    (0x2045, 0x2046, 3.58), (0x2047, 0x2047, 8.88), (0x2048, 0x2049, 7.77), (0x204a, 0x204b, 6.96),
+    (0x204c, 0x204c, 5.96), (0x204d, 0x204d, 5.97), (0x204e, 0x204e, 5.3), (0x204f, 0x204f, 3.48),
+    (0x2050, 0x2050, 5.5), (0x2051, 0x2051, 5.3), (0x2052, 0x2052, 6.06), (0x2053, 0x2053, 11.0),
+    (0x2054, 0x2054, 5.5), (0x2055, 0x2055, 7.67), (0x2056, 0x2056, 6.53), (0x2057, 0x2057, 10.72),
+    (0x2058, 0x2059, 6.53), (0x205a, 0x205a, 11.0), (0x205b, 0x205b, 6.53), (0x205c, 0x205c, 7.68),
+    (0x205d, 0x205d, 11.0), (0x205e, 0x205e, 3.06), (0x205f, 0x205f, 2.44), (0x2060, 0x206e, 0.0),
+    (0x2070, 0x2070, 4.64), (0x2071, 0x2071, 2.38), (0x2072, 0x2073, 11.0), (0x2074, 0x2075, 5.96),
+    (0x2076, 0x2076, 4.64), (0x2077, 0x2078, 5.96), (0x2079, 0x207c, 4.64), (0x207d, 0x207e, 2.48),
+    (0x207f, 0x207f, 6.0), (0x2080, 0x208c, 4.64), (0x208d, 0x208e, 2.48), (0x208f, 0x208f, 11.0),
+    (0x2090, 0x2090, 3.01), (0x2091, 0x2091, 2.82), (0x2092, 0x2092, 3.17), (0x2093, 0x2093, 3.19),
+    (0x2094, 0x2094, 2.82), (0x2095, 0x209e, 11.0), (0x20a0, 0x20a0, 6.99), (0x20a1, 0x20a2, 7.68),
+    (0x20a3, 0x20a4, 6.99), (0x20a5, 0x20a5, 10.7), (0x20a6, 0x20a6, 8.23), (0x20a7, 0x20a7, 12.8),
+    (0x20a8, 0x20a8, 12.83), (0x20a9, 0x20a9, 10.88), (0x20aa, 0x20aa, 9.18),
+    (0x20ab, 0x20ab, 6.85), (0x20ac, 0x20ac, 6.99), (0x20ad, 0x20ad, 7.62), (0x20ae, 0x20ae, 6.78),
+    (0x20af, 0x20af, 11.0), (0x20b0, 0x20b0, 5.73), (0x20b1, 0x20b1, 6.63), (0x20b2, 0x20b2, 8.53),
+    (0x20b3, 0x20b4, 7.52), (0x20b5, 0x20b5, 7.68), (0x20b6, 0x20b6, 7.44), (0x20b7, 0x20b7, 8.03),
+    (0x20b8, 0x20b8, 6.22), (0x20b9, 0x20b9, 6.6), (0x20ba, 0x20ba, 6.12), (0x20bb, 0x20bb, 9.14),
+    (0x20bc, 0x20bd, 6.22), (0x20be, 0x20be, 8.28), (0x20bf, 0x20bf, 7.22), (0x20c0, 0x20ce, 11.0),
+    (0x20d0, 0x20d2, 0.0), (0x20d3, 0x20d3, 6.6), (0x20d4, 0x20d5, 7.7), (0x20d6, 0x20d8, 0.0),
+    (0x20d9, 0x20da, 6.6), (0x20db, 0x20dd, 0.0), (0x20de, 0x20de, 11.0), (0x20df, 0x20df, 9.51),
+    (0x20e0, 0x20e0, 9.9), (0x20e1, 0x20e1, 0.0), (0x20e2, 0x20e2, 11.0), (0x20e3, 0x20f0, 0.0),
+    (0x20f1, 0x20fe, 11.0), (0x2100, 0x2101, 8.25), (0x2102, 0x2102, 6.62), (0x2103, 0x2103, 10.88),
+    (0x2104, 0x2104, 7.04), (0x2105, 0x2105, 11.84), (0x2106, 0x2106, 8.25), (0x2107, 0x2107, 6.58),
+    (0x2108, 0x2108, 6.63), (0x2109, 0x2109, 9.31), (0x210a, 0x210a, 11.0), (0x210b, 0x210b, 10.97),
+    (0x210c, 0x210c, 9.06), (0x210d, 0x210d, 6.62), (0x210e, 0x210e, 6.35), (0x210f, 0x210f, 6.76),
+    (0x2110, 0x2110, 9.87), (0x2111, 0x2111, 8.38), (0x2112, 0x2112, 10.41), (0x2113, 0x2113, 3.56),
+    (0x2114, 0x2114, 8.56), (0x2115, 0x2115, 6.62), (0x2116, 0x2116, 12.89), (0x2117, 0x2117, 9.41),
+    (0x2118, 0x2118, 9.09), (0x2119, 0x211a, 6.62), (0x211b, 0x211b, 10.38), (0x211c, 0x211c, 9.61),
+    (0x211d, 0x211d, 6.62), (0x211e, 0x211e, 6.96), (0x211f, 0x211f, 7.34), (0x2120, 0x2120, 12.41),
+    (0x2121, 0x2121, 16.53), (0x2122, 0x2122, 10.74), (0x2123, 0x2123, 7.94),
+    (0x2124, 0x2124, 6.62), (0x2125, 0x2125, 5.63), (0x2126, 0x2126, 9.0), (0x2127, 0x2127, 8.75),
+    (0x2128, 0x2128, 7.99), (0x2129, 0x2129, 4.4), (0x212a, 0x212a, 7.62), (0x212b, 0x212b, 7.52),
+    (0x212c, 0x212c, 10.45), (0x212d, 0x212d, 7.89), (0x212e, 0x212e, 7.9), (0x212f, 0x212f, 6.9),
+    (0x2130, 0x2130, 8.25), (0x2131, 0x2131, 10.11), (0x2132, 0x2132, 7.3), (0x2133, 0x2133, 11.79),
+    (0x2134, 0x2134, 7.67), (0x2135, 0x2135, 7.1), (0x2136, 0x2136, 6.19), (0x2137, 0x2137, 4.7),
+    (0x2138, 0x2138, 5.51), (0x2139, 0x2139, 7.26), (0x213a, 0x213a, 10.27),
+    (0x213b, 0x213b, 18.04), (0x213c, 0x213c, 7.4), (0x213d, 0x213d, 5.94), (0x213e, 0x213e, 5.16),
+    (0x213f, 0x213f, 7.9), (0x2140, 0x2140, 10.15), (0x2141, 0x2141, 7.95), (0x2142, 0x2143, 5.87),
+    (0x2144, 0x2144, 6.85), (0x2145, 0x2145, 7.84), (0x2146, 0x2146, 6.39), (0x2147, 0x2147, 5.66),
+    (0x2148, 0x2148, 3.22), (0x2149, 0x2149, 3.75), (0x214a, 0x214a, 7.3), (0x214b, 0x214b, 7.67),
+    (0x214c, 0x214c, 8.91), (0x214d, 0x214d, 9.7), (0x214e, 0x214e, 4.58), (0x2150, 0x2152, 11.0),
+    (0x2153, 0x2154, 8.25), (0x2155, 0x215a, 8.81), (0x215b, 0x215e, 11.0), (0x215f, 0x215f, 4.18),
+    (0x2160, 0x2160, 3.17), (0x2161, 0x2161, 6.49), (0x2162, 0x2162, 9.21), (0x2163, 0x2163, 9.95),
+    (0x2164, 0x2164, 7.19), (0x2165, 0x2165, 10.1), (0x2166, 0x2166, 13.34),
+    (0x2167, 0x2167, 16.06), (0x2168, 0x2168, 9.59), (0x2169, 0x2169, 6.89), (0x216a, 0x216a, 9.38),
+    (0x216b, 0x216b, 12.62), (0x216c, 0x216c, 5.87), (0x216d, 0x216d, 7.61), (0x216e, 0x216e, 8.24),
+    (0x216f, 0x216f, 9.47), (0x2170, 0x2170, 3.18), (0x2171, 0x2171, 6.36), (0x2172, 0x2172, 9.01),
+    (0x2173, 0x2173, 8.57), (0x2174, 0x2174, 5.69), (0x2175, 0x2175, 8.6), (0x2176, 0x2176, 11.78),
+    (0x2177, 0x2177, 14.43), (0x2178, 0x2178, 9.18), (0x2179, 0x2179, 6.75), (0x217a, 0x217a, 9.16),
+    (0x217b, 0x217b, 12.34), (0x217c, 0x217c, 3.18), (0x217d, 0x217d, 5.63), (0x217e, 0x217e, 6.92),
+    (0x217f, 0x217f, 10.27), (0x2180, 0x2180, 11.45), (0x2181, 0x2181, 8.24),
+    (0x2182, 0x2182, 11.45), (0x2183, 0x2183, 7.61), (0x2184, 0x2184, 4.88), (0x2185, 0x2185, 7.17),
+    (0x2186, 0x2186, 6.21), (0x2187, 0x218e, 11.0), (0x2190, 0x2190, 11.0), (0x2191, 0x2191, 5.5),
+    (0x2192, 0x2192, 11.0), (0x2193, 0x2193, 5.5), (0x2194, 0x2194, 11.0), (0x2195, 0x2195, 5.5),
+    (0x2196, 0x219b, 11.0), (0x219c, 0x21a7, 6.62), (0x21a8, 0x21a8, 5.5), (0x21a9, 0x21a9, 11.0),
+    (0x21aa, 0x21aa, 10.87), (0x21ab, 0x21ad, 6.62), (0x21ae, 0x21ae, 11.0), (0x21af, 0x21af, 6.62),
+    (0x21b0, 0x21b2, 9.51), (0x21b3, 0x21b3, 11.0), (0x21b4, 0x21b4, 9.51), (0x21b5, 0x21b5, 11.0),
+    (0x21b6, 0x21b7, 9.51), (0x21b8, 0x21b9, 11.0), (0x21ba, 0x21bb, 6.62), (0x21bc, 0x21bc, 9.51),
+    (0x21bd, 0x21bf, 6.62), (0x21c0, 0x21c0, 9.51), (0x21c1, 0x21c3, 6.62), (0x21c4, 0x21c6, 11.0),
+    (0x21c7, 0x21ca, 6.62), (0x21cb, 0x21cc, 11.0), (0x21cd, 0x21cd, 9.68), (0x21ce, 0x21ce, 6.62),
+    (0x21cf, 0x21cf, 9.56), (0x21d0, 0x21d0, 11.0), (0x21d1, 0x21d1, 9.51), (0x21d2, 0x21d2, 10.24),
+    (0x21d3, 0x21d3, 9.51), (0x21d4, 0x21d4, 10.24), (0x21d5, 0x21dd, 6.62), (0x21de, 0x21df, 7.1),
+    (0x21e0, 0x21e0, 11.0), (0x21e1, 0x21e1, 7.1), (0x21e2, 0x21e2, 11.0), (0x21e3, 0x21e3, 7.1),
+    (0x21e4, 0x21ea, 11.0), (0x21eb, 0x21ef, 6.62), (0x21f0, 0x21f0, 9.51), (0x21f1, 0x21f4, 6.62),
+    (0x21f5, 0x21f5, 11.0), (0x21f6, 0x21fe, 6.62), (0x2200, 0x2200, 6.93), (0x2201, 0x2201, 5.16),
+    (0x2202, 0x2202, 6.99), (0x2203, 0x2203, 5.78), (0x2204, 0x2204, 5.88), (0x2205, 0x2205, 7.89),
+    (0x2206, 0x2206, 8.0), (0x2207, 0x2207, 7.7), (0x2208, 0x2208, 6.34), (0x2209, 0x2209, 6.73),
+    (0x220a, 0x220a, 5.6), (0x220b, 0x220b, 6.34), (0x220c, 0x220c, 6.48), (0x220d, 0x220d, 5.6),
+    (0x220e, 0x220e, 5.74), (0x220f, 0x220f, 9.0), (0x2210, 0x2210, 8.75), (0x2211, 0x2211, 8.0),
+    (0x2212, 0x2212, 9.0), (0x2213, 0x2213, 8.75), (0x2214, 0x2214, 6.58), (0x2215, 0x2215, 3.97),
+    (0x2216, 0x2216, 8.75), (0x2217, 0x2217, 5.76), (0x2218, 0x2218, 3.9), (0x2219, 0x2219, 4.0),
+    (0x221a, 0x221a, 9.0), (0x221b, 0x221b, 8.23), (0x221c, 0x221c, 7.97), (0x221d, 0x221d, 5.7),
+    (0x221e, 0x221e, 11.0), (0x221f, 0x221f, 10.77), (0x2220, 0x2220, 6.57), (0x2221, 0x2221, 6.61),
+    (0x2222, 0x2222, 6.66), (0x2223, 0x2223, 2.74), (0x2224, 0x2224, 5.2), (0x2225, 0x2225, 3.8),
+    (0x2226, 0x2226, 5.78), (0x2227, 0x2228, 6.53), (0x2229, 0x2229, 7.94), (0x222a, 0x222a, 5.84),
+    (0x222b, 0x222b, 6.99), (0x222c, 0x222c, 10.06), (0x222d, 0x222d, 12.36),
+    (0x222e, 0x222e, 7.46), (0x222f, 0x222f, 10.16), (0x2230, 0x2230, 12.83),
+    (0x2231, 0x2231, 6.58), (0x2232, 0x2232, 6.91), (0x2233, 0x2233, 6.57), (0x2234, 0x2234, 6.64),
+    (0x2235, 0x2235, 6.57), (0x2236, 0x2236, 3.7), (0x2237, 0x2237, 6.33), (0x2238, 0x2238, 6.19),
+    (0x2239, 0x2239, 6.33), (0x223a, 0x223a, 6.38), (0x223b, 0x223b, 6.16), (0x223c, 0x223d, 6.05),
+    (0x223e, 0x223e, 5.97), (0x223f, 0x223f, 6.42), (0x2240, 0x2240, 4.29), (0x2241, 0x2241, 6.32),
+    (0x2242, 0x2242, 6.38), (0x2243, 0x2243, 6.43), (0x2244, 0x2244, 7.38), (0x2245, 0x2245, 7.66),
+    (0x2246, 0x2246, 6.92), (0x2247, 0x2247, 6.72), (0x2248, 0x2248, 9.0), (0x2249, 0x2249, 6.04),
+    (0x224a, 0x224a, 6.64), (0x224b, 0x224b, 7.38), (0x224c, 0x224c, 5.97), (0x224d, 0x224d, 6.63),
+    (0x224e, 0x224e, 6.57), (0x224f, 0x224f, 6.52), (0x2250, 0x2250, 6.16), (0x2251, 0x2251, 7.02),
+    (0x2252, 0x2253, 6.96), (0x2254, 0x2255, 8.4), (0x2256, 0x2256, 6.36), (0x2257, 0x2257, 6.55),
+    (0x2258, 0x2258, 6.48), (0x2259, 0x2259, 6.69), (0x225a, 0x225a, 6.81), (0x225b, 0x225b, 7.11),
+    (0x225c, 0x225c, 7.0), (0x225d, 0x225d, 6.19), (0x225e, 0x225e, 6.32), (0x225f, 0x225f, 6.49),
+    (0x2260, 0x2261, 9.0), (0x2262, 0x2262, 6.2), (0x2263, 0x2263, 6.52), (0x2264, 0x2265, 9.0),
+    (0x2266, 0x2266, 6.32), (0x2267, 0x2267, 6.2), (0x2268, 0x2268, 6.84), (0x2269, 0x2269, 6.48),
+    (0x226a, 0x226a, 8.07), (0x226b, 0x226b, 7.71), (0x226c, 0x226c, 3.94), (0x226d, 0x226d, 6.09),
+    (0x226e, 0x226f, 6.2), (0x2270, 0x2271, 6.04), (0x2272, 0x2272, 6.2), (0x2273, 0x2273, 6.24),
+    (0x2274, 0x2274, 6.41), (0x2275, 0x2275, 6.39), (0x2276, 0x2277, 6.66), (0x2278, 0x2278, 6.17),
+    (0x2279, 0x2279, 6.34), (0x227a, 0x227a, 6.24), (0x227b, 0x227b, 6.36), (0x227c, 0x227c, 6.56),
+    (0x227d, 0x227d, 6.36), (0x227e, 0x227e, 6.44), (0x227f, 0x227f, 6.4), (0x2280, 0x2280, 6.68),
+    (0x2281, 0x2281, 6.36), (0x2282, 0x2283, 6.95), (0x2284, 0x2284, 7.08), (0x2285, 0x2285, 6.94),
+    (0x2286, 0x2287, 7.29), (0x2288, 0x2288, 7.34), (0x2289, 0x2289, 7.14), (0x228a, 0x228b, 6.18),
+    (0x228c, 0x228d, 6.4), (0x228e, 0x228e, 6.7), (0x228f, 0x2290, 7.19), (0x2291, 0x2292, 7.17),
+    (0x2293, 0x2294, 6.72), (0x2295, 0x2295, 7.96), (0x2296, 0x2296, 8.2), (0x2297, 0x2297, 7.83),
+    (0x2298, 0x2298, 7.94), (0x2299, 0x2299, 8.32), (0x229a, 0x229a, 8.09), (0x229b, 0x229b, 7.9),
+    (0x229c, 0x229c, 8.37), (0x229d, 0x229d, 8.31), (0x229e, 0x229e, 8.62), (0x229f, 0x229f, 8.36),
+    (0x22a0, 0x22a0, 8.06), (0x22a1, 0x22a1, 7.87), (0x22a2, 0x22a5, 8.26), (0x22a6, 0x22a6, 3.63),
+    (0x22a7, 0x22a7, 3.67), (0x22a8, 0x22a8, 6.86), (0x22a9, 0x22a9, 8.02), (0x22aa, 0x22aa, 6.85),
+    (0x22ab, 0x22ab, 8.31), (0x22ac, 0x22ac, 8.53), (0x22ad, 0x22ad, 8.56), (0x22ae, 0x22ae, 9.61),
+    (0x22af, 0x22af, 9.2), (0x22b0, 0x22b1, 6.96), (0x22b2, 0x22b3, 8.15), (0x22b4, 0x22b5, 7.73),
+    (0x22b6, 0x22b7, 8.08), (0x22b8, 0x22b8, 6.55), (0x22b9, 0x22b9, 7.51), (0x22ba, 0x22ba, 5.28),
+    (0x22bb, 0x22bc, 7.66), (0x22bd, 0x22bd, 7.42), (0x22be, 0x22be, 8.16), (0x22bf, 0x22bf, 6.94),
+    (0x22c0, 0x22c1, 7.38), (0x22c2, 0x22c3, 7.05), (0x22c4, 0x22c4, 4.19), (0x22c5, 0x22c5, 4.85),
+    (0x22c6, 0x22c6, 4.93), (0x22c7, 0x22c7, 6.94), (0x22c8, 0x22c8, 8.5), (0x22c9, 0x22ca, 8.14),
+    (0x22cb, 0x22cc, 7.96), (0x22cd, 0x22cd, 6.43), (0x22ce, 0x22cf, 6.98), (0x22d0, 0x22d1, 7.13),
+    (0x22d2, 0x22d3, 6.26), (0x22d4, 0x22d4, 6.04), (0x22d5, 0x22d5, 6.75), (0x22d6, 0x22d7, 6.63),
+    (0x22d8, 0x22d9, 10.65), (0x22da, 0x22db, 7.16), (0x22dc, 0x22dd, 7.11), (0x22de, 0x22df, 7.36),
+    (0x22e0, 0x22e0, 7.33), (0x22e1, 0x22e1, 7.21), (0x22e2, 0x22e2, 7.55), (0x22e3, 0x22e3, 7.3),
+    (0x22e4, 0x22e4, 7.38), (0x22e5, 0x22e5, 7.41), (0x22e6, 0x22e6, 7.44), (0x22e7, 0x22e7, 7.55),
+    (0x22e8, 0x22e8, 7.91), (0x22e9, 0x22e9, 7.57), (0x22ea, 0x22ea, 8.23), (0x22eb, 0x22eb, 8.42),
+    (0x22ec, 0x22ec, 8.49), (0x22ed, 0x22ed, 8.54), (0x22ee, 0x22ee, 4.52), (0x22ef, 0x22ef, 11.0),
+    (0x22f0, 0x22f0, 10.12), (0x22f1, 0x22f1, 10.33), (0x22f2, 0x22f2, 7.15),
+    (0x22f3, 0x22f3, 6.34), (0x22f4, 0x22f4, 5.6), (0x22f5, 0x22f6, 6.34), (0x22f7, 0x22f7, 5.6),
+    (0x22f8, 0x22f8, 6.34), (0x22f9, 0x22f9, 6.95), (0x22fa, 0x22fa, 7.15), (0x22fb, 0x22fb, 6.34),
+    (0x22fc, 0x22fc, 5.6), (0x22fd, 0x22fd, 6.34), (0x22fe, 0x22fe, 5.6), (0x2300, 0x2301, 6.62),
+    (0x2302, 0x2302, 6.6), (0x2303, 0x2303, 8.74), (0x2304, 0x2304, 6.62), (0x2305, 0x2305, 8.74),
+    (0x2306, 0x2306, 7.3), (0x2307, 0x2307, 11.0), (0x2308, 0x230f, 6.62), (0x2310, 0x2310, 6.2),
+    (0x2311, 0x2311, 6.62), (0x2312, 0x2312, 11.0), (0x2313, 0x2313, 6.62), (0x2314, 0x2314, 9.51),
+    (0x2315, 0x2315, 6.62), (0x2316, 0x2317, 12.1), (0x2318, 0x2318, 11.0), (0x2319, 0x2319, 6.62),
+    (0x231a, 0x231b, 11.0), (0x231c, 0x231f, 6.62), (0x2320, 0x2321, 6.64), (0x2322, 0x2323, 9.51),
+    (0x2324, 0x2324, 8.74), (0x2325, 0x2325, 11.0), (0x2326, 0x2326, 13.35), (0x2327, 0x2327, 11.0),
+    (0x2328, 0x2328, 6.62), (0x2329, 0x232a, 11.0), (0x232b, 0x232b, 13.35),
+    (0x232c, 0x232c, 10.19), (0x232d, 0x232d, 10.85), (0x232e, 0x232e, 6.86),
+    (0x232f, 0x2331, 6.04), (0x2332, 0x2332, 9.78), (0x2333, 0x2334, 6.04), (0x2335, 0x2335, 6.62),
+    (0x2336, 0x2336, 10.19), (0x2337, 0x233c, 6.62), (0x233d, 0x233d, 11.0), (0x233e, 0x233e, 6.62),
+    (0x233f, 0x2340, 7.51), (0x2341, 0x2344, 6.62), (0x2345, 0x2346, 6.6), (0x2347, 0x2349, 6.62),
+    (0x234a, 0x234a, 6.6), (0x234b, 0x234d, 6.62), (0x234e, 0x234f, 6.6), (0x2350, 0x2350, 6.62),
+    (0x2351, 0x2351, 6.6), (0x2352, 0x2354, 6.62), (0x2355, 0x2356, 6.6), (0x2357, 0x235c, 6.62),
+    (0x235d, 0x235d, 6.6), (0x235e, 0x2360, 6.62), (0x2361, 0x2362, 6.6), (0x2363, 0x2365, 6.62),
+    (0x2366, 0x2367, 6.6), (0x2368, 0x2369, 6.62), (0x236a, 0x236a, 6.6), (0x236b, 0x2370, 6.62),
+    (0x2371, 0x2372, 6.6), (0x2373, 0x237a, 6.62), (0x237b, 0x237b, 7.36), (0x237c, 0x237c, 9.58),
+    (0x237d, 0x237d, 3.48), (0x237e, 0x237e, 10.33), (0x237f, 0x237f, 4.08), (0x2380, 0x2383, 6.62),
+    (0x2384, 0x2384, 8.63), (0x2385, 0x2385, 7.52), (0x2386, 0x2386, 7.42), (0x2387, 0x2388, 11.0),
+    (0x2389, 0x238a, 6.62), (0x238b, 0x238b, 11.0), (0x238c, 0x238c, 8.88), (0x238d, 0x238d, 8.65),
+    (0x238e, 0x238e, 8.22), (0x238f, 0x2392, 7.24), (0x2393, 0x2393, 10.04),
+    (0x2394, 0x2394, 10.19), (0x2395, 0x2395, 6.62), (0x2396, 0x2396, 5.19), (0x2397, 0x2398, 8.67),
+    (0x2399, 0x239a, 8.97), (0x239b, 0x23ad, 11.0), (0x23ae, 0x23ae, 6.62), (0x23af, 0x23af, 3.46),
+    (0x23b0, 0x23b1, 11.0), (0x23b2, 0x23b3, 16.37), (0x23b4, 0x23b6, 10.19),
+    (0x23b7, 0x23b7, 7.07), (0x23b8, 0x23bd, 5.54), (0x23be, 0x23cc, 11.0), (0x23cd, 0x23cd, 10.97),
+    (0x23ce, 0x23ce, 11.0), (0x23cf, 0x23cf, 9.43), (0x23d0, 0x23d0, 5.62), (0x23d1, 0x23d1, 5.96),
+    (0x23d2, 0x23d3, 5.97), (0x23d4, 0x23d5, 11.34), (0x23d6, 0x23d6, 10.54),
+    (0x23d7, 0x23d9, 10.73), (0x23da, 0x23e1, 11.0), (0x23e2, 0x23e2, 12.58),
+    (0x23e3, 0x23e3, 10.01), (0x23e4, 0x23e4, 12.03), (0x23e5, 0x23e5, 11.2),
+    (0x23e6, 0x23e6, 10.19), (0x23e7, 0x23e7, 8.78), (0x23e8, 0x23fe, 11.0), (0x2400, 0x241f, 11.0),
+    (0x2420, 0x2420, 6.6), (0x2421, 0x2421, 11.0), (0x2422, 0x2422, 6.6), (0x2423, 0x2423, 3.48),
+    (0x2424, 0x2424, 6.6), (0x2425, 0x2425, 8.71), (0x2426, 0x2426, 4.44), (0x2427, 0x243e, 11.0),
+    (0x2440, 0x2445, 6.64), (0x2446, 0x2449, 8.25), (0x244a, 0x244a, 6.64), (0x244b, 0x245e, 11.0),
+    (0x2460, 0x24c4, 11.0), (0x24c5, 0x24c5, 9.41), (0x24c6, 0x24fe, 11.0), (0x2500, 0x2500, 7.79),
+    (0x2501, 0x2501, 11.0), (0x2502, 0x2502, 7.79), (0x2503, 0x250b, 11.0), (0x250c, 0x250c, 7.79),
+    (0x250d, 0x250f, 11.0), (0x2510, 0x2510, 7.79), (0x2511, 0x2513, 11.0), (0x2514, 0x2514, 7.79),
+    (0x2515, 0x2517, 11.0), (0x2518, 0x2518, 7.79), (0x2519, 0x251b, 11.0), (0x251c, 0x251c, 7.79),
+    (0x251d, 0x2523, 11.0), (0x2524, 0x2524, 7.79), (0x2525, 0x252b, 11.0), (0x252c, 0x252c, 7.79),
+    (0x252d, 0x2533, 11.0), (0x2534, 0x2534, 7.79), (0x2535, 0x253b, 11.0), (0x253c, 0x253c, 7.79),
+    (0x253d, 0x254b, 11.0), (0x254c, 0x254f, 6.62), (0x2550, 0x256c, 7.79), (0x256d, 0x2574, 11.0),
+    (0x2575, 0x257e, 6.62), (0x2580, 0x2580, 7.79), (0x2581, 0x2583, 11.0), (0x2584, 0x2584, 7.79),
+    (0x2585, 0x2587, 11.0), (0x2588, 0x2588, 7.79), (0x2589, 0x258b, 11.0), (0x258c, 0x258c, 7.79),
+    (0x258d, 0x258f, 11.0), (0x2590, 0x2592, 7.79), (0x2593, 0x2593, 8.02), (0x2594, 0x2595, 11.0),
+    (0x2596, 0x259e, 6.62), (0x25a0, 0x25a1, 6.64), (0x25a2, 0x25a2, 11.0), (0x25a3, 0x25a9, 9.51),
+    (0x25aa, 0x25ab, 3.9), (0x25ac, 0x25ac, 11.0), (0x25ad, 0x25ad, 9.51), (0x25ae, 0x25b0, 6.62),
+    (0x25b1, 0x25b1, 11.0), (0x25b2, 0x25b2, 10.89), (0x25b3, 0x25b3, 11.0), (0x25b4, 0x25b4, 6.06),
+    (0x25b5, 0x25b5, 9.51), (0x25b6, 0x25b6, 9.68), (0x25b7, 0x25b7, 11.0), (0x25b8, 0x25b9, 5.83),
+    (0x25ba, 0x25ba, 10.89), (0x25bb, 0x25bb, 6.62), (0x25bc, 0x25bc, 10.89),
+    (0x25bd, 0x25bd, 11.0), (0x25be, 0x25bf, 7.03), (0x25c0, 0x25c0, 11.0), (0x25c1, 0x25c1, 8.74),
+    (0x25c2, 0x25c2, 6.62), (0x25c3, 0x25c3, 9.51), (0x25c4, 0x25c4, 10.89), (0x25c5, 0x25c5, 6.62),
+    (0x25c6, 0x25c6, 7.95), (0x25c7, 0x25c7, 6.76), (0x25c8, 0x25c8, 9.51), (0x25c9, 0x25c9, 11.0),
+    (0x25ca, 0x25ca, 9.0), (0x25cb, 0x25cb, 6.64), (0x25cc, 0x25cc, 6.54), (0x25cd, 0x25cd, 6.62),
+    (0x25ce, 0x25ce, 11.0), (0x25cf, 0x25cf, 6.64), (0x25d0, 0x25d3, 11.0), (0x25d4, 0x25d7, 6.62),
+    (0x25d8, 0x25d9, 6.64), (0x25da, 0x25e1, 6.62), (0x25e2, 0x25e5, 11.0), (0x25e6, 0x25e6, 3.9),
+    (0x25e7, 0x25ee, 6.62), (0x25ef, 0x25ef, 11.0), (0x25f0, 0x25fa, 6.62), (0x25fb, 0x25fe, 11.0),
+    (0x2600, 0x2603, 11.0), (0x2604, 0x2604, 6.62), (0x2605, 0x2606, 11.0), (0x2607, 0x2608, 6.62),
+    (0x2609, 0x2609, 11.0), (0x260a, 0x260d, 6.62), (0x260e, 0x260e, 11.0), (0x260f, 0x260f, 9.51),
+    (0x2610, 0x2612, 9.13), (0x2613, 0x2613, 6.62), (0x2614, 0x2617, 11.0), (0x2618, 0x261b, 6.62),
+    (0x261c, 0x261f, 11.0), (0x2620, 0x262e, 6.62), (0x262f, 0x262f, 9.51), (0x2630, 0x2637, 9.9),
+    (0x2638, 0x2639, 6.62), (0x263a, 0x263a, 11.23), (0x263b, 0x263b, 11.57),
+    (0x263c, 0x263c, 10.08), (0x263d, 0x263f, 6.62), (0x2640, 0x2640, 8.25), (0x2641, 0x2641, 6.62),
+    (0x2642, 0x2642, 8.25), (0x2643, 0x2647, 6.62), (0x2648, 0x2653, 11.0), (0x2654, 0x265f, 6.62),
+    (0x2660, 0x2660, 5.84), (0x2661, 0x2662, 11.0), (0x2663, 0x2663, 7.22), (0x2664, 0x2664, 11.0),
+    (0x2665, 0x2665, 6.53), (0x2666, 0x2666, 5.61), (0x2667, 0x2669, 11.0), (0x266a, 0x266a, 5.5),
+    (0x266b, 0x266b, 8.25), (0x266c, 0x266e, 11.0), (0x266f, 0x266f, 5.5), (0x2670, 0x2671, 6.62),
+    (0x2672, 0x267d, 11.0), (0x267e, 0x267e, 6.62), (0x267f, 0x267f, 11.0), (0x2680, 0x268b, 6.62),
+    (0x268c, 0x268c, 9.67), (0x268d, 0x268d, 9.49), (0x268e, 0x268e, 9.45), (0x268f, 0x268f, 9.86),
+    (0x2690, 0x2692, 6.62), (0x2693, 0x2693, 11.0), (0x2694, 0x269c, 6.62), (0x269d, 0x26a1, 11.0),
+    (0x26a2, 0x26a2, 9.81), (0x26a3, 0x26a3, 9.87), (0x26a4, 0x26a4, 9.71), (0x26a5, 0x26a5, 7.26),
+    (0x26a6, 0x26a6, 8.15), (0x26a7, 0x26a7, 10.16), (0x26a8, 0x26a8, 5.86),
+    (0x26a9, 0x26a9, 10.12), (0x26aa, 0x26ab, 11.0), (0x26ac, 0x26ac, 6.21), (0x26ad, 0x26ad, 8.54),
+    (0x26ae, 0x26ae, 11.61), (0x26af, 0x26af, 12.3), (0x26b0, 0x26b1, 6.62), (0x26b2, 0x26b2, 6.63),
+    (0x26b3, 0x26e1, 11.0), (0x26e2, 0x26e2, 7.7), (0x26e3, 0x26fe, 11.0), (0x2700, 0x2700, 11.0),
+    (0x2701, 0x2701, 10.72), (0x2702, 0x2702, 10.57), (0x2703, 0x2703, 10.72),
+    (0x2704, 0x2704, 10.78), (0x2705, 0x2705, 11.0), (0x2706, 0x2706, 8.68), (0x2707, 0x2707, 8.69),
+    (0x2708, 0x2708, 8.7), (0x2709, 0x2709, 7.59), (0x270a, 0x270d, 11.0), (0x270e, 0x270e, 8.66),
+    (0x270f, 0x270f, 10.26), (0x2710, 0x2710, 10.02), (0x2711, 0x2711, 10.39),
+    (0x2712, 0x2712, 10.72), (0x2713, 0x2713, 8.41), (0x2714, 0x2714, 9.31), (0x2715, 0x2715, 8.38),
+    (0x2716, 0x2716, 8.37), (0x2717, 0x2717, 6.28), (0x2718, 0x2718, 7.44), (0x2719, 0x2719, 8.4),
+    (0x271a, 0x271a, 8.36), (0x271b, 0x271b, 8.35), (0x271c, 0x271c, 8.29), (0x271d, 0x271d, 5.44),
+    (0x271e, 0x271e, 6.07), (0x271f, 0x271f, 5.91), (0x2720, 0x2720, 6.35), (0x2721, 0x2721, 7.61),
+    (0x2722, 0x2722, 8.65), (0x2723, 0x2724, 8.67), (0x2725, 0x2725, 8.69), (0x2726, 0x2726, 8.72),
+    (0x2727, 0x2727, 8.73), (0x2728, 0x2728, 11.0), (0x2729, 0x2729, 9.06), (0x272a, 0x272a, 8.68),
+    (0x272b, 0x272b, 9.25), (0x272c, 0x272c, 9.06), (0x272d, 0x272d, 9.16), (0x272e, 0x272e, 8.98),
+    (0x272f, 0x272f, 9.14), (0x2730, 0x2730, 10.15), (0x2731, 0x2731, 8.19), (0x2732, 0x2732, 7.95),
+    (0x2733, 0x2733, 8.24), (0x2734, 0x2734, 8.69), (0x2735, 0x2735, 8.71), (0x2736, 0x2736, 7.64),
+    (0x2737, 0x2737, 8.53), (0x2738, 0x2738, 8.45), (0x2739, 0x2739, 8.71), (0x273a, 0x273a, 8.35),
+    (0x273b, 0x273b, 7.78), (0x273c, 0x273c, 7.79), (0x273d, 0x273d, 7.5), (0x273e, 0x273e, 7.71),
+    (0x273f, 0x273f, 9.09), (0x2740, 0x2740, 8.96), (0x2741, 0x2742, 8.68), (0x2743, 0x2743, 7.78),
+    (0x2744, 0x2744, 7.56), (0x2745, 0x2745, 7.65), (0x2746, 0x2746, 7.58), (0x2747, 0x2747, 8.65),
+    (0x2748, 0x2748, 8.66), (0x2749, 0x2749, 7.84), (0x274a, 0x274a, 8.7), (0x274b, 0x274b, 8.64),
+    (0x274c, 0x274c, 11.0), (0x274d, 0x274d, 9.6), (0x274e, 0x274e, 11.0), (0x274f, 0x2750, 8.38),
+    (0x2751, 0x2752, 8.35), (0x2753, 0x2755, 11.0), (0x2756, 0x2756, 8.63), (0x2757, 0x2757, 11.0),
+    (0x2758, 0x2758, 3.18), (0x2759, 0x2759, 3.05), (0x275a, 0x275a, 4.57), (0x275b, 0x275c, 4.31),
+    (0x275d, 0x275e, 7.35), (0x275f, 0x2760, 11.0), (0x2761, 0x2761, 8.05), (0x2762, 0x2763, 5.98),
+    (0x2764, 0x2764, 10.01), (0x2765, 0x2765, 7.34), (0x2766, 0x2767, 8.36), (0x2768, 0x2769, 4.29),
+    (0x276a, 0x276b, 3.49), (0x276c, 0x276d, 3.03), (0x276e, 0x276f, 5.6), (0x2770, 0x2771, 4.51),
+    (0x2772, 0x2773, 2.57), (0x2774, 0x2775, 3.67), (0x2776, 0x2793, 8.67), (0x2794, 0x2794, 9.83),
+    (0x2795, 0x2797, 11.0), (0x2798, 0x2798, 8.23), (0x2799, 0x2799, 10.16), (0x279a, 0x279a, 8.23),
+    (0x279b, 0x279b, 10.1), (0x279c, 0x279c, 10.19), (0x279d, 0x279e, 10.21),
+    (0x279f, 0x279f, 9.17), (0x27a0, 0x27a0, 9.6), (0x27a1, 0x27a1, 9.11), (0x27a2, 0x27a3, 10.16),
+    (0x27a4, 0x27a4, 10.09), (0x27a5, 0x27a5, 10.23), (0x27a6, 0x27a6, 10.24),
+    (0x27a7, 0x27a7, 5.09), (0x27a8, 0x27a8, 9.71), (0x27a9, 0x27aa, 9.2), (0x27ab, 0x27ac, 9.54),
+    (0x27ad, 0x27ae, 7.65), (0x27af, 0x27af, 9.61), (0x27b0, 0x27b0, 11.0), (0x27b1, 0x27b1, 9.61),
+    (0x27b2, 0x27b2, 8.36), (0x27b3, 0x27b3, 10.4), (0x27b4, 0x27b4, 8.48), (0x27b5, 0x27b5, 9.52),
+    (0x27b6, 0x27b6, 8.48), (0x27b7, 0x27b7, 9.77), (0x27b8, 0x27b8, 10.63), (0x27b9, 0x27b9, 9.77),
+    (0x27ba, 0x27ba, 9.14), (0x27bb, 0x27bb, 9.6), (0x27bc, 0x27bc, 10.19), (0x27bd, 0x27bd, 10.67),
+    (0x27be, 0x27be, 10.1), (0x27c0, 0x27c0, 6.03), (0x27c1, 0x27c1, 10.43), (0x27c2, 0x27c2, 7.58),
+    (0x27c3, 0x27c4, 6.21), (0x27c5, 0x27c6, 6.62), (0x27c7, 0x27c7, 6.82), (0x27c8, 0x27c9, 10.1),
+    (0x27ca, 0x27ca, 3.74), (0x27cb, 0x27cb, 9.22), (0x27cc, 0x27cc, 3.57), (0x27cd, 0x27cd, 9.22),
+    (0x27ce, 0x27cf, 11.0), (0x27d0, 0x27d0, 8.21), (0x27d1, 0x27d1, 6.82), (0x27d2, 0x27d2, 6.57),
+    (0x27d3, 0x27d4, 5.97), (0x27d5, 0x27d6, 9.93), (0x27d7, 0x27d7, 12.26), (0x27d8, 0x27d9, 7.58),
+    (0x27da, 0x27da, 9.57), (0x27db, 0x27db, 9.42), (0x27dc, 0x27dc, 11.6), (0x27dd, 0x27de, 9.33),
+    (0x27df, 0x27df, 4.36), (0x27e0, 0x27e0, 6.06), (0x27e1, 0x27e1, 7.7), (0x27e2, 0x27e3, 9.64),
+    (0x27e4, 0x27e5, 10.41), (0x27e6, 0x27e7, 4.64), (0x27e8, 0x27e9, 4.02), (0x27ea, 0x27eb, 5.1),
+    (0x27ec, 0x27ed, 5.37), (0x27ee, 0x27ee, 2.56), (0x27f0, 0x27f1, 11.36),
+    (0x27f2, 0x27f3, 10.71), (0x27f4, 0x27f4, 13.2), (0x27f5, 0x27fe, 17.31),
+    (0x2800, 0x28fe, 7.52), (0x2900, 0x2907, 10.19), (0x2908, 0x2909, 5.62),
+    (0x290a, 0x290f, 10.19), (0x2910, 0x2910, 15.53), (0x2911, 0x2911, 10.19),
+    (0x2912, 0x2913, 5.62), (0x2914, 0x2933, 10.19), (0x2934, 0x2935, 11.0), (0x2936, 0x2937, 9.51),
+    (0x2938, 0x2938, 5.62), (0x2939, 0x2939, 9.51), (0x293a, 0x2948, 10.19), (0x2949, 0x2949, 5.62),
+    (0x294a, 0x294b, 10.19), (0x294c, 0x294d, 5.62), (0x294e, 0x294e, 10.19),
+    (0x294f, 0x294f, 5.62), (0x2950, 0x2950, 10.19), (0x2951, 0x2951, 5.62),
+    (0x2952, 0x2953, 10.19), (0x2954, 0x2955, 5.62), (0x2956, 0x2957, 10.19),
+    (0x2958, 0x2959, 5.62), (0x295a, 0x295b, 10.19), (0x295c, 0x295d, 5.62),
+    (0x295e, 0x295f, 10.19), (0x2960, 0x2961, 5.62), (0x2962, 0x2965, 9.51),
+    (0x2966, 0x296d, 10.19), (0x296e, 0x296f, 7.53), (0x2970, 0x2975, 10.19),
+    (0x2976, 0x2976, 7.53), (0x2977, 0x2977, 10.19), (0x2978, 0x2979, 7.53),
+    (0x297a, 0x297a, 10.19), (0x297b, 0x297b, 7.53), (0x297c, 0x297d, 10.19),
+    (0x297e, 0x297e, 7.53), (0x2980, 0x2980, 4.01), (0x2981, 0x2981, 4.18), (0x2982, 0x2982, 3.45),
+    (0x2983, 0x2984, 4.11), (0x2985, 0x2986, 4.92), (0x2987, 0x2988, 3.68), (0x2989, 0x298a, 4.35),
+    (0x298b, 0x298c, 3.31), (0x298d, 0x2990, 3.3), (0x2991, 0x2991, 4.02), (0x2992, 0x2992, 4.04),
+    (0x2993, 0x2994, 7.25), (0x2995, 0x2996, 7.65), (0x2997, 0x2998, 7.13), (0x2999, 0x2999, 2.48),
+    (0x299a, 0x299a, 3.05), (0x299b, 0x299b, 6.53), (0x299c, 0x299d, 6.51), (0x299e, 0x299e, 7.27),
+    (0x299f, 0x299f, 6.03), (0x29a0, 0x29a1, 6.53), (0x29a2, 0x29a5, 6.03), (0x29a6, 0x29a7, 8.28),
+    (0x29a8, 0x29af, 6.53), (0x29b0, 0x29b4, 7.73), (0x29b5, 0x29b5, 9.56), (0x29b6, 0x29b6, 7.7),
+    (0x29b7, 0x29b7, 7.72), (0x29b8, 0x29b8, 7.94), (0x29b9, 0x29b9, 7.72), (0x29ba, 0x29ba, 7.73),
+    (0x29bb, 0x29bb, 7.74), (0x29bc, 0x29c1, 7.72), (0x29c2, 0x29c2, 9.41), (0x29c3, 0x29c3, 9.61),
+    (0x29c4, 0x29c8, 8.22), (0x29c9, 0x29c9, 8.75), (0x29ca, 0x29cc, 8.23), (0x29cd, 0x29cd, 10.38),
+    (0x29ce, 0x29ce, 7.15), (0x29cf, 0x29d0, 8.34), (0x29d1, 0x29d3, 7.56), (0x29d4, 0x29d5, 7.17),
+    (0x29d6, 0x29d7, 8.44), (0x29d8, 0x29d9, 3.05), (0x29da, 0x29db, 4.66), (0x29dc, 0x29dc, 7.01),
+    (0x29dd, 0x29dd, 7.12), (0x29de, 0x29de, 7.01), (0x29df, 0x29df, 7.41), (0x29e0, 0x29e0, 8.22),
+    (0x29e1, 0x29e1, 7.19), (0x29e2, 0x29e2, 7.51), (0x29e3, 0x29e5, 7.3), (0x29e6, 0x29e6, 7.81),
+    (0x29e7, 0x29e7, 6.99), (0x29e8, 0x29e9, 8.23), (0x29ea, 0x29ea, 7.89), (0x29eb, 0x29eb, 5.2),
+    (0x29ec, 0x29ed, 9.89), (0x29ee, 0x29ef, 6.57), (0x29f0, 0x29f1, 7.79), (0x29f2, 0x29f3, 7.72),
+    (0x29f4, 0x29f4, 9.0), (0x29f5, 0x29f5, 6.58), (0x29f6, 0x29f6, 6.34), (0x29f7, 0x29f9, 5.59),
+    (0x29fa, 0x29fb, 7.43), (0x29fc, 0x29fd, 5.91), (0x29fe, 0x29fe, 5.2), (0x2a00, 0x2a01, 9.89),
+    (0x2a02, 0x2a02, 9.92), (0x2a03, 0x2a04, 5.99), (0x2a05, 0x2a06, 6.55), (0x2a07, 0x2a08, 8.43),
+    (0x2a09, 0x2a09, 7.33), (0x2a0a, 0x2a0a, 9.41), (0x2a0b, 0x2a0b, 7.18), (0x2a0c, 0x2a0c, 16.56),
+    (0x2a0d, 0x2a0f, 6.72), (0x2a10, 0x2a10, 5.49), (0x2a11, 0x2a11, 8.01), (0x2a12, 0x2a12, 5.71),
+    (0x2a13, 0x2a13, 5.49), (0x2a14, 0x2a14, 6.91), (0x2a15, 0x2a15, 5.49), (0x2a16, 0x2a16, 6.72),
+    (0x2a17, 0x2a17, 7.43), (0x2a18, 0x2a1a, 6.72), (0x2a1b, 0x2a1b, 7.02), (0x2a1c, 0x2a1c, 6.79),
+    (0x2a1d, 0x2a1d, 7.56), (0x2a1e, 0x2a1e, 4.35), (0x2a1f, 0x2a1f, 3.67), (0x2a20, 0x2a20, 8.92),
+    (0x2a21, 0x2a21, 3.59), (0x2a22, 0x2a23, 5.95), (0x2a24, 0x2a24, 5.99), (0x2a25, 0x2a25, 5.95),
+    (0x2a26, 0x2a26, 5.99), (0x2a27, 0x2a27, 7.12), (0x2a28, 0x2a28, 7.02), (0x2a29, 0x2a2a, 5.99),
+    (0x2a2b, 0x2a2c, 5.97), (0x2a2d, 0x2a2e, 8.37), (0x2a2f, 0x2a2f, 5.04), (0x2a30, 0x2a30, 5.82),
+    (0x2a31, 0x2a31, 5.96), (0x2a32, 0x2a32, 5.74), (0x2a33, 0x2a33, 8.85), (0x2a34, 0x2a35, 7.86),
+    (0x2a36, 0x2a38, 9.89), (0x2a39, 0x2a3b, 8.23), (0x2a3c, 0x2a3d, 5.63), (0x2a3e, 0x2a3e, 3.67),
+    (0x2a3f, 0x2a3f, 6.77), (0x2a40, 0x2a45, 5.99), (0x2a46, 0x2a47, 4.27), (0x2a48, 0x2a49, 5.96),
+    (0x2a4a, 0x2a4b, 5.62), (0x2a4c, 0x2a4d, 8.13), (0x2a4e, 0x2a4f, 6.55), (0x2a50, 0x2a50, 8.13),
+    (0x2a51, 0x2a52, 6.71), (0x2a53, 0x2a54, 7.17), (0x2a55, 0x2a56, 8.28), (0x2a57, 0x2a58, 7.17),
+    (0x2a59, 0x2a59, 6.67), (0x2a5a, 0x2a5b, 7.17), (0x2a5c, 0x2a5d, 8.12), (0x2a5e, 0x2a60, 6.71),
+    (0x2a61, 0x2a61, 5.97), (0x2a62, 0x2a63, 6.71), (0x2a64, 0x2a65, 7.34), (0x2a66, 0x2a69, 5.96),
+    (0x2a6a, 0x2a6d, 5.98), (0x2a6e, 0x2a6e, 5.33), (0x2a6f, 0x2a70, 5.98), (0x2a71, 0x2a72, 5.97),
+    (0x2a73, 0x2a73, 5.99), (0x2a74, 0x2a74, 9.33), (0x2a75, 0x2a75, 10.82),
+    (0x2a76, 0x2a76, 16.03), (0x2a77, 0x2a77, 6.0), (0x2a78, 0x2a78, 6.55), (0x2a79, 0x2a7a, 7.25),
+    (0x2a7b, 0x2a7c, 5.63), (0x2a7d, 0x2a7f, 5.97), (0x2a80, 0x2a80, 5.98), (0x2a81, 0x2a84, 5.97),
+    (0x2a85, 0x2a86, 5.98), (0x2a87, 0x2a87, 6.06), (0x2a88, 0x2a88, 6.27), (0x2a89, 0x2a8a, 5.98),
+    (0x2a8b, 0x2a8c, 5.97), (0x2a8d, 0x2a8d, 6.06), (0x2a8e, 0x2a8e, 6.03), (0x2a8f, 0x2a90, 5.99),
+    (0x2a91, 0x2a97, 5.97), (0x2a98, 0x2a9a, 5.98), (0x2a9b, 0x2a9c, 5.97), (0x2a9d, 0x2a9e, 5.98),
+    (0x2a9f, 0x2a9f, 6.07), (0x2aa0, 0x2aa0, 6.0), (0x2aa1, 0x2aa2, 7.51), (0x2aa3, 0x2aa3, 8.87),
+    (0x2aa4, 0x2aa4, 5.99), (0x2aa5, 0x2aa5, 10.29), (0x2aa6, 0x2aa9, 6.75), (0x2aaa, 0x2aaa, 5.63),
+    (0x2aab, 0x2aab, 5.76), (0x2aac, 0x2aac, 6.06), (0x2aad, 0x2aad, 6.11), (0x2aae, 0x2aae, 5.97),
+    (0x2aaf, 0x2ab1, 6.08), (0x2ab2, 0x2ab2, 6.06), (0x2ab3, 0x2ab6, 6.08), (0x2ab7, 0x2ab7, 6.02),
+    (0x2ab8, 0x2ab8, 6.03), (0x2ab9, 0x2ab9, 6.02), (0x2aba, 0x2aba, 6.03), (0x2abb, 0x2abc, 9.76),
+    (0x2abd, 0x2abe, 6.21), (0x2abf, 0x2ac6, 5.05), (0x2ac7, 0x2aca, 6.0), (0x2acb, 0x2acc, 5.05),
+    (0x2acd, 0x2ace, 10.48), (0x2acf, 0x2ad0, 6.21), (0x2ad1, 0x2ad2, 6.2), (0x2ad3, 0x2ad6, 5.05),
+    (0x2ad7, 0x2ad8, 9.35), (0x2ad9, 0x2adb, 6.57), (0x2adc, 0x2adc, 6.71), (0x2add, 0x2add, 6.57),
+    (0x2ade, 0x2ade, 4.32), (0x2adf, 0x2ae1, 7.58), (0x2ae2, 0x2ae2, 7.55), (0x2ae3, 0x2ae3, 7.52),
+    (0x2ae4, 0x2ae4, 7.55), (0x2ae5, 0x2ae5, 9.21), (0x2ae6, 0x2ae6, 7.54), (0x2ae7, 0x2ae9, 7.58),
+    (0x2aea, 0x2aeb, 7.62), (0x2aec, 0x2aed, 5.63), (0x2aee, 0x2aee, 3.44), (0x2aef, 0x2af0, 3.13),
+    (0x2af1, 0x2af1, 3.29), (0x2af2, 0x2af2, 4.29), (0x2af3, 0x2af3, 5.98), (0x2af4, 0x2af4, 3.84),
+    (0x2af5, 0x2af5, 5.26), (0x2af6, 0x2af6, 2.48), (0x2af7, 0x2af8, 10.68), (0x2af9, 0x2afa, 5.97),
+    (0x2afb, 0x2afb, 5.48), (0x2afc, 0x2afc, 3.9), (0x2afd, 0x2afd, 4.52), (0x2afe, 0x2afe, 3.23),
+    (0x2e80, 0x2efe, 11.0), (0x2ff0, 0x2ffe, 11.0), (0x3000, 0x302d, 11.0), (0x302e, 0x302f, 17.6),
+    (0x3030, 0x303e, 11.0), (0x3200, 0x321b, 9.51), (0x321c, 0x321c, 9.36), (0x321d, 0x325f, 11.0),
+    (0x3260, 0x327b, 9.51), (0x327c, 0x327e, 11.0), (0x327f, 0x327f, 9.31), (0x3280, 0x32fe, 11.0),
+    (0x3300, 0x337f, 11.0), (0x3380, 0x3384, 9.51), (0x3385, 0x3389, 11.0), (0x338a, 0x338c, 9.51),
+    (0x338d, 0x3390, 11.0), (0x3391, 0x3395, 9.51), (0x3396, 0x3398, 11.0), (0x3399, 0x339a, 9.51),
+    (0x339b, 0x33a6, 11.0), (0x33a7, 0x33af, 9.51), (0x33b0, 0x33b3, 11.0), (0x33b4, 0x33c1, 9.51),
+    (0x33c2, 0x33c2, 11.0), (0x33c3, 0x33c3, 9.51), (0x33c4, 0x33c4, 11.0), (0x33c5, 0x33c6, 9.51),
+    (0x33c7, 0x33c7, 9.31), (0x33c8, 0x33c8, 11.0), (0x33c9, 0x33ca, 9.51), (0x33cb, 0x33ce, 11.0),
+    (0x33cf, 0x33d0, 9.51), (0x33d1, 0x33d2, 11.0), (0x33d3, 0x33d3, 9.51), (0x33d4, 0x33d5, 11.0),
+    (0x33d6, 0x33d6, 9.51), (0x33d7, 0x33da, 11.0), (0x33db, 0x33dd, 9.51), (0x33de, 0x33fe, 11.0),
+    (0x3400, 0x4dbe, 11.0), (0x4e00, 0x9ffe, 11.0), (0xf900, 0xfafe, 11.0), (0xfe30, 0xfe4e, 11.0),
+    (0xfe70, 0xfe70, 3.43), (0xfe71, 0xfe71, 2.23), (0xfe72, 0xfe72, 3.43), (0xfe73, 0xfe73, 3.3),
+    (0xfe74, 0xfe74, 3.43), (0xfe75, 0xfe75, 11.0), (0xfe76, 0xfe76, 3.43), (0xfe77, 0xfe77, 2.23),
+    (0xfe78, 0xfe78, 3.43), (0xfe79, 0xfe79, 2.23), (0xfe7a, 0xfe7a, 3.43), (0xfe7b, 0xfe7b, 2.23),
+    (0xfe7c, 0xfe7c, 3.43), (0xfe7d, 0xfe7d, 2.23), (0xfe7e, 0xfe7e, 3.43), (0xfe7f, 0xfe7f, 2.23),
+    (0xfe80, 0xfe80, 4.54), (0xfe81, 0xfe81, 2.28), (0xfe82, 0xfe82, 2.52), (0xfe83, 0xfe83, 2.28),
+    (0xfe84, 0xfe84, 2.52), (0xfe85, 0xfe86, 4.75), (0xfe87, 0xfe87, 2.28), (0xfe88, 0xfe88, 2.52),
+    (0xfe89, 0xfe89, 7.02), (0xfe8a, 0xfe8a, 6.47), (0xfe8b, 0xfe8c, 2.69), (0xfe8d, 0xfe8d, 2.28),
+    (0xfe8e, 0xfe8e, 2.52), (0xfe8f, 0xfe90, 7.85), (0xfe91, 0xfe92, 2.69), (0xfe93, 0xfe93, 3.1),
+    (0xfe94, 0xfe94, 4.13), (0xfe95, 0xfe96, 7.85), (0xfe97, 0xfe98, 2.69), (0xfe99, 0xfe9a, 7.85),
+    (0xfe9b, 0xfe9c, 2.69), (0xfe9d, 0xfe9d, 6.19), (0xfe9e, 0xfe9e, 5.78), (0xfe9f, 0xfea0, 5.83),
+    (0xfea1, 0xfea1, 6.19), (0xfea2, 0xfea2, 5.78), (0xfea3, 0xfea4, 5.83), (0xfea5, 0xfea5, 6.19),
+    (0xfea6, 0xfea6, 5.78), (0xfea7, 0xfea8, 5.83), (0xfea9, 0xfeac, 3.71), (0xfead, 0xfeb0, 5.38),
+    (0xfeb1, 0xfeb2, 9.03), (0xfeb3, 0xfeb4, 5.84), (0xfeb5, 0xfeb6, 9.03), (0xfeb7, 0xfeb8, 5.84),
+    (0xfeb9, 0xfeba, 12.08), (0xfebb, 0xfebc, 9.31), (0xfebd, 0xfebe, 12.08),
+    (0xfebf, 0xfec0, 9.31), (0xfec1, 0xfec8, 6.4), (0xfec9, 0xfec9, 5.98), (0xfeca, 0xfeca, 4.95),
+    (0xfecb, 0xfecb, 5.78), (0xfecc, 0xfecc, 4.33), (0xfecd, 0xfecd, 5.98), (0xfece, 0xfece, 4.95),
+    (0xfecf, 0xfecf, 5.78), (0xfed0, 0xfed0, 4.33), (0xfed1, 0xfed2, 8.67), (0xfed3, 0xfed3, 2.94),
+    (0xfed4, 0xfed4, 2.89), (0xfed5, 0xfed6, 6.4), (0xfed7, 0xfed7, 2.94), (0xfed8, 0xfed8, 2.89),
+    (0xfed9, 0xfeda, 6.61), (0xfedb, 0xfedc, 4.33), (0xfedd, 0xfede, 5.57), (0xfedf, 0xfee0, 2.28),
+    (0xfee1, 0xfee2, 3.72), (0xfee3, 0xfee4, 4.33), (0xfee5, 0xfee6, 5.78), (0xfee7, 0xfee8, 2.69),
+    (0xfee9, 0xfee9, 3.1), (0xfeea, 0xfeea, 4.13), (0xfeeb, 0xfeeb, 4.95), (0xfeec, 0xfeec, 4.33),
+    (0xfeed, 0xfeee, 4.75), (0xfeef, 0xfeef, 7.02), (0xfef0, 0xfef0, 6.47), (0xfef1, 0xfef1, 7.02),
+    (0xfef2, 0xfef2, 6.47), (0xfef3, 0xfef4, 2.69), (0xfef5, 0xfef5, 5.98), (0xfef6, 0xfef6, 6.61),
+    (0xfef7, 0xfef7, 5.98), (0xfef8, 0xfef8, 6.61), (0xfef9, 0xfef9, 5.98), (0xfefa, 0xfefa, 6.61),
+    (0xfefb, 0xfefb, 5.98), (0xfefc, 0xfefc, 6.61), (0xfefd, 0xfefe, 11.0), (0xff00, 0xff60, 11.0),
+    (0xff61, 0xffa0, 5.5), (0xffa1, 0xffa1, 5.31), (0xffa2, 0xffa3, 5.5), (0xffa4, 0xffa4, 5.31),
+    (0xffa5, 0xffa6, 5.5), (0xffa7, 0xffa7, 5.31), (0xffa8, 0xffbe, 5.5), (0xffbf, 0xffc1, 11.0),
+    (0xffc2, 0xffc7, 5.5), (0xffc8, 0xffc9, 11.0), (0xffca, 0xffcf, 5.5), (0xffd0, 0xffd1, 11.0),
+    (0xffd2, 0xffd7, 5.5), (0xffd8, 0xffd9, 11.0), (0xffda, 0xffdc, 5.5), (0xffdd, 0xffe4, 11.0),
+    (0xffe5, 0xffe5, 6.99), (0xffe6, 0xffe6, 10.88), (0xffe7, 0xffe8, 11.0), (0xffe9, 0xffee, 5.5),
+    (0x10100, 0x10100, 7.52), (0x10101, 0x10101, 6.61), (0x10102, 0x10102, 7.52),
+    (0x10103, 0x10103, 6.61), (0x10104, 0x10104, 7.52), (0x10105, 0x10105, 6.61),
+    (0x10106, 0x10106, 7.68), (0x10107, 0x10107, 5.73), (0x10108, 0x10108, 7.68),
+    (0x10109, 0x10109, 5.73), (0x1010a, 0x1010a, 7.68), (0x1010b, 0x1010b, 5.73),
+    (0x1010c, 0x1010c, 7.68), (0x1010d, 0x1010d, 5.73), (0x1010e, 0x1010e, 8.48),
+    (0x1010f, 0x1010f, 7.12), (0x10110, 0x10110, 8.53), (0x10111, 0x10111, 6.85),
+    (0x10112, 0x10112, 6.96), (0x10113, 0x10113, 6.55), (0x10114, 0x10114, 6.96),
+    (0x10115, 0x10115, 6.55), (0x10116, 0x10116, 6.96), (0x10117, 0x10117, 6.55),
+    (0x10118, 0x10118, 6.96), (0x10119, 0x10119, 6.55), (0x1011a, 0x1011a, 6.96),
+    (0x1011b, 0x1011b, 6.55), (0x1011c, 0x1011c, 8.53), (0x1011d, 0x1011d, 6.85),
+    (0x1011e, 0x1011e, 8.53), (0x1011f, 0x1011f, 6.85), (0x10120, 0x10120, 8.53),
+    (0x10121, 0x10121, 6.85), (0x10122, 0x10122, 8.53), (0x10123, 0x10123, 6.85),
+    (0x10124, 0x10124, 8.27), (0x10125, 0x10125, 6.96), (0x10126, 0x10126, 8.27),
+    (0x10127, 0x10127, 6.96), (0x10128, 0x10128, 4.63), (0x10129, 0x10129, 3.02),
+    (0x1012a, 0x1012a, 4.63), (0x1012b, 0x1012b, 3.02), (0x1012c, 0x1012c, 4.63),
+    (0x1012d, 0x1012d, 3.02), (0x1012e, 0x1012e, 4.63), (0x1012f, 0x1012f, 3.02),
+    (0x10130, 0x10130, 4.63), (0x10131, 0x10131, 3.02), (0x10132, 0x10132, 9.58),
+    (0x10133, 0x10133, 6.75), (0x10134, 0x10134, 5.0), (0x10135, 0x10135, 3.79),
+    (0x10136, 0x10136, 7.62), (0x10137, 0x10138, 6.51), (0x10139, 0x10139, 6.12),
+    (0x1013a, 0x1013a, 3.02), (0x1013b, 0x1013b, 6.12), (0x1013c, 0x1013c, 3.02),
+    (0x1013d, 0x1013d, 6.12), (0x1013e, 0x1013e, 3.25), (0x10300, 0x1032e, 0.0),
+    (0x10330, 0x1034e, 0.0), (0x10400, 0x10401, 6.96), (0x10402, 0x10402, 8.72),
+    (0x10403, 0x10403, 6.23), (0x10404, 0x10404, 7.71), (0x10405, 0x10405, 7.52),
+    (0x10406, 0x10407, 4.63), (0x10408, 0x10408, 5.0), (0x10409, 0x10409, 12.3),
+    (0x1040a, 0x1040a, 12.13), (0x1040b, 0x1040b, 9.0), (0x1040c, 0x1040c, 7.62),
+    (0x1040d, 0x1040d, 8.26), (0x1040e, 0x1040e, 6.77), (0x1040f, 0x1040f, 8.27),
+    (0x10410, 0x10410, 7.52), (0x10411, 0x10412, 7.54), (0x10413, 0x10413, 6.23),
+    (0x10414, 0x10414, 8.2), (0x10415, 0x10415, 6.96), (0x10416, 0x10416, 10.7),
+    (0x10417, 0x10417, 6.77), (0x10418, 0x10419, 8.26), (0x1041a, 0x1041a, 7.62),
+    (0x1041b, 0x1041b, 8.08), (0x1041c, 0x1041c, 9.27), (0x1041d, 0x1041d, 8.27),
+    (0x1041e, 0x1041e, 8.66), (0x1041f, 0x1041f, 8.27), (0x10420, 0x10420, 6.63),
+    (0x10421, 0x10421, 7.68), (0x10422, 0x10422, 6.78), (0x10423, 0x10423, 6.77),
+    (0x10424, 0x10424, 9.01), (0x10425, 0x10425, 7.54), (0x10426, 0x10426, 8.37),
+    (0x10427, 0x10427, 7.83), (0x10428, 0x10428, 11.33), (0x10429, 0x10429, 11.49),
+    (0x1042a, 0x1042a, 8.62), (0x1042b, 0x1042b, 10.12), (0x1042c, 0x1042c, 7.49),
+    (0x1042d, 0x1042d, 7.71), (0x1042e, 0x1042e, 11.38), (0x1042f, 0x1042f, 7.77),
+    (0x10430, 0x10430, 6.61), (0x10431, 0x10431, 6.76), (0x10432, 0x10432, 6.54),
+    (0x10433, 0x10433, 5.18), (0x10434, 0x10434, 6.84), (0x10435, 0x10435, 6.55),
+    (0x10436, 0x10436, 8.78), (0x10437, 0x10437, 5.77), (0x10438, 0x10439, 7.04),
+    (0x1043a, 0x1043a, 6.51), (0x1043b, 0x1043b, 6.83), (0x1043c, 0x1043c, 7.66),
+    (0x1043d, 0x1043d, 7.01), (0x1043e, 0x1043e, 6.68), (0x1043f, 0x1043f, 7.01),
+    (0x10440, 0x10440, 6.85), (0x10441, 0x10441, 5.88), (0x10442, 0x10442, 5.46),
+    (0x10443, 0x10443, 6.51), (0x10444, 0x10444, 9.25), (0x10445, 0x10445, 6.51),
+    (0x10446, 0x10446, 7.09), (0x10447, 0x10447, 6.66), (0x10448, 0x10448, 9.64),
+    (0x10449, 0x10449, 9.76), (0x1044a, 0x1044a, 7.05), (0x1044b, 0x1044b, 8.74),
+    (0x1044c, 0x1044c, 6.28), (0x1044d, 0x1044d, 6.02), (0x1044e, 0x1044e, 9.23),
+    (0x1d100, 0x1d1fe, 9.51), (0x1d400, 0x1d7a3, 9.51), (0x1d7a4, 0x1d7fe, 11.0),
+];
+
+/// 10px Verdana, the label font of the for-the-badge style
+// Measured widths that happen to be close to 2π are not meant as TAU
+#[allow(clippy::approx_constant)]
+#[rustfmt::skip]
+pub(super) static VERDANA_10PX: &[(u32, u32, f64)] = &[
+    (0x20, 0x20, 3.52), (0x21, 0x21, 3.94), (0x22, 0x22, 4.59), (0x23, 0x23, 8.18),
+    (0x24, 0x24, 6.36), (0x25, 0x25, 10.76), (0x26, 0x26, 7.27), (0x27, 0x27, 2.69),
+    (0x28, 0x29, 4.54), (0x2a, 0x2a, 6.36), (0x2b, 0x2b, 8.18), (0x2c, 0x2c, 3.64),
+    (0x2d, 0x2d, 4.54), (0x2e, 0x2e, 3.64), (0x2f, 0x2f, 4.54), (0x30, 0x39, 6.36),
+    (0x3a, 0x3b, 4.54), (0x3c, 0x3e, 8.18), (0x3f, 0x3f, 5.45), (0x40, 0x40, 10.0),
+    (0x41, 0x41, 6.84), (0x42, 0x42, 6.86), (0x43, 0x43, 6.98), (0x44, 0x44, 7.71),
+    (0x45, 0x45, 6.32), (0x46, 0x46, 5.75), (0x47, 0x47, 7.75), (0x48, 0x48, 7.51),
+    (0x49, 0x49, 4.21), (0x4a, 0x4a, 4.55), (0x4b, 0x4b, 6.93), (0x4c, 0x4c, 5.57),
+    (0x4d, 0x4d, 8.43), (0x4e, 0x4e, 7.48), (0x4f, 0x4f, 7.87), (0x50, 0x50, 6.03),
+    (0x51, 0x51, 7.87), (0x52, 0x52, 6.95), (0x53, 0x53, 6.84), (0x54, 0x54, 6.16),
+    (0x55, 0x55, 7.32), (0x56, 0x56, 6.84), (0x57, 0x57, 9.89), (0x58, 0x58, 6.85),
+    (0x59, 0x59, 6.15), (0x5a, 0x5a, 6.85), (0x5b, 0x5d, 4.54), (0x5e, 0x5e, 8.18),
+    (0x5f, 0x60, 6.36), (0x61, 0x61, 6.01), (0x62, 0x62, 6.23), (0x63, 0x63, 5.21),
+    (0x64, 0x64, 6.23), (0x65, 0x65, 5.96), (0x66, 0x66, 3.52), (0x67, 0x67, 6.23),
+    (0x68, 0x68, 6.33), (0x69, 0x69, 2.74), (0x6a, 0x6a, 3.44), (0x6b, 0x6b, 5.92),
+    (0x6c, 0x6c, 2.74), (0x6d, 0x6d, 9.73), (0x6e, 0x6e, 6.33), (0x6f, 0x6f, 6.07),
+    (0x70, 0x71, 6.23), (0x72, 0x72, 4.27), (0x73, 0x73, 5.21), (0x74, 0x74, 3.94),
+    (0x75, 0x75, 6.33), (0x76, 0x76, 5.92), (0x77, 0x77, 8.18), (0x78, 0x79, 5.92),
+    (0x7a, 0x7a, 5.25), (0x7b, 0x7b, 6.35), (0x7c, 0x7c, 4.54), (0x7d, 0x7d, 6.35),
+    (0x7e, 0x7e, 8.18), (0xa0, 0xa0, 3.52), (0xa1, 0xa1, 3.94), (0xa2, 0xa5, 6.36),
+    (0xa6, 0xa6, 4.54), (0xa7, 0xa8, 6.36), (0xa9, 0xa9, 10.0), (0xaa, 0xaa, 5.45),
+    (0xab, 0xab, 6.45), (0xac, 0xac, 8.18), (0xad, 0xad, 0.0), (0xae, 0xae, 10.0),
+    (0xaf, 0xaf, 6.36), (0xb0, 0xb0, 5.42), (0xb1, 0xb1, 8.18), (0xb2, 0xb3, 5.42),
+    (0xb4, 0xb4, 6.36), (0xb5, 0xb5, 6.42), (0xb6, 0xb6, 6.36), (0xb7, 0xb7, 3.64),
+    (0xb8, 0xb8, 6.36), (0xb9, 0xb9, 5.42), (0xba, 0xba, 5.45), (0xbb, 0xbb, 6.45),
+    (0xbc, 0xbe, 10.0), (0xbf, 0xbf, 5.45), (0xc0, 0xc5, 6.84), (0xc6, 0xc6, 9.84),
+    (0xc7, 0xc7, 6.98), (0xc8, 0xcb, 6.32), (0xcc, 0xcf, 4.21), (0xd0, 0xd0, 7.75),
+    (0xd1, 0xd1, 7.48), (0xd2, 0xd6, 7.87), (0xd7, 0xd7, 8.18), (0xd8, 0xd8, 7.87),
+    (0xd9, 0xdc, 7.32), (0xdd, 0xdd, 6.15), (0xde, 0xde, 6.05), (0xdf, 0xdf, 6.2),
+    (0xe0, 0xe5, 6.01), (0xe6, 0xe6, 9.55), (0xe7, 0xe7, 5.21), (0xe8, 0xeb, 5.96),
+    (0xec, 0xef, 2.74), (0xf0, 0xf0, 6.12), (0xf1, 0xf1, 6.33), (0xf2, 0xf6, 6.07),
+    (0xf7, 0xf7, 8.18), (0xf8, 0xf8, 6.07), (0xf9, 0xfc, 6.33), (0xfd, 0xfd, 5.92),
+    (0xfe, 0xfe, 6.23), (0x100, 0x100, 6.84), (0x101, 0x101, 6.01), (0x102, 0x102, 6.84),
+    (0x103, 0x103, 6.01), (0x104, 0x104, 6.84), (0x105, 0x105, 6.01), (0x106, 0x106, 6.98),
+    (0x107, 0x107, 5.21), (0x108, 0x108, 6.98), (0x109, 0x109, 5.21), (0x10a, 0x10a, 6.98),
+    (0x10b, 0x10b, 5.21), (0x10c, 0x10c, 6.98), (0x10d, 0x10d, 5.21), (0x10e, 0x10e, 7.71),
+    (0x10f, 0x10f, 6.47), (0x110, 0x110, 7.75), (0x111, 0x111, 6.23), (0x112, 0x112, 6.32),
+    (0x113, 0x113, 5.96), (0x114, 0x114, 6.32), (0x115, 0x115, 5.96), (0x116, 0x116, 6.32),
+    (0x117, 0x117, 5.96), (0x118, 0x118, 6.32), (0x119, 0x119, 5.96), (0x11a, 0x11a, 6.32),
+    (0x11b, 0x11b, 5.96), (0x11c, 0x11c, 7.75), (0x11d, 0x11d, 6.23), (0x11e, 0x11e, 7.75),
+    (0x11f, 0x11f, 6.23), (0x120, 0x120, 7.75), (0x121, 0x121, 6.23), (0x122, 0x122, 7.75),
+    (0x123, 0x123, 6.23), (0x124, 0x124, 7.51), (0x125, 0x125, 6.33), (0x126, 0x126, 7.51),
+    (0x127, 0x127, 6.33), (0x128, 0x128, 4.21), (0x129, 0x129, 2.74), (0x12a, 0x12a, 4.21),
+    (0x12b, 0x12b, 2.74), (0x12c, 0x12c, 4.21), (0x12d, 0x12d, 2.74), (0x12e, 0x12e, 4.21),
+    (0x12f, 0x12f, 2.74), (0x130, 0x130, 4.21), (0x131, 0x131, 2.74), (0x132, 0x132, 8.71),
+    (0x133, 0x133, 6.14), (0x134, 0x134, 4.55), (0x135, 0x135, 3.44), (0x136, 0x136, 6.93),
+    (0x137, 0x138, 5.92), (0x139, 0x139, 5.57), (0x13a, 0x13a, 2.74), (0x13b, 0x13b, 5.57),
+    (0x13c, 0x13c, 2.74), (0x13d, 0x13d, 5.57), (0x13e, 0x13e, 2.96), (0x13f, 0x13f, 5.57),
+    (0x140, 0x140, 4.58), (0x141, 0x141, 5.62), (0x142, 0x142, 2.84), (0x143, 0x143, 7.48),
+    (0x144, 0x144, 6.33), (0x145, 0x145, 7.48), (0x146, 0x146, 6.33), (0x147, 0x147, 7.48),
+    (0x148, 0x148, 6.33), (0x149, 0x149, 7.3), (0x14a, 0x14a, 7.48), (0x14b, 0x14b, 6.33),
+    (0x14c, 0x14c, 7.87), (0x14d, 0x14d, 6.07), (0x14e, 0x14e, 7.87), (0x14f, 0x14f, 6.07),
+    (0x150, 0x150, 7.87), (0x151, 0x151, 6.07), (0x152, 0x152, 10.7), (0x153, 0x153, 9.81),
+    (0x154, 0x154, 6.95), (0x155, 0x155, 4.27), (0x156, 0x156, 6.95), (0x157, 0x157, 4.27),
+    (0x158, 0x158, 6.95), (0x159, 0x159, 4.27), (0x15a, 0x15a, 6.84), (0x15b, 0x15b, 5.21),
+    (0x15c, 0x15c, 6.84), (0x15d, 0x15d, 5.21), (0x15e, 0x15e, 6.84), (0x15f, 0x15f, 5.21),
+    (0x160, 0x160, 6.84), (0x161, 0x161, 5.21), (0x162, 0x162, 6.16), (0x163, 0x163, 3.94),
+    (0x164, 0x164, 6.16), (0x165, 0x165, 3.94), (0x166, 0x166, 6.16), (0x167, 0x167, 3.94),
+    (0x168, 0x168, 7.32), (0x169, 0x169, 6.33), (0x16a, 0x16a, 7.32), (0x16b, 0x16b, 6.33),
+    (0x16c, 0x16c, 7.32), (0x16d, 0x16d, 6.3), (0x16e, 0x16e, 7.32), (0x16f, 0x16f, 6.33),
+    (0x170, 0x170, 7.32), (0x171, 0x171, 6.33), (0x172, 0x172, 7.32), (0x173, 0x173, 6.3),
+    (0x174, 0x174, 9.89), (0x175, 0x175, 8.18), (0x176, 0x176, 6.15), (0x177, 0x177, 5.92),
+    (0x178, 0x178, 6.15), (0x179, 0x179, 6.85), (0x17a, 0x17a, 5.25), (0x17b, 0x17b, 6.85),
+    (0x17c, 0x17c, 5.25), (0x17d, 0x17d, 6.85), (0x17e, 0x17e, 5.25), (0x180, 0x180, 5.0),
+    (0x181, 0x181, 7.6), (0x182, 0x182, 5.74), (0x183, 0x183, 5.0), (0x184, 0x184, 5.74),
+    (0x185, 0x185, 5.0), (0x186, 0x187, 6.67), (0x188, 0x188, 4.44), (0x189, 0x189, 7.22),
+    (0x18a, 0x18a, 8.15), (0x18b, 0x18b, 5.74), (0x18c, 0x18c, 5.0), (0x18d, 0x18d, 4.71),
+    (0x18e, 0x18e, 6.11), (0x18f, 0x18f, 7.55), (0x190, 0x190, 5.01), (0x191, 0x191, 5.56),
+    (0x192, 0x192, 6.36), (0x193, 0x194, 7.22), (0x195, 0x195, 7.7), (0x196, 0x196, 2.53),
+    (0x197, 0x197, 3.33), (0x198, 0x198, 7.22), (0x199, 0x199, 5.0), (0x19a, 0x19a, 2.78),
+    (0x19b, 0x19b, 4.85), (0x19c, 0x19c, 8.16), (0x19d, 0x19d, 7.22), (0x19e, 0x19e, 5.0),
+    (0x19f, 0x19f, 7.22), (0x1a0, 0x1a0, 8.07), (0x1a1, 0x1a1, 6.07), (0x1a2, 0x1a2, 9.25),
+    (0x1a3, 0x1a3, 6.91), (0x1a4, 0x1a4, 6.5), (0x1a5, 0x1a5, 5.0), (0x1a6, 0x1a7, 5.56),
+    (0x1a8, 0x1a8, 3.89), (0x1a9, 0x1a9, 5.82), (0x1aa, 0x1aa, 3.44), (0x1ab, 0x1ab, 2.78),
+    (0x1ac, 0x1ac, 6.11), (0x1ad, 0x1ad, 2.78), (0x1ae, 0x1ae, 6.11), (0x1af, 0x1af, 7.56),
+    (0x1b0, 0x1b0, 6.6), (0x1b1, 0x1b1, 7.43), (0x1b2, 0x1b2, 7.22), (0x1b3, 0x1b3, 7.81),
+    (0x1b4, 0x1b4, 5.0), (0x1b5, 0x1b5, 6.11), (0x1b6, 0x1b6, 4.44), (0x1b7, 0x1b8, 5.39),
+    (0x1b9, 0x1ba, 4.44), (0x1bb, 0x1bc, 5.0), (0x1bd, 0x1bd, 4.43), (0x1be, 0x1be, 4.22),
+    (0x1bf, 0x1bf, 5.0), (0x1c0, 0x1c0, 2.0), (0x1c1, 0x1c1, 2.81), (0x1c2, 0x1c2, 2.49),
+    (0x1c3, 0x1c3, 3.33), (0x1c4, 0x1c4, 13.33), (0x1c5, 0x1c5, 11.66), (0x1c6, 0x1c6, 9.44),
+    (0x1c7, 0x1c7, 10.0), (0x1c8, 0x1c8, 8.89), (0x1c9, 0x1c9, 5.56), (0x1ca, 0x1ca, 11.11),
+    (0x1cb, 0x1cb, 10.0), (0x1cc, 0x1cc, 7.78), (0x1cd, 0x1cd, 7.22), (0x1ce, 0x1ce, 4.44),
+    (0x1cf, 0x1cf, 3.33), (0x1d0, 0x1d0, 2.78), (0x1d1, 0x1d1, 7.22), (0x1d2, 0x1d2, 5.0),
+    (0x1d3, 0x1d3, 7.22), (0x1d4, 0x1d4, 5.0), (0x1d5, 0x1d5, 7.22), (0x1d6, 0x1d6, 5.0),
+    (0x1d7, 0x1d7, 7.32), (0x1d8, 0x1d8, 6.33), (0x1d9, 0x1d9, 7.22), (0x1da, 0x1da, 5.0),
+    (0x1db, 0x1db, 7.32), (0x1dc, 0x1dc, 6.33), (0x1dd, 0x1dd, 4.44), (0x1de, 0x1de, 7.22),
+    (0x1df, 0x1df, 4.44), (0x1e0, 0x1e0, 7.22), (0x1e1, 0x1e1, 4.44), (0x1e2, 0x1e2, 8.89),
+    (0x1e3, 0x1e3, 6.67), (0x1e4, 0x1e4, 7.22), (0x1e5, 0x1e5, 5.0), (0x1e6, 0x1e6, 7.22),
+    (0x1e7, 0x1e7, 5.0), (0x1e8, 0x1e8, 7.22), (0x1e9, 0x1e9, 5.0), (0x1ea, 0x1ea, 7.22),
+    (0x1eb, 0x1eb, 5.0), (0x1ec, 0x1ec, 7.22), (0x1ed, 0x1ed, 5.0), (0x1ee, 0x1ee, 5.39),
+    (0x1ef, 0x1ef, 4.44), (0x1f0, 0x1f0, 2.78), (0x1f1, 0x1f1, 13.33), (0x1f2, 0x1f2, 11.66),
+    (0x1f3, 0x1f3, 9.44), (0x1f4, 0x1f4, 7.75), (0x1f5, 0x1f5, 6.23), (0x1f6, 0x1f6, 9.5),
+    (0x1f7, 0x1f7, 5.6), (0x1f8, 0x1f8, 7.48), (0x1f9, 0x1f9, 6.33), (0x1fa, 0x1fa, 6.84),
+    (0x1fb, 0x1fb, 6.01), (0x1fc, 0x1fc, 9.84), (0x1fd, 0x1fd, 9.55), (0x1fe, 0x1fe, 7.87),
+    (0x1ff, 0x1ff, 6.07), (0x200, 0x200, 7.22), (0x201, 0x201, 4.44), (0x202, 0x202, 7.22),
+    (0x203, 0x203, 4.44), (0x204, 0x204, 6.11), (0x205, 0x205, 4.44), (0x206, 0x206, 6.11),
+    (0x207, 0x207, 4.44), (0x208, 0x208, 3.33), (0x209, 0x209, 2.78), (0x20a, 0x20a, 3.33),
+    (0x20b, 0x20b, 2.78), (0x20c, 0x20c, 7.22), (0x20d, 0x20d, 5.0), (0x20e, 0x20e, 7.22),
+    (0x20f, 0x20f, 5.0), (0x210, 0x210, 6.67), (0x211, 0x211, 3.33), (0x212, 0x212, 6.67),
+    (0x213, 0x213, 3.33), (0x214, 0x214, 7.22), (0x215, 0x215, 5.0), (0x216, 0x216, 7.22),
+    (0x217, 0x217, 5.0), (0x218, 0x218, 6.84), (0x219, 0x219, 5.21), (0x21a, 0x21a, 6.16),
+    (0x21b, 0x21b, 3.94), (0x21c, 0x21c, 5.63), (0x21d, 0x21d, 3.95), (0x21e, 0x21e, 7.22),
+    (0x21f, 0x21f, 5.0), (0x220, 0x220, 6.5), (0x221, 0x221, 5.0), (0x222, 0x222, 6.04),
+    (0x223, 0x223, 5.0), (0x224, 0x224, 6.11), (0x225, 0x225, 4.44), (0x226, 0x226, 7.22),
+    (0x227, 0x227, 4.44), (0x228, 0x228, 6.11), (0x229, 0x229, 4.44), (0x22a, 0x22a, 7.22),
+    (0x22b, 0x22b, 5.0), (0x22c, 0x22c, 7.22), (0x22d, 0x22d, 5.0), (0x22e, 0x22e, 7.22),
+    (0x22f, 0x22f, 5.0), (0x230, 0x230, 7.22), (0x231, 0x231, 5.0), (0x232, 0x232, 7.22),
+    (0x233, 0x233, 5.0), (0x234, 0x234, 2.78), (0x235, 0x235, 5.0), (0x236, 0x236, 3.17),
+    (0x237, 0x237, 2.78), (0x238, 0x238, 7.74), (0x239, 0x239, 7.72), (0x23a, 0x23a, 7.22),
+    (0x23b, 0x23b, 6.67), (0x23c, 0x23c, 5.0), (0x23d, 0x23e, 6.11), (0x23f, 0x23f, 3.89),
+    (0x240, 0x240, 4.44), (0x241, 0x241, 5.34), (0x242, 0x242, 3.97), (0x243, 0x243, 6.67),
+    (0x244, 0x244, 7.22), (0x245, 0x245, 7.25), (0x246, 0x246, 6.11), (0x247, 0x247, 4.44),
+    (0x248, 0x248, 3.89), (0x249, 0x249, 2.78), (0x24a, 0x24a, 7.02), (0x24b, 0x24b, 5.0),
+    (0x24c, 0x24c, 6.67), (0x24d, 0x24d, 3.33), (0x24e, 0x24e, 7.22), (0x370, 0x370, 4.19),
+    (0x371, 0x371, 3.32), (0x372, 0x372, 5.87), (0x373, 0x373, 4.67), (0x374, 0x375, 3.33),
+    (0x376, 0x376, 7.21), (0x377, 0x377, 5.8), (0x378, 0x379, 10.0), (0x37a, 0x37a, 3.33),
+    (0x37b, 0x37d, 4.44), (0x37e, 0x37e, 4.54), (0x37f, 0x37f, 3.33), (0x380, 0x383, 10.0),
+    (0x384, 0x385, 6.36), (0x386, 0x386, 6.84), (0x387, 0x387, 4.54), (0x388, 0x388, 7.51),
+    (0x389, 0x389, 8.7), (0x38a, 0x38a, 5.4), (0x38b, 0x38b, 10.0), (0x38c, 0x38c, 8.81),
+    (0x38d, 0x38d, 10.0), (0x38e, 0x38e, 7.53), (0x38f, 0x38f, 9.08), (0x390, 0x390, 2.74),
+    (0x391, 0x391, 6.84), (0x392, 0x392, 6.86), (0x393, 0x393, 5.66), (0x394, 0x394, 7.03),
+    (0x395, 0x395, 6.32), (0x396, 0x396, 6.85), (0x397, 0x397, 7.51), (0x398, 0x398, 7.87),
+    (0x399, 0x399, 4.21), (0x39a, 0x39a, 6.93), (0x39b, 0x39b, 6.86), (0x39c, 0x39c, 8.43),
+    (0x39d, 0x39d, 7.48), (0x39e, 0x39e, 6.49), (0x39f, 0x39f, 7.87), (0x3a0, 0x3a0, 7.51),
+    (0x3a1, 0x3a1, 6.03), (0x3a2, 0x3a2, 10.0), (0x3a3, 0x3a3, 6.72), (0x3a4, 0x3a4, 6.16),
+    (0x3a5, 0x3a5, 6.15), (0x3a6, 0x3a6, 8.19), (0x3a7, 0x3a7, 6.85), (0x3a8, 0x3a8, 8.71),
+    (0x3a9, 0x3a9, 8.18), (0x3aa, 0x3aa, 4.21), (0x3ab, 0x3ab, 6.15), (0x3ac, 0x3ac, 6.23),
+    (0x3ad, 0x3ad, 5.13), (0x3ae, 0x3ae, 6.33), (0x3af, 0x3af, 2.74), (0x3b0, 0x3b0, 6.31),
+    (0x3b1, 0x3b1, 6.23), (0x3b2, 0x3b2, 6.2), (0x3b3, 0x3b3, 5.92), (0x3b4, 0x3b4, 6.08),
+    (0x3b5, 0x3b5, 5.13), (0x3b6, 0x3b6, 4.58), (0x3b7, 0x3b7, 6.33), (0x3b8, 0x3b8, 6.24),
+    (0x3b9, 0x3b9, 2.74), (0x3ba, 0x3bb, 5.92), (0x3bc, 0x3bc, 6.4), (0x3bd, 0x3bd, 5.92),
+    (0x3be, 0x3be, 5.03), (0x3bf, 0x3bf, 6.07), (0x3c0, 0x3c0, 6.37), (0x3c1, 0x3c1, 6.25),
+    (0x3c2, 0x3c2, 5.08), (0x3c3, 0x3c3, 6.3), (0x3c4, 0x3c4, 4.96), (0x3c5, 0x3c5, 6.31),
+    (0x3c6, 0x3c6, 7.9), (0x3c7, 0x3c7, 5.9), (0x3c8, 0x3c8, 8.22), (0x3c9, 0x3c9, 8.13),
+    (0x3ca, 0x3ca, 2.74), (0x3cb, 0x3cb, 6.31), (0x3cc, 0x3cc, 6.07), (0x3cd, 0x3cd, 6.31),
+    (0x3ce, 0x3ce, 8.13), (0x3cf, 0x3cf, 5.66), (0x3d0, 0x3d0, 5.09), (0x3d1, 0x3d1, 4.95),
+    (0x3d2, 0x3d2, 7.22), (0x3d3, 0x3d3, 8.9), (0x3d4, 0x3d4, 7.22), (0x3d5, 0x3d5, 5.26),
+    (0x3d6, 0x3d6, 6.58), (0x3d7, 0x3d7, 5.6), (0x3d8, 0x3d8, 7.22), (0x3d9, 0x3d9, 5.0),
+    (0x3da, 0x3da, 6.67), (0x3db, 0x3db, 4.2), (0x3dc, 0x3dc, 5.56), (0x3dd, 0x3dd, 4.47),
+    (0x3de, 0x3de, 5.76), (0x3df, 0x3df, 4.51), (0x3e0, 0x3e0, 7.31), (0x3e1, 0x3e1, 5.54),
+    (0x3e2, 0x3e2, 8.28), (0x3e3, 0x3e3, 7.78), (0x3e4, 0x3e4, 6.16), (0x3e5, 0x3e5, 5.21),
+    (0x3e6, 0x3e6, 6.61), (0x3e7, 0x3e7, 4.44), (0x3e8, 0x3e9, 5.42), (0x3ea, 0x3ea, 6.56),
+    (0x3eb, 0x3eb, 5.81), (0x3ec, 0x3ec, 6.99), (0x3ed, 0x3ed, 5.09), (0x3ee, 0x3ee, 4.75),
+    (0x3ef, 0x3ef, 3.86), (0x3f0, 0x3f0, 5.6), (0x3f1, 0x3f1, 5.09), (0x3f2, 0x3f2, 4.44),
+    (0x3f3, 0x3f3, 2.78), (0x3f4, 0x3f4, 7.22), (0x3f5, 0x3f6, 4.05), (0x3f7, 0x3f7, 5.56),
+    (0x3f8, 0x3f8, 5.0), (0x3f9, 0x3f9, 6.67), (0x3fa, 0x3fa, 8.89), (0x3fb, 0x3fb, 6.33),
+    (0x3fc, 0x3fc, 4.99), (0x3fd, 0x3fe, 6.67), (0x400, 0x401, 6.32), (0x402, 0x402, 7.92),
+    (0x403, 0x403, 5.66), (0x404, 0x404, 7.01), (0x405, 0x405, 6.84), (0x406, 0x407, 4.21),
+    (0x408, 0x408, 4.55), (0x409, 0x409, 11.18), (0x40a, 0x40a, 11.03), (0x40b, 0x40b, 8.18),
+    (0x40c, 0x40c, 6.93), (0x40d, 0x40d, 7.5), (0x40e, 0x40e, 6.15), (0x40f, 0x40f, 7.51),
+    (0x410, 0x410, 6.84), (0x411, 0x412, 6.86), (0x413, 0x413, 5.66), (0x414, 0x414, 7.46),
+    (0x415, 0x415, 6.32), (0x416, 0x416, 9.73), (0x417, 0x417, 6.16), (0x418, 0x419, 7.5),
+    (0x41a, 0x41a, 6.93), (0x41b, 0x41b, 7.34), (0x41c, 0x41c, 8.43), (0x41d, 0x41d, 7.51),
+    (0x41e, 0x41e, 7.87), (0x41f, 0x41f, 7.51), (0x420, 0x420, 6.03), (0x421, 0x421, 6.98),
+    (0x422, 0x422, 6.16), (0x423, 0x423, 6.15), (0x424, 0x424, 8.19), (0x425, 0x425, 6.85),
+    (0x426, 0x426, 7.61), (0x427, 0x427, 7.12), (0x428, 0x428, 10.3), (0x429, 0x429, 10.45),
+    (0x42a, 0x42a, 7.83), (0x42b, 0x42b, 9.2), (0x42c, 0x42c, 6.81), (0x42d, 0x42d, 7.01),
+    (0x42e, 0x42e, 10.34), (0x42f, 0x42f, 7.06), (0x430, 0x430, 6.01), (0x431, 0x431, 6.14),
+    (0x432, 0x432, 5.95), (0x433, 0x433, 4.71), (0x434, 0x434, 6.22), (0x435, 0x435, 5.96),
+    (0x436, 0x436, 7.98), (0x437, 0x437, 5.24), (0x438, 0x439, 6.4), (0x43a, 0x43a, 5.92),
+    (0x43b, 0x43b, 6.21), (0x43c, 0x43c, 6.96), (0x43d, 0x43d, 6.37), (0x43e, 0x43e, 6.07),
+    (0x43f, 0x43f, 6.37), (0x440, 0x440, 6.23), (0x441, 0x441, 5.34), (0x442, 0x442, 4.96),
+    (0x443, 0x443, 5.92), (0x444, 0x444, 8.41), (0x445, 0x445, 5.92), (0x446, 0x446, 6.45),
+    (0x447, 0x447, 6.05), (0x448, 0x448, 8.76), (0x449, 0x449, 8.88), (0x44a, 0x44a, 6.41),
+    (0x44b, 0x44b, 7.94), (0x44c, 0x44c, 5.71), (0x44d, 0x44d, 5.47), (0x44e, 0x44e, 8.39),
+    (0x44f, 0x44f, 6.0), (0x450, 0x451, 5.96), (0x452, 0x452, 6.33), (0x453, 0x453, 4.71),
+    (0x454, 0x454, 5.47), (0x455, 0x455, 5.21), (0x456, 0x457, 2.74), (0x458, 0x458, 3.44),
+    (0x459, 0x45a, 9.14), (0x45b, 0x45b, 6.33), (0x45c, 0x45c, 5.92), (0x45d, 0x45d, 6.4),
+    (0x45e, 0x45e, 5.92), (0x45f, 0x45f, 6.37), (0x460, 0x460, 11.72), (0x461, 0x461, 6.33),
+    (0x462, 0x462, 6.71), (0x463, 0x463, 5.43), (0x464, 0x464, 9.68), (0x465, 0x465, 6.77),
+    (0x466, 0x466, 7.22), (0x467, 0x467, 5.9), (0x468, 0x468, 10.33), (0x469, 0x469, 8.34),
+    (0x46a, 0x46a, 8.96), (0x46b, 0x46b, 6.91), (0x46c, 0x46c, 12.08), (0x46d, 0x46d, 9.35),
+    (0x46e, 0x46e, 5.01), (0x46f, 0x46f, 3.95), (0x470, 0x470, 7.38), (0x471, 0x471, 6.26),
+    (0x472, 0x472, 7.22), (0x473, 0x473, 5.0), (0x474, 0x474, 8.13), (0x475, 0x475, 5.89),
+    (0x476, 0x476, 8.13), (0x477, 0x477, 5.89), (0x478, 0x478, 11.88), (0x479, 0x479, 10.4),
+    (0x47a, 0x47a, 7.63), (0x47b, 0x47b, 5.66), (0x47c, 0x47c, 9.83), (0x47d, 0x47d, 8.13),
+    (0x47e, 0x47e, 11.72), (0x47f, 0x47f, 6.33), (0x480, 0x480, 6.67), (0x481, 0x481, 4.44),
+    (0x482, 0x482, 3.34), (0x483, 0x486, 0.0), (0x487, 0x487, 6.36), (0x488, 0x489, 0.0),
+    (0x48a, 0x48a, 7.22), (0x48b, 0x48b, 5.35), (0x48c, 0x48c, 5.74), (0x48d, 0x48d, 4.7),
+    (0x48e, 0x48e, 5.56), (0x48f, 0x48f, 5.0), (0x490, 0x490, 5.66), (0x491, 0x491, 4.71),
+    (0x492, 0x492, 5.66), (0x493, 0x493, 4.71), (0x494, 0x494, 6.31), (0x495, 0x495, 5.15),
+    (0x496, 0x496, 9.73), (0x497, 0x497, 7.98), (0x498, 0x498, 5.01), (0x499, 0x499, 3.95),
+    (0x49a, 0x49a, 6.93), (0x49b, 0x49b, 5.92), (0x49c, 0x49c, 6.93), (0x49d, 0x49d, 5.92),
+    (0x49e, 0x49e, 6.67), (0x49f, 0x49f, 4.86), (0x4a0, 0x4a0, 7.86), (0x4a1, 0x4a1, 5.73),
+    (0x4a2, 0x4a2, 7.51), (0x4a3, 0x4a3, 6.37), (0x4a4, 0x4a4, 8.51), (0x4a5, 0x4a5, 6.23),
+    (0x4a6, 0x4a6, 10.32), (0x4a7, 0x4a7, 7.89), (0x4a8, 0x4a8, 7.87), (0x4a9, 0x4a9, 6.17),
+    (0x4aa, 0x4aa, 6.67), (0x4ab, 0x4ab, 4.44), (0x4ac, 0x4ac, 6.11), (0x4ad, 0x4ad, 4.37),
+    (0x4ae, 0x4ae, 6.15), (0x4af, 0x4af, 5.92), (0x4b0, 0x4b0, 6.15), (0x4b1, 0x4b1, 5.92),
+    (0x4b2, 0x4b2, 6.85), (0x4b3, 0x4b3, 5.92), (0x4b4, 0x4b4, 7.97), (0x4b5, 0x4b5, 6.03),
+    (0x4b6, 0x4b6, 6.5), (0x4b7, 0x4b7, 5.03), (0x4b8, 0x4b8, 7.12), (0x4b9, 0x4b9, 6.05),
+    (0x4ba, 0x4ba, 7.12), (0x4bb, 0x4bb, 6.33), (0x4bc, 0x4bc, 8.78), (0x4bd, 0x4bd, 6.89),
+    (0x4be, 0x4be, 8.78), (0x4bf, 0x4bf, 6.89), (0x4c0, 0x4c0, 3.33), (0x4c1, 0x4c1, 8.96),
+    (0x4c2, 0x4c2, 6.91), (0x4c3, 0x4c3, 6.67), (0x4c4, 0x4c4, 5.15), (0x4c5, 0x4c5, 6.78),
+    (0x4c6, 0x4c6, 4.99), (0x4c7, 0x4c7, 7.22), (0x4c8, 0x4c8, 5.35), (0x4c9, 0x4c9, 7.22),
+    (0x4ca, 0x4ca, 5.35), (0x4cb, 0x4cb, 6.5), (0x4cc, 0x4cc, 5.03), (0x4cd, 0x4cd, 8.89),
+    (0x4ce, 0x4ce, 6.33), (0x4cf, 0x4cf, 2.78), (0x4d0, 0x4d0, 7.22), (0x4d1, 0x4d1, 4.44),
+    (0x4d2, 0x4d2, 7.22), (0x4d3, 0x4d3, 4.44), (0x4d4, 0x4d4, 8.89), (0x4d5, 0x4d5, 6.67),
+    (0x4d6, 0x4d6, 6.11), (0x4d7, 0x4d7, 4.44), (0x4d8, 0x4d8, 7.55), (0x4d9, 0x4d9, 5.96),
+    (0x4da, 0x4da, 7.22), (0x4db, 0x4db, 4.44), (0x4dc, 0x4dc, 8.96), (0x4dd, 0x4dd, 6.91),
+    (0x4de, 0x4de, 5.01), (0x4df, 0x4df, 3.95), (0x4e0, 0x4e0, 5.01), (0x4e1, 0x4e1, 4.44),
+    (0x4e2, 0x4e2, 7.22), (0x4e3, 0x4e3, 5.35), (0x4e4, 0x4e4, 7.22), (0x4e5, 0x4e5, 5.35),
+    (0x4e6, 0x4e6, 7.22), (0x4e7, 0x4e7, 5.0), (0x4e8, 0x4e8, 7.87), (0x4e9, 0x4e9, 6.07),
+    (0x4ea, 0x4ea, 7.22), (0x4eb, 0x4eb, 5.0), (0x4ec, 0x4ec, 6.6), (0x4ed, 0x4ed, 4.29),
+    (0x4ee, 0x4ee, 7.08), (0x4ef, 0x4ef, 5.0), (0x4f0, 0x4f0, 7.08), (0x4f1, 0x4f1, 5.0),
+    (0x4f2, 0x4f2, 7.08), (0x4f3, 0x4f3, 5.0), (0x4f4, 0x4f4, 6.5), (0x4f5, 0x4f5, 5.03),
+    (0x4f6, 0x4f6, 5.78), (0x4f7, 0x4f7, 4.1), (0x4f8, 0x4f8, 8.72), (0x4f9, 0x4f9, 6.72),
+    (0x4fa, 0x4fa, 5.78), (0x4fb, 0x4fb, 4.1), (0x4fc, 0x4fc, 7.22), (0x4fd, 0x4fd, 5.0),
+    (0x4fe, 0x4fe, 7.22), (0x500, 0x500, 5.74), (0x501, 0x501, 5.0), (0x502, 0x502, 8.04),
+    (0x503, 0x503, 7.71), (0x504, 0x504, 8.02), (0x505, 0x505, 7.06), (0x506, 0x506, 5.74),
+    (0x507, 0x507, 4.53), (0x508, 0x508, 9.07), (0x509, 0x509, 7.52), (0x50a, 0x50a, 9.5),
+    (0x50b, 0x50b, 7.88), (0x50c, 0x50c, 7.22), (0x50d, 0x50d, 4.53), (0x50e, 0x50e, 6.96),
+    (0x50f, 0x50f, 6.02), (0x510, 0x510, 5.01), (0x511, 0x511, 3.95), (0x512, 0x512, 6.78),
+    (0x513, 0x513, 4.99), (0x514, 0x514, 7.62), (0x515, 0x515, 6.93), (0x516, 0x516, 8.3),
+    (0x517, 0x517, 6.81), (0x518, 0x518, 10.31), (0x519, 0x519, 7.37), (0x51a, 0x51a, 7.87),
+    (0x51b, 0x51b, 6.23), (0x51c, 0x51c, 9.89), (0x51d, 0x51d, 8.18), (0x51e, 0x51e, 6.85),
+    (0x51f, 0x51f, 6.31), (0x520, 0x520, 9.81), (0x521, 0x521, 7.96), (0x522, 0x522, 11.11),
+    (0x523, 0x523, 9.08), (0x524, 0x524, 7.08), (0x525, 0x525, 6.18), (0x526, 0x526, 6.89),
+    (0x527, 0x527, 6.31), (0x528, 0x528, 4.44), (0x529, 0x529, 4.03), (0x52a, 0x52a, 8.91),
+    (0x52b, 0x52b, 8.13), (0x52c, 0x52c, 5.62), (0x52d, 0x52d, 5.24), (0x52e, 0x52e, 6.83),
+    (0x1d00, 0x1d00, 5.02), (0x1d01, 0x1d01, 6.45), (0x1d02, 0x1d02, 6.67), (0x1d03, 0x1d03, 4.65),
+    (0x1d04, 0x1d04, 4.78), (0x1d05, 0x1d06, 5.24), (0x1d07, 0x1d07, 4.44), (0x1d08, 0x1d08, 3.95),
+    (0x1d09, 0x1d09, 2.78), (0x1d0a, 0x1d0a, 2.96), (0x1d0b, 0x1d0b, 5.4), (0x1d0c, 0x1d0c, 4.45),
+    (0x1d0d, 0x1d0d, 6.33), (0x1d0e, 0x1d0e, 5.35), (0x1d0f, 0x1d0f, 5.0), (0x1d10, 0x1d10, 4.78),
+    (0x1d11, 0x1d11, 5.42), (0x1d12, 0x1d12, 5.38), (0x1d13, 0x1d13, 5.42), (0x1d14, 0x1d14, 7.22),
+    (0x1d15, 0x1d15, 4.37), (0x1d16, 0x1d17, 5.0), (0x1d18, 0x1d18, 4.56), (0x1d19, 0x1d1a, 4.6),
+    (0x1d1b, 0x1d1b, 4.37), (0x1d1c, 0x1d1c, 5.37), (0x1d1d, 0x1d1d, 5.05), (0x1d1e, 0x1d1e, 6.55),
+    (0x1d1f, 0x1d1f, 5.04), (0x1d20, 0x1d20, 5.0), (0x1d21, 0x1d21, 7.22), (0x1d22, 0x1d22, 4.44),
+    (0x1d23, 0x1d23, 3.95), (0x1d24, 0x1d24, 3.97), (0x1d25, 0x1d25, 5.39), (0x1d26, 0x1d26, 4.1),
+    (0x1d27, 0x1d27, 5.0), (0x1d28, 0x1d28, 5.35), (0x1d29, 0x1d29, 4.56), (0x1d2a, 0x1d2a, 5.28),
+    (0x1d2b, 0x1d2b, 4.99), (0x1d2c, 0x1d2c, 4.22), (0x1d2d, 0x1d2d, 5.38), (0x1d2e, 0x1d2f, 3.87),
+    (0x1d30, 0x1d30, 4.3), (0x1d31, 0x1d32, 3.7), (0x1d33, 0x1d33, 4.33), (0x1d34, 0x1d34, 4.4),
+    (0x1d35, 0x1d35, 2.0), (0x1d36, 0x1d36, 2.47), (0x1d37, 0x1d37, 4.58), (0x1d38, 0x1d38, 3.71),
+    (0x1d39, 0x1d39, 5.4), (0x1d3a, 0x1d3a, 4.62), (0x1d3b, 0x1d3b, 4.41), (0x1d3c, 0x1d3c, 4.18),
+    (0x1d3d, 0x1d3d, 3.36), (0x1d3e, 0x1d3e, 3.32), (0x1d3f, 0x1d3f, 4.1), (0x1d40, 0x1d40, 3.62),
+    (0x1d41, 0x1d41, 4.53), (0x1d42, 0x1d42, 5.54), (0x1d43, 0x1d44, 2.73), (0x1d45, 0x1d45, 3.14),
+    (0x1d46, 0x1d46, 3.9), (0x1d47, 0x1d47, 3.1), (0x1d48, 0x1d48, 3.11), (0x1d49, 0x1d4a, 2.56),
+    (0x1d4b, 0x1d4c, 2.42), (0x1d4d, 0x1d4d, 3.01), (0x1d4e, 0x1d4e, 1.64), (0x1d4f, 0x1d4f, 3.15),
+    (0x1d50, 0x1d50, 4.89), (0x1d51, 0x1d51, 2.82), (0x1d52, 0x1d52, 2.88), (0x1d53, 0x1d53, 2.56),
+    (0x1d54, 0x1d55, 2.88), (0x1d56, 0x1d56, 3.11), (0x1d57, 0x1d57, 1.91), (0x1d58, 0x1d58, 3.28),
+    (0x1d59, 0x1d59, 3.06), (0x1d5a, 0x1d5a, 4.89), (0x1d5b, 0x1d5b, 2.94), (0x1d5c, 0x1d5c, 3.19),
+    (0x1d5d, 0x1d5d, 2.82), (0x1d5e, 0x1d5e, 2.65), (0x1d5f, 0x1d5f, 2.71), (0x1d60, 0x1d60, 3.34),
+    (0x1d61, 0x1d61, 2.82), (0x1d62, 0x1d62, 1.64), (0x1d63, 0x1d63, 2.29), (0x1d64, 0x1d64, 3.28),
+    (0x1d65, 0x1d65, 2.94), (0x1d66, 0x1d66, 2.82), (0x1d67, 0x1d67, 2.65), (0x1d68, 0x1d68, 2.96),
+    (0x1d69, 0x1d69, 3.34), (0x1d6a, 0x1d6a, 2.82), (0x1d6b, 0x1d6b, 7.61), (0x1d6c, 0x1d6d, 5.0),
+    (0x1d6e, 0x1d6e, 3.33), (0x1d6f, 0x1d6f, 7.78), (0x1d70, 0x1d71, 5.0), (0x1d72, 0x1d73, 3.33),
+    (0x1d74, 0x1d74, 3.89), (0x1d75, 0x1d75, 2.78), (0x1d76, 0x1d76, 4.44), (0x1d77, 0x1d77, 5.0),
+    (0x1d78, 0x1d78, 3.23), (0x1d79, 0x1d79, 4.71), (0x1d7a, 0x1d7a, 7.66), (0x1d7b, 0x1d7b, 2.78),
+    (0x1d7c, 0x1d7c, 2.69), (0x1d7d, 0x1d7d, 5.0), (0x1d7e, 0x1d7e, 5.37), (0x1e00, 0x1e00, 7.22),
+    (0x1e01, 0x1e01, 4.44), (0x1e02, 0x1e02, 6.67), (0x1e03, 0x1e03, 5.0), (0x1e04, 0x1e04, 6.86),
+    (0x1e05, 0x1e05, 6.23), (0x1e06, 0x1e06, 6.67), (0x1e07, 0x1e07, 5.0), (0x1e08, 0x1e08, 6.98),
+    (0x1e09, 0x1e09, 5.21), (0x1e0a, 0x1e0a, 7.22), (0x1e0b, 0x1e0b, 5.0), (0x1e0c, 0x1e0c, 7.71),
+    (0x1e0d, 0x1e0d, 6.23), (0x1e0e, 0x1e0e, 7.22), (0x1e0f, 0x1e0f, 5.0), (0x1e10, 0x1e10, 7.22),
+    (0x1e11, 0x1e11, 5.0), (0x1e12, 0x1e12, 7.22), (0x1e13, 0x1e13, 5.0), (0x1e14, 0x1e14, 6.32),
+    (0x1e15, 0x1e15, 5.96), (0x1e16, 0x1e16, 6.32), (0x1e17, 0x1e17, 5.96), (0x1e18, 0x1e18, 6.11),
+    (0x1e19, 0x1e19, 4.44), (0x1e1a, 0x1e1a, 6.11), (0x1e1b, 0x1e1b, 4.44), (0x1e1c, 0x1e1c, 6.11),
+    (0x1e1d, 0x1e1d, 4.44), (0x1e1e, 0x1e1e, 5.56), (0x1e1f, 0x1e1f, 3.33), (0x1e20, 0x1e20, 7.22),
+    (0x1e21, 0x1e21, 5.0), (0x1e22, 0x1e22, 7.22), (0x1e23, 0x1e23, 5.0), (0x1e24, 0x1e24, 7.51),
+    (0x1e25, 0x1e25, 6.33), (0x1e26, 0x1e26, 7.22), (0x1e27, 0x1e27, 5.0), (0x1e28, 0x1e28, 7.22),
+    (0x1e29, 0x1e29, 5.0), (0x1e2a, 0x1e2a, 7.22), (0x1e2b, 0x1e2b, 5.0), (0x1e2c, 0x1e2c, 3.33),
+    (0x1e2d, 0x1e2d, 2.78), (0x1e2e, 0x1e2e, 4.21), (0x1e2f, 0x1e2f, 2.74), (0x1e30, 0x1e30, 6.93),
+    (0x1e31, 0x1e31, 5.92), (0x1e32, 0x1e32, 6.93), (0x1e33, 0x1e33, 5.92), (0x1e34, 0x1e34, 7.22),
+    (0x1e35, 0x1e35, 5.0), (0x1e36, 0x1e36, 5.57), (0x1e37, 0x1e37, 2.74), (0x1e38, 0x1e38, 6.11),
+    (0x1e39, 0x1e39, 2.78), (0x1e3a, 0x1e3a, 6.11), (0x1e3b, 0x1e3b, 2.78), (0x1e3c, 0x1e3c, 6.11),
+    (0x1e3d, 0x1e3d, 2.78), (0x1e3e, 0x1e3e, 8.43), (0x1e3f, 0x1e3f, 9.73), (0x1e40, 0x1e40, 8.89),
+    (0x1e41, 0x1e41, 7.78), (0x1e42, 0x1e42, 8.43), (0x1e43, 0x1e43, 9.73), (0x1e44, 0x1e44, 7.22),
+    (0x1e45, 0x1e45, 5.0), (0x1e46, 0x1e46, 7.48), (0x1e47, 0x1e47, 6.33), (0x1e48, 0x1e48, 7.22),
+    (0x1e49, 0x1e49, 5.0), (0x1e4a, 0x1e4a, 7.22), (0x1e4b, 0x1e4b, 5.0), (0x1e4c, 0x1e4c, 7.87),
+    (0x1e4d, 0x1e4d, 6.07), (0x1e4e, 0x1e4e, 7.22), (0x1e4f, 0x1e4f, 5.0), (0x1e50, 0x1e50, 7.87),
+    (0x1e51, 0x1e51, 6.07), (0x1e52, 0x1e52, 7.87), (0x1e53, 0x1e53, 6.07), (0x1e54, 0x1e54, 6.03),
+    (0x1e55, 0x1e55, 6.23), (0x1e56, 0x1e56, 5.56), (0x1e57, 0x1e57, 5.0), (0x1e58, 0x1e58, 6.67),
+    (0x1e59, 0x1e59, 3.33), (0x1e5a, 0x1e5a, 6.95), (0x1e5b, 0x1e5b, 4.27), (0x1e5c, 0x1e5c, 6.67),
+    (0x1e5d, 0x1e5d, 3.33), (0x1e5e, 0x1e5e, 6.67), (0x1e5f, 0x1e5f, 3.33), (0x1e60, 0x1e60, 5.56),
+    (0x1e61, 0x1e61, 3.89), (0x1e62, 0x1e62, 6.84), (0x1e63, 0x1e63, 5.21), (0x1e64, 0x1e64, 5.56),
+    (0x1e65, 0x1e65, 3.89), (0x1e66, 0x1e66, 5.56), (0x1e67, 0x1e67, 3.89), (0x1e68, 0x1e68, 5.56),
+    (0x1e69, 0x1e69, 3.89), (0x1e6a, 0x1e6a, 6.11), (0x1e6b, 0x1e6b, 2.78), (0x1e6c, 0x1e6c, 6.16),
+    (0x1e6d, 0x1e6d, 3.94), (0x1e6e, 0x1e6e, 6.11), (0x1e6f, 0x1e6f, 2.78), (0x1e70, 0x1e70, 6.11),
+    (0x1e71, 0x1e71, 2.78), (0x1e72, 0x1e72, 7.22), (0x1e73, 0x1e73, 5.0), (0x1e74, 0x1e74, 7.22),
+    (0x1e75, 0x1e75, 5.0), (0x1e76, 0x1e76, 7.22), (0x1e77, 0x1e77, 5.0), (0x1e78, 0x1e78, 7.32),
+    (0x1e79, 0x1e79, 6.33), (0x1e7a, 0x1e7a, 7.22), (0x1e7b, 0x1e7b, 5.0), (0x1e7c, 0x1e7c, 6.84),
+    (0x1e7d, 0x1e7d, 5.92), (0x1e7e, 0x1e7e, 6.84), (0x1e7f, 0x1e7f, 5.92), (0x1e80, 0x1e80, 9.89),
+    (0x1e81, 0x1e81, 8.18), (0x1e82, 0x1e82, 9.89), (0x1e83, 0x1e83, 8.18), (0x1e84, 0x1e84, 9.89),
+    (0x1e85, 0x1e85, 8.18), (0x1e86, 0x1e86, 9.44), (0x1e87, 0x1e87, 7.22), (0x1e88, 0x1e88, 9.89),
+    (0x1e89, 0x1e89, 8.18), (0x1e8a, 0x1e8a, 7.22), (0x1e8b, 0x1e8b, 5.0), (0x1e8c, 0x1e8c, 7.22),
+    (0x1e8d, 0x1e8d, 5.0), (0x1e8e, 0x1e8e, 7.22), (0x1e8f, 0x1e8f, 5.0), (0x1e90, 0x1e90, 6.11),
+    (0x1e91, 0x1e91, 4.44), (0x1e92, 0x1e92, 6.85), (0x1e93, 0x1e93, 5.25), (0x1e94, 0x1e94, 6.11),
+    (0x1e95, 0x1e95, 4.44), (0x1e96, 0x1e96, 5.0), (0x1e97, 0x1e97, 2.78), (0x1e98, 0x1e98, 7.22),
+    (0x1e99, 0x1e99, 5.0), (0x1e9a, 0x1e9a, 4.44), (0x1e9b, 0x1e9b, 2.78), (0x1e9c, 0x1e9c, 4.23),
+    (0x1e9d, 0x1e9d, 4.22), (0x1e9e, 0x1e9e, 6.78), (0x1e9f, 0x1e9f, 6.02), (0x1ea0, 0x1ea0, 6.84),
+    (0x1ea1, 0x1ea1, 6.01), (0x1ea2, 0x1ea2, 6.84), (0x1ea3, 0x1ea3, 6.01), (0x1ea4, 0x1ea4, 6.84),
+    (0x1ea5, 0x1ea5, 6.01), (0x1ea6, 0x1ea6, 6.84), (0x1ea7, 0x1ea7, 6.01), (0x1ea8, 0x1ea8, 6.84),
+    (0x1ea9, 0x1ea9, 6.01), (0x1eaa, 0x1eaa, 6.84), (0x1eab, 0x1eab, 6.01), (0x1eac, 0x1eac, 6.84),
+    (0x1ead, 0x1ead, 6.01), (0x1eae, 0x1eae, 6.84), (0x1eaf, 0x1eaf, 6.01), (0x1eb0, 0x1eb0, 6.84),
+    (0x1eb1, 0x1eb1, 6.01), (0x1eb2, 0x1eb2, 6.84), (0x1eb3, 0x1eb3, 6.01), (0x1eb4, 0x1eb4, 6.84),
+    (0x1eb5, 0x1eb5, 6.01), (0x1eb6, 0x1eb6, 6.84), (0x1eb7, 0x1eb7, 6.01), (0x1eb8, 0x1eb8, 6.32),
+    (0x1eb9, 0x1eb9, 5.96), (0x1eba, 0x1eba, 6.32), (0x1ebb, 0x1ebb, 5.96), (0x1ebc, 0x1ebc, 6.32),
+    (0x1ebd, 0x1ebd, 5.96), (0x1ebe, 0x1ebe, 6.32), (0x1ebf, 0x1ebf, 5.96), (0x1ec0, 0x1ec0, 6.32),
+    (0x1ec1, 0x1ec1, 5.96), (0x1ec2, 0x1ec2, 6.32), (0x1ec3, 0x1ec3, 5.96), (0x1ec4, 0x1ec4, 6.32),
+    (0x1ec5, 0x1ec5, 5.96), (0x1ec6, 0x1ec6, 6.32), (0x1ec7, 0x1ec7, 5.96), (0x1ec8, 0x1ec8, 4.21),
+    (0x1ec9, 0x1ec9, 2.74), (0x1eca, 0x1eca, 4.21), (0x1ecb, 0x1ecb, 2.74), (0x1ecc, 0x1ecc, 7.87),
+    (0x1ecd, 0x1ecd, 6.07), (0x1ece, 0x1ece, 7.87), (0x1ecf, 0x1ecf, 6.07), (0x1ed0, 0x1ed0, 7.87),
+    (0x1ed1, 0x1ed1, 6.07), (0x1ed2, 0x1ed2, 7.87), (0x1ed3, 0x1ed3, 6.07), (0x1ed4, 0x1ed4, 7.87),
+    (0x1ed5, 0x1ed5, 6.07), (0x1ed6, 0x1ed6, 7.87), (0x1ed7, 0x1ed7, 6.07), (0x1ed8, 0x1ed8, 7.87),
+    (0x1ed9, 0x1ed9, 6.07), (0x1eda, 0x1eda, 8.07), (0x1edb, 0x1edb, 6.07), (0x1edc, 0x1edc, 8.07),
+    (0x1edd, 0x1edd, 6.07), (0x1ede, 0x1ede, 8.07), (0x1edf, 0x1edf, 6.07), (0x1ee0, 0x1ee0, 8.07),
+    (0x1ee1, 0x1ee1, 6.07), (0x1ee2, 0x1ee2, 8.07), (0x1ee3, 0x1ee3, 6.07), (0x1ee4, 0x1ee4, 7.32),
+    (0x1ee5, 0x1ee5, 6.33), (0x1ee6, 0x1ee6, 7.32), (0x1ee7, 0x1ee7, 6.33), (0x1ee8, 0x1ee8, 7.56),
+    (0x1ee9, 0x1ee9, 6.6), (0x1eea, 0x1eea, 7.56), (0x1eeb, 0x1eeb, 6.6), (0x1eec, 0x1eec, 7.56),
+    (0x1eed, 0x1eed, 6.6), (0x1eee, 0x1eee, 7.56), (0x1eef, 0x1eef, 6.6), (0x1ef0, 0x1ef0, 7.56),
+    (0x1ef1, 0x1ef1, 6.6), (0x1ef2, 0x1ef2, 6.15), (0x1ef3, 0x1ef3, 5.92), (0x1ef4, 0x1ef4, 6.15),
+    (0x1ef5, 0x1ef5, 5.92), (0x1ef6, 0x1ef6, 6.15), (0x1ef7, 0x1ef7, 5.92), (0x1ef8, 0x1ef8, 6.15),
+    (0x1ef9, 0x1ef9, 5.92), (0x1efa, 0x1efa, 9.36), (0x1efb, 0x1efb, 5.89), (0x1efc, 0x1efc, 5.06),
+    (0x1efd, 0x1efd, 4.45), (0x1efe, 0x1efe, 4.58), (0x1f00, 0x1f07, 5.24), (0x1f08, 0x1f09, 7.22),
+    (0x1f0a, 0x1f0f, 7.91), (0x1f10, 0x1f15, 4.2), (0x1f16, 0x1f17, 10.0), (0x1f18, 0x1f19, 7.48),
+    (0x1f1a, 0x1f1d, 9.04), (0x1f1e, 0x1f1f, 10.0), (0x1f20, 0x1f27, 5.23), (0x1f28, 0x1f29, 8.59),
+    (0x1f2a, 0x1f2f, 10.16), (0x1f30, 0x1f37, 2.69), (0x1f38, 0x1f39, 4.7), (0x1f3a, 0x1f3f, 6.26),
+    (0x1f40, 0x1f45, 5.0), (0x1f46, 0x1f47, 10.0), (0x1f48, 0x1f49, 7.91), (0x1f4a, 0x1f4b, 9.96),
+    (0x1f4c, 0x1f4d, 8.98), (0x1f4e, 0x1f4f, 10.0), (0x1f50, 0x1f57, 4.95), (0x1f58, 0x1f58, 10.0),
+    (0x1f59, 0x1f59, 8.59), (0x1f5a, 0x1f5a, 10.0), (0x1f5b, 0x1f5b, 10.21), (0x1f5c, 0x1f5c, 10.0),
+    (0x1f5d, 0x1f5d, 10.21), (0x1f5e, 0x1f5e, 10.0), (0x1f5f, 0x1f5f, 10.21),
+    (0x1f60, 0x1f67, 6.58), (0x1f68, 0x1f69, 8.12), (0x1f6a, 0x1f6b, 9.98), (0x1f6c, 0x1f6f, 9.29),
+    (0x1f70, 0x1f71, 6.23), (0x1f72, 0x1f73, 5.13), (0x1f74, 0x1f75, 6.33), (0x1f76, 0x1f77, 2.74),
+    (0x1f78, 0x1f79, 6.07), (0x1f7a, 0x1f7b, 6.31), (0x1f7c, 0x1f7d, 8.13), (0x1f7e, 0x1f7f, 10.0),
+    (0x1f80, 0x1f87, 5.24), (0x1f88, 0x1f89, 7.22), (0x1f8a, 0x1f8f, 7.91), (0x1f90, 0x1f97, 5.23),
+    (0x1f98, 0x1f99, 8.59), (0x1f9a, 0x1f9f, 10.16), (0x1fa0, 0x1fa7, 6.58), (0x1fa8, 0x1fa9, 8.12),
+    (0x1faa, 0x1fab, 9.98), (0x1fac, 0x1faf, 9.29), (0x1fb0, 0x1fb4, 5.24), (0x1fb5, 0x1fb5, 10.0),
+    (0x1fb6, 0x1fb7, 5.24), (0x1fb8, 0x1fb9, 7.22), (0x1fba, 0x1fbb, 6.84), (0x1fbc, 0x1fbc, 7.22),
+    (0x1fbd, 0x1fbd, 3.33), (0x1fbe, 0x1fbe, 2.74), (0x1fbf, 0x1fc1, 3.33), (0x1fc2, 0x1fc4, 5.23),
+    (0x1fc5, 0x1fc5, 10.0), (0x1fc6, 0x1fc7, 5.23), (0x1fc8, 0x1fc8, 6.32), (0x1fc9, 0x1fca, 7.51),
+    (0x1fcb, 0x1fcb, 8.7), (0x1fcc, 0x1fcc, 7.22), (0x1fcd, 0x1fcf, 3.33), (0x1fd0, 0x1fd1, 2.69),
+    (0x1fd2, 0x1fd3, 2.74), (0x1fd4, 0x1fd5, 10.0), (0x1fd6, 0x1fd7, 2.69), (0x1fd8, 0x1fd9, 3.33),
+    (0x1fda, 0x1fda, 4.21), (0x1fdb, 0x1fdb, 5.4), (0x1fdc, 0x1fdc, 10.0), (0x1fdd, 0x1fdf, 3.33),
+    (0x1fe0, 0x1fe1, 4.95), (0x1fe2, 0x1fe3, 6.31), (0x1fe4, 0x1fe5, 4.99), (0x1fe6, 0x1fe7, 4.95),
+    (0x1fe8, 0x1fe9, 7.22), (0x1fea, 0x1fea, 6.15), (0x1feb, 0x1feb, 7.53), (0x1fec, 0x1fec, 6.93),
+    (0x1fed, 0x1fef, 6.36), (0x1ff0, 0x1ff1, 10.0), (0x1ff2, 0x1ff4, 6.58), (0x1ff5, 0x1ff5, 10.0),
+    (0x1ff6, 0x1ff7, 6.58), (0x1ff8, 0x1ff8, 7.87), (0x1ff9, 0x1ff9, 8.81), (0x1ffa, 0x1ffa, 8.18),
+    (0x1ffb, 0x1ffb, 9.08), (0x1ffc, 0x1ffc, 7.43), (0x1ffd, 0x1ffd, 6.36), (0x1ffe, 0x1ffe, 3.33),
+    (0x2000, 0x2000, 5.0), (0x2001, 0x2001, 10.0), (0x2002, 0x2002, 5.0), (0x2003, 0x2003, 10.0),
+    (0x2004, 0x2004, 3.33), (0x2005, 0x2005, 2.5), (0x2006, 0x2006, 1.67), (0x2007, 0x2007, 6.36),
+    (0x2008, 0x2008, 3.64), (0x2009, 0x2009, 2.0), (0x200a, 0x200a, 0.63), (0x200b, 0x200f, 0.0),
+    (0x2010, 0x2011, 5.79), (0x2012, 0x2013, 6.36), (0x2014, 0x2015, 10.0), (0x2016, 0x2016, 2.81),
+    (0x2017, 0x2017, 6.36), (0x2018, 0x201b, 2.69), (0x201c, 0x201f, 4.59), (0x2020, 0x2021, 6.36),
+    (0x2022, 0x2022, 5.45), (0x2023, 0x2023, 4.7), (0x2024, 0x2024, 3.33), (0x2025, 0x2025, 6.67),
+    (0x2026, 0x2026, 8.18), (0x2027, 0x2027, 3.16), (0x2028, 0x202e, 0.0), (0x202f, 0x202f, 1.76),
+    (0x2030, 0x2030, 15.21), (0x2031, 0x2031, 13.55), (0x2032, 0x2032, 3.61),
+    (0x2033, 0x2034, 5.57), (0x2035, 0x2035, 3.25), (0x2036, 0x2036, 5.42), (0x2037, 0x2037, 7.59),
+    (0x2038, 0x2038, 3.53), (0x2039, 0x203a, 4.54), (0x203b, 0x203b, 7.23), (0x203c, 0x203c, 6.25),
+    (0x203d, 0x203d, 4.22), (0x203e, 0x203e, 6.36), (0x203f, 0x2040, 5.0), (0x2041, 0x2041, 4.65),
+    (0x2042, 0x2042, 9.11), (0x2043, 0x2043, 3.25), (0x2044, 0x2044, 3.61), (0x2045, 0x2046, 3.25),
+    (0x2047, 0x2047, 8.07), (0x2048, 0x2049, 7.07), (0x204a, 0x204b, 6.32), (0x204c, 0x204d, 5.42),
+    (0x204e, 0x204e, 4.82), (0x204f, 0x204f, 3.16), (0x2050, 0x2050, 5.0), (0x2051, 0x2051, 4.82),
+    (0x2052, 0x2052, 5.51), (0x2053, 0x2053, 10.0), (0x2054, 0x2054, 5.0), (0x2055, 0x2055, 6.97),
+    (0x2056, 0x2056, 5.93), (0x2057, 0x2057, 9.74), (0x2058, 0x2059, 5.93), (0x205a, 0x205a, 10.0),
+    (0x205b, 0x205b, 5.93), (0x205c, 0x205c, 6.98), (0x205d, 0x205d, 10.0), (0x205e, 0x205e, 2.78),
+    (0x205f, 0x205f, 2.22), (0x2060, 0x206e, 0.0), (0x2070, 0x2070, 4.22), (0x2071, 0x2071, 2.17),
+    (0x2072, 0x2073, 10.0), (0x2074, 0x2075, 5.42), (0x2076, 0x2076, 4.22), (0x2077, 0x2078, 5.42),
+    (0x2079, 0x207c, 4.22), (0x207d, 0x207e, 2.26), (0x207f, 0x207f, 5.45), (0x2080, 0x208c, 4.22),
+    (0x208d, 0x208e, 2.26), (0x208f, 0x208f, 10.0), (0x2090, 0x2090, 2.73), (0x2091, 0x2091, 2.56),
+    (0x2092, 0x2092, 2.88), (0x2093, 0x2093, 2.9), (0x2094, 0x2094, 2.56), (0x2095, 0x209e, 10.0),
+    (0x20a0, 0x20a0, 6.36), (0x20a1, 0x20a2, 6.98), (0x20a3, 0x20a4, 6.36), (0x20a5, 0x20a5, 9.73),
+    (0x20a6, 0x20a6, 7.48), (0x20a7, 0x20a7, 11.64), (0x20a8, 0x20a8, 11.67),
+    (0x20a9, 0x20a9, 9.89), (0x20aa, 0x20aa, 8.35), (0x20ab, 0x20ab, 6.23), (0x20ac, 0x20ac, 6.36),
+    (0x20ad, 0x20ad, 6.93), (0x20ae, 0x20ae, 6.16), (0x20af, 0x20af, 10.0), (0x20b0, 0x20b0, 5.21),
+    (0x20b1, 0x20b1, 6.03), (0x20b2, 0x20b2, 7.75), (0x20b3, 0x20b4, 6.84), (0x20b5, 0x20b5, 6.98),
+    (0x20b6, 0x20b6, 6.77), (0x20b7, 0x20b7, 7.3), (0x20b8, 0x20b8, 5.65), (0x20b9, 0x20b9, 6.0),
+    (0x20ba, 0x20ba, 5.56), (0x20bb, 0x20bb, 8.31), (0x20bc, 0x20bd, 5.65), (0x20be, 0x20be, 7.53),
+    (0x20bf, 0x20bf, 6.59), (0x20c0, 0x20ce, 10.0), (0x20d0, 0x20d2, 0.0), (0x20d3, 0x20d3, 6.0),
+    (0x20d4, 0x20d5, 7.0), (0x20d6, 0x20d8, 0.0), (0x20d9, 0x20da, 6.0), (0x20db, 0x20dd, 0.0),
+    (0x20de, 0x20de, 10.0), (0x20df, 0x20df, 8.65), (0x20e0, 0x20e0, 9.0), (0x20e1, 0x20e1, 0.0),
+    (0x20e2, 0x20e2, 10.0), (0x20e3, 0x20f0, 0.0), (0x20f1, 0x20fe, 10.0), (0x2100, 0x2101, 7.5),
+    (0x2102, 0x2102, 6.02), (0x2103, 0x2103, 9.89), (0x2104, 0x2104, 6.4), (0x2105, 0x2105, 10.76),
+    (0x2106, 0x2106, 7.5), (0x2107, 0x2107, 5.98), (0x2108, 0x2108, 6.03), (0x2109, 0x2109, 8.47),
+    (0x210a, 0x210a, 10.0), (0x210b, 0x210b, 9.97), (0x210c, 0x210c, 8.24), (0x210d, 0x210d, 6.02),
+    (0x210e, 0x210e, 5.77), (0x210f, 0x210f, 6.15), (0x2110, 0x2110, 8.97), (0x2111, 0x2111, 7.62),
+    (0x2112, 0x2112, 9.46), (0x2113, 0x2113, 3.23), (0x2114, 0x2114, 7.78), (0x2115, 0x2115, 6.02),
+    (0x2116, 0x2116, 11.72), (0x2117, 0x2117, 8.55), (0x2118, 0x2118, 8.26), (0x2119, 0x211a, 6.02),
+    (0x211b, 0x211b, 9.44), (0x211c, 0x211c, 8.74), (0x211d, 0x211d, 6.02), (0x211e, 0x211e, 6.32),
+    (0x211f, 0x211f, 6.67), (0x2120, 0x2120, 11.28), (0x2121, 0x2121, 15.03),
+    (0x2122, 0x2122, 9.77), (0x2123, 0x2123, 7.22), (0x2124, 0x2124, 6.02), (0x2125, 0x2125, 5.12),
+    (0x2126, 0x2126, 8.18), (0x2127, 0x2127, 7.95), (0x2128, 0x2128, 7.26), (0x2129, 0x2129, 4.0),
+    (0x212a, 0x212a, 6.93), (0x212b, 0x212b, 6.84), (0x212c, 0x212c, 9.5), (0x212d, 0x212d, 7.17),
+    (0x212e, 0x212e, 7.18), (0x212f, 0x212f, 6.27), (0x2130, 0x2130, 7.5), (0x2131, 0x2131, 9.19),
+    (0x2132, 0x2132, 6.64), (0x2133, 0x2133, 10.72), (0x2134, 0x2134, 6.97), (0x2135, 0x2135, 6.45),
+    (0x2136, 0x2136, 5.63), (0x2137, 0x2137, 4.27), (0x2138, 0x2138, 5.01), (0x2139, 0x2139, 6.6),
+    (0x213a, 0x213a, 9.34), (0x213b, 0x213b, 16.4), (0x213c, 0x213c, 6.73), (0x213d, 0x213d, 5.4),
+    (0x213e, 0x213e, 4.69), (0x213f, 0x213f, 7.18), (0x2140, 0x2140, 9.23), (0x2141, 0x2141, 7.23),
+    (0x2142, 0x2143, 5.33), (0x2144, 0x2144, 6.23), (0x2145, 0x2145, 7.13), (0x2146, 0x2146, 5.81),
+    (0x2147, 0x2147, 5.15), (0x2148, 0x2148, 2.93), (0x2149, 0x2149, 3.41), (0x214a, 0x214a, 6.64),
+    (0x214b, 0x214b, 6.97), (0x214c, 0x214c, 8.1), (0x214d, 0x214d, 8.82), (0x214e, 0x214e, 4.17),
+    (0x2150, 0x2152, 10.0), (0x2153, 0x2154, 7.5), (0x2155, 0x215a, 8.01), (0x215b, 0x215e, 10.0),
+    (0x215f, 0x215f, 3.8), (0x2160, 0x2160, 2.88), (0x2161, 0x2161, 5.9), (0x2162, 0x2162, 8.37),
+    (0x2163, 0x2163, 9.05), (0x2164, 0x2164, 6.53), (0x2165, 0x2165, 9.18), (0x2166, 0x2166, 12.13),
+    (0x2167, 0x2167, 14.6), (0x2168, 0x2168, 8.72), (0x2169, 0x2169, 6.26), (0x216a, 0x216a, 8.53),
+    (0x216b, 0x216b, 11.47), (0x216c, 0x216c, 5.33), (0x216d, 0x216d, 6.92), (0x216e, 0x216e, 7.49),
+    (0x216f, 0x216f, 8.61), (0x2170, 0x2170, 2.89), (0x2171, 0x2171, 5.78), (0x2172, 0x2172, 8.19),
+    (0x2173, 0x2173, 7.79), (0x2174, 0x2174, 5.18), (0x2175, 0x2175, 7.82), (0x2176, 0x2176, 10.71),
+    (0x2177, 0x2177, 13.12), (0x2178, 0x2178, 8.35), (0x2179, 0x2179, 6.13), (0x217a, 0x217a, 8.33),
+    (0x217b, 0x217b, 11.22), (0x217c, 0x217c, 2.89), (0x217d, 0x217d, 5.12), (0x217e, 0x217e, 6.29),
+    (0x217f, 0x217f, 9.34), (0x2180, 0x2180, 10.41), (0x2181, 0x2181, 7.49),
+    (0x2182, 0x2182, 10.41), (0x2183, 0x2183, 6.92), (0x2184, 0x2184, 4.44), (0x2185, 0x2185, 6.51),
+    (0x2186, 0x2186, 5.64), (0x2187, 0x218e, 10.0), (0x2190, 0x2190, 10.0), (0x2191, 0x2191, 5.0),
+    (0x2192, 0x2192, 10.0), (0x2193, 0x2193, 5.0), (0x2194, 0x2194, 10.0), (0x2195, 0x2195, 5.0),
+    (0x2196, 0x219b, 10.0), (0x219c, 0x21a7, 6.02), (0x21a8, 0x21a8, 5.0), (0x21a9, 0x21a9, 10.0),
+    (0x21aa, 0x21aa, 9.88), (0x21ab, 0x21ad, 6.02), (0x21ae, 0x21ae, 10.0), (0x21af, 0x21af, 6.02),
+    (0x21b0, 0x21b2, 8.65), (0x21b3, 0x21b3, 10.0), (0x21b4, 0x21b4, 8.65), (0x21b5, 0x21b5, 10.0),
+    (0x21b6, 0x21b7, 8.65), (0x21b8, 0x21b9, 10.0), (0x21ba, 0x21bb, 6.02), (0x21bc, 0x21bc, 8.65),
+    (0x21bd, 0x21bf, 6.02), (0x21c0, 0x21c0, 8.65), (0x21c1, 0x21c3, 6.02), (0x21c4, 0x21c6, 10.0),
+    (0x21c7, 0x21ca, 6.02), (0x21cb, 0x21cc, 10.0), (0x21cd, 0x21cd, 8.8), (0x21ce, 0x21ce, 6.02),
+    (0x21cf, 0x21cf, 8.69), (0x21d0, 0x21d0, 10.0), (0x21d1, 0x21d1, 8.65), (0x21d2, 0x21d2, 9.31),
+    (0x21d3, 0x21d3, 8.65), (0x21d4, 0x21d4, 9.31), (0x21d5, 0x21dd, 6.02), (0x21de, 0x21df, 6.45),
+    (0x21e0, 0x21e0, 10.0), (0x21e1, 0x21e1, 6.45), (0x21e2, 0x21e2, 10.0), (0x21e3, 0x21e3, 6.45),
+    (0x21e4, 0x21ea, 10.0), (0x21eb, 0x21ef, 6.02), (0x21f0, 0x21f0, 8.65), (0x21f1, 0x21f4, 6.02),
+    (0x21f5, 0x21f5, 10.0), (0x21f6, 0x21fe, 6.02), (0x2200, 0x2200, 6.3), (0x2201, 0x2201, 4.69),
+    (0x2202, 0x2202, 6.36), (0x2203, 0x2203, 5.26), (0x2204, 0x2204, 5.35), (0x2205, 0x2205, 7.17),
+    (0x2206, 0x2206, 7.27), (0x2207, 0x2207, 7.0), (0x2208, 0x2208, 5.76), (0x2209, 0x2209, 6.12),
+    (0x220a, 0x220a, 5.09), (0x220b, 0x220b, 5.76), (0x220c, 0x220c, 5.89), (0x220d, 0x220d, 5.09),
+    (0x220e, 0x220e, 5.22), (0x220f, 0x220f, 8.18), (0x2210, 0x2210, 7.95), (0x2211, 0x2211, 7.27),
+    (0x2212, 0x2212, 8.18), (0x2213, 0x2213, 7.95), (0x2214, 0x2214, 5.99), (0x2215, 0x2215, 3.61),
+    (0x2216, 0x2216, 7.95), (0x2217, 0x2217, 5.23), (0x2218, 0x2218, 3.55), (0x2219, 0x2219, 3.64),
+    (0x221a, 0x221a, 8.18), (0x221b, 0x221b, 7.49), (0x221c, 0x221c, 7.24), (0x221d, 0x221d, 5.19),
+    (0x221e, 0x221e, 10.0), (0x221f, 0x221f, 9.79), (0x2220, 0x2220, 5.98), (0x2221, 0x2221, 6.01),
+    (0x2222, 0x2222, 6.05), (0x2223, 0x2223, 2.5), (0x2224, 0x2224, 4.73), (0x2225, 0x2225, 3.46),
+    (0x2226, 0x2226, 5.25), (0x2227, 0x2228, 5.94), (0x2229, 0x2229, 7.22), (0x222a, 0x222a, 5.31),
+    (0x222b, 0x222b, 6.36), (0x222c, 0x222c, 9.15), (0x222d, 0x222d, 11.24), (0x222e, 0x222e, 6.78),
+    (0x222f, 0x222f, 9.24), (0x2230, 0x2230, 11.66), (0x2231, 0x2231, 5.98), (0x2232, 0x2232, 6.28),
+    (0x2233, 0x2233, 5.97), (0x2234, 0x2234, 6.04), (0x2235, 0x2235, 5.97), (0x2236, 0x2236, 3.36),
+    (0x2237, 0x2237, 5.76), (0x2238, 0x2238, 5.63), (0x2239, 0x2239, 5.76), (0x223a, 0x223a, 5.8),
+    (0x223b, 0x223b, 5.6), (0x223c, 0x223d, 5.5), (0x223e, 0x223e, 5.43), (0x223f, 0x223f, 5.83),
+    (0x2240, 0x2240, 3.9), (0x2241, 0x2241, 5.74), (0x2242, 0x2242, 5.8), (0x2243, 0x2243, 5.84),
+    (0x2244, 0x2244, 6.71), (0x2245, 0x2245, 6.96), (0x2246, 0x2246, 6.29), (0x2247, 0x2247, 6.11),
+    (0x2248, 0x2248, 8.18), (0x2249, 0x2249, 5.49), (0x224a, 0x224a, 6.04), (0x224b, 0x224b, 6.71),
+    (0x224c, 0x224c, 5.43), (0x224d, 0x224d, 6.03), (0x224e, 0x224e, 5.97), (0x224f, 0x224f, 5.93),
+    (0x2250, 0x2250, 5.6), (0x2251, 0x2251, 6.38), (0x2252, 0x2253, 6.32), (0x2254, 0x2255, 7.64),
+    (0x2256, 0x2256, 5.79), (0x2257, 0x2257, 5.96), (0x2258, 0x2258, 5.89), (0x2259, 0x2259, 6.08),
+    (0x225a, 0x225a, 6.19), (0x225b, 0x225b, 6.46), (0x225c, 0x225c, 6.36), (0x225d, 0x225d, 5.63),
+    (0x225e, 0x225e, 5.75), (0x225f, 0x225f, 5.9), (0x2260, 0x2261, 8.18), (0x2262, 0x2262, 5.64),
+    (0x2263, 0x2263, 5.93), (0x2264, 0x2265, 8.18), (0x2266, 0x2266, 5.75), (0x2267, 0x2267, 5.64),
+    (0x2268, 0x2268, 6.22), (0x2269, 0x2269, 5.89), (0x226a, 0x226a, 7.33), (0x226b, 0x226b, 7.01),
+    (0x226c, 0x226c, 3.58), (0x226d, 0x226d, 5.53), (0x226e, 0x226f, 5.64), (0x2270, 0x2271, 5.49),
+    (0x2272, 0x2272, 5.64), (0x2273, 0x2273, 5.67), (0x2274, 0x2274, 5.83), (0x2275, 0x2275, 5.81),
+    (0x2276, 0x2277, 6.05), (0x2278, 0x2278, 5.61), (0x2279, 0x2279, 5.77), (0x227a, 0x227a, 5.67),
+    (0x227b, 0x227b, 5.78), (0x227c, 0x227c, 5.96), (0x227d, 0x227d, 5.78), (0x227e, 0x227e, 5.85),
+    (0x227f, 0x227f, 5.82), (0x2280, 0x2280, 6.07), (0x2281, 0x2281, 5.79), (0x2282, 0x2283, 6.32),
+    (0x2284, 0x2284, 6.44), (0x2285, 0x2285, 6.31), (0x2286, 0x2287, 6.63), (0x2288, 0x2288, 6.67),
+    (0x2289, 0x2289, 6.49), (0x228a, 0x228b, 5.62), (0x228c, 0x228d, 5.82), (0x228e, 0x228e, 6.09),
+    (0x228f, 0x2290, 6.54), (0x2291, 0x2292, 6.51), (0x2293, 0x2294, 6.11), (0x2295, 0x2295, 7.24),
+    (0x2296, 0x2296, 7.45), (0x2297, 0x2297, 7.11), (0x2298, 0x2298, 7.22), (0x2299, 0x2299, 7.56),
+    (0x229a, 0x229a, 7.35), (0x229b, 0x229b, 7.18), (0x229c, 0x229c, 7.61), (0x229d, 0x229d, 7.56),
+    (0x229e, 0x229e, 7.84), (0x229f, 0x229f, 7.6), (0x22a0, 0x22a0, 7.33), (0x22a1, 0x22a1, 7.16),
+    (0x22a2, 0x22a5, 7.5), (0x22a6, 0x22a6, 3.3), (0x22a7, 0x22a7, 3.33), (0x22a8, 0x22a8, 6.24),
+    (0x22a9, 0x22a9, 7.29), (0x22aa, 0x22aa, 6.23), (0x22ab, 0x22ab, 7.55), (0x22ac, 0x22ac, 7.76),
+    (0x22ad, 0x22ad, 7.78), (0x22ae, 0x22ae, 8.74), (0x22af, 0x22af, 8.36), (0x22b0, 0x22b1, 6.32),
+    (0x22b2, 0x22b3, 7.41), (0x22b4, 0x22b5, 7.03), (0x22b6, 0x22b7, 7.35), (0x22b8, 0x22b8, 5.96),
+    (0x22b9, 0x22b9, 6.83), (0x22ba, 0x22ba, 4.8), (0x22bb, 0x22bc, 6.96), (0x22bd, 0x22bd, 6.74),
+    (0x22be, 0x22be, 7.42), (0x22bf, 0x22bf, 6.31), (0x22c0, 0x22c1, 6.71), (0x22c2, 0x22c3, 6.41),
+    (0x22c4, 0x22c4, 3.81), (0x22c5, 0x22c5, 4.41), (0x22c6, 0x22c6, 4.48), (0x22c7, 0x22c7, 6.31),
+    (0x22c8, 0x22c8, 7.72), (0x22c9, 0x22ca, 7.4), (0x22cb, 0x22cc, 7.24), (0x22cd, 0x22cd, 5.84),
+    (0x22ce, 0x22cf, 6.35), (0x22d0, 0x22d1, 6.48), (0x22d2, 0x22d3, 5.69), (0x22d4, 0x22d4, 5.49),
+    (0x22d5, 0x22d5, 6.14), (0x22d6, 0x22d7, 6.03), (0x22d8, 0x22d9, 9.68), (0x22da, 0x22db, 6.51),
+    (0x22dc, 0x22dd, 6.46), (0x22de, 0x22df, 6.69), (0x22e0, 0x22e0, 6.66), (0x22e1, 0x22e1, 6.56),
+    (0x22e2, 0x22e2, 6.86), (0x22e3, 0x22e3, 6.64), (0x22e4, 0x22e4, 6.71), (0x22e5, 0x22e5, 6.73),
+    (0x22e6, 0x22e6, 6.76), (0x22e7, 0x22e7, 6.86), (0x22e8, 0x22e8, 7.19), (0x22e9, 0x22e9, 6.88),
+    (0x22ea, 0x22ea, 7.49), (0x22eb, 0x22eb, 7.66), (0x22ec, 0x22ec, 7.71), (0x22ed, 0x22ed, 7.76),
+    (0x22ee, 0x22ee, 4.11), (0x22ef, 0x22ef, 10.0), (0x22f0, 0x22f0, 9.2), (0x22f1, 0x22f1, 9.39),
+    (0x22f2, 0x22f2, 6.5), (0x22f3, 0x22f3, 5.76), (0x22f4, 0x22f4, 5.09), (0x22f5, 0x22f6, 5.76),
+    (0x22f7, 0x22f7, 5.09), (0x22f8, 0x22f8, 5.76), (0x22f9, 0x22f9, 6.32), (0x22fa, 0x22fa, 6.5),
+    (0x22fb, 0x22fb, 5.76), (0x22fc, 0x22fc, 5.09), (0x22fd, 0x22fd, 5.76), (0x22fe, 0x22fe, 5.09),
+    (0x2300, 0x2301, 6.02), (0x2302, 0x2302, 6.0), (0x2303, 0x2303, 7.95), (0x2304, 0x2304, 6.02),
+    (0x2305, 0x2305, 7.95), (0x2306, 0x2306, 6.64), (0x2307, 0x2307, 10.0), (0x2308, 0x230f, 6.02),
+    (0x2310, 0x2310, 5.64), (0x2311, 0x2311, 6.02), (0x2312, 0x2312, 10.0), (0x2313, 0x2313, 6.02),
+    (0x2314, 0x2314, 8.65), (0x2315, 0x2315, 6.02), (0x2316, 0x2317, 11.0), (0x2318, 0x2318, 10.0),
+    (0x2319, 0x2319, 6.02), (0x231a, 0x231b, 10.0), (0x231c, 0x231f, 6.02), (0x2320, 0x2321, 6.04),
+    (0x2322, 0x2323, 8.65), (0x2324, 0x2324, 7.95), (0x2325, 0x2325, 10.0), (0x2326, 0x2326, 12.14),
+    (0x2327, 0x2327, 10.0), (0x2328, 0x2328, 6.02), (0x2329, 0x232a, 10.0), (0x232b, 0x232b, 12.14),
+    (0x232c, 0x232c, 9.26), (0x232d, 0x232d, 9.86), (0x232e, 0x232e, 6.24), (0x232f, 0x2331, 5.49),
+    (0x2332, 0x2332, 8.89), (0x2333, 0x2334, 5.49), (0x2335, 0x2335, 6.02), (0x2336, 0x2336, 9.26),
+    (0x2337, 0x233c, 6.02), (0x233d, 0x233d, 10.0), (0x233e, 0x233e, 6.02), (0x233f, 0x2340, 6.83),
+    (0x2341, 0x2344, 6.02), (0x2345, 0x2346, 6.0), (0x2347, 0x2349, 6.02), (0x234a, 0x234a, 6.0),
+    (0x234b, 0x234d, 6.02), (0x234e, 0x234f, 6.0), (0x2350, 0x2350, 6.02), (0x2351, 0x2351, 6.0),
+    (0x2352, 0x2354, 6.02), (0x2355, 0x2356, 6.0), (0x2357, 0x235c, 6.02), (0x235d, 0x235d, 6.0),
+    (0x235e, 0x2360, 6.02), (0x2361, 0x2362, 6.0), (0x2363, 0x2365, 6.02), (0x2366, 0x2367, 6.0),
+    (0x2368, 0x2369, 6.02), (0x236a, 0x236a, 6.0), (0x236b, 0x2370, 6.02), (0x2371, 0x2372, 6.0),
+    (0x2373, 0x237a, 6.02), (0x237b, 0x237b, 6.69), (0x237c, 0x237c, 8.71), (0x237d, 0x237d, 3.16),
+    (0x237e, 0x237e, 9.39), (0x237f, 0x237f, 3.71), (0x2380, 0x2383, 6.02), (0x2384, 0x2384, 7.85),
+    (0x2385, 0x2385, 6.84), (0x2386, 0x2386, 6.75), (0x2387, 0x2388, 10.0), (0x2389, 0x238a, 6.02),
+    (0x238b, 0x238b, 10.0), (0x238c, 0x238c, 8.08), (0x238d, 0x238d, 7.86), (0x238e, 0x238e, 7.47),
+    (0x238f, 0x2392, 6.58), (0x2393, 0x2393, 9.13), (0x2394, 0x2394, 9.26), (0x2395, 0x2395, 6.02),
+    (0x2396, 0x2396, 4.72), (0x2397, 0x2398, 7.89), (0x2399, 0x239a, 8.15), (0x239b, 0x23ad, 10.0),
+    (0x23ae, 0x23ae, 6.02), (0x23af, 0x23af, 3.15), (0x23b0, 0x23b1, 10.0), (0x23b2, 0x23b3, 14.88),
+    (0x23b4, 0x23b6, 9.26), (0x23b7, 0x23b7, 6.43), (0x23b8, 0x23bd, 5.03), (0x23be, 0x23cc, 10.0),
+    (0x23cd, 0x23cd, 9.98), (0x23ce, 0x23ce, 10.0), (0x23cf, 0x23cf, 8.57), (0x23d0, 0x23d0, 5.11),
+    (0x23d1, 0x23d3, 5.42), (0x23d4, 0x23d5, 10.31), (0x23d6, 0x23d6, 9.58), (0x23d7, 0x23d9, 9.76),
+    (0x23da, 0x23e1, 10.0), (0x23e2, 0x23e2, 11.44), (0x23e3, 0x23e3, 9.1), (0x23e4, 0x23e4, 10.94),
+    (0x23e5, 0x23e5, 10.18), (0x23e6, 0x23e6, 9.26), (0x23e7, 0x23e7, 7.98), (0x23e8, 0x23fe, 10.0),
+    (0x2400, 0x241f, 10.0), (0x2420, 0x2420, 6.0), (0x2421, 0x2421, 10.0), (0x2422, 0x2422, 6.0),
+    (0x2423, 0x2423, 3.16), (0x2424, 0x2424, 6.0), (0x2425, 0x2425, 7.92), (0x2426, 0x2426, 4.03),
+    (0x2427, 0x243e, 10.0), (0x2440, 0x2445, 6.04), (0x2446, 0x2449, 7.5), (0x244a, 0x244a, 6.04),
+    (0x244b, 0x245e, 10.0), (0x2460, 0x24c4, 10.0), (0x24c5, 0x24c5, 8.55), (0x24c6, 0x24fe, 10.0),
+    (0x2500, 0x2500, 7.08), (0x2501, 0x2501, 10.0), (0x2502, 0x2502, 7.08), (0x2503, 0x250b, 10.0),
+    (0x250c, 0x250c, 7.08), (0x250d, 0x250f, 10.0), (0x2510, 0x2510, 7.08), (0x2511, 0x2513, 10.0),
+    (0x2514, 0x2514, 7.08), (0x2515, 0x2517, 10.0), (0x2518, 0x2518, 7.08), (0x2519, 0x251b, 10.0),
+    (0x251c, 0x251c, 7.08), (0x251d, 0x2523, 10.0), (0x2524, 0x2524, 7.08), (0x2525, 0x252b, 10.0),
+    (0x252c, 0x252c, 7.08), (0x252d, 0x2533, 10.0), (0x2534, 0x2534, 7.08), (0x2535, 0x253b, 10.0),
+    (0x253c, 0x253c, 7.08), (0x253d, 0x254b, 10.0), (0x254c, 0x254f, 6.02), (0x2550, 0x256c, 7.08),
+    (0x256d, 0x2574, 10.0), (0x2575, 0x257e, 6.02), (0x2580, 0x2580, 7.08), (0x2581, 0x2583, 10.0),
+    (0x2584, 0x2584, 7.08), (0x2585, 0x2587, 10.0), (0x2588, 0x2588, 7.08), (0x2589, 0x258b, 10.0),
+    (0x258c, 0x258c, 7.08), (0x258d, 0x258f, 10.0), (0x2590, 0x2592, 7.08), (0x2593, 0x2593, 7.29),
+    (0x2594, 0x2595, 10.0), (0x2596, 0x259e, 6.02), (0x25a0, 0x25a1, 6.04), (0x25a2, 0x25a2, 10.0),
+    (0x25a3, 0x25a9, 8.65), (0x25aa, 0x25ab, 3.54), (0x25ac, 0x25ac, 10.0), (0x25ad, 0x25ad, 8.65),
+    (0x25ae, 0x25b0, 6.02), (0x25b1, 0x25b1, 10.0), (0x25b2, 0x25b2, 9.9), (0x25b3, 0x25b3, 10.0),
+    (0x25b4, 0x25b4, 5.51), (0x25b5, 0x25b5, 8.65), (0x25b6, 0x25b6, 8.8), (0x25b7, 0x25b7, 10.0),
+    (0x25b8, 0x25b9, 5.3), (0x25ba, 0x25ba, 9.9), (0x25bb, 0x25bb, 6.02), (0x25bc, 0x25bc, 9.9),
+    (0x25bd, 0x25bd, 10.0), (0x25be, 0x25bf, 6.39), (0x25c0, 0x25c0, 10.0), (0x25c1, 0x25c1, 7.95),
+    (0x25c2, 0x25c2, 6.02), (0x25c3, 0x25c3, 8.65), (0x25c4, 0x25c4, 9.9), (0x25c5, 0x25c5, 6.02),
+    (0x25c6, 0x25c6, 7.23), (0x25c7, 0x25c7, 6.15), (0x25c8, 0x25c8, 8.65), (0x25c9, 0x25c9, 10.0),
+    (0x25ca, 0x25ca, 8.18), (0x25cb, 0x25cb, 6.04), (0x25cc, 0x25cc, 5.94), (0x25cd, 0x25cd, 6.02),
+    (0x25ce, 0x25ce, 10.0), (0x25cf, 0x25cf, 6.04), (0x25d0, 0x25d3, 10.0), (0x25d4, 0x25d7, 6.02),
+    (0x25d8, 0x25d9, 6.04), (0x25da, 0x25e1, 6.02), (0x25e2, 0x25e5, 10.0), (0x25e6, 0x25e6, 3.54),
+    (0x25e7, 0x25ee, 6.02), (0x25ef, 0x25ef, 10.0), (0x25f0, 0x25fa, 6.02), (0x25fb, 0x25fe, 10.0),
+    (0x2600, 0x2603, 10.0), (0x2604, 0x2604, 6.02), (0x2605, 0x2606, 10.0), (0x2607, 0x2608, 6.02),
+    (0x2609, 0x2609, 10.0), (0x260a, 0x260d, 6.02), (0x260e, 0x260e, 10.0), (0x260f, 0x260f, 8.65),
+    (0x2610, 0x2612, 8.3), (0x2613, 0x2613, 6.02), (0x2614, 0x2617, 10.0), (0x2618, 0x261b, 6.02),
+    (0x261c, 0x261f, 10.0), (0x2620, 0x262e, 6.02), (0x262f, 0x262f, 8.65), (0x2630, 0x2637, 9.0),
+    (0x2638, 0x2639, 6.02), (0x263a, 0x263a, 10.21), (0x263b, 0x263b, 10.52),
+    (0x263c, 0x263c, 9.17), (0x263d, 0x263f, 6.02), (0x2640, 0x2640, 7.5), (0x2641, 0x2641, 6.02),
+    (0x2642, 0x2642, 7.5), (0x2643, 0x2647, 6.02), (0x2648, 0x2653, 10.0), (0x2654, 0x265f, 6.02),
+    (0x2660, 0x2660, 5.31), (0x2661, 0x2662, 10.0), (0x2663, 0x2663, 6.56), (0x2664, 0x2664, 10.0),
+    (0x2665, 0x2665, 5.94), (0x2666, 0x2666, 5.1), (0x2667, 0x2669, 10.0), (0x266a, 0x266a, 5.0),
+    (0x266b, 0x266b, 7.5), (0x266c, 0x266e, 10.0), (0x266f, 0x266f, 5.0), (0x2670, 0x2671, 6.02),
+    (0x2672, 0x267d, 10.0), (0x267e, 0x267e, 6.02), (0x267f, 0x267f, 10.0), (0x2680, 0x268b, 6.02),
+    (0x268c, 0x268c, 8.79), (0x268d, 0x268d, 8.63), (0x268e, 0x268e, 8.59), (0x268f, 0x268f, 8.96),
+    (0x2690, 0x2692, 6.02), (0x2693, 0x2693, 10.0), (0x2694, 0x269c, 6.02), (0x269d, 0x26a1, 10.0),
+    (0x26a2, 0x26a2, 8.92), (0x26a3, 0x26a3, 8.97), (0x26a4, 0x26a4, 8.83), (0x26a5, 0x26a5, 6.6),
+    (0x26a6, 0x26a6, 7.41), (0x26a7, 0x26a7, 9.23), (0x26a8, 0x26a8, 5.33), (0x26a9, 0x26a9, 9.2),
+    (0x26aa, 0x26ab, 10.0), (0x26ac, 0x26ac, 5.65), (0x26ad, 0x26ad, 7.76), (0x26ae, 0x26ae, 10.55),
+    (0x26af, 0x26af, 11.18), (0x26b0, 0x26b1, 6.02), (0x26b2, 0x26b2, 6.03), (0x26b3, 0x26e1, 10.0),
+    (0x26e2, 0x26e2, 7.0), (0x26e3, 0x26fe, 10.0), (0x2700, 0x2700, 10.0), (0x2701, 0x2701, 9.74),
+    (0x2702, 0x2702, 9.61), (0x2703, 0x2703, 9.74), (0x2704, 0x2704, 9.8), (0x2705, 0x2705, 10.0),
+    (0x2706, 0x2706, 7.89), (0x2707, 0x2707, 7.9), (0x2708, 0x2708, 7.91), (0x2709, 0x2709, 6.9),
+    (0x270a, 0x270d, 10.0), (0x270e, 0x270e, 7.88), (0x270f, 0x270f, 9.33), (0x2710, 0x2710, 9.11),
+    (0x2711, 0x2711, 9.45), (0x2712, 0x2712, 9.74), (0x2713, 0x2713, 7.64), (0x2714, 0x2714, 8.46),
+    (0x2715, 0x2715, 7.62), (0x2716, 0x2716, 7.61), (0x2717, 0x2717, 5.71), (0x2718, 0x2718, 6.77),
+    (0x2719, 0x2719, 7.63), (0x271a, 0x271a, 7.6), (0x271b, 0x271b, 7.59), (0x271c, 0x271c, 7.54),
+    (0x271d, 0x271d, 4.94), (0x271e, 0x271e, 5.52), (0x271f, 0x271f, 5.37), (0x2720, 0x2720, 5.77),
+    (0x2721, 0x2721, 6.92), (0x2722, 0x2722, 7.86), (0x2723, 0x2724, 7.88), (0x2725, 0x2725, 7.9),
+    (0x2726, 0x2726, 7.93), (0x2727, 0x2727, 7.94), (0x2728, 0x2728, 10.0), (0x2729, 0x2729, 8.23),
+    (0x272a, 0x272a, 7.89), (0x272b, 0x272b, 8.41), (0x272c, 0x272c, 8.23), (0x272d, 0x272d, 8.33),
+    (0x272e, 0x272e, 8.16), (0x272f, 0x272f, 8.31), (0x2730, 0x2730, 9.23), (0x2731, 0x2731, 7.44),
+    (0x2732, 0x2732, 7.23), (0x2733, 0x2733, 7.49), (0x2734, 0x2734, 7.9), (0x2735, 0x2735, 7.92),
+    (0x2736, 0x2736, 6.95), (0x2737, 0x2737, 7.76), (0x2738, 0x2738, 7.68), (0x2739, 0x2739, 7.92),
+    (0x273a, 0x273a, 7.59), (0x273b, 0x273b, 7.07), (0x273c, 0x273c, 7.08), (0x273d, 0x273d, 6.82),
+    (0x273e, 0x273e, 7.01), (0x273f, 0x273f, 8.26), (0x2740, 0x2740, 8.15), (0x2741, 0x2742, 7.89),
+    (0x2743, 0x2743, 7.07), (0x2744, 0x2744, 6.87), (0x2745, 0x2745, 6.96), (0x2746, 0x2746, 6.89),
+    (0x2747, 0x2747, 7.86), (0x2748, 0x2748, 7.87), (0x2749, 0x2749, 7.13), (0x274a, 0x274a, 7.91),
+    (0x274b, 0x274b, 7.85), (0x274c, 0x274c, 10.0), (0x274d, 0x274d, 8.73), (0x274e, 0x274e, 10.0),
+    (0x274f, 0x2750, 7.62), (0x2751, 0x2752, 7.59), (0x2753, 0x2755, 10.0), (0x2756, 0x2756, 7.84),
+    (0x2757, 0x2757, 10.0), (0x2758, 0x2758, 2.89), (0x2759, 0x2759, 2.77), (0x275a, 0x275a, 4.15),
+    (0x275b, 0x275c, 3.92), (0x275d, 0x275e, 6.68), (0x275f, 0x2760, 10.0), (0x2761, 0x2761, 7.32),
+    (0x2762, 0x2763, 5.44), (0x2764, 0x2764, 9.1), (0x2765, 0x2765, 6.67), (0x2766, 0x2767, 7.6),
+    (0x2768, 0x2769, 3.9), (0x276a, 0x276b, 3.17), (0x276c, 0x276d, 2.76), (0x276e, 0x276f, 5.09),
+    (0x2770, 0x2771, 4.1), (0x2772, 0x2773, 2.34), (0x2774, 0x2775, 3.34), (0x2776, 0x2793, 7.88),
+    (0x2794, 0x2794, 8.94), (0x2795, 0x2797, 10.0), (0x2798, 0x2798, 7.48), (0x2799, 0x2799, 9.24),
+    (0x279a, 0x279a, 7.48), (0x279b, 0x279b, 9.18), (0x279c, 0x279c, 9.27), (0x279d, 0x279e, 9.28),
+    (0x279f, 0x279f, 8.34), (0x27a0, 0x27a0, 8.73), (0x27a1, 0x27a1, 8.28), (0x27a2, 0x27a3, 9.24),
+    (0x27a4, 0x27a4, 9.17), (0x27a5, 0x27a5, 9.3), (0x27a6, 0x27a6, 9.31), (0x27a7, 0x27a7, 4.63),
+    (0x27a8, 0x27a8, 8.83), (0x27a9, 0x27aa, 8.36), (0x27ab, 0x27ac, 8.67), (0x27ad, 0x27ae, 6.96),
+    (0x27af, 0x27af, 8.74), (0x27b0, 0x27b0, 10.0), (0x27b1, 0x27b1, 8.74), (0x27b2, 0x27b2, 7.6),
+    (0x27b3, 0x27b3, 9.46), (0x27b4, 0x27b4, 7.71), (0x27b5, 0x27b5, 8.65), (0x27b6, 0x27b6, 7.71),
+    (0x27b7, 0x27b7, 8.88), (0x27b8, 0x27b8, 9.67), (0x27b9, 0x27b9, 8.88), (0x27ba, 0x27ba, 8.31),
+    (0x27bb, 0x27bb, 8.73), (0x27bc, 0x27bc, 9.27), (0x27bd, 0x27bd, 9.7), (0x27be, 0x27be, 9.18),
+    (0x27c0, 0x27c0, 5.48), (0x27c1, 0x27c1, 9.48), (0x27c2, 0x27c2, 6.89), (0x27c3, 0x27c4, 5.64),
+    (0x27c5, 0x27c6, 6.02), (0x27c7, 0x27c7, 6.2), (0x27c8, 0x27c9, 9.18), (0x27ca, 0x27ca, 3.4),
+    (0x27cb, 0x27cb, 8.38), (0x27cc, 0x27cc, 3.25), (0x27cd, 0x27cd, 8.38), (0x27ce, 0x27cf, 10.0),
+    (0x27d0, 0x27d0, 7.46), (0x27d1, 0x27d1, 6.2), (0x27d2, 0x27d2, 5.97), (0x27d3, 0x27d4, 5.42),
+    (0x27d5, 0x27d6, 9.02), (0x27d7, 0x27d7, 11.15), (0x27d8, 0x27d9, 6.89), (0x27da, 0x27da, 8.7),
+    (0x27db, 0x27db, 8.56), (0x27dc, 0x27dc, 10.55), (0x27dd, 0x27de, 8.49), (0x27df, 0x27df, 3.96),
+    (0x27e0, 0x27e0, 5.51), (0x27e1, 0x27e1, 7.0), (0x27e2, 0x27e3, 8.76), (0x27e4, 0x27e5, 9.46),
+    (0x27e6, 0x27e7, 4.22), (0x27e8, 0x27e9, 3.66), (0x27ea, 0x27eb, 4.63), (0x27ec, 0x27ed, 4.88),
+    (0x27ee, 0x27ee, 2.33), (0x27f0, 0x27f1, 10.33), (0x27f2, 0x27f3, 9.74), (0x27f4, 0x27f4, 12.0),
+    (0x27f5, 0x27fe, 15.74), (0x2800, 0x28fe, 6.84), (0x2900, 0x2907, 9.26), (0x2908, 0x2909, 5.11),
+    (0x290a, 0x290f, 9.26), (0x2910, 0x2910, 14.12), (0x2911, 0x2911, 9.26), (0x2912, 0x2913, 5.11),
+    (0x2914, 0x2933, 9.26), (0x2934, 0x2935, 10.0), (0x2936, 0x2937, 8.65), (0x2938, 0x2938, 5.11),
+    (0x2939, 0x2939, 8.65), (0x293a, 0x2948, 9.26), (0x2949, 0x2949, 5.11), (0x294a, 0x294b, 9.26),
+    (0x294c, 0x294d, 5.11), (0x294e, 0x294e, 9.26), (0x294f, 0x294f, 5.11), (0x2950, 0x2950, 9.26),
+    (0x2951, 0x2951, 5.11), (0x2952, 0x2953, 9.26), (0x2954, 0x2955, 5.11), (0x2956, 0x2957, 9.26),
+    (0x2958, 0x2959, 5.11), (0x295a, 0x295b, 9.26), (0x295c, 0x295d, 5.11), (0x295e, 0x295f, 9.26),
+    (0x2960, 0x2961, 5.11), (0x2962, 0x2965, 8.65), (0x2966, 0x296d, 9.26), (0x296e, 0x296f, 6.85),
+    (0x2970, 0x2975, 9.26), (0x2976, 0x2976, 6.85), (0x2977, 0x2977, 9.26), (0x2978, 0x2979, 6.85),
+    (0x297a, 0x297a, 9.26), (0x297b, 0x297b, 6.85), (0x297c, 0x297d, 9.26), (0x297e, 0x297e, 6.85),
+    (0x2980, 0x2980, 3.65), (0x2981, 0x2981, 3.8), (0x2982, 0x2982, 3.13), (0x2983, 0x2984, 3.74),
+    (0x2985, 0x2986, 4.47), (0x2987, 0x2988, 3.35), (0x2989, 0x298a, 3.95), (0x298b, 0x298c, 3.01),
+    (0x298d, 0x2990, 3.0), (0x2991, 0x2991, 3.66), (0x2992, 0x2992, 3.68), (0x2993, 0x2994, 6.59),
+    (0x2995, 0x2996, 6.95), (0x2997, 0x2998, 6.48), (0x2999, 0x2999, 2.26), (0x299a, 0x299a, 2.77),
+    (0x299b, 0x299b, 5.94), (0x299c, 0x299d, 5.92), (0x299e, 0x299e, 6.61), (0x299f, 0x299f, 5.48),
+    (0x29a0, 0x29a1, 5.94), (0x29a2, 0x29a5, 5.48), (0x29a6, 0x29a7, 7.53), (0x29a8, 0x29af, 5.94),
+    (0x29b0, 0x29b4, 7.03), (0x29b5, 0x29b5, 8.69), (0x29b6, 0x29b6, 7.0), (0x29b7, 0x29b7, 7.02),
+    (0x29b8, 0x29b8, 7.22), (0x29b9, 0x29b9, 7.02), (0x29ba, 0x29ba, 7.03), (0x29bb, 0x29bb, 7.04),
+    (0x29bc, 0x29c1, 7.02), (0x29c2, 0x29c2, 8.55), (0x29c3, 0x29c3, 8.74), (0x29c4, 0x29c8, 7.47),
+    (0x29c9, 0x29c9, 7.96), (0x29ca, 0x29cc, 7.49), (0x29cd, 0x29cd, 9.44), (0x29ce, 0x29ce, 6.5),
+    (0x29cf, 0x29d0, 7.58), (0x29d1, 0x29d3, 6.87), (0x29d4, 0x29d5, 6.51), (0x29d6, 0x29d7, 7.67),
+    (0x29d8, 0x29d9, 2.77), (0x29da, 0x29db, 4.23), (0x29dc, 0x29dc, 6.37), (0x29dd, 0x29dd, 6.47),
+    (0x29de, 0x29de, 6.37), (0x29df, 0x29df, 6.74), (0x29e0, 0x29e0, 7.47), (0x29e1, 0x29e1, 6.53),
+    (0x29e2, 0x29e2, 6.83), (0x29e3, 0x29e5, 6.64), (0x29e6, 0x29e6, 7.1), (0x29e7, 0x29e7, 6.35),
+    (0x29e8, 0x29e9, 7.49), (0x29ea, 0x29ea, 7.17), (0x29eb, 0x29eb, 4.73), (0x29ec, 0x29ed, 8.99),
+    (0x29ee, 0x29ef, 5.98), (0x29f0, 0x29f1, 7.08), (0x29f2, 0x29f3, 7.02), (0x29f4, 0x29f4, 8.18),
+    (0x29f5, 0x29f5, 5.99), (0x29f6, 0x29f6, 5.76), (0x29f7, 0x29f9, 5.08), (0x29fa, 0x29fb, 6.76),
+    (0x29fc, 0x29fd, 5.37), (0x29fe, 0x29fe, 4.73), (0x2a00, 0x2a01, 8.99), (0x2a02, 0x2a02, 9.01),
+    (0x2a03, 0x2a04, 5.44), (0x2a05, 0x2a06, 5.95), (0x2a07, 0x2a08, 7.66), (0x2a09, 0x2a09, 6.66),
+    (0x2a0a, 0x2a0a, 8.55), (0x2a0b, 0x2a0b, 6.52), (0x2a0c, 0x2a0c, 15.05), (0x2a0d, 0x2a0f, 6.11),
+    (0x2a10, 0x2a10, 4.99), (0x2a11, 0x2a11, 7.28), (0x2a12, 0x2a12, 5.19), (0x2a13, 0x2a13, 4.99),
+    (0x2a14, 0x2a14, 6.28), (0x2a15, 0x2a15, 4.99), (0x2a16, 0x2a16, 6.11), (0x2a17, 0x2a17, 6.76),
+    (0x2a18, 0x2a1a, 6.11), (0x2a1b, 0x2a1b, 6.38), (0x2a1c, 0x2a1c, 6.17), (0x2a1d, 0x2a1d, 6.87),
+    (0x2a1e, 0x2a1e, 3.95), (0x2a1f, 0x2a1f, 3.34), (0x2a20, 0x2a20, 8.11), (0x2a21, 0x2a21, 3.26),
+    (0x2a22, 0x2a23, 5.41), (0x2a24, 0x2a24, 5.44), (0x2a25, 0x2a25, 5.41), (0x2a26, 0x2a26, 5.44),
+    (0x2a27, 0x2a27, 6.47), (0x2a28, 0x2a28, 6.38), (0x2a29, 0x2a2a, 5.45), (0x2a2b, 0x2a2c, 5.43),
+    (0x2a2d, 0x2a2e, 7.61), (0x2a2f, 0x2a2f, 4.58), (0x2a30, 0x2a30, 5.29), (0x2a31, 0x2a31, 5.42),
+    (0x2a32, 0x2a32, 5.21), (0x2a33, 0x2a33, 8.05), (0x2a34, 0x2a35, 7.14), (0x2a36, 0x2a38, 8.99),
+    (0x2a39, 0x2a3b, 7.49), (0x2a3c, 0x2a3d, 5.12), (0x2a3e, 0x2a3e, 3.34), (0x2a3f, 0x2a3f, 6.15),
+    (0x2a40, 0x2a45, 5.44), (0x2a46, 0x2a47, 3.88), (0x2a48, 0x2a49, 5.42), (0x2a4a, 0x2a4b, 5.11),
+    (0x2a4c, 0x2a4d, 7.39), (0x2a4e, 0x2a4f, 5.95), (0x2a50, 0x2a50, 7.39), (0x2a51, 0x2a52, 6.1),
+    (0x2a53, 0x2a54, 6.52), (0x2a55, 0x2a56, 7.53), (0x2a57, 0x2a58, 6.52), (0x2a59, 0x2a59, 6.06),
+    (0x2a5a, 0x2a5b, 6.52), (0x2a5c, 0x2a5d, 7.38), (0x2a5e, 0x2a60, 6.1), (0x2a61, 0x2a61, 5.43),
+    (0x2a62, 0x2a63, 6.1), (0x2a64, 0x2a65, 6.67), (0x2a66, 0x2a69, 5.42), (0x2a6a, 0x2a6d, 5.43),
+    (0x2a6e, 0x2a6e, 4.85), (0x2a6f, 0x2a72, 5.43), (0x2a73, 0x2a73, 5.44), (0x2a74, 0x2a74, 8.48),
+    (0x2a75, 0x2a75, 9.84), (0x2a76, 0x2a76, 14.57), (0x2a77, 0x2a77, 5.45), (0x2a78, 0x2a78, 5.95),
+    (0x2a79, 0x2a7a, 6.59), (0x2a7b, 0x2a7c, 5.12), (0x2a7d, 0x2a7f, 5.43), (0x2a80, 0x2a80, 5.44),
+    (0x2a81, 0x2a86, 5.43), (0x2a87, 0x2a87, 5.51), (0x2a88, 0x2a88, 5.7), (0x2a89, 0x2a8c, 5.43),
+    (0x2a8d, 0x2a8d, 5.51), (0x2a8e, 0x2a8e, 5.48), (0x2a8f, 0x2a90, 5.45), (0x2a91, 0x2a97, 5.43),
+    (0x2a98, 0x2a9a, 5.44), (0x2a9b, 0x2a9e, 5.43), (0x2a9f, 0x2a9f, 5.52), (0x2aa0, 0x2aa0, 5.46),
+    (0x2aa1, 0x2aa2, 6.83), (0x2aa3, 0x2aa3, 8.07), (0x2aa4, 0x2aa4, 5.44), (0x2aa5, 0x2aa5, 9.35),
+    (0x2aa6, 0x2aa9, 6.13), (0x2aaa, 0x2aaa, 5.12), (0x2aab, 0x2aab, 5.24), (0x2aac, 0x2aac, 5.51),
+    (0x2aad, 0x2aad, 5.56), (0x2aae, 0x2aae, 5.42), (0x2aaf, 0x2ab1, 5.53), (0x2ab2, 0x2ab2, 5.51),
+    (0x2ab3, 0x2ab6, 5.53), (0x2ab7, 0x2ab7, 5.47), (0x2ab8, 0x2ab8, 5.48), (0x2ab9, 0x2ab9, 5.47),
+    (0x2aba, 0x2aba, 5.48), (0x2abb, 0x2abc, 8.88), (0x2abd, 0x2abe, 5.64), (0x2abf, 0x2ac6, 4.59),
+    (0x2ac7, 0x2aca, 5.46), (0x2acb, 0x2acc, 4.59), (0x2acd, 0x2ace, 9.53), (0x2acf, 0x2ad0, 5.64),
+    (0x2ad1, 0x2ad2, 5.63), (0x2ad3, 0x2ad6, 4.59), (0x2ad7, 0x2ad8, 8.5), (0x2ad9, 0x2adb, 5.97),
+    (0x2adc, 0x2adc, 6.1), (0x2add, 0x2add, 5.97), (0x2ade, 0x2ade, 3.93), (0x2adf, 0x2ae1, 6.89),
+    (0x2ae2, 0x2ae2, 6.86), (0x2ae3, 0x2ae3, 6.84), (0x2ae4, 0x2ae4, 6.86), (0x2ae5, 0x2ae5, 8.37),
+    (0x2ae6, 0x2ae6, 6.86), (0x2ae7, 0x2ae9, 6.89), (0x2aea, 0x2aeb, 6.93), (0x2aec, 0x2aed, 5.12),
+    (0x2aee, 0x2aee, 3.13), (0x2aef, 0x2af0, 2.84), (0x2af1, 0x2af1, 2.99), (0x2af2, 0x2af2, 3.9),
+    (0x2af3, 0x2af3, 5.43), (0x2af4, 0x2af4, 3.49), (0x2af5, 0x2af5, 4.78), (0x2af6, 0x2af6, 2.26),
+    (0x2af7, 0x2af8, 9.71), (0x2af9, 0x2afa, 5.43), (0x2afb, 0x2afb, 4.99), (0x2afc, 0x2afc, 3.55),
+    (0x2afd, 0x2afd, 4.11), (0x2afe, 0x2afe, 2.94), (0x2e80, 0x2efe, 10.0), (0x2ff0, 0x2ffe, 10.0),
+    (0x3000, 0x302d, 10.0), (0x302e, 0x302f, 16.0), (0x3030, 0x303e, 10.0), (0x3200, 0x321b, 8.65),
+    (0x321c, 0x321c, 8.51), (0x321d, 0x325f, 10.0), (0x3260, 0x327b, 8.65), (0x327c, 0x327e, 10.0),
+    (0x327f, 0x327f, 8.46), (0x3280, 0x32fe, 10.0), (0x3300, 0x337f, 10.0), (0x3380, 0x3384, 8.65),
+    (0x3385, 0x3389, 10.0), (0x338a, 0x338c, 8.65), (0x338d, 0x3390, 10.0), (0x3391, 0x3395, 8.65),
+    (0x3396, 0x3398, 10.0), (0x3399, 0x339a, 8.65), (0x339b, 0x33a6, 10.0), (0x33a7, 0x33af, 8.65),
+    (0x33b0, 0x33b3, 10.0), (0x33b4, 0x33c1, 8.65), (0x33c2, 0x33c2, 10.0), (0x33c3, 0x33c3, 8.65),
+    (0x33c4, 0x33c4, 10.0), (0x33c5, 0x33c6, 8.65), (0x33c7, 0x33c7, 8.46), (0x33c8, 0x33c8, 10.0),
+    (0x33c9, 0x33ca, 8.65), (0x33cb, 0x33ce, 10.0), (0x33cf, 0x33d0, 8.65), (0x33d1, 0x33d2, 10.0),
+    (0x33d3, 0x33d3, 8.65), (0x33d4, 0x33d5, 10.0), (0x33d6, 0x33d6, 8.65), (0x33d7, 0x33da, 10.0),
+    (0x33db, 0x33dd, 8.65), (0x33de, 0x33fe, 10.0), (0x3400, 0x4dbe, 10.0), (0x4e00, 0x9ffe, 10.0),
+    (0xf900, 0xfafe, 10.0), (0xfe30, 0xfe4e, 10.0), (0xfe70, 0xfe70, 3.12), (0xfe71, 0xfe71, 2.03),
+    (0xfe72, 0xfe72, 3.12), (0xfe73, 0xfe73, 3.0), (0xfe74, 0xfe74, 3.12), (0xfe75, 0xfe75, 10.0),
+    (0xfe76, 0xfe76, 3.12), (0xfe77, 0xfe77, 2.03), (0xfe78, 0xfe78, 3.12), (0xfe79, 0xfe79, 2.03),
+    (0xfe7a, 0xfe7a, 3.12), (0xfe7b, 0xfe7b, 2.03), (0xfe7c, 0xfe7c, 3.12), (0xfe7d, 0xfe7d, 2.03),
+    (0xfe7e, 0xfe7e, 3.12), (0xfe7f, 0xfe7f, 2.03), (0xfe80, 0xfe80, 4.13), (0xfe81, 0xfe81, 2.07),
+    (0xfe82, 0xfe82, 2.29), (0xfe83, 0xfe83, 2.07), (0xfe84, 0xfe84, 2.29), (0xfe85, 0xfe86, 4.32),
+    (0xfe87, 0xfe87, 2.07), (0xfe88, 0xfe88, 2.29), (0xfe89, 0xfe89, 6.38), (0xfe8a, 0xfe8a, 5.88),
+    (0xfe8b, 0xfe8c, 2.44), (0xfe8d, 0xfe8d, 2.07), (0xfe8e, 0xfe8e, 2.29), (0xfe8f, 0xfe90, 7.13),
+    (0xfe91, 0xfe92, 2.44), (0xfe93, 0xfe93, 2.82), (0xfe94, 0xfe94, 3.75), (0xfe95, 0xfe96, 7.13),
+    (0xfe97, 0xfe98, 2.44), (0xfe99, 0xfe9a, 7.13), (0xfe9b, 0xfe9c, 2.44), (0xfe9d, 0xfe9d, 5.63),
+    (0xfe9e, 0xfe9e, 5.26), (0xfe9f, 0xfea0, 5.3), (0xfea1, 0xfea1, 5.63), (0xfea2, 0xfea2, 5.26),
+    (0xfea3, 0xfea4, 5.3), (0xfea5, 0xfea5, 5.63), (0xfea6, 0xfea6, 5.26), (0xfea7, 0xfea8, 5.3),
+    (0xfea9, 0xfeac, 3.37), (0xfead, 0xfeb0, 4.89), (0xfeb1, 0xfeb2, 8.21), (0xfeb3, 0xfeb4, 5.31),
+    (0xfeb5, 0xfeb6, 8.21), (0xfeb7, 0xfeb8, 5.31), (0xfeb9, 0xfeba, 10.98), (0xfebb, 0xfebc, 8.46),
+    (0xfebd, 0xfebe, 10.98), (0xfebf, 0xfec0, 8.46), (0xfec1, 0xfec8, 5.82), (0xfec9, 0xfec9, 5.44),
+    (0xfeca, 0xfeca, 4.5), (0xfecb, 0xfecb, 5.26), (0xfecc, 0xfecc, 3.94), (0xfecd, 0xfecd, 5.44),
+    (0xfece, 0xfece, 4.5), (0xfecf, 0xfecf, 5.26), (0xfed0, 0xfed0, 3.94), (0xfed1, 0xfed2, 7.89),
+    (0xfed3, 0xfed3, 2.68), (0xfed4, 0xfed4, 2.63), (0xfed5, 0xfed6, 5.82), (0xfed7, 0xfed7, 2.68),
+    (0xfed8, 0xfed8, 2.63), (0xfed9, 0xfeda, 6.01), (0xfedb, 0xfedc, 3.94), (0xfedd, 0xfede, 5.06),
+    (0xfedf, 0xfee0, 2.07), (0xfee1, 0xfee2, 3.38), (0xfee3, 0xfee4, 3.94), (0xfee5, 0xfee6, 5.26),
+    (0xfee7, 0xfee8, 2.44), (0xfee9, 0xfee9, 2.82), (0xfeea, 0xfeea, 3.75), (0xfeeb, 0xfeeb, 4.5),
+    (0xfeec, 0xfeec, 3.94), (0xfeed, 0xfeee, 4.32), (0xfeef, 0xfeef, 6.38), (0xfef0, 0xfef0, 5.88),
+    (0xfef1, 0xfef1, 6.38), (0xfef2, 0xfef2, 5.88), (0xfef3, 0xfef4, 2.44), (0xfef5, 0xfef5, 5.44),
+    (0xfef6, 0xfef6, 6.01), (0xfef7, 0xfef7, 5.44), (0xfef8, 0xfef8, 6.01), (0xfef9, 0xfef9, 5.44),
+    (0xfefa, 0xfefa, 6.01), (0xfefb, 0xfefb, 5.44), (0xfefc, 0xfefc, 6.01), (0xfefd, 0xfefe, 10.0),
+    (0xff00, 0xff60, 10.0), (0xff61, 0xffa0, 5.0), (0xffa1, 0xffa1, 4.83), (0xffa2, 0xffa3, 5.0),
+    (0xffa4, 0xffa4, 4.83), (0xffa5, 0xffa6, 5.0), (0xffa7, 0xffa7, 4.83), (0xffa8, 0xffbe, 5.0),
+    (0xffbf, 0xffc1, 10.0), (0xffc2, 0xffc7, 5.0), (0xffc8, 0xffc9, 10.0), (0xffca, 0xffcf, 5.0),
+    (0xffd0, 0xffd1, 10.0), (0xffd2, 0xffd7, 5.0), (0xffd8, 0xffd9, 10.0), (0xffda, 0xffdc, 5.0),
+    (0xffdd, 0xffe4, 10.0), (0xffe5, 0xffe5, 6.36), (0xffe6, 0xffe6, 9.89), (0xffe7, 0xffe8, 10.0),
+    (0xffe9, 0xffee, 5.0), (0x10100, 0x10100, 6.84), (0x10101, 0x10101, 6.01),
+    (0x10102, 0x10102, 6.84), (0x10103, 0x10103, 6.01), (0x10104, 0x10104, 6.84),
+    (0x10105, 0x10105, 6.01), (0x10106, 0x10106, 6.98), (0x10107, 0x10107, 5.21),
+    (0x10108, 0x10108, 6.98), (0x10109, 0x10109, 5.21), (0x1010a, 0x1010a, 6.98),
+    (0x1010b, 0x1010b, 5.21), (0x1010c, 0x1010c, 6.98), (0x1010d, 0x1010d, 5.21),
+    (0x1010e, 0x1010e, 7.71), (0x1010f, 0x1010f, 6.47), (0x10110, 0x10110, 7.75),
+    (0x10111, 0x10111, 6.23), (0x10112, 0x10112, 6.32), (0x10113, 0x10113, 5.96),
+    (0x10114, 0x10114, 6.32), (0x10115, 0x10115, 5.96), (0x10116, 0x10116, 6.32),
+    (0x10117, 0x10117, 5.96), (0x10118, 0x10118, 6.32), (0x10119, 0x10119, 5.96),
+    (0x1011a, 0x1011a, 6.32), (0x1011b, 0x1011b, 5.96), (0x1011c, 0x1011c, 7.75),
+    (0x1011d, 0x1011d, 6.23), (0x1011e, 0x1011e, 7.75), (0x1011f, 0x1011f, 6.23),
+    (0x10120, 0x10120, 7.75), (0x10121, 0x10121, 6.23), (0x10122, 0x10122, 7.75),
+    (0x10123, 0x10123, 6.23), (0x10124, 0x10124, 7.51), (0x10125, 0x10125, 6.33),
+    (0x10126, 0x10126, 7.51), (0x10127, 0x10127, 6.33), (0x10128, 0x10128, 4.21),
+    (0x10129, 0x10129, 2.74), (0x1012a, 0x1012a, 4.21), (0x1012b, 0x1012b, 2.74),
+    (0x1012c, 0x1012c, 4.21), (0x1012d, 0x1012d, 2.74), (0x1012e, 0x1012e, 4.21),
+    (0x1012f, 0x1012f, 2.74), (0x10130, 0x10130, 4.21), (0x10131, 0x10131, 2.74),
+    (0x10132, 0x10132, 8.71), (0x10133, 0x10133, 6.14), (0x10134, 0x10134, 4.55),
+    (0x10135, 0x10135, 3.44), (0x10136, 0x10136, 6.93), (0x10137, 0x10138, 5.92),
+    (0x10139, 0x10139, 5.57), (0x1013a, 0x1013a, 2.74), (0x1013b, 0x1013b, 5.57),
+    (0x1013c, 0x1013c, 2.74), (0x1013d, 0x1013d, 5.57), (0x1013e, 0x1013e, 2.96),
+    (0x10300, 0x1032e, 0.0), (0x10330, 0x1034e, 0.0), (0x10400, 0x10401, 6.32),
+    (0x10402, 0x10402, 7.92), (0x10403, 0x10403, 5.66), (0x10404, 0x10404, 7.01),
+    (0x10405, 0x10405, 6.84), (0x10406, 0x10407, 4.21), (0x10408, 0x10408, 4.55),
+    (0x10409, 0x10409, 11.18), (0x1040a, 0x1040a, 11.03), (0x1040b, 0x1040b, 8.18),
+    (0x1040c, 0x1040c, 6.93), (0x1040d, 0x1040d, 7.5), (0x1040e, 0x1040e, 6.15),
+    (0x1040f, 0x1040f, 7.51), (0x10410, 0x10410, 6.84), (0x10411, 0x10412, 6.86),
+    (0x10413, 0x10413, 5.66), (0x10414, 0x10414, 7.46), (0x10415, 0x10415, 6.32),
+    (0x10416, 0x10416, 9.73), (0x10417, 0x10417, 6.16), (0x10418, 0x10419, 7.5),
+    (0x1041a, 0x1041a, 6.93), (0x1041b, 0x1041b, 7.34), (0x1041c, 0x1041c, 8.43),
+    (0x1041d, 0x1041d, 7.51), (0x1041e, 0x1041e, 7.87), (0x1041f, 0x1041f, 7.51),
+    (0x10420, 0x10420, 6.03), (0x10421, 0x10421, 6.98), (0x10422, 0x10422, 6.16),
+    (0x10423, 0x10423, 6.15), (0x10424, 0x10424, 8.19), (0x10425, 0x10425, 6.85),
+    (0x10426, 0x10426, 7.61), (0x10427, 0x10427, 7.12), (0x10428, 0x10428, 10.3),
+    (0x10429, 0x10429, 10.45), (0x1042a, 0x1042a, 7.83), (0x1042b, 0x1042b, 9.2),
+    (0x1042c, 0x1042c, 6.81), (0x1042d, 0x1042d, 7.01), (0x1042e, 0x1042e, 10.34),
+    (0x1042f, 0x1042f, 7.06), (0x10430, 0x10430, 6.01), (0x10431, 0x10431, 6.14),
+    (0x10432, 0x10432, 5.95), (0x10433, 0x10433, 4.71), (0x10434, 0x10434, 6.22),
+    (0x10435, 0x10435, 5.96), (0x10436, 0x10436, 7.98), (0x10437, 0x10437, 5.24),
+    (0x10438, 0x10439, 6.4), (0x1043a, 0x1043a, 5.92), (0x1043b, 0x1043b, 6.21),
+    (0x1043c, 0x1043c, 6.96), (0x1043d, 0x1043d, 6.37), (0x1043e, 0x1043e, 6.07),
+    (0x1043f, 0x1043f, 6.37), (0x10440, 0x10440, 6.23), (0x10441, 0x10441, 5.34),
+    (0x10442, 0x10442, 4.96), (0x10443, 0x10443, 5.92), (0x10444, 0x10444, 8.41),
+    (0x10445, 0x10445, 5.92), (0x10446, 0x10446, 6.45), (0x10447, 0x10447, 6.05),
+    (0x10448, 0x10448, 8.76), (0x10449, 0x10449, 8.88), (0x1044a, 0x1044a, 6.41),
+    (0x1044b, 0x1044b, 7.94), (0x1044c, 0x1044c, 5.71), (0x1044d, 0x1044d, 5.47),
+    (0x1044e, 0x1044e, 8.39), (0x1d100, 0x1d1fe, 8.65), (0x1d400, 0x1d7a3, 8.65),
+    (0x1d7a4, 0x1d7fe, 10.0),
+];
+
+/// 10px bold Verdana, the value font of the for-the-badge style
+// Measured widths that happen to be close to 2π are not meant as TAU
+#[allow(clippy::approx_constant)]
+#[rustfmt::skip]
+pub(super) static VERDANA_10PX_BOLD: &[(u32, u32, f64)] = &[
+    (0x20, 0x20, 3.42), (0x21, 0x21, 4.02), (0x22, 0x22, 5.87), (0x23, 0x23, 8.67),
+    (0x24, 0x24, 7.11), (0x25, 0x25, 12.72), (0x26, 0x26, 8.62), (0x27, 0x27, 3.32),
+    (0x28, 0x29, 5.43), (0x2a, 0x2a, 7.11), (0x2b, 0x2b, 8.67), (0x2c, 0x2c, 3.61),
+    (0x2d, 0x2d, 4.8), (0x2e, 0x2e, 3.61), (0x2f, 0x2f, 6.89), (0x30, 0x39, 7.11),
+    (0x3a, 0x3b, 4.02), (0x3c, 0x3e, 8.67), (0x3f, 0x3f, 6.17), (0x40, 0x40, 9.64),
+    (0x41, 0x41, 7.76), (0x42, 0x42, 7.62), (0x43, 0x43, 7.24), (0x44, 0x44, 8.3),
+    (0x45, 0x45, 6.83), (0x46, 0x46, 6.5), (0x47, 0x47, 8.11), (0x48, 0x48, 8.37),
+    (0x49, 0x49, 5.46), (0x4a, 0x4a, 5.55), (0x4b, 0x4b, 7.71), (0x4c, 0x4c, 6.37),
+    (0x4d, 0x4d, 9.48), (0x4e, 0x4e, 8.47), (0x4f, 0x4f, 8.5), (0x50, 0x50, 7.33),
+    (0x51, 0x51, 8.5), (0x52, 0x52, 7.82), (0x53, 0x53, 7.1), (0x54, 0x54, 6.82),
+    (0x55, 0x55, 8.12), (0x56, 0x56, 7.64), (0x57, 0x57, 11.28), (0x58, 0x58, 7.64),
+    (0x59, 0x59, 7.37), (0x5a, 0x5a, 6.92), (0x5b, 0x5b, 5.43), (0x5c, 0x5c, 6.89),
+    (0x5d, 0x5d, 5.43), (0x5e, 0x5e, 8.67), (0x5f, 0x60, 7.11), (0x61, 0x61, 6.68),
+    (0x62, 0x62, 6.99), (0x63, 0x63, 5.88), (0x64, 0x64, 6.99), (0x65, 0x65, 6.64),
+    (0x66, 0x66, 4.22), (0x67, 0x67, 6.99), (0x68, 0x68, 7.12), (0x69, 0x69, 3.42),
+    (0x6a, 0x6a, 4.03), (0x6b, 0x6b, 6.71), (0x6c, 0x6c, 3.42), (0x6d, 0x6d, 10.58),
+    (0x6e, 0x6e, 7.12), (0x6f, 0x6f, 6.87), (0x70, 0x71, 6.99), (0x72, 0x72, 4.97),
+    (0x73, 0x73, 5.93), (0x74, 0x74, 4.56), (0x75, 0x75, 7.12), (0x76, 0x76, 6.5),
+    (0x77, 0x77, 9.79), (0x78, 0x78, 6.69), (0x79, 0x79, 6.51), (0x7a, 0x7a, 5.97),
+    (0x7b, 0x7b, 7.11), (0x7c, 0x7c, 5.43), (0x7d, 0x7d, 7.11), (0x7e, 0x7e, 8.67),
+    (0xa0, 0xa0, 3.42), (0xa1, 0xa1, 4.02), (0xa2, 0xa5, 7.11), (0xa6, 0xa6, 5.43),
+    (0xa7, 0xa8, 7.11), (0xa9, 0xa9, 9.64), (0xaa, 0xaa, 5.98), (0xab, 0xab, 8.5),
+    (0xac, 0xac, 8.67), (0xad, 0xad, 0.0), (0xae, 0xae, 9.64), (0xaf, 0xaf, 7.11),
+    (0xb0, 0xb0, 5.87), (0xb1, 0xb1, 8.67), (0xb2, 0xb3, 5.98), (0xb4, 0xb4, 7.11),
+    (0xb5, 0xb5, 7.21), (0xb6, 0xb6, 7.11), (0xb7, 0xb7, 3.61), (0xb8, 0xb8, 7.11),
+    (0xb9, 0xba, 5.98), (0xbb, 0xbb, 8.5), (0xbc, 0xbe, 11.82), (0xbf, 0xbf, 6.17),
+    (0xc0, 0xc5, 7.76), (0xc6, 0xc6, 10.94), (0xc7, 0xc7, 7.24), (0xc8, 0xcb, 6.83),
+    (0xcc, 0xcf, 5.46), (0xd0, 0xd0, 8.3), (0xd1, 0xd1, 8.47), (0xd2, 0xd6, 8.5),
+    (0xd7, 0xd7, 8.67), (0xd8, 0xd8, 8.5), (0xd9, 0xdc, 8.12), (0xdd, 0xdd, 7.37),
+    (0xde, 0xde, 7.35), (0xdf, 0xdf, 7.13), (0xe0, 0xe5, 6.68), (0xe6, 0xe6, 10.18),
+    (0xe7, 0xe7, 5.88), (0xe8, 0xeb, 6.64), (0xec, 0xef, 3.42), (0xf0, 0xf0, 6.79),
+    (0xf1, 0xf1, 7.12), (0xf2, 0xf6, 6.87), (0xf7, 0xf7, 8.67), (0xf8, 0xf8, 6.87),
+    (0xf9, 0xfc, 7.12), (0xfd, 0xfd, 6.51), (0xfe, 0xfe, 6.99), (0x100, 0x100, 7.76),
+    (0x101, 0x101, 6.68), (0x102, 0x102, 7.76), (0x103, 0x103, 6.68), (0x104, 0x104, 7.76),
+    (0x105, 0x105, 6.68), (0x106, 0x106, 7.24), (0x107, 0x107, 5.88), (0x108, 0x108, 7.24),
+    (0x109, 0x109, 5.88), (0x10a, 0x10a, 7.24), (0x10b, 0x10b, 5.88), (0x10c, 0x10c, 7.24),
+    (0x10d, 0x10d, 5.88), (0x10e, 0x10e, 8.3), (0x10f, 0x10f, 8.8), (0x110, 0x110, 8.3),
+    (0x111, 0x111, 6.99), (0x112, 0x112, 6.83), (0x113, 0x113, 6.64), (0x114, 0x114, 6.83),
+    (0x115, 0x115, 6.64), (0x116, 0x116, 6.83), (0x117, 0x117, 6.64), (0x118, 0x118, 6.83),
+    (0x119, 0x119, 6.64), (0x11a, 0x11a, 6.83), (0x11b, 0x11b, 6.64), (0x11c, 0x11c, 8.11),
+    (0x11d, 0x11d, 6.99), (0x11e, 0x11e, 8.11), (0x11f, 0x11f, 6.99), (0x120, 0x120, 8.11),
+    (0x121, 0x121, 6.99), (0x122, 0x122, 8.11), (0x123, 0x123, 6.99), (0x124, 0x124, 8.37),
+    (0x125, 0x125, 7.12), (0x126, 0x126, 8.37), (0x127, 0x127, 7.12), (0x128, 0x128, 5.46),
+    (0x129, 0x129, 3.42), (0x12a, 0x12a, 5.46), (0x12b, 0x12b, 3.42), (0x12c, 0x12c, 5.46),
+    (0x12d, 0x12d, 3.42), (0x12e, 0x12e, 5.46), (0x12f, 0x12f, 3.42), (0x130, 0x130, 5.46),
+    (0x131, 0x131, 3.42), (0x132, 0x132, 10.07), (0x133, 0x133, 7.28), (0x134, 0x134, 5.55),
+    (0x135, 0x135, 4.03), (0x136, 0x136, 7.71), (0x137, 0x138, 6.71), (0x139, 0x139, 6.37),
+    (0x13a, 0x13a, 3.42), (0x13b, 0x13b, 6.37), (0x13c, 0x13c, 3.42), (0x13d, 0x13d, 6.37),
+    (0x13e, 0x13e, 5.22), (0x13f, 0x13f, 6.37), (0x140, 0x140, 5.57), (0x141, 0x141, 6.42),
+    (0x142, 0x142, 3.52), (0x143, 0x143, 8.47), (0x144, 0x144, 7.12), (0x145, 0x145, 8.47),
+    (0x146, 0x146, 7.12), (0x147, 0x147, 8.47), (0x148, 0x148, 7.12), (0x149, 0x149, 8.25),
+    (0x14a, 0x14a, 8.47), (0x14b, 0x14b, 7.12), (0x14c, 0x14c, 8.5), (0x14d, 0x14d, 6.87),
+    (0x14e, 0x14e, 8.5), (0x14f, 0x14f, 6.87), (0x150, 0x150, 8.5), (0x151, 0x151, 6.87),
+    (0x152, 0x152, 11.35), (0x153, 0x153, 10.68), (0x154, 0x154, 7.82), (0x155, 0x155, 4.97),
+    (0x156, 0x156, 7.82), (0x157, 0x157, 4.97), (0x158, 0x158, 7.82), (0x159, 0x159, 4.97),
+    (0x15a, 0x15a, 7.1), (0x15b, 0x15b, 5.93), (0x15c, 0x15c, 7.1), (0x15d, 0x15d, 5.93),
+    (0x15e, 0x15e, 7.1), (0x15f, 0x15f, 5.93), (0x160, 0x160, 7.1), (0x161, 0x161, 5.93),
+    (0x162, 0x162, 6.82), (0x163, 0x163, 4.56), (0x164, 0x164, 6.82), (0x165, 0x165, 4.65),
+    (0x166, 0x166, 6.82), (0x167, 0x167, 4.56), (0x168, 0x168, 8.12), (0x169, 0x169, 7.12),
+    (0x16a, 0x16a, 8.12), (0x16b, 0x16b, 7.12), (0x16c, 0x16c, 8.12), (0x16d, 0x16d, 7.12),
+    (0x16e, 0x16e, 8.12), (0x16f, 0x16f, 7.12), (0x170, 0x170, 8.12), (0x171, 0x171, 7.12),
+    (0x172, 0x172, 8.12), (0x173, 0x173, 7.12), (0x174, 0x174, 11.28), (0x175, 0x175, 9.79),
+    (0x176, 0x176, 7.37), (0x177, 0x177, 6.51), (0x178, 0x178, 7.37), (0x179, 0x179, 6.92),
+    (0x17a, 0x17a, 5.97), (0x17b, 0x17b, 6.92), (0x17c, 0x17c, 5.97), (0x17d, 0x17d, 6.92),
+    (0x17e, 0x17e, 5.97), (0x180, 0x180, 5.56), (0x181, 0x181, 7.55), (0x182, 0x182, 6.61),
+    (0x183, 0x183, 5.56), (0x184, 0x184, 6.61), (0x185, 0x185, 5.56), (0x186, 0x187, 7.22),
+    (0x188, 0x188, 4.44), (0x189, 0x189, 7.22), (0x18a, 0x18a, 8.16), (0x18b, 0x18b, 6.61),
+    (0x18c, 0x18c, 5.56), (0x18d, 0x18d, 5.19), (0x18e, 0x18e, 6.67), (0x18f, 0x18f, 8.05),
+    (0x190, 0x190, 5.28), (0x191, 0x191, 6.11), (0x192, 0x192, 7.11), (0x193, 0x193, 7.78),
+    (0x194, 0x194, 7.22), (0x195, 0x195, 7.82), (0x196, 0x196, 3.07), (0x197, 0x197, 3.89),
+    (0x198, 0x198, 7.78), (0x199, 0x199, 5.56), (0x19a, 0x19a, 2.78), (0x19b, 0x19b, 4.91),
+    (0x19c, 0x19c, 9.4), (0x19d, 0x19d, 7.22), (0x19e, 0x19e, 5.56), (0x19f, 0x19f, 7.78),
+    (0x1a0, 0x1a0, 9.14), (0x1a1, 0x1a1, 6.87), (0x1a2, 0x1a2, 10.56), (0x1a3, 0x1a3, 7.71),
+    (0x1a4, 0x1a4, 6.94), (0x1a5, 0x1a5, 5.56), (0x1a6, 0x1a6, 6.11), (0x1a7, 0x1a7, 5.56),
+    (0x1a8, 0x1a8, 3.89), (0x1a9, 0x1a9, 6.54), (0x1aa, 0x1aa, 5.13), (0x1ab, 0x1ab, 3.33),
+    (0x1ac, 0x1ac, 6.67), (0x1ad, 0x1ad, 3.33), (0x1ae, 0x1ae, 6.67), (0x1af, 0x1af, 8.46),
+    (0x1b0, 0x1b0, 7.42), (0x1b1, 0x1b1, 8.01), (0x1b2, 0x1b2, 6.52), (0x1b3, 0x1b3, 7.91),
+    (0x1b4, 0x1b4, 5.0), (0x1b5, 0x1b5, 6.67), (0x1b6, 0x1b6, 4.44), (0x1b7, 0x1b8, 5.82),
+    (0x1b9, 0x1b9, 4.58), (0x1ba, 0x1ba, 4.53), (0x1bb, 0x1bb, 5.0), (0x1bc, 0x1bc, 5.04),
+    (0x1bd, 0x1bd, 3.85), (0x1be, 0x1be, 3.33), (0x1bf, 0x1bf, 5.96), (0x1c0, 0x1c0, 2.2),
+    (0x1c1, 0x1c1, 3.24), (0x1c2, 0x1c2, 2.79), (0x1c3, 0x1c3, 3.33), (0x1c4, 0x1c4, 13.89),
+    (0x1c5, 0x1c5, 11.66), (0x1c6, 0x1c6, 10.0), (0x1c7, 0x1c7, 11.67), (0x1c8, 0x1c8, 10.0),
+    (0x1c9, 0x1c9, 6.11), (0x1ca, 0x1ca, 12.22), (0x1cb, 0x1cb, 10.55), (0x1cc, 0x1cc, 8.89),
+    (0x1cd, 0x1cd, 7.22), (0x1ce, 0x1ce, 5.0), (0x1cf, 0x1cf, 3.89), (0x1d0, 0x1d0, 2.78),
+    (0x1d1, 0x1d1, 7.78), (0x1d2, 0x1d2, 5.0), (0x1d3, 0x1d3, 7.22), (0x1d4, 0x1d4, 5.56),
+    (0x1d5, 0x1d5, 7.22), (0x1d6, 0x1d6, 5.56), (0x1d7, 0x1d7, 8.12), (0x1d8, 0x1d8, 7.12),
+    (0x1d9, 0x1d9, 7.22), (0x1da, 0x1da, 5.56), (0x1db, 0x1db, 8.12), (0x1dc, 0x1dc, 7.12),
+    (0x1dd, 0x1dd, 4.44), (0x1de, 0x1de, 7.22), (0x1df, 0x1df, 5.0), (0x1e0, 0x1e0, 7.22),
+    (0x1e1, 0x1e1, 5.0), (0x1e2, 0x1e2, 10.0), (0x1e3, 0x1e3, 7.22), (0x1e4, 0x1e4, 7.78),
+    (0x1e5, 0x1e5, 5.0), (0x1e6, 0x1e6, 7.78), (0x1e7, 0x1e7, 5.0), (0x1e8, 0x1e8, 7.78),
+    (0x1e9, 0x1e9, 5.56), (0x1ea, 0x1ea, 7.78), (0x1eb, 0x1eb, 5.0), (0x1ec, 0x1ec, 7.78),
+    (0x1ed, 0x1ed, 5.0), (0x1ee, 0x1ee, 5.82), (0x1ef, 0x1ef, 4.58), (0x1f0, 0x1f0, 3.33),
+    (0x1f1, 0x1f1, 13.89), (0x1f2, 0x1f2, 11.66), (0x1f3, 0x1f3, 10.0), (0x1f4, 0x1f4, 8.11),
+    (0x1f5, 0x1f5, 6.99), (0x1f6, 0x1f6, 9.7), (0x1f7, 0x1f7, 6.46), (0x1f8, 0x1f8, 8.47),
+    (0x1f9, 0x1f9, 7.12), (0x1fa, 0x1fa, 7.76), (0x1fb, 0x1fb, 6.68), (0x1fc, 0x1fc, 10.94),
+    (0x1fd, 0x1fd, 10.18), (0x1fe, 0x1fe, 8.5), (0x1ff, 0x1ff, 6.87), (0x200, 0x200, 7.22),
+    (0x201, 0x201, 5.0), (0x202, 0x202, 7.22), (0x203, 0x203, 5.0), (0x204, 0x204, 6.67),
+    (0x205, 0x205, 4.44), (0x206, 0x206, 6.67), (0x207, 0x207, 4.44), (0x208, 0x208, 3.89),
+    (0x209, 0x209, 2.78), (0x20a, 0x20a, 3.89), (0x20b, 0x20b, 2.78), (0x20c, 0x20c, 7.78),
+    (0x20d, 0x20d, 5.0), (0x20e, 0x20e, 7.78), (0x20f, 0x20f, 5.0), (0x210, 0x210, 7.22),
+    (0x211, 0x211, 4.44), (0x212, 0x212, 7.22), (0x213, 0x213, 4.44), (0x214, 0x214, 7.22),
+    (0x215, 0x215, 5.56), (0x216, 0x216, 7.22), (0x217, 0x217, 5.56), (0x218, 0x218, 7.1),
+    (0x219, 0x219, 5.93), (0x21a, 0x21a, 6.82), (0x21b, 0x21b, 4.56), (0x21c, 0x21c, 5.54),
+    (0x21d, 0x21d, 3.74), (0x21e, 0x21e, 7.78), (0x21f, 0x21f, 5.56), (0x220, 0x220, 7.34),
+    (0x221, 0x221, 5.56), (0x222, 0x222, 6.76), (0x223, 0x223, 5.01), (0x224, 0x224, 6.67),
+    (0x225, 0x225, 4.44), (0x226, 0x226, 7.22), (0x227, 0x227, 5.0), (0x228, 0x228, 6.67),
+    (0x229, 0x229, 4.44), (0x22a, 0x22a, 7.78), (0x22b, 0x22b, 5.0), (0x22c, 0x22c, 7.78),
+    (0x22d, 0x22d, 5.0), (0x22e, 0x22e, 7.78), (0x22f, 0x22f, 5.0), (0x230, 0x230, 7.78),
+    (0x231, 0x231, 5.0), (0x232, 0x232, 7.22), (0x233, 0x233, 5.0), (0x234, 0x234, 2.78),
+    (0x235, 0x235, 5.56), (0x236, 0x236, 3.61), (0x237, 0x237, 3.33), (0x238, 0x239, 8.23),
+    (0x23a, 0x23b, 7.22), (0x23c, 0x23c, 5.0), (0x23d, 0x23e, 6.67), (0x23f, 0x23f, 3.89),
+    (0x240, 0x240, 4.44), (0x241, 0x241, 5.32), (0x242, 0x242, 4.66), (0x243, 0x243, 6.67),
+    (0x244, 0x244, 7.22), (0x245, 0x245, 7.17), (0x246, 0x246, 6.67), (0x247, 0x247, 4.44),
+    (0x248, 0x248, 5.0), (0x249, 0x249, 3.33), (0x24a, 0x24a, 8.25), (0x24b, 0x24b, 5.56),
+    (0x24c, 0x24c, 7.22), (0x24d, 0x24d, 4.44), (0x24e, 0x24e, 7.22), (0x370, 0x370, 4.25),
+    (0x371, 0x371, 3.35), (0x372, 0x372, 6.3), (0x373, 0x373, 5.3), (0x374, 0x375, 3.33),
+    (0x376, 0x376, 7.3), (0x377, 0x377, 5.93), (0x378, 0x379, 10.0), (0x37a, 0x37a, 3.33),
+    (0x37b, 0x37d, 4.44), (0x37e, 0x37e, 4.02), (0x37f, 0x37f, 3.33), (0x380, 0x383, 10.0),
+    (0x384, 0x385, 7.11), (0x386, 0x386, 7.97), (0x387, 0x387, 4.02), (0x388, 0x388, 8.48),
+    (0x389, 0x389, 10.01), (0x38a, 0x38a, 7.05), (0x38b, 0x38b, 10.0), (0x38c, 0x38c, 9.68),
+    (0x38d, 0x38d, 10.0), (0x38e, 0x38e, 9.39), (0x38f, 0x38f, 9.7), (0x390, 0x390, 3.42),
+    (0x391, 0x391, 7.76), (0x392, 0x392, 7.62), (0x393, 0x393, 6.37), (0x394, 0x394, 8.06),
+    (0x395, 0x395, 6.83), (0x396, 0x396, 6.92), (0x397, 0x397, 8.37), (0x398, 0x398, 8.5),
+    (0x399, 0x399, 5.46), (0x39a, 0x39a, 7.71), (0x39b, 0x39b, 7.76), (0x39c, 0x39c, 9.48),
+    (0x39d, 0x39d, 8.47), (0x39e, 0x39e, 7.15), (0x39f, 0x39f, 8.5), (0x3a0, 0x3a0, 8.37),
+    (0x3a1, 0x3a1, 7.33), (0x3a2, 0x3a2, 10.0), (0x3a3, 0x3a3, 6.84), (0x3a4, 0x3a4, 6.82),
+    (0x3a5, 0x3a5, 7.37), (0x3a6, 0x3a6, 9.52), (0x3a7, 0x3a7, 7.64), (0x3a8, 0x3a8, 9.76),
+    (0x3a9, 0x3a9, 8.44), (0x3aa, 0x3aa, 5.46), (0x3ab, 0x3ab, 7.37), (0x3ac, 0x3ac, 6.99),
+    (0x3ad, 0x3ad, 5.85), (0x3ae, 0x3ae, 7.12), (0x3af, 0x3af, 3.42), (0x3b0, 0x3b0, 7.06),
+    (0x3b1, 0x3b1, 6.99), (0x3b2, 0x3b2, 7.16), (0x3b3, 0x3b3, 6.51), (0x3b4, 0x3b4, 6.87),
+    (0x3b5, 0x3b5, 5.85), (0x3b6, 0x3b6, 5.49), (0x3b7, 0x3b7, 7.12), (0x3b8, 0x3b8, 7.0),
+    (0x3b9, 0x3b9, 3.42), (0x3ba, 0x3ba, 6.71), (0x3bb, 0x3bb, 6.51), (0x3bc, 0x3bc, 7.21),
+    (0x3bd, 0x3bd, 6.5), (0x3be, 0x3be, 5.81), (0x3bf, 0x3bf, 6.87), (0x3c0, 0x3c0, 7.2),
+    (0x3c1, 0x3c1, 6.99), (0x3c2, 0x3c2, 5.62), (0x3c3, 0x3c3, 7.26), (0x3c4, 0x3c4, 5.35),
+    (0x3c5, 0x3c5, 7.06), (0x3c6, 0x3c6, 9.15), (0x3c7, 0x3c7, 6.35), (0x3c8, 0x3c8, 9.41),
+    (0x3c9, 0x3c9, 8.94), (0x3ca, 0x3ca, 3.42), (0x3cb, 0x3cb, 7.06), (0x3cc, 0x3cc, 6.87),
+    (0x3cd, 0x3cd, 7.06), (0x3ce, 0x3ce, 8.94), (0x3cf, 0x3cf, 5.58), (0x3d0, 0x3d0, 5.27),
+    (0x3d1, 0x3d1, 5.99), (0x3d2, 0x3d2, 7.22), (0x3d3, 0x3d3, 9.23), (0x3d4, 0x3d4, 7.22),
+    (0x3d5, 0x3d5, 6.24), (0x3d6, 0x3d6, 7.31), (0x3d7, 0x3d7, 5.8), (0x3d8, 0x3d8, 7.78),
+    (0x3d9, 0x3d9, 5.0), (0x3da, 0x3da, 7.22), (0x3db, 0x3db, 4.22), (0x3dc, 0x3dc, 6.11),
+    (0x3dd, 0x3dd, 5.46), (0x3de, 0x3de, 5.95), (0x3df, 0x3df, 4.61), (0x3e0, 0x3e0, 7.51),
+    (0x3e1, 0x3e1, 5.54), (0x3e2, 0x3e2, 8.7), (0x3e3, 0x3e3, 8.33), (0x3e4, 0x3e4, 7.25),
+    (0x3e5, 0x3e5, 5.86), (0x3e6, 0x3e6, 7.4), (0x3e7, 0x3e7, 4.44), (0x3e8, 0x3e8, 6.25),
+    (0x3e9, 0x3e9, 5.86), (0x3ea, 0x3ea, 6.82), (0x3eb, 0x3eb, 6.05), (0x3ec, 0x3ec, 7.25),
+    (0x3ed, 0x3ed, 5.16), (0x3ee, 0x3ee, 5.4), (0x3ef, 0x3ef, 4.5), (0x3f0, 0x3f0, 5.8),
+    (0x3f1, 0x3f1, 5.0), (0x3f2, 0x3f2, 4.44), (0x3f3, 0x3f3, 3.33), (0x3f4, 0x3f4, 7.78),
+    (0x3f5, 0x3f6, 4.21), (0x3f7, 0x3f7, 6.11), (0x3f8, 0x3f8, 5.56), (0x3f9, 0x3f9, 7.22),
+    (0x3fa, 0x3fa, 9.44), (0x3fb, 0x3fb, 6.81), (0x3fc, 0x3fc, 5.35), (0x3fd, 0x3fe, 7.22),
+    (0x400, 0x401, 6.83), (0x402, 0x402, 9.11), (0x403, 0x403, 6.37), (0x404, 0x404, 7.42),
+    (0x405, 0x405, 7.1), (0x406, 0x407, 5.46), (0x408, 0x408, 5.55), (0x409, 0x409, 12.22),
+    (0x40a, 0x40a, 12.14), (0x40b, 0x40b, 9.36), (0x40c, 0x40c, 7.71), (0x40d, 0x40d, 8.46),
+    (0x40e, 0x40e, 7.37), (0x40f, 0x40f, 8.37), (0x410, 0x410, 7.76), (0x411, 0x411, 7.58),
+    (0x412, 0x412, 7.62), (0x413, 0x413, 6.37), (0x414, 0x414, 8.41), (0x415, 0x415, 6.83),
+    (0x416, 0x416, 11.15), (0x417, 0x417, 7.07), (0x418, 0x419, 8.46), (0x41a, 0x41a, 7.71),
+    (0x41b, 0x41b, 8.45), (0x41c, 0x41c, 9.48), (0x41d, 0x41d, 8.37), (0x41e, 0x41e, 8.5),
+    (0x41f, 0x41f, 8.37), (0x420, 0x420, 7.33), (0x421, 0x421, 7.24), (0x422, 0x422, 6.82),
+    (0x423, 0x423, 7.37), (0x424, 0x424, 9.52), (0x425, 0x425, 7.64), (0x426, 0x426, 8.5),
+    (0x427, 0x427, 7.87), (0x428, 0x428, 11.64), (0x429, 0x429, 11.77), (0x42a, 0x42a, 9.07),
+    (0x42b, 0x42b, 10.63), (0x42c, 0x42c, 7.58), (0x42d, 0x42d, 7.42), (0x42e, 0x42e, 11.95),
+    (0x42f, 0x42f, 7.95), (0x430, 0x430, 6.68), (0x431, 0x431, 6.97), (0x432, 0x432, 6.77),
+    (0x433, 0x433, 5.31), (0x434, 0x434, 6.91), (0x435, 0x435, 6.64), (0x436, 0x436, 9.99),
+    (0x437, 0x437, 5.88), (0x438, 0x439, 7.2), (0x43a, 0x43a, 6.71), (0x43b, 0x43b, 7.09),
+    (0x43c, 0x43c, 8.3), (0x43d, 0x43d, 7.2), (0x43e, 0x43e, 6.87), (0x43f, 0x43f, 7.2),
+    (0x440, 0x440, 6.99), (0x441, 0x441, 5.98), (0x442, 0x442, 5.35), (0x443, 0x443, 6.51),
+    (0x444, 0x444, 9.66), (0x445, 0x445, 6.69), (0x446, 0x446, 7.29), (0x447, 0x447, 6.84),
+    (0x448, 0x448, 10.02), (0x449, 0x449, 10.13), (0x44a, 0x44a, 7.43), (0x44b, 0x44b, 9.37),
+    (0x44c, 0x44c, 6.49), (0x44d, 0x44d, 6.06), (0x44e, 0x44e, 9.94), (0x44f, 0x44f, 6.82),
+    (0x450, 0x451, 6.64), (0x452, 0x452, 7.12), (0x453, 0x453, 5.31), (0x454, 0x454, 6.05),
+    (0x455, 0x455, 5.93), (0x456, 0x457, 3.42), (0x458, 0x458, 4.03), (0x459, 0x459, 10.12),
+    (0x45a, 0x45a, 10.2), (0x45b, 0x45b, 7.12), (0x45c, 0x45c, 6.71), (0x45d, 0x45d, 7.2),
+    (0x45e, 0x45e, 6.51), (0x45f, 0x45f, 7.2), (0x460, 0x460, 12.5), (0x461, 0x461, 6.19),
+    (0x462, 0x462, 7.64), (0x463, 0x463, 6.0), (0x464, 0x464, 10.07), (0x465, 0x465, 6.64),
+    (0x466, 0x466, 7.22), (0x467, 0x467, 5.0), (0x468, 0x468, 10.98), (0x469, 0x469, 7.73),
+    (0x46a, 0x46a, 9.89), (0x46b, 0x46b, 7.25), (0x46c, 0x46c, 13.6), (0x46d, 0x46d, 9.99),
+    (0x46e, 0x46e, 5.28), (0x46f, 0x46f, 4.02), (0x470, 0x470, 7.8), (0x471, 0x471, 6.88),
+    (0x472, 0x472, 7.78), (0x473, 0x473, 5.0), (0x474, 0x474, 8.15), (0x475, 0x475, 6.05),
+    (0x476, 0x476, 8.15), (0x477, 0x477, 6.05), (0x478, 0x478, 13.52), (0x479, 0x479, 12.28),
+    (0x47a, 0x47a, 8.24), (0x47b, 0x47b, 5.85), (0x47c, 0x47c, 10.46), (0x47d, 0x47d, 8.98),
+    (0x47e, 0x47e, 12.5), (0x47f, 0x47f, 6.19), (0x480, 0x480, 7.22), (0x481, 0x481, 4.44),
+    (0x482, 0x482, 3.38), (0x483, 0x486, 0.0), (0x487, 0x487, 6.36), (0x488, 0x489, 0.0),
+    (0x48a, 0x48a, 7.78), (0x48b, 0x48b, 5.76), (0x48c, 0x48c, 6.61), (0x48d, 0x48d, 5.29),
+    (0x48e, 0x48e, 6.11), (0x48f, 0x48f, 5.56), (0x490, 0x490, 6.37), (0x491, 0x491, 5.31),
+    (0x492, 0x492, 6.37), (0x493, 0x493, 5.31), (0x494, 0x494, 7.22), (0x495, 0x495, 5.77),
+    (0x496, 0x496, 11.15), (0x497, 0x497, 9.99), (0x498, 0x498, 5.28), (0x499, 0x499, 4.02),
+    (0x49a, 0x49a, 7.71), (0x49b, 0x49b, 6.71), (0x49c, 0x49c, 7.71), (0x49d, 0x49d, 6.71),
+    (0x49e, 0x49e, 7.25), (0x49f, 0x49f, 5.76), (0x4a0, 0x4a0, 8.29), (0x4a1, 0x4a1, 6.3),
+    (0x4a2, 0x4a2, 8.37), (0x4a3, 0x4a3, 7.2), (0x4a4, 0x4a4, 9.21), (0x4a5, 0x4a5, 6.67),
+    (0x4a6, 0x4a6, 11.15), (0x4a7, 0x4a7, 8.44), (0x4a8, 0x4a8, 8.71), (0x4a9, 0x4a9, 6.87),
+    (0x4aa, 0x4aa, 7.22), (0x4ab, 0x4ab, 4.44), (0x4ac, 0x4ac, 6.67), (0x4ad, 0x4ad, 4.91),
+    (0x4ae, 0x4ae, 7.37), (0x4af, 0x4af, 6.51), (0x4b0, 0x4b0, 7.37), (0x4b1, 0x4b1, 6.51),
+    (0x4b2, 0x4b2, 7.64), (0x4b3, 0x4b3, 6.69), (0x4b4, 0x4b4, 8.67), (0x4b5, 0x4b5, 6.09),
+    (0x4b6, 0x4b6, 7.34), (0x4b7, 0x4b7, 5.64), (0x4b8, 0x4b8, 7.87), (0x4b9, 0x4b9, 6.84),
+    (0x4ba, 0x4ba, 7.87), (0x4bb, 0x4bb, 7.12), (0x4bc, 0x4bc, 10.15), (0x4bd, 0x4bd, 7.88),
+    (0x4be, 0x4be, 10.15), (0x4bf, 0x4bf, 7.88), (0x4c0, 0x4c0, 3.89), (0x4c1, 0x4c1, 9.89),
+    (0x4c2, 0x4c2, 7.25), (0x4c3, 0x4c3, 7.37), (0x4c4, 0x4c4, 5.93), (0x4c5, 0x4c5, 7.45),
+    (0x4c6, 0x4c6, 5.61), (0x4c7, 0x4c7, 7.78), (0x4c8, 0x4c8, 5.76), (0x4c9, 0x4c9, 7.78),
+    (0x4ca, 0x4ca, 5.76), (0x4cb, 0x4cb, 7.34), (0x4cc, 0x4cc, 5.64), (0x4cd, 0x4cd, 9.44),
+    (0x4ce, 0x4ce, 6.81), (0x4cf, 0x4cf, 2.78), (0x4d0, 0x4d0, 7.22), (0x4d1, 0x4d1, 5.0),
+    (0x4d2, 0x4d2, 7.22), (0x4d3, 0x4d3, 5.0), (0x4d4, 0x4d4, 10.0), (0x4d5, 0x4d5, 7.22),
+    (0x4d6, 0x4d6, 6.67), (0x4d7, 0x4d7, 4.44), (0x4d8, 0x4d8, 8.05), (0x4d9, 0x4d9, 6.64),
+    (0x4da, 0x4da, 7.39), (0x4db, 0x4db, 4.44), (0x4dc, 0x4dc, 9.89), (0x4dd, 0x4dd, 7.25),
+    (0x4de, 0x4de, 5.28), (0x4df, 0x4df, 4.02), (0x4e0, 0x4e0, 5.28), (0x4e1, 0x4e1, 4.58),
+    (0x4e2, 0x4e2, 7.78), (0x4e3, 0x4e3, 5.76), (0x4e4, 0x4e4, 7.78), (0x4e5, 0x4e5, 5.76),
+    (0x4e6, 0x4e6, 7.78), (0x4e7, 0x4e7, 5.0), (0x4e8, 0x4e8, 8.5), (0x4e9, 0x4e9, 6.87),
+    (0x4ea, 0x4ea, 7.78), (0x4eb, 0x4eb, 5.0), (0x4ec, 0x4ec, 6.78), (0x4ed, 0x4ed, 4.32),
+    (0x4ee, 0x4ee, 7.34), (0x4ef, 0x4ef, 5.0), (0x4f0, 0x4f0, 7.34), (0x4f1, 0x4f1, 5.0),
+    (0x4f2, 0x4f2, 7.34), (0x4f3, 0x4f3, 5.0), (0x4f4, 0x4f4, 7.34), (0x4f5, 0x4f5, 5.64),
+    (0x4f6, 0x4f6, 6.36), (0x4f7, 0x4f7, 4.54), (0x4f8, 0x4f8, 9.82), (0x4f9, 0x4f9, 7.81),
+    (0x4fa, 0x4fa, 6.36), (0x4fb, 0x4fb, 4.54), (0x4fc, 0x4fc, 7.22), (0x4fd, 0x4fd, 5.0),
+    (0x4fe, 0x4fe, 7.22), (0x500, 0x500, 6.61), (0x501, 0x501, 5.56), (0x502, 0x502, 8.52),
+    (0x503, 0x503, 7.81), (0x504, 0x504, 7.97), (0x505, 0x505, 6.78), (0x506, 0x506, 5.95),
+    (0x507, 0x507, 4.6), (0x508, 0x508, 9.36), (0x509, 0x509, 7.79), (0x50a, 0x50a, 9.7),
+    (0x50b, 0x50b, 7.93), (0x50c, 0x50c, 7.78), (0x50d, 0x50d, 5.91), (0x50e, 0x50e, 7.16),
+    (0x50f, 0x50f, 6.18), (0x510, 0x510, 5.28), (0x511, 0x511, 4.02), (0x512, 0x512, 7.45),
+    (0x513, 0x513, 5.61), (0x514, 0x514, 7.62), (0x515, 0x515, 6.93), (0x516, 0x516, 8.3),
+    (0x517, 0x517, 6.81), (0x518, 0x518, 10.31), (0x519, 0x519, 7.37), (0x51a, 0x51a, 8.5),
+    (0x51b, 0x51b, 6.99), (0x51c, 0x51c, 11.28), (0x51d, 0x51d, 9.79), (0x51e, 0x51e, 6.85),
+    (0x51f, 0x51f, 6.31), (0x520, 0x520, 9.81), (0x521, 0x521, 7.96), (0x522, 0x522, 11.11),
+    (0x523, 0x523, 9.08), (0x524, 0x524, 7.08), (0x525, 0x525, 6.18), (0x526, 0x526, 6.89),
+    (0x527, 0x527, 6.31), (0x528, 0x528, 4.44), (0x529, 0x529, 4.03), (0x52a, 0x52a, 8.91),
+    (0x52b, 0x52b, 8.13), (0x52c, 0x52c, 5.62), (0x52d, 0x52d, 5.24), (0x52e, 0x52e, 6.83),
+    (0x1d00, 0x1d00, 5.19), (0x1d01, 0x1d01, 7.03), (0x1d02, 0x1d02, 7.22), (0x1d03, 0x1d03, 5.4),
+    (0x1d04, 0x1d04, 5.23), (0x1d05, 0x1d06, 5.77), (0x1d07, 0x1d07, 4.79), (0x1d08, 0x1d08, 4.02),
+    (0x1d09, 0x1d09, 2.78), (0x1d0a, 0x1d0a, 3.98), (0x1d0b, 0x1d0b, 6.13), (0x1d0c, 0x1d0c, 4.84),
+    (0x1d0d, 0x1d0d, 6.81), (0x1d0e, 0x1d0e, 5.76), (0x1d0f, 0x1d0f, 5.0), (0x1d10, 0x1d10, 5.23),
+    (0x1d11, 0x1d13, 5.56), (0x1d14, 0x1d14, 7.22), (0x1d15, 0x1d15, 5.01), (0x1d16, 0x1d17, 5.0),
+    (0x1d18, 0x1d18, 5.29), (0x1d19, 0x1d1a, 5.41), (0x1d1b, 0x1d1b, 4.91), (0x1d1c, 0x1d1c, 5.46),
+    (0x1d1d, 0x1d1d, 5.39), (0x1d1e, 0x1d1e, 7.13), (0x1d1f, 0x1d1f, 5.39), (0x1d20, 0x1d20, 5.0),
+    (0x1d21, 0x1d21, 7.22), (0x1d22, 0x1d22, 4.44), (0x1d23, 0x1d23, 4.2), (0x1d24, 0x1d24, 4.63),
+    (0x1d25, 0x1d25, 5.44), (0x1d26, 0x1d26, 4.54), (0x1d27, 0x1d27, 4.87), (0x1d28, 0x1d28, 5.76),
+    (0x1d29, 0x1d29, 5.29), (0x1d2a, 0x1d2a, 5.99), (0x1d2b, 0x1d2b, 5.61), (0x1d2c, 0x1d2c, 4.27),
+    (0x1d2d, 0x1d2d, 5.97), (0x1d2e, 0x1d2f, 3.96), (0x1d30, 0x1d30, 4.3), (0x1d31, 0x1d32, 3.9),
+    (0x1d33, 0x1d33, 4.65), (0x1d34, 0x1d34, 4.72), (0x1d35, 0x1d35, 2.37), (0x1d36, 0x1d36, 3.19),
+    (0x1d37, 0x1d37, 4.77), (0x1d38, 0x1d38, 3.97), (0x1d39, 0x1d39, 5.73), (0x1d3a, 0x1d3a, 4.4),
+    (0x1d3b, 0x1d3b, 4.79), (0x1d3c, 0x1d3c, 4.51), (0x1d3d, 0x1d3d, 3.93), (0x1d3e, 0x1d3e, 3.63),
+    (0x1d3f, 0x1d3f, 4.44), (0x1d40, 0x1d40, 3.85), (0x1d41, 0x1d41, 4.38), (0x1d42, 0x1d42, 5.95),
+    (0x1d43, 0x1d44, 2.92), (0x1d45, 0x1d45, 3.37), (0x1d46, 0x1d46, 4.19), (0x1d47, 0x1d47, 3.24),
+    (0x1d48, 0x1d48, 3.26), (0x1d49, 0x1d4a, 2.59), (0x1d4b, 0x1d4c, 2.52), (0x1d4d, 0x1d4d, 2.96),
+    (0x1d4e, 0x1d4e, 1.77), (0x1d4f, 0x1d4f, 3.36), (0x1d50, 0x1d50, 4.92), (0x1d51, 0x1d51, 3.07),
+    (0x1d52, 0x1d52, 2.87), (0x1d53, 0x1d53, 2.59), (0x1d54, 0x1d55, 2.86), (0x1d56, 0x1d56, 3.26),
+    (0x1d57, 0x1d57, 2.12), (0x1d58, 0x1d58, 3.25), (0x1d59, 0x1d59, 3.11), (0x1d5a, 0x1d5a, 4.92),
+    (0x1d5b, 0x1d5b, 2.99), (0x1d5c, 0x1d5c, 3.22), (0x1d5d, 0x1d5d, 2.92), (0x1d5e, 0x1d5e, 2.81),
+    (0x1d5f, 0x1d5f, 2.94), (0x1d60, 0x1d60, 3.57), (0x1d61, 0x1d61, 2.84), (0x1d62, 0x1d62, 1.77),
+    (0x1d63, 0x1d63, 2.54), (0x1d64, 0x1d64, 3.25), (0x1d65, 0x1d65, 2.99), (0x1d66, 0x1d66, 2.92),
+    (0x1d67, 0x1d67, 2.81), (0x1d68, 0x1d68, 2.87), (0x1d69, 0x1d69, 3.57), (0x1d6a, 0x1d6a, 2.84),
+    (0x1d6b, 0x1d6b, 7.51), (0x1d6c, 0x1d6d, 5.56), (0x1d6e, 0x1d6e, 3.33), (0x1d6f, 0x1d6f, 8.33),
+    (0x1d70, 0x1d71, 5.56), (0x1d72, 0x1d72, 4.44), (0x1d73, 0x1d73, 4.01), (0x1d74, 0x1d74, 3.89),
+    (0x1d75, 0x1d75, 3.33), (0x1d76, 0x1d76, 4.44), (0x1d77, 0x1d77, 5.0), (0x1d78, 0x1d78, 3.33),
+    (0x1d79, 0x1d79, 5.19), (0x1d7a, 0x1d7a, 8.45), (0x1d7b, 0x1d7b, 2.88), (0x1d7c, 0x1d7c, 3.1),
+    (0x1d7d, 0x1d7d, 5.56), (0x1d7e, 0x1d7e, 5.46), (0x1e00, 0x1e00, 7.22), (0x1e01, 0x1e01, 5.0),
+    (0x1e02, 0x1e02, 6.67), (0x1e03, 0x1e03, 5.56), (0x1e04, 0x1e04, 7.62), (0x1e05, 0x1e05, 6.99),
+    (0x1e06, 0x1e06, 6.67), (0x1e07, 0x1e07, 5.56), (0x1e08, 0x1e08, 7.24), (0x1e09, 0x1e09, 5.88),
+    (0x1e0a, 0x1e0a, 7.22), (0x1e0b, 0x1e0b, 5.56), (0x1e0c, 0x1e0c, 8.3), (0x1e0d, 0x1e0d, 6.99),
+    (0x1e0e, 0x1e0e, 7.22), (0x1e0f, 0x1e0f, 5.56), (0x1e10, 0x1e10, 7.22), (0x1e11, 0x1e11, 5.56),
+    (0x1e12, 0x1e12, 7.22), (0x1e13, 0x1e13, 5.56), (0x1e14, 0x1e14, 6.83), (0x1e15, 0x1e15, 6.64),
+    (0x1e16, 0x1e16, 6.83), (0x1e17, 0x1e17, 6.64), (0x1e18, 0x1e18, 6.67), (0x1e19, 0x1e19, 4.44),
+    (0x1e1a, 0x1e1a, 6.67), (0x1e1b, 0x1e1b, 4.44), (0x1e1c, 0x1e1c, 6.67), (0x1e1d, 0x1e1d, 4.44),
+    (0x1e1e, 0x1e1e, 6.11), (0x1e1f, 0x1e1f, 3.33), (0x1e20, 0x1e20, 7.78), (0x1e21, 0x1e21, 5.0),
+    (0x1e22, 0x1e22, 7.78), (0x1e23, 0x1e23, 5.56), (0x1e24, 0x1e24, 8.37), (0x1e25, 0x1e25, 7.12),
+    (0x1e26, 0x1e26, 7.78), (0x1e27, 0x1e27, 5.56), (0x1e28, 0x1e28, 7.78), (0x1e29, 0x1e29, 5.56),
+    (0x1e2a, 0x1e2a, 7.78), (0x1e2b, 0x1e2b, 5.56), (0x1e2c, 0x1e2c, 3.89), (0x1e2d, 0x1e2d, 2.78),
+    (0x1e2e, 0x1e2e, 5.46), (0x1e2f, 0x1e2f, 3.42), (0x1e30, 0x1e30, 7.71), (0x1e31, 0x1e31, 6.71),
+    (0x1e32, 0x1e32, 7.71), (0x1e33, 0x1e33, 6.71), (0x1e34, 0x1e34, 7.78), (0x1e35, 0x1e35, 5.56),
+    (0x1e36, 0x1e36, 6.37), (0x1e37, 0x1e37, 3.42), (0x1e38, 0x1e38, 6.67), (0x1e39, 0x1e39, 2.78),
+    (0x1e3a, 0x1e3a, 6.67), (0x1e3b, 0x1e3b, 2.78), (0x1e3c, 0x1e3c, 6.67), (0x1e3d, 0x1e3d, 2.78),
+    (0x1e3e, 0x1e3e, 9.48), (0x1e3f, 0x1e3f, 10.58), (0x1e40, 0x1e40, 9.44), (0x1e41, 0x1e41, 8.33),
+    (0x1e42, 0x1e42, 9.48), (0x1e43, 0x1e43, 10.58), (0x1e44, 0x1e44, 7.22), (0x1e45, 0x1e45, 5.56),
+    (0x1e46, 0x1e46, 8.47), (0x1e47, 0x1e47, 7.12), (0x1e48, 0x1e48, 7.22), (0x1e49, 0x1e49, 5.56),
+    (0x1e4a, 0x1e4a, 7.22), (0x1e4b, 0x1e4b, 5.56), (0x1e4c, 0x1e4c, 8.5), (0x1e4d, 0x1e4d, 6.87),
+    (0x1e4e, 0x1e4e, 7.78), (0x1e4f, 0x1e4f, 5.0), (0x1e50, 0x1e50, 8.5), (0x1e51, 0x1e51, 6.87),
+    (0x1e52, 0x1e52, 8.5), (0x1e53, 0x1e53, 6.87), (0x1e54, 0x1e54, 7.33), (0x1e55, 0x1e55, 6.99),
+    (0x1e56, 0x1e56, 6.11), (0x1e57, 0x1e57, 5.56), (0x1e58, 0x1e58, 7.22), (0x1e59, 0x1e59, 4.44),
+    (0x1e5a, 0x1e5a, 7.82), (0x1e5b, 0x1e5b, 4.97), (0x1e5c, 0x1e5c, 7.22), (0x1e5d, 0x1e5d, 4.44),
+    (0x1e5e, 0x1e5e, 7.22), (0x1e5f, 0x1e5f, 4.44), (0x1e60, 0x1e60, 5.56), (0x1e61, 0x1e61, 3.89),
+    (0x1e62, 0x1e62, 7.1), (0x1e63, 0x1e63, 5.93), (0x1e64, 0x1e64, 5.56), (0x1e65, 0x1e65, 3.89),
+    (0x1e66, 0x1e66, 5.56), (0x1e67, 0x1e67, 3.89), (0x1e68, 0x1e68, 5.56), (0x1e69, 0x1e69, 3.89),
+    (0x1e6a, 0x1e6a, 6.67), (0x1e6b, 0x1e6b, 3.33), (0x1e6c, 0x1e6c, 6.82), (0x1e6d, 0x1e6d, 4.56),
+    (0x1e6e, 0x1e6e, 6.67), (0x1e6f, 0x1e6f, 3.33), (0x1e70, 0x1e70, 6.67), (0x1e71, 0x1e71, 3.33),
+    (0x1e72, 0x1e72, 7.22), (0x1e73, 0x1e73, 5.56), (0x1e74, 0x1e74, 7.22), (0x1e75, 0x1e75, 5.56),
+    (0x1e76, 0x1e76, 7.22), (0x1e77, 0x1e77, 5.56), (0x1e78, 0x1e78, 8.12), (0x1e79, 0x1e79, 7.12),
+    (0x1e7a, 0x1e7a, 7.22), (0x1e7b, 0x1e7b, 5.56), (0x1e7c, 0x1e7c, 7.64), (0x1e7d, 0x1e7d, 6.5),
+    (0x1e7e, 0x1e7e, 7.64), (0x1e7f, 0x1e7f, 6.5), (0x1e80, 0x1e80, 11.28), (0x1e81, 0x1e81, 9.79),
+    (0x1e82, 0x1e82, 11.28), (0x1e83, 0x1e83, 9.79), (0x1e84, 0x1e84, 11.28),
+    (0x1e85, 0x1e85, 9.79), (0x1e86, 0x1e86, 10.0), (0x1e87, 0x1e87, 7.22), (0x1e88, 0x1e88, 11.28),
+    (0x1e89, 0x1e89, 9.79), (0x1e8a, 0x1e8a, 7.22), (0x1e8b, 0x1e8b, 5.0), (0x1e8c, 0x1e8c, 7.22),
+    (0x1e8d, 0x1e8d, 5.0), (0x1e8e, 0x1e8e, 7.22), (0x1e8f, 0x1e8f, 5.0), (0x1e90, 0x1e90, 6.67),
+    (0x1e91, 0x1e91, 4.44), (0x1e92, 0x1e92, 6.92), (0x1e93, 0x1e93, 5.97), (0x1e94, 0x1e94, 6.67),
+    (0x1e95, 0x1e95, 4.44), (0x1e96, 0x1e96, 5.56), (0x1e97, 0x1e97, 3.33), (0x1e98, 0x1e98, 7.22),
+    (0x1e99, 0x1e9a, 5.0), (0x1e9b, 0x1e9b, 2.78), (0x1e9c, 0x1e9c, 4.23), (0x1e9d, 0x1e9d, 4.22),
+    (0x1e9e, 0x1e9e, 7.48), (0x1e9f, 0x1e9f, 6.02), (0x1ea0, 0x1ea0, 7.76), (0x1ea1, 0x1ea1, 6.68),
+    (0x1ea2, 0x1ea2, 7.76), (0x1ea3, 0x1ea3, 6.68), (0x1ea4, 0x1ea4, 7.76), (0x1ea5, 0x1ea5, 6.68),
+    (0x1ea6, 0x1ea6, 7.76), (0x1ea7, 0x1ea7, 6.68), (0x1ea8, 0x1ea8, 7.76), (0x1ea9, 0x1ea9, 6.68),
+    (0x1eaa, 0x1eaa, 7.76), (0x1eab, 0x1eab, 6.68), (0x1eac, 0x1eac, 7.76), (0x1ead, 0x1ead, 6.68),
+    (0x1eae, 0x1eae, 7.76), (0x1eaf, 0x1eaf, 6.68), (0x1eb0, 0x1eb0, 7.76), (0x1eb1, 0x1eb1, 6.68),
+    (0x1eb2, 0x1eb2, 7.76), (0x1eb3, 0x1eb3, 6.68), (0x1eb4, 0x1eb4, 7.76), (0x1eb5, 0x1eb5, 6.68),
+    (0x1eb6, 0x1eb6, 7.76), (0x1eb7, 0x1eb7, 6.68), (0x1eb8, 0x1eb8, 6.83), (0x1eb9, 0x1eb9, 6.64),
+    (0x1eba, 0x1eba, 6.83), (0x1ebb, 0x1ebb, 6.64), (0x1ebc, 0x1ebc, 6.83), (0x1ebd, 0x1ebd, 6.64),
+    (0x1ebe, 0x1ebe, 6.83), (0x1ebf, 0x1ebf, 6.64), (0x1ec0, 0x1ec0, 6.83), (0x1ec1, 0x1ec1, 6.64),
+    (0x1ec2, 0x1ec2, 6.83), (0x1ec3, 0x1ec3, 6.64), (0x1ec4, 0x1ec4, 6.83), (0x1ec5, 0x1ec5, 6.64),
+    (0x1ec6, 0x1ec6, 6.83), (0x1ec7, 0x1ec7, 6.64), (0x1ec8, 0x1ec8, 5.46), (0x1ec9, 0x1ec9, 3.42),
+    (0x1eca, 0x1eca, 5.46), (0x1ecb, 0x1ecb, 3.42), (0x1ecc, 0x1ecc, 8.5), (0x1ecd, 0x1ecd, 6.87),
+    (0x1ece, 0x1ece, 8.5), (0x1ecf, 0x1ecf, 6.87), (0x1ed0, 0x1ed0, 8.5), (0x1ed1, 0x1ed1, 6.87),
+    (0x1ed2, 0x1ed2, 8.5), (0x1ed3, 0x1ed3, 6.87), (0x1ed4, 0x1ed4, 8.5), (0x1ed5, 0x1ed5, 6.87),
+    (0x1ed6, 0x1ed6, 8.5), (0x1ed7, 0x1ed7, 6.87), (0x1ed8, 0x1ed8, 8.5), (0x1ed9, 0x1ed9, 6.87),
+    (0x1eda, 0x1eda, 9.14), (0x1edb, 0x1edb, 6.87), (0x1edc, 0x1edc, 9.14), (0x1edd, 0x1edd, 6.87),
+    (0x1ede, 0x1ede, 9.14), (0x1edf, 0x1edf, 6.87), (0x1ee0, 0x1ee0, 9.14), (0x1ee1, 0x1ee1, 6.87),
+    (0x1ee2, 0x1ee2, 9.14), (0x1ee3, 0x1ee3, 6.87), (0x1ee4, 0x1ee4, 8.12), (0x1ee5, 0x1ee5, 7.12),
+    (0x1ee6, 0x1ee6, 8.12), (0x1ee7, 0x1ee7, 7.12), (0x1ee8, 0x1ee8, 8.46), (0x1ee9, 0x1ee9, 7.42),
+    (0x1eea, 0x1eea, 8.46), (0x1eeb, 0x1eeb, 7.42), (0x1eec, 0x1eec, 8.46), (0x1eed, 0x1eed, 7.42),
+    (0x1eee, 0x1eee, 8.46), (0x1eef, 0x1eef, 7.42), (0x1ef0, 0x1ef0, 8.46), (0x1ef1, 0x1ef1, 7.42),
+    (0x1ef2, 0x1ef2, 7.37), (0x1ef3, 0x1ef3, 6.51), (0x1ef4, 0x1ef4, 7.37), (0x1ef5, 0x1ef5, 6.51),
+    (0x1ef6, 0x1ef6, 7.37), (0x1ef7, 0x1ef7, 6.51), (0x1ef8, 0x1ef8, 7.37), (0x1ef9, 0x1ef9, 6.51),
+    (0x1efa, 0x1efa, 9.36), (0x1efb, 0x1efb, 5.89), (0x1efc, 0x1efc, 5.06), (0x1efd, 0x1efd, 4.45),
+    (0x1efe, 0x1efe, 4.58), (0x1f00, 0x1f07, 5.58), (0x1f08, 0x1f09, 7.22), (0x1f0a, 0x1f0b, 8.0),
+    (0x1f0c, 0x1f0d, 7.47), (0x1f0e, 0x1f0f, 7.22), (0x1f10, 0x1f15, 4.27), (0x1f16, 0x1f17, 10.0),
+    (0x1f18, 0x1f19, 8.01), (0x1f1a, 0x1f1d, 9.89), (0x1f1e, 0x1f1f, 10.0), (0x1f20, 0x1f27, 5.68),
+    (0x1f28, 0x1f29, 9.12), (0x1f2a, 0x1f2f, 11.0), (0x1f30, 0x1f37, 3.1), (0x1f38, 0x1f39, 5.23),
+    (0x1f3a, 0x1f3f, 7.11), (0x1f40, 0x1f45, 5.0), (0x1f46, 0x1f47, 10.0), (0x1f48, 0x1f49, 8.75),
+    (0x1f4a, 0x1f4b, 10.61), (0x1f4c, 0x1f4d, 9.88), (0x1f4e, 0x1f4f, 10.0), (0x1f50, 0x1f57, 5.19),
+    (0x1f58, 0x1f58, 10.0), (0x1f59, 0x1f59, 8.66), (0x1f5a, 0x1f5a, 10.0), (0x1f5b, 0x1f5b, 10.44),
+    (0x1f5c, 0x1f5c, 10.0), (0x1f5d, 0x1f5d, 10.44), (0x1f5e, 0x1f5e, 10.0),
+    (0x1f5f, 0x1f5f, 10.44), (0x1f60, 0x1f67, 7.31), (0x1f68, 0x1f69, 8.86),
+    (0x1f6a, 0x1f6b, 10.74), (0x1f6c, 0x1f6f, 10.11), (0x1f70, 0x1f71, 6.99),
+    (0x1f72, 0x1f73, 5.85), (0x1f74, 0x1f75, 7.12), (0x1f76, 0x1f77, 3.42), (0x1f78, 0x1f79, 6.87),
+    (0x1f7a, 0x1f7b, 7.06), (0x1f7c, 0x1f7d, 8.94), (0x1f7e, 0x1f7f, 10.0), (0x1f80, 0x1f87, 5.58),
+    (0x1f88, 0x1f89, 7.22), (0x1f8a, 0x1f8b, 8.0), (0x1f8c, 0x1f8d, 7.47), (0x1f8e, 0x1f8f, 7.22),
+    (0x1f90, 0x1f97, 5.68), (0x1f98, 0x1f99, 9.12), (0x1f9a, 0x1f9f, 11.0), (0x1fa0, 0x1fa7, 7.31),
+    (0x1fa8, 0x1fa9, 8.86), (0x1faa, 0x1fab, 10.74), (0x1fac, 0x1faf, 10.11),
+    (0x1fb0, 0x1fb4, 5.58), (0x1fb5, 0x1fb5, 10.0), (0x1fb6, 0x1fb7, 5.58), (0x1fb8, 0x1fb9, 7.22),
+    (0x1fba, 0x1fba, 7.76), (0x1fbb, 0x1fbb, 7.97), (0x1fbc, 0x1fbc, 7.22), (0x1fbd, 0x1fbd, 3.33),
+    (0x1fbe, 0x1fbe, 3.42), (0x1fbf, 0x1fc1, 3.33), (0x1fc2, 0x1fc4, 5.68), (0x1fc5, 0x1fc5, 10.0),
+    (0x1fc6, 0x1fc7, 5.68), (0x1fc8, 0x1fc8, 6.83), (0x1fc9, 0x1fc9, 8.48), (0x1fca, 0x1fca, 8.37),
+    (0x1fcb, 0x1fcb, 10.01), (0x1fcc, 0x1fcc, 7.78), (0x1fcd, 0x1fcf, 3.33), (0x1fd0, 0x1fd1, 3.1),
+    (0x1fd2, 0x1fd3, 3.42), (0x1fd4, 0x1fd5, 10.0), (0x1fd6, 0x1fd7, 3.1), (0x1fd8, 0x1fd9, 3.89),
+    (0x1fda, 0x1fda, 5.46), (0x1fdb, 0x1fdb, 7.05), (0x1fdc, 0x1fdc, 10.0), (0x1fdd, 0x1fdf, 3.33),
+    (0x1fe0, 0x1fe1, 5.19), (0x1fe2, 0x1fe3, 7.06), (0x1fe4, 0x1fe5, 5.35), (0x1fe6, 0x1fe7, 5.19),
+    (0x1fe8, 0x1fe9, 7.22), (0x1fea, 0x1fea, 7.37), (0x1feb, 0x1feb, 9.39), (0x1fec, 0x1fec, 7.45),
+    (0x1fed, 0x1fef, 7.11), (0x1ff0, 0x1ff1, 10.0), (0x1ff2, 0x1ff4, 7.31), (0x1ff5, 0x1ff5, 10.0),
+    (0x1ff6, 0x1ff7, 7.31), (0x1ff8, 0x1ff8, 8.5), (0x1ff9, 0x1ff9, 9.68), (0x1ffa, 0x1ffa, 8.44),
+    (0x1ffb, 0x1ffb, 9.7), (0x1ffc, 0x1ffc, 8.01), (0x1ffd, 0x1ffd, 7.11), (0x1ffe, 0x1ffe, 3.33),
+    (0x2000, 0x2000, 5.0), (0x2001, 0x2001, 10.0), (0x2002, 0x2002, 5.0), (0x2003, 0x2003, 10.0),
+    (0x2004, 0x2004, 3.33), (0x2005, 0x2005, 2.5), (0x2006, 0x2006, 1.67), (0x2007, 0x2007, 7.11),
+    (0x2008, 0x2008, 3.61), (0x2009, 0x2009, 2.0), (0x200a, 0x200a, 0.63), (0x200b, 0x200f, 0.0),
+    (0x2010, 0x2011, 6.39), (0x2012, 0x2013, 7.11), (0x2014, 0x2015, 10.0), (0x2016, 0x2016, 3.24),
+    (0x2017, 0x2017, 7.11), (0x2018, 0x201b, 3.32), (0x201c, 0x201f, 5.87), (0x2020, 0x2022, 7.11),
+    (0x2023, 0x2023, 5.3), (0x2024, 0x2024, 3.33), (0x2025, 0x2025, 6.67), (0x2026, 0x2026, 10.49),
+    (0x2027, 0x2027, 2.47), (0x2028, 0x202e, 0.0), (0x202f, 0x202f, 1.71), (0x2030, 0x2030, 17.77),
+    (0x2031, 0x2031, 15.42), (0x2032, 0x2032, 3.53), (0x2033, 0x2034, 6.16), (0x2035, 0x2035, 3.5),
+    (0x2036, 0x2036, 5.66), (0x2037, 0x2037, 7.83), (0x2038, 0x2038, 3.53), (0x2039, 0x203a, 5.43),
+    (0x203b, 0x203b, 7.23), (0x203c, 0x203c, 7.03), (0x203d, 0x203d, 4.97), (0x203e, 0x203e, 7.11),
+    (0x203f, 0x2041, 5.0), (0x2042, 0x2042, 8.95), (0x2043, 0x2043, 3.33), (0x2044, 0x2044, 4.39),
+    (0x2045, 0x2046, 3.74), (0x2047, 0x2047, 9.57), (0x2048, 0x2048, 7.89), (0x2049, 0x2049, 7.86),
+    (0x204a, 0x204d, 6.6), (0x204e, 0x204e, 4.66), (0x204f, 0x204f, 2.47), (0x2050, 0x2050, 5.0),
+    (0x2051, 0x2051, 4.66), (0x2052, 0x2052, 5.75), (0x2053, 0x2053, 10.0), (0x2054, 0x2054, 5.0),
+    (0x2055, 0x2055, 6.97), (0x2056, 0x2056, 5.93), (0x2057, 0x2057, 10.0), (0x2058, 0x2059, 5.93),
+    (0x205a, 0x205a, 10.0), (0x205b, 0x205b, 5.93), (0x205c, 0x205c, 6.98), (0x205d, 0x205d, 10.0),
+    (0x205e, 0x205e, 3.33), (0x205f, 0x205f, 2.22), (0x2060, 0x206e, 0.0), (0x2070, 0x2070, 4.82),
+    (0x2071, 0x2071, 3.01), (0x2072, 0x2073, 10.0), (0x2074, 0x2075, 5.98), (0x2076, 0x2076, 4.82),
+    (0x2077, 0x2078, 5.98), (0x2079, 0x207c, 4.82), (0x207d, 0x207e, 2.41), (0x207f, 0x207f, 5.98),
+    (0x2080, 0x208c, 4.82), (0x208d, 0x208e, 2.41), (0x208f, 0x208f, 10.0), (0x2090, 0x2090, 2.92),
+    (0x2091, 0x2091, 2.59), (0x2092, 0x2092, 2.87), (0x2093, 0x2093, 2.86), (0x2094, 0x2094, 2.59),
+    (0x2095, 0x209e, 10.0), (0x20a0, 0x20a0, 7.11), (0x20a1, 0x20a2, 7.24), (0x20a3, 0x20a4, 7.11),
+    (0x20a5, 0x20a5, 10.58), (0x20a6, 0x20a6, 8.47), (0x20a7, 0x20a7, 13.43),
+    (0x20a8, 0x20a8, 13.4), (0x20a9, 0x20a9, 11.28), (0x20aa, 0x20aa, 9.15), (0x20ab, 0x20ab, 6.99),
+    (0x20ac, 0x20ac, 7.11), (0x20ad, 0x20ad, 7.71), (0x20ae, 0x20ae, 6.82), (0x20af, 0x20af, 11.25),
+    (0x20b0, 0x20b0, 6.71), (0x20b1, 0x20b1, 7.33), (0x20b2, 0x20b2, 8.11), (0x20b3, 0x20b3, 7.76),
+    (0x20b4, 0x20b4, 7.1), (0x20b5, 0x20b5, 7.24), (0x20b6, 0x20b6, 6.77), (0x20b7, 0x20b7, 9.39),
+    (0x20b8, 0x20b8, 5.65), (0x20b9, 0x20b9, 6.0), (0x20ba, 0x20ba, 5.56), (0x20bb, 0x20bb, 9.84),
+    (0x20bc, 0x20bd, 5.65), (0x20be, 0x20be, 7.53), (0x20bf, 0x20bf, 6.59), (0x20c0, 0x20ce, 10.0),
+    (0x20d0, 0x20d2, 0.0), (0x20d3, 0x20d3, 6.0), (0x20d4, 0x20d5, 7.0), (0x20d6, 0x20d8, 0.0),
+    (0x20d9, 0x20da, 6.0), (0x20db, 0x20dd, 0.0), (0x20de, 0x20de, 10.0), (0x20df, 0x20df, 8.65),
+    (0x20e0, 0x20e0, 9.0), (0x20e1, 0x20e1, 0.0), (0x20e2, 0x20e2, 10.0), (0x20e3, 0x20f0, 0.0),
+    (0x20f1, 0x20fe, 10.0), (0x2100, 0x2101, 8.25), (0x2102, 0x2102, 6.02), (0x2103, 0x2103, 10.03),
+    (0x2104, 0x2104, 6.4), (0x2105, 0x2105, 12.72), (0x2106, 0x2106, 8.25), (0x2107, 0x2107, 6.99),
+    (0x2108, 0x2108, 6.35), (0x2109, 0x2109, 8.81), (0x210a, 0x210a, 10.0), (0x210b, 0x210b, 9.06),
+    (0x210c, 0x210c, 8.43), (0x210d, 0x210d, 6.02), (0x210e, 0x210e, 5.76), (0x210f, 0x210f, 6.46),
+    (0x2110, 0x2110, 6.88), (0x2111, 0x2111, 7.9), (0x2112, 0x2112, 7.08), (0x2113, 0x2113, 4.14),
+    (0x2114, 0x2114, 7.78), (0x2115, 0x2115, 6.02), (0x2116, 0x2116, 12.94), (0x2117, 0x2117, 8.4),
+    (0x2118, 0x2118, 8.5), (0x2119, 0x211a, 6.02), (0x211b, 0x211b, 9.27), (0x211c, 0x211c, 8.84),
+    (0x211d, 0x211d, 6.02), (0x211e, 0x211e, 6.83), (0x211f, 0x211f, 6.67), (0x2120, 0x2120, 13.4),
+    (0x2121, 0x2121, 14.57), (0x2122, 0x2122, 9.64), (0x2123, 0x2123, 7.22), (0x2124, 0x2124, 6.02),
+    (0x2125, 0x2125, 5.42), (0x2126, 0x2126, 8.44), (0x2127, 0x2127, 8.43), (0x2128, 0x2128, 7.55),
+    (0x2129, 0x2129, 3.92), (0x212a, 0x212a, 7.71), (0x212b, 0x212b, 7.76), (0x212c, 0x212c, 9.27),
+    (0x212d, 0x212d, 7.73), (0x212e, 0x212e, 7.48), (0x212f, 0x212f, 3.85), (0x2130, 0x2130, 6.15),
+    (0x2131, 0x2131, 6.88), (0x2132, 0x2132, 5.74), (0x2133, 0x2133, 11.15), (0x2134, 0x2134, 4.06),
+    (0x2135, 0x2135, 6.55), (0x2136, 0x2136, 6.04), (0x2137, 0x2137, 4.65), (0x2138, 0x2138, 5.62),
+    (0x2139, 0x2139, 6.6), (0x213a, 0x213a, 9.21), (0x213b, 0x213b, 17.49), (0x213c, 0x213c, 8.04),
+    (0x213d, 0x213d, 6.46), (0x213e, 0x213e, 4.97), (0x213f, 0x213f, 7.68), (0x2140, 0x2140, 9.76),
+    (0x2141, 0x2141, 7.46), (0x2142, 0x2143, 5.81), (0x2144, 0x2144, 6.87), (0x2145, 0x2145, 8.08),
+    (0x2146, 0x2146, 6.94), (0x2147, 0x2147, 6.12), (0x2148, 0x2148, 4.11), (0x2149, 0x2149, 4.23),
+    (0x214a, 0x214a, 6.76), (0x214b, 0x214b, 7.45), (0x214c, 0x214c, 8.1), (0x214d, 0x214d, 9.18),
+    (0x214e, 0x214e, 4.79), (0x2150, 0x2152, 10.0), (0x2153, 0x2154, 7.5), (0x2155, 0x215a, 9.5),
+    (0x215b, 0x215e, 11.82), (0x215f, 0x215f, 9.5), (0x2160, 0x2160, 3.32), (0x2161, 0x2161, 6.26),
+    (0x2162, 0x2162, 9.22), (0x2163, 0x2163, 9.75), (0x2164, 0x2164, 6.99), (0x2165, 0x2165, 9.78),
+    (0x2166, 0x2166, 12.73), (0x2167, 0x2167, 15.68), (0x2168, 0x2168, 9.32),
+    (0x2169, 0x2169, 6.67), (0x216a, 0x216a, 9.35), (0x216b, 0x216b, 12.29), (0x216c, 0x216c, 5.81),
+    (0x216d, 0x216d, 7.12), (0x216e, 0x216e, 7.93), (0x216f, 0x216f, 9.08), (0x2170, 0x2170, 3.25),
+    (0x2171, 0x2171, 6.14), (0x2172, 0x2172, 9.04), (0x2173, 0x2173, 8.58), (0x2174, 0x2174, 5.93),
+    (0x2175, 0x2175, 8.57), (0x2176, 0x2176, 11.46), (0x2177, 0x2177, 14.36), (0x2178, 0x2178, 8.4),
+    (0x2179, 0x2179, 5.91), (0x217a, 0x217a, 8.44), (0x217b, 0x217b, 11.33), (0x217c, 0x217c, 3.25),
+    (0x217d, 0x217d, 5.32), (0x217e, 0x217e, 6.63), (0x217f, 0x217f, 9.7), (0x2180, 0x2180, 10.82),
+    (0x2181, 0x2181, 7.93), (0x2182, 0x2182, 12.61), (0x2183, 0x2183, 7.12), (0x2184, 0x2184, 4.44),
+    (0x2185, 0x2185, 6.51), (0x2186, 0x2186, 5.64), (0x2187, 0x218e, 10.0), (0x2190, 0x2190, 10.0),
+    (0x2191, 0x2191, 5.0), (0x2192, 0x2192, 10.0), (0x2193, 0x2193, 5.0), (0x2194, 0x2194, 10.0),
+    (0x2195, 0x2195, 5.0), (0x2196, 0x2196, 9.16), (0x2197, 0x2197, 10.0), (0x2198, 0x2198, 9.16),
+    (0x2199, 0x219b, 10.0), (0x219c, 0x21a7, 6.02), (0x21a8, 0x21a8, 5.0), (0x21a9, 0x21aa, 10.0),
+    (0x21ab, 0x21ad, 6.02), (0x21ae, 0x21ae, 10.0), (0x21af, 0x21af, 6.02), (0x21b0, 0x21b2, 8.65),
+    (0x21b3, 0x21b3, 10.0), (0x21b4, 0x21b4, 8.65), (0x21b5, 0x21b5, 10.0), (0x21b6, 0x21b7, 8.65),
+    (0x21b8, 0x21b9, 10.0), (0x21ba, 0x21bb, 6.02), (0x21bc, 0x21bc, 8.65), (0x21bd, 0x21bf, 6.02),
+    (0x21c0, 0x21c0, 8.65), (0x21c1, 0x21c3, 6.02), (0x21c4, 0x21c6, 10.0), (0x21c7, 0x21ca, 6.02),
+    (0x21cb, 0x21cc, 10.0), (0x21cd, 0x21cd, 8.8), (0x21ce, 0x21ce, 6.02), (0x21cf, 0x21cf, 8.69),
+    (0x21d0, 0x21d0, 10.0), (0x21d1, 0x21d1, 8.65), (0x21d2, 0x21d2, 10.0), (0x21d3, 0x21d3, 8.65),
+    (0x21d4, 0x21d4, 10.0), (0x21d5, 0x21dd, 6.02), (0x21de, 0x21df, 7.23), (0x21e0, 0x21e0, 10.0),
+    (0x21e1, 0x21e1, 7.23), (0x21e2, 0x21e2, 10.0), (0x21e3, 0x21e3, 7.23), (0x21e4, 0x21e6, 10.0),
+    (0x21e7, 0x21e7, 11.81), (0x21e8, 0x21e9, 10.0), (0x21ea, 0x21ea, 11.81),
+    (0x21eb, 0x21ef, 6.02), (0x21f0, 0x21f0, 8.65), (0x21f1, 0x21f4, 6.02), (0x21f5, 0x21f5, 10.0),
+    (0x21f6, 0x21fe, 6.02), (0x2200, 0x2200, 6.3), (0x2201, 0x2201, 4.69), (0x2202, 0x2202, 7.11),
+    (0x2203, 0x2203, 5.26), (0x2204, 0x2204, 5.35), (0x2205, 0x2205, 7.17), (0x2206, 0x2206, 8.06),
+    (0x2207, 0x2207, 7.33), (0x2208, 0x2208, 5.76), (0x2209, 0x2209, 6.12), (0x220a, 0x220a, 5.09),
+    (0x220b, 0x220b, 5.76), (0x220c, 0x220c, 5.89), (0x220d, 0x220d, 5.09), (0x220e, 0x220e, 5.22),
+    (0x220f, 0x220f, 8.69), (0x2210, 0x2210, 7.95), (0x2211, 0x2211, 6.98), (0x2212, 0x2212, 8.67),
+    (0x2213, 0x2213, 7.95), (0x2214, 0x2214, 5.99), (0x2215, 0x2215, 4.39), (0x2216, 0x2216, 8.04),
+    (0x2217, 0x2217, 5.23), (0x2218, 0x2218, 3.55), (0x2219, 0x2219, 3.61), (0x221a, 0x221a, 8.67),
+    (0x221b, 0x221b, 7.49), (0x221c, 0x221c, 7.24), (0x221d, 0x221d, 5.19), (0x221e, 0x221e, 10.59),
+    (0x221f, 0x221f, 9.79), (0x2220, 0x2220, 5.98), (0x2221, 0x2221, 6.01), (0x2222, 0x2222, 6.05),
+    (0x2223, 0x2223, 2.5), (0x2224, 0x2224, 4.73), (0x2225, 0x2225, 3.46), (0x2226, 0x2226, 5.25),
+    (0x2227, 0x2228, 5.94), (0x2229, 0x2229, 7.19), (0x222a, 0x222a, 5.31), (0x222b, 0x222b, 5.38),
+    (0x222c, 0x222c, 9.15), (0x222d, 0x222d, 11.24), (0x222e, 0x222e, 6.78), (0x222f, 0x222f, 9.24),
+    (0x2230, 0x2230, 11.66), (0x2231, 0x2231, 5.98), (0x2232, 0x2232, 6.28), (0x2233, 0x2233, 5.97),
+    (0x2234, 0x2234, 6.04), (0x2235, 0x2235, 5.97), (0x2236, 0x2236, 3.36), (0x2237, 0x2237, 5.76),
+    (0x2238, 0x2238, 5.63), (0x2239, 0x2239, 5.76), (0x223a, 0x223a, 5.8), (0x223b, 0x223b, 5.6),
+    (0x223c, 0x223d, 5.5), (0x223e, 0x223e, 5.43), (0x223f, 0x223f, 5.83), (0x2240, 0x2240, 3.9),
+    (0x2241, 0x2241, 5.74), (0x2242, 0x2242, 5.8), (0x2243, 0x2243, 5.84), (0x2244, 0x2244, 6.71),
+    (0x2245, 0x2245, 6.96), (0x2246, 0x2246, 6.29), (0x2247, 0x2247, 6.11), (0x2248, 0x2248, 8.67),
+    (0x2249, 0x2249, 5.49), (0x224a, 0x224a, 6.04), (0x224b, 0x224b, 6.71), (0x224c, 0x224c, 5.43),
+    (0x224d, 0x224d, 6.03), (0x224e, 0x224e, 5.97), (0x224f, 0x224f, 5.93), (0x2250, 0x2250, 5.6),
+    (0x2251, 0x2251, 6.38), (0x2252, 0x2253, 6.32), (0x2254, 0x2255, 7.64), (0x2256, 0x2256, 5.79),
+    (0x2257, 0x2257, 5.96), (0x2258, 0x2258, 5.89), (0x2259, 0x2259, 6.08), (0x225a, 0x225a, 6.19),
+    (0x225b, 0x225b, 6.46), (0x225c, 0x225c, 6.36), (0x225d, 0x225d, 5.63), (0x225e, 0x225e, 5.75),
+    (0x225f, 0x225f, 5.9), (0x2260, 0x2261, 8.67), (0x2262, 0x2262, 5.7), (0x2263, 0x2263, 5.93),
+    (0x2264, 0x2265, 8.67), (0x2266, 0x2266, 5.75), (0x2267, 0x2267, 5.64), (0x2268, 0x2268, 6.22),
+    (0x2269, 0x2269, 5.89), (0x226a, 0x226a, 7.33), (0x226b, 0x226b, 7.01), (0x226c, 0x226c, 3.58),
+    (0x226d, 0x226d, 5.53), (0x226e, 0x226f, 5.7), (0x2270, 0x2271, 5.49), (0x2272, 0x2272, 5.64),
+    (0x2273, 0x2273, 5.67), (0x2274, 0x2274, 5.83), (0x2275, 0x2275, 5.81), (0x2276, 0x2277, 6.05),
+    (0x2278, 0x2278, 5.61), (0x2279, 0x2279, 5.77), (0x227a, 0x227a, 5.67), (0x227b, 0x227b, 5.78),
+    (0x227c, 0x227c, 5.96), (0x227d, 0x227d, 5.78), (0x227e, 0x227e, 5.85), (0x227f, 0x227f, 5.82),
+    (0x2280, 0x2280, 6.07), (0x2281, 0x2281, 5.79), (0x2282, 0x2283, 6.32), (0x2284, 0x2284, 6.44),
+    (0x2285, 0x2285, 6.31), (0x2286, 0x2287, 6.63), (0x2288, 0x2288, 6.67), (0x2289, 0x2289, 6.49),
+    (0x228a, 0x228b, 5.62), (0x228c, 0x228d, 5.82), (0x228e, 0x228e, 6.09), (0x228f, 0x2290, 6.54),
+    (0x2291, 0x2292, 6.51), (0x2293, 0x2294, 6.11), (0x2295, 0x2295, 7.24), (0x2296, 0x2296, 7.45),
+    (0x2297, 0x2297, 7.11), (0x2298, 0x2298, 7.22), (0x2299, 0x2299, 7.56), (0x229a, 0x229a, 7.35),
+    (0x229b, 0x229b, 7.18), (0x229c, 0x229c, 7.61), (0x229d, 0x229d, 7.56), (0x229e, 0x229e, 7.84),
+    (0x229f, 0x229f, 7.6), (0x22a0, 0x22a0, 7.33), (0x22a1, 0x22a1, 7.16), (0x22a2, 0x22a5, 7.5),
+    (0x22a6, 0x22a6, 3.3), (0x22a7, 0x22a7, 3.33), (0x22a8, 0x22a8, 6.24), (0x22a9, 0x22a9, 7.29),
+    (0x22aa, 0x22aa, 6.23), (0x22ab, 0x22ab, 7.55), (0x22ac, 0x22ac, 7.76), (0x22ad, 0x22ad, 7.78),
+    (0x22ae, 0x22ae, 8.74), (0x22af, 0x22af, 8.36), (0x22b0, 0x22b1, 6.32), (0x22b2, 0x22b3, 7.41),
+    (0x22b4, 0x22b5, 7.03), (0x22b6, 0x22b7, 7.35), (0x22b8, 0x22b8, 5.96), (0x22b9, 0x22b9, 6.83),
+    (0x22ba, 0x22ba, 4.8), (0x22bb, 0x22bc, 6.96), (0x22bd, 0x22bd, 6.74), (0x22be, 0x22be, 7.42),
+    (0x22bf, 0x22bf, 6.31), (0x22c0, 0x22c1, 6.71), (0x22c2, 0x22c3, 6.41), (0x22c4, 0x22c4, 3.81),
+    (0x22c5, 0x22c5, 4.41), (0x22c6, 0x22c6, 4.48), (0x22c7, 0x22c7, 6.31), (0x22c8, 0x22c8, 7.72),
+    (0x22c9, 0x22ca, 7.4), (0x22cb, 0x22cc, 7.24), (0x22cd, 0x22cd, 5.84), (0x22ce, 0x22cf, 6.35),
+    (0x22d0, 0x22d1, 6.48), (0x22d2, 0x22d3, 5.69), (0x22d4, 0x22d4, 5.49), (0x22d5, 0x22d5, 6.14),
+    (0x22d6, 0x22d7, 6.03), (0x22d8, 0x22d9, 9.68), (0x22da, 0x22db, 6.51), (0x22dc, 0x22dd, 6.46),
+    (0x22de, 0x22df, 6.69), (0x22e0, 0x22e0, 6.66), (0x22e1, 0x22e1, 6.56), (0x22e2, 0x22e2, 6.86),
+    (0x22e3, 0x22e3, 6.64), (0x22e4, 0x22e4, 6.71), (0x22e5, 0x22e5, 6.73), (0x22e6, 0x22e6, 6.76),
+    (0x22e7, 0x22e7, 6.86), (0x22e8, 0x22e8, 7.19), (0x22e9, 0x22e9, 6.88), (0x22ea, 0x22ea, 7.49),
+    (0x22eb, 0x22eb, 7.66), (0x22ec, 0x22ec, 7.71), (0x22ed, 0x22ed, 7.76), (0x22ee, 0x22ee, 4.11),
+    (0x22ef, 0x22ef, 10.0), (0x22f0, 0x22f0, 9.2), (0x22f1, 0x22f1, 9.39), (0x22f2, 0x22f2, 6.5),
+    (0x22f3, 0x22f3, 5.76), (0x22f4, 0x22f4, 5.09), (0x22f5, 0x22f6, 5.76), (0x22f7, 0x22f7, 5.09),
+    (0x22f8, 0x22f8, 5.76), (0x22f9, 0x22f9, 6.32), (0x22fa, 0x22fa, 6.5), (0x22fb, 0x22fb, 5.76),
+    (0x22fc, 0x22fc, 5.09), (0x22fd, 0x22fd, 5.76), (0x22fe, 0x22fe, 5.09), (0x2300, 0x2301, 6.02),
+    (0x2302, 0x2302, 6.0), (0x2303, 0x2303, 9.28), (0x2304, 0x2304, 6.02), (0x2305, 0x2305, 7.95),
+    (0x2306, 0x2306, 6.85), (0x2307, 0x2307, 10.0), (0x2308, 0x230f, 6.02), (0x2310, 0x2310, 5.7),
+    (0x2311, 0x2311, 6.02), (0x2312, 0x2312, 10.0), (0x2313, 0x2313, 6.02), (0x2314, 0x2314, 8.65),
+    (0x2315, 0x2315, 6.02), (0x2316, 0x2316, 5.49), (0x2317, 0x2317, 6.5), (0x2318, 0x2318, 9.28),
+    (0x2319, 0x2319, 6.02), (0x231a, 0x231b, 10.0), (0x231c, 0x231f, 6.02), (0x2320, 0x2321, 6.04),
+    (0x2322, 0x2323, 8.65), (0x2324, 0x2324, 7.95), (0x2325, 0x2325, 10.0), (0x2326, 0x2326, 13.07),
+    (0x2327, 0x2327, 10.0), (0x2328, 0x2328, 6.02), (0x2329, 0x232a, 10.0), (0x232b, 0x232b, 13.07),
+    (0x232c, 0x2334, 5.49), (0x2335, 0x2335, 6.02), (0x2336, 0x2336, 9.26), (0x2337, 0x233c, 6.02),
+    (0x233d, 0x233d, 10.0), (0x233e, 0x233e, 6.02), (0x233f, 0x233f, 7.28), (0x2340, 0x2340, 6.0),
+    (0x2341, 0x2344, 6.02), (0x2345, 0x2346, 6.0), (0x2347, 0x2349, 6.02), (0x234a, 0x234a, 6.0),
+    (0x234b, 0x234d, 6.02), (0x234e, 0x234f, 6.0), (0x2350, 0x2350, 6.02), (0x2351, 0x2351, 6.0),
+    (0x2352, 0x2354, 6.02), (0x2355, 0x2356, 6.0), (0x2357, 0x235c, 6.02), (0x235d, 0x235d, 6.0),
+    (0x235e, 0x2360, 6.02), (0x2361, 0x2362, 6.0), (0x2363, 0x2365, 6.02), (0x2366, 0x2367, 6.0),
+    (0x2368, 0x2369, 6.02), (0x236a, 0x236a, 6.0), (0x236b, 0x2370, 6.02), (0x2371, 0x2372, 6.0),
+    (0x2373, 0x237a, 6.02), (0x237b, 0x237b, 6.69), (0x237c, 0x237c, 9.37), (0x237d, 0x237d, 3.19),
+    (0x237e, 0x237e, 9.39), (0x237f, 0x237f, 3.71), (0x2380, 0x2383, 6.02), (0x2384, 0x2384, 7.85),
+    (0x2385, 0x2385, 6.84), (0x2386, 0x2386, 6.75), (0x2387, 0x2388, 10.0), (0x2389, 0x238a, 6.02),
+    (0x238b, 0x238b, 8.92), (0x238c, 0x238c, 8.08), (0x238d, 0x238d, 7.86), (0x238e, 0x238e, 7.47),
+    (0x238f, 0x2393, 6.58), (0x2394, 0x2394, 7.43), (0x2395, 0x2395, 6.02), (0x2396, 0x2396, 4.72),
+    (0x2397, 0x2398, 7.89), (0x2399, 0x239a, 8.15), (0x239b, 0x23ad, 10.0), (0x23ae, 0x23ae, 6.02),
+    (0x23af, 0x23af, 3.15), (0x23b0, 0x23b1, 10.0), (0x23b2, 0x23b3, 14.88), (0x23b4, 0x23b5, 6.16),
+    (0x23b6, 0x23b6, 7.24), (0x23b7, 0x23b7, 6.43), (0x23b8, 0x23bd, 5.03), (0x23be, 0x23cc, 10.0),
+    (0x23cd, 0x23cd, 9.98), (0x23ce, 0x23ce, 10.0), (0x23cf, 0x23cf, 8.57), (0x23d0, 0x23d0, 5.11),
+    (0x23d1, 0x23d3, 5.42), (0x23d4, 0x23d5, 10.31), (0x23d6, 0x23d6, 9.58), (0x23d7, 0x23d9, 9.76),
+    (0x23da, 0x23db, 10.0), (0x23dc, 0x23df, 6.17), (0x23e0, 0x23e0, 6.16), (0x23e1, 0x23e1, 10.0),
+    (0x23e2, 0x23e2, 11.44), (0x23e3, 0x23e3, 9.1), (0x23e4, 0x23e4, 10.94),
+    (0x23e5, 0x23e5, 10.18), (0x23e6, 0x23e6, 9.26), (0x23e7, 0x23e7, 7.98), (0x23e8, 0x23fe, 10.0),
+    (0x2400, 0x241f, 10.0), (0x2420, 0x2420, 6.0), (0x2421, 0x2421, 10.0), (0x2422, 0x2422, 6.0),
+    (0x2423, 0x2423, 5.9), (0x2424, 0x2424, 6.0), (0x2425, 0x2425, 7.92), (0x2426, 0x2426, 4.03),
+    (0x2427, 0x243e, 10.0), (0x2440, 0x2445, 6.04), (0x2446, 0x2449, 7.5), (0x244a, 0x244a, 6.04),
+    (0x244b, 0x245e, 10.0), (0x2460, 0x24c4, 10.0), (0x24c5, 0x24c5, 8.4), (0x24c6, 0x24fe, 10.0),
+    (0x2500, 0x2500, 7.08), (0x2501, 0x2501, 10.0), (0x2502, 0x2502, 7.08), (0x2503, 0x250b, 10.0),
+    (0x250c, 0x250c, 7.08), (0x250d, 0x250f, 10.0), (0x2510, 0x2510, 7.08), (0x2511, 0x2513, 10.0),
+    (0x2514, 0x2514, 7.08), (0x2515, 0x2517, 10.0), (0x2518, 0x2518, 7.08), (0x2519, 0x251b, 10.0),
+    (0x251c, 0x251c, 7.08), (0x251d, 0x2523, 10.0), (0x2524, 0x2524, 7.08), (0x2525, 0x252b, 10.0),
+    (0x252c, 0x252c, 7.08), (0x252d, 0x2533, 10.0), (0x2534, 0x2534, 7.08), (0x2535, 0x253b, 10.0),
+    (0x253c, 0x253c, 7.08), (0x253d, 0x254b, 10.0), (0x254c, 0x254f, 6.18), (0x2550, 0x256c, 7.08),
+    (0x256d, 0x2574, 10.0), (0x2575, 0x257e, 6.18), (0x2580, 0x2580, 7.08), (0x2581, 0x2583, 10.0),
+    (0x2584, 0x2584, 7.08), (0x2585, 0x2587, 10.0), (0x2588, 0x2588, 7.08), (0x2589, 0x258b, 10.0),
+    (0x258c, 0x258c, 7.08), (0x258d, 0x258f, 10.0), (0x2590, 0x2592, 7.08), (0x2593, 0x2593, 7.29),
+    (0x2594, 0x2595, 10.0), (0x2596, 0x259e, 6.02), (0x25a0, 0x25a1, 6.04), (0x25a2, 0x25a2, 10.0),
+    (0x25a3, 0x25a9, 8.65), (0x25aa, 0x25ab, 3.54), (0x25ac, 0x25ac, 10.0), (0x25ad, 0x25ad, 8.65),
+    (0x25ae, 0x25b0, 6.02), (0x25b1, 0x25b1, 10.0), (0x25b2, 0x25b2, 9.9), (0x25b3, 0x25b3, 10.0),
+    (0x25b4, 0x25b4, 6.32), (0x25b5, 0x25b5, 8.65), (0x25b6, 0x25b6, 8.8), (0x25b7, 0x25b7, 10.0),
+    (0x25b8, 0x25b9, 5.3), (0x25ba, 0x25ba, 9.9), (0x25bb, 0x25bb, 6.02), (0x25bc, 0x25bc, 9.9),
+    (0x25bd, 0x25bd, 10.0), (0x25be, 0x25bf, 6.39), (0x25c0, 0x25c0, 10.0), (0x25c1, 0x25c1, 7.95),
+    (0x25c2, 0x25c2, 6.02), (0x25c3, 0x25c3, 8.65), (0x25c4, 0x25c4, 9.9), (0x25c5, 0x25c5, 6.02),
+    (0x25c6, 0x25c6, 7.23), (0x25c7, 0x25c7, 6.15), (0x25c8, 0x25c8, 8.65), (0x25c9, 0x25c9, 10.0),
+    (0x25ca, 0x25ca, 8.67), (0x25cb, 0x25cb, 6.04), (0x25cc, 0x25cc, 5.94), (0x25cd, 0x25cd, 6.02),
+    (0x25ce, 0x25ce, 10.0), (0x25cf, 0x25cf, 6.04), (0x25d0, 0x25d3, 10.0), (0x25d4, 0x25d7, 6.02),
+    (0x25d8, 0x25d9, 6.04), (0x25da, 0x25e1, 6.02), (0x25e2, 0x25e5, 10.0), (0x25e6, 0x25e6, 3.54),
+    (0x25e7, 0x25ee, 6.02), (0x25ef, 0x25ef, 10.0), (0x25f0, 0x25fa, 6.02), (0x25fb, 0x25fe, 10.0),
+    (0x2600, 0x2603, 10.0), (0x2604, 0x2604, 6.02), (0x2605, 0x2606, 10.0), (0x2607, 0x2608, 6.02),
+    (0x2609, 0x2609, 10.0), (0x260a, 0x260d, 6.02), (0x260e, 0x260e, 10.0), (0x260f, 0x260f, 8.65),
+    (0x2610, 0x2612, 8.3), (0x2613, 0x2613, 6.02), (0x2614, 0x2617, 10.0), (0x2618, 0x261b, 6.02),
+    (0x261c, 0x261f, 10.0), (0x2620, 0x262e, 6.02), (0x262f, 0x262f, 8.65), (0x2630, 0x2637, 9.0),
+    (0x2638, 0x2639, 6.02), (0x263a, 0x263a, 10.21), (0x263b, 0x263b, 10.52),
+    (0x263c, 0x263c, 9.17), (0x263d, 0x263f, 6.02), (0x2640, 0x2640, 7.5), (0x2641, 0x2641, 6.02),
+    (0x2642, 0x2642, 7.5), (0x2643, 0x2647, 6.02), (0x2648, 0x2653, 10.0), (0x2654, 0x265f, 6.02),
+    (0x2660, 0x2660, 5.31), (0x2661, 0x2662, 10.0), (0x2663, 0x2663, 6.56), (0x2664, 0x2664, 10.0),
+    (0x2665, 0x2665, 5.94), (0x2666, 0x2666, 5.1), (0x2667, 0x2669, 10.0), (0x266a, 0x266a, 5.0),
+    (0x266b, 0x266b, 7.5), (0x266c, 0x266e, 10.0), (0x266f, 0x266f, 5.0), (0x2670, 0x2671, 6.02),
+    (0x2672, 0x267d, 10.0), (0x267e, 0x267e, 6.02), (0x267f, 0x267f, 10.0), (0x2680, 0x268b, 6.02),
+    (0x268c, 0x268c, 8.79), (0x268d, 0x268d, 8.63), (0x268e, 0x268e, 8.59), (0x268f, 0x268f, 8.96),
+    (0x2690, 0x2692, 6.02), (0x2693, 0x2693, 10.0), (0x2694, 0x269c, 6.02), (0x269d, 0x26a1, 10.0),
+    (0x26a2, 0x26a2, 8.92), (0x26a3, 0x26a3, 8.97), (0x26a4, 0x26a4, 8.83), (0x26a5, 0x26a5, 6.6),
+    (0x26a6, 0x26a6, 7.41), (0x26a7, 0x26a7, 9.23), (0x26a8, 0x26a8, 5.33), (0x26a9, 0x26a9, 9.2),
+    (0x26aa, 0x26ab, 10.0), (0x26ac, 0x26ac, 6.47), (0x26ad, 0x26ad, 7.76), (0x26ae, 0x26ae, 10.55),
+    (0x26af, 0x26af, 11.18), (0x26b0, 0x26b1, 6.02), (0x26b2, 0x26b2, 6.03), (0x26b3, 0x26e1, 10.0),
+    (0x26e2, 0x26e2, 7.0), (0x26e3, 0x26fe, 10.0), (0x2700, 0x2700, 10.0), (0x2701, 0x2701, 9.74),
+    (0x2702, 0x2702, 9.61), (0x2703, 0x2703, 9.74), (0x2704, 0x2704, 9.8), (0x2705, 0x2705, 10.0),
+    (0x2706, 0x2706, 7.89), (0x2707, 0x2707, 7.9), (0x2708, 0x2708, 7.91), (0x2709, 0x2709, 6.9),
+    (0x270a, 0x270d, 10.0), (0x270e, 0x270e, 7.93), (0x270f, 0x270f, 9.33), (0x2710, 0x2710, 9.11),
+    (0x2711, 0x2711, 9.45), (0x2712, 0x2712, 9.74), (0x2713, 0x2713, 7.64), (0x2714, 0x2714, 8.46),
+    (0x2715, 0x2715, 7.62), (0x2716, 0x2716, 7.61), (0x2717, 0x2717, 5.71), (0x2718, 0x2718, 6.77),
+    (0x2719, 0x2719, 7.63), (0x271a, 0x271a, 7.6), (0x271b, 0x271b, 7.59), (0x271c, 0x271c, 7.54),
+    (0x271d, 0x271d, 4.94), (0x271e, 0x271e, 5.52), (0x271f, 0x271f, 5.37), (0x2720, 0x2720, 5.77),
+    (0x2721, 0x2721, 6.92), (0x2722, 0x2722, 7.86), (0x2723, 0x2724, 7.88), (0x2725, 0x2725, 7.9),
+    (0x2726, 0x2726, 7.93), (0x2727, 0x2727, 7.94), (0x2728, 0x2728, 10.0), (0x2729, 0x2729, 8.23),
+    (0x272a, 0x272a, 7.89), (0x272b, 0x272b, 8.41), (0x272c, 0x272c, 8.23), (0x272d, 0x272d, 8.33),
+    (0x272e, 0x272e, 8.16), (0x272f, 0x272f, 8.31), (0x2730, 0x2730, 9.23), (0x2731, 0x2731, 7.44),
+    (0x2732, 0x2732, 7.23), (0x2733, 0x2733, 7.49), (0x2734, 0x2734, 7.9), (0x2735, 0x2735, 7.92),
+    (0x2736, 0x2736, 6.95), (0x2737, 0x2737, 7.76), (0x2738, 0x2738, 7.68), (0x2739, 0x2739, 7.92),
+    (0x273a, 0x273a, 7.59), (0x273b, 0x273b, 7.07), (0x273c, 0x273c, 7.08), (0x273d, 0x273d, 6.82),
+    (0x273e, 0x273e, 7.01), (0x273f, 0x273f, 8.26), (0x2740, 0x2740, 8.15), (0x2741, 0x2742, 7.89),
+    (0x2743, 0x2743, 7.07), (0x2744, 0x2744, 6.87), (0x2745, 0x2745, 6.96), (0x2746, 0x2746, 6.89),
+    (0x2747, 0x2747, 7.86), (0x2748, 0x2748, 7.87), (0x2749, 0x2749, 7.13), (0x274a, 0x274a, 7.91),
+    (0x274b, 0x274b, 7.85), (0x274c, 0x274c, 10.0), (0x274d, 0x274d, 8.73), (0x274e, 0x274e, 10.0),
+    (0x274f, 0x2750, 7.62), (0x2751, 0x2752, 7.59), (0x2753, 0x2755, 10.0), (0x2756, 0x2756, 7.84),
+    (0x2757, 0x2757, 10.0), (0x2758, 0x2758, 3.01), (0x2759, 0x2759, 2.77), (0x275a, 0x275a, 4.15),
+    (0x275b, 0x275c, 3.92), (0x275d, 0x275e, 6.68), (0x275f, 0x2760, 10.0), (0x2761, 0x2761, 7.32),
+    (0x2762, 0x2763, 5.44), (0x2764, 0x2764, 9.1), (0x2765, 0x2765, 6.67), (0x2766, 0x2767, 7.6),
+    (0x2768, 0x2769, 3.9), (0x276a, 0x276b, 3.17), (0x276c, 0x276d, 2.76), (0x276e, 0x276f, 5.09),
+    (0x2770, 0x2771, 4.1), (0x2772, 0x2773, 2.34), (0x2774, 0x2775, 3.34), (0x2776, 0x2793, 7.88),
+    (0x2794, 0x2794, 8.94), (0x2795, 0x2797, 10.0), (0x2798, 0x2798, 7.48), (0x2799, 0x2799, 9.24),
+    (0x279a, 0x279a, 7.48), (0x279b, 0x279b, 9.18), (0x279c, 0x279c, 9.27), (0x279d, 0x279e, 9.28),
+    (0x279f, 0x279f, 8.34), (0x27a0, 0x27a0, 8.73), (0x27a1, 0x27a1, 8.28), (0x27a2, 0x27a3, 9.24),
+    (0x27a4, 0x27a4, 9.17), (0x27a5, 0x27a5, 9.3), (0x27a6, 0x27a6, 9.31), (0x27a7, 0x27a7, 4.63),
+    (0x27a8, 0x27a8, 8.83), (0x27a9, 0x27aa, 8.36), (0x27ab, 0x27ac, 8.67), (0x27ad, 0x27ae, 6.96),
+    (0x27af, 0x27af, 8.74), (0x27b0, 0x27b0, 10.0), (0x27b1, 0x27b1, 8.74), (0x27b2, 0x27b2, 7.6),
+    (0x27b3, 0x27b3, 9.46), (0x27b4, 0x27b4, 7.71), (0x27b5, 0x27b5, 8.65), (0x27b6, 0x27b6, 7.71),
+    (0x27b7, 0x27b7, 8.88), (0x27b8, 0x27b8, 9.67), (0x27b9, 0x27b9, 8.88), (0x27ba, 0x27ba, 8.31),
+    (0x27bb, 0x27bb, 8.73), (0x27bc, 0x27bc, 9.27), (0x27bd, 0x27bd, 9.7), (0x27be, 0x27be, 9.18),
+    (0x27c0, 0x27c0, 5.48), (0x27c1, 0x27c1, 9.48), (0x27c2, 0x27c2, 6.89), (0x27c3, 0x27c4, 5.64),
+    (0x27c5, 0x27c6, 6.02), (0x27c7, 0x27c7, 6.2), (0x27c8, 0x27c9, 9.18), (0x27ca, 0x27ca, 3.4),
+    (0x27cb, 0x27cb, 8.38), (0x27cc, 0x27cc, 3.25), (0x27cd, 0x27cd, 8.38), (0x27ce, 0x27cf, 10.0),
+    (0x27d0, 0x27d0, 7.46), (0x27d1, 0x27d1, 6.2), (0x27d2, 0x27d2, 5.97), (0x27d3, 0x27d4, 5.42),
+    (0x27d5, 0x27d6, 9.02), (0x27d7, 0x27d7, 11.15), (0x27d8, 0x27d9, 6.89), (0x27da, 0x27da, 8.7),
+    (0x27db, 0x27db, 8.56), (0x27dc, 0x27dc, 10.55), (0x27dd, 0x27de, 8.49), (0x27df, 0x27df, 3.96),
+    (0x27e0, 0x27e0, 5.51), (0x27e1, 0x27e1, 7.0), (0x27e2, 0x27e3, 8.76), (0x27e4, 0x27e5, 9.46),
+    (0x27e6, 0x27e7, 4.22), (0x27e8, 0x27e9, 3.66), (0x27ea, 0x27eb, 4.63), (0x27ec, 0x27ed, 4.88),
+    (0x27ee, 0x27ee, 2.33), (0x27f0, 0x27f1, 9.82), (0x27f2, 0x27f3, 9.53), (0x27f4, 0x27f4, 12.46),
+    (0x27f5, 0x27fe, 11.79), (0x2800, 0x28fe, 6.84), (0x2900, 0x2901, 9.36), (0x2902, 0x2903, 8.66),
+    (0x2904, 0x2904, 10.35), (0x2905, 0x2905, 9.36), (0x2906, 0x2907, 8.66), (0x2908, 0x2909, 4.78),
+    (0x290a, 0x290b, 7.17), (0x290c, 0x290f, 8.82), (0x2910, 0x2910, 10.43), (0x2911, 0x2911, 8.38),
+    (0x2912, 0x2913, 7.59), (0x2914, 0x2915, 8.65), (0x2916, 0x2918, 10.43), (0x2919, 0x291c, 8.65),
+    (0x291d, 0x2920, 8.82), (0x2921, 0x2922, 7.46), (0x2923, 0x2926, 6.5), (0x2927, 0x2927, 7.79),
+    (0x2928, 0x2928, 7.39), (0x2929, 0x2929, 7.79), (0x292a, 0x292a, 7.39), (0x292b, 0x292c, 6.98),
+    (0x292d, 0x2930, 7.39), (0x2931, 0x2932, 7.79), (0x2933, 0x2933, 8.47), (0x2934, 0x2935, 10.0),
+    (0x2936, 0x2937, 8.65), (0x2938, 0x2938, 4.08), (0x2939, 0x2939, 8.65), (0x293a, 0x293d, 8.08),
+    (0x293e, 0x293f, 6.55), (0x2940, 0x2941, 7.63), (0x2942, 0x2947, 8.38), (0x2948, 0x2948, 9.21),
+    (0x2949, 0x2949, 4.87), (0x294a, 0x294b, 8.38), (0x294c, 0x294d, 4.94), (0x294e, 0x294e, 8.38),
+    (0x294f, 0x294f, 4.33), (0x2950, 0x2950, 8.38), (0x2951, 0x2951, 4.33), (0x2952, 0x2953, 8.62),
+    (0x2954, 0x2955, 4.33), (0x2956, 0x2957, 8.62), (0x2958, 0x2959, 4.33), (0x295a, 0x295b, 8.72),
+    (0x295c, 0x295d, 4.33), (0x295e, 0x295f, 8.72), (0x2960, 0x2961, 4.33), (0x2962, 0x2965, 8.65),
+    (0x2966, 0x296d, 8.38), (0x296e, 0x296f, 7.14), (0x2970, 0x2975, 8.38), (0x2976, 0x2976, 7.23),
+    (0x2977, 0x2977, 10.43), (0x2978, 0x2978, 7.23), (0x2979, 0x2979, 7.65), (0x297a, 0x297a, 9.21),
+    (0x297b, 0x297b, 7.65), (0x297c, 0x297d, 5.56), (0x297e, 0x297e, 5.98), (0x2980, 0x2980, 3.65),
+    (0x2981, 0x2981, 3.8), (0x2982, 0x2982, 3.13), (0x2983, 0x2984, 3.74), (0x2985, 0x2986, 4.47),
+    (0x2987, 0x2988, 3.35), (0x2989, 0x298a, 3.95), (0x298b, 0x298c, 3.01), (0x298d, 0x2990, 3.0),
+    (0x2991, 0x2991, 3.66), (0x2992, 0x2992, 3.68), (0x2993, 0x2994, 6.59), (0x2995, 0x2996, 6.95),
+    (0x2997, 0x2998, 6.48), (0x2999, 0x2999, 2.26), (0x299a, 0x299a, 2.77), (0x299b, 0x299b, 5.94),
+    (0x299c, 0x299d, 5.92), (0x299e, 0x299e, 6.61), (0x299f, 0x299f, 5.48), (0x29a0, 0x29a1, 5.94),
+    (0x29a2, 0x29a5, 5.48), (0x29a6, 0x29a7, 7.53), (0x29a8, 0x29af, 5.94), (0x29b0, 0x29b4, 7.03),
+    (0x29b5, 0x29b5, 8.69), (0x29b6, 0x29b6, 7.0), (0x29b7, 0x29b7, 7.02), (0x29b8, 0x29b8, 7.22),
+    (0x29b9, 0x29b9, 7.02), (0x29ba, 0x29ba, 7.03), (0x29bb, 0x29bb, 7.04), (0x29bc, 0x29c1, 7.02),
+    (0x29c2, 0x29c2, 8.55), (0x29c3, 0x29c3, 8.74), (0x29c4, 0x29c8, 7.47), (0x29c9, 0x29c9, 7.96),
+    (0x29ca, 0x29cc, 7.49), (0x29cd, 0x29cd, 9.44), (0x29ce, 0x29ce, 6.5), (0x29cf, 0x29d0, 7.58),
+    (0x29d1, 0x29d3, 6.87), (0x29d4, 0x29d5, 6.51), (0x29d6, 0x29d7, 7.67), (0x29d8, 0x29d9, 2.77),
+    (0x29da, 0x29db, 4.23), (0x29dc, 0x29dc, 6.37), (0x29dd, 0x29dd, 6.47), (0x29de, 0x29de, 6.37),
+    (0x29df, 0x29df, 6.74), (0x29e0, 0x29e0, 7.47), (0x29e1, 0x29e1, 6.53), (0x29e2, 0x29e2, 6.83),
+    (0x29e3, 0x29e5, 6.64), (0x29e6, 0x29e6, 7.1), (0x29e7, 0x29e7, 6.35), (0x29e8, 0x29e9, 7.49),
+    (0x29ea, 0x29ea, 7.17), (0x29eb, 0x29eb, 4.73), (0x29ec, 0x29ed, 8.99), (0x29ee, 0x29ef, 5.98),
+    (0x29f0, 0x29f1, 7.08), (0x29f2, 0x29f3, 7.02), (0x29f4, 0x29f4, 8.18), (0x29f5, 0x29f5, 6.1),
+    (0x29f6, 0x29f6, 5.76), (0x29f7, 0x29f9, 5.08), (0x29fa, 0x29fb, 6.76), (0x29fc, 0x29fd, 5.37),
+    (0x29fe, 0x29fe, 4.73), (0x2a00, 0x2a01, 8.99), (0x2a02, 0x2a02, 9.01), (0x2a03, 0x2a04, 5.44),
+    (0x2a05, 0x2a06, 5.95), (0x2a07, 0x2a08, 7.66), (0x2a09, 0x2a09, 6.66), (0x2a0a, 0x2a0a, 8.55),
+    (0x2a0b, 0x2a0b, 6.52), (0x2a0c, 0x2a0c, 15.05), (0x2a0d, 0x2a0f, 6.11), (0x2a10, 0x2a10, 5.93),
+    (0x2a11, 0x2a11, 7.28), (0x2a12, 0x2a12, 6.13), (0x2a13, 0x2a13, 5.93), (0x2a14, 0x2a14, 6.75),
+    (0x2a15, 0x2a15, 5.93), (0x2a16, 0x2a16, 6.11), (0x2a17, 0x2a17, 6.76), (0x2a18, 0x2a1a, 6.11),
+    (0x2a1b, 0x2a1b, 6.38), (0x2a1c, 0x2a1c, 6.17), (0x2a1d, 0x2a1d, 6.87), (0x2a1e, 0x2a1e, 3.95),
+    (0x2a1f, 0x2a1f, 3.34), (0x2a20, 0x2a20, 8.11), (0x2a21, 0x2a21, 3.26), (0x2a22, 0x2a23, 5.41),
+    (0x2a24, 0x2a24, 5.44), (0x2a25, 0x2a25, 5.41), (0x2a26, 0x2a26, 5.44), (0x2a27, 0x2a27, 6.47),
+    (0x2a28, 0x2a28, 6.38), (0x2a29, 0x2a2a, 5.45), (0x2a2b, 0x2a2c, 5.43), (0x2a2d, 0x2a2e, 7.61),
+    (0x2a2f, 0x2a2f, 4.58), (0x2a30, 0x2a30, 5.29), (0x2a31, 0x2a31, 5.42), (0x2a32, 0x2a32, 5.21),
+    (0x2a33, 0x2a33, 8.05), (0x2a34, 0x2a35, 7.14), (0x2a36, 0x2a38, 8.99), (0x2a39, 0x2a3b, 7.49),
+    (0x2a3c, 0x2a3d, 5.12), (0x2a3e, 0x2a3e, 3.34), (0x2a3f, 0x2a3f, 6.15), (0x2a40, 0x2a45, 5.44),
+    (0x2a46, 0x2a47, 3.88), (0x2a48, 0x2a49, 5.42), (0x2a4a, 0x2a4b, 5.11), (0x2a4c, 0x2a4d, 7.39),
+    (0x2a4e, 0x2a4f, 5.95), (0x2a50, 0x2a50, 7.39), (0x2a51, 0x2a52, 6.1), (0x2a53, 0x2a54, 6.52),
+    (0x2a55, 0x2a56, 7.53), (0x2a57, 0x2a58, 6.52), (0x2a59, 0x2a59, 6.06), (0x2a5a, 0x2a5b, 6.52),
+    (0x2a5c, 0x2a5d, 7.38), (0x2a5e, 0x2a60, 6.1), (0x2a61, 0x2a61, 5.43), (0x2a62, 0x2a63, 6.1),
+    (0x2a64, 0x2a65, 6.67), (0x2a66, 0x2a69, 5.42), (0x2a6a, 0x2a6d, 5.43), (0x2a6e, 0x2a6e, 4.85),
+    (0x2a6f, 0x2a72, 5.43), (0x2a73, 0x2a73, 5.44), (0x2a74, 0x2a74, 8.48), (0x2a75, 0x2a75, 9.84),
+    (0x2a76, 0x2a76, 14.57), (0x2a77, 0x2a77, 5.45), (0x2a78, 0x2a78, 5.95), (0x2a79, 0x2a7a, 6.59),
+    (0x2a7b, 0x2a7c, 5.12), (0x2a7d, 0x2a7f, 5.43), (0x2a80, 0x2a80, 5.44), (0x2a81, 0x2a86, 5.43),
+    (0x2a87, 0x2a87, 5.51), (0x2a88, 0x2a88, 5.7), (0x2a89, 0x2a8c, 5.43), (0x2a8d, 0x2a8d, 5.51),
+    (0x2a8e, 0x2a8e, 5.48), (0x2a8f, 0x2a90, 5.45), (0x2a91, 0x2a97, 5.43), (0x2a98, 0x2a9a, 5.44),
+    (0x2a9b, 0x2a9e, 5.43), (0x2a9f, 0x2a9f, 5.52), (0x2aa0, 0x2aa0, 5.46), (0x2aa1, 0x2aa2, 6.83),
+    (0x2aa3, 0x2aa3, 8.07), (0x2aa4, 0x2aa4, 5.44), (0x2aa5, 0x2aa5, 9.35), (0x2aa6, 0x2aa9, 6.13),
+    (0x2aaa, 0x2aaa, 5.12), (0x2aab, 0x2aab, 5.24), (0x2aac, 0x2aac, 5.51), (0x2aad, 0x2aad, 5.56),
+    (0x2aae, 0x2aae, 5.42), (0x2aaf, 0x2ab1, 5.53), (0x2ab2, 0x2ab2, 5.51), (0x2ab3, 0x2ab6, 5.53),
+    (0x2ab7, 0x2ab7, 5.47), (0x2ab8, 0x2ab8, 5.48), (0x2ab9, 0x2ab9, 5.47), (0x2aba, 0x2aba, 5.48),
+    (0x2abb, 0x2abc, 8.88), (0x2abd, 0x2abe, 5.64), (0x2abf, 0x2ac6, 4.59), (0x2ac7, 0x2aca, 5.46),
+    (0x2acb, 0x2acc, 4.59), (0x2acd, 0x2ace, 9.53), (0x2acf, 0x2ad0, 5.64), (0x2ad1, 0x2ad2, 5.63),
+    (0x2ad3, 0x2ad6, 4.59), (0x2ad7, 0x2ad8, 8.5), (0x2ad9, 0x2adb, 5.97), (0x2adc, 0x2adc, 6.1),
+    (0x2add, 0x2add, 5.97), (0x2ade, 0x2ade, 3.93), (0x2adf, 0x2ae1, 6.89), (0x2ae2, 0x2ae2, 6.86),
+    (0x2ae3, 0x2ae3, 6.84), (0x2ae4, 0x2ae4, 6.86), (0x2ae5, 0x2ae5, 8.37), (0x2ae6, 0x2ae6, 6.86),
+    (0x2ae7, 0x2ae9, 6.89), (0x2aea, 0x2aeb, 6.93), (0x2aec, 0x2aed, 5.12), (0x2aee, 0x2aee, 3.13),
+    (0x2aef, 0x2af0, 2.84), (0x2af1, 0x2af1, 2.99), (0x2af2, 0x2af2, 3.9), (0x2af3, 0x2af3, 5.43),
+    (0x2af4, 0x2af4, 3.49), (0x2af5, 0x2af5, 4.78), (0x2af6, 0x2af6, 2.26), (0x2af7, 0x2af8, 9.71),
+    (0x2af9, 0x2afa, 5.43), (0x2afb, 0x2afb, 4.99), (0x2afc, 0x2afc, 3.55), (0x2afd, 0x2afd, 4.11),
+    (0x2afe, 0x2afe, 2.94), (0x2e80, 0x2efe, 10.0), (0x2ff0, 0x2ffe, 10.0), (0x3000, 0x302d, 10.0),
+    (0x302e, 0x302f, 16.0), (0x3030, 0x303e, 10.0), (0x3200, 0x321b, 8.65), (0x321c, 0x321c, 8.69),
+    (0x321d, 0x325f, 10.0), (0x3260, 0x327b, 8.65), (0x327c, 0x327e, 10.0), (0x327f, 0x327f, 8.65),
+    (0x3280, 0x32fe, 10.0), (0x3300, 0x337f, 10.0), (0x3380, 0x3384, 8.65), (0x3385, 0x3389, 10.0),
+    (0x338a, 0x338c, 8.65), (0x338d, 0x3390, 10.0), (0x3391, 0x3395, 8.65), (0x3396, 0x3398, 10.0),
+    (0x3399, 0x339a, 8.65), (0x339b, 0x33a6, 10.0), (0x33a7, 0x33af, 8.65), (0x33b0, 0x33b3, 10.0),
+    (0x33b4, 0x33c1, 8.65), (0x33c2, 0x33c2, 10.0), (0x33c3, 0x33c3, 8.65), (0x33c4, 0x33c4, 10.0),
+    (0x33c5, 0x33c7, 8.65), (0x33c8, 0x33c8, 10.0), (0x33c9, 0x33ca, 8.65), (0x33cb, 0x33ce, 10.0),
+    (0x33cf, 0x33d0, 8.65), (0x33d1, 0x33d2, 10.0), (0x33d3, 0x33d3, 8.65), (0x33d4, 0x33d5, 10.0),
+    (0x33d6, 0x33d6, 8.65), (0x33d7, 0x33da, 10.0), (0x33db, 0x33dd, 8.65), (0x33de, 0x33fe, 10.0),
+    (0x3400, 0x4dbe, 10.0), (0x4e00, 0x9ffe, 10.0), (0xf900, 0xfafe, 10.0), (0xfe30, 0xfe4e, 10.0),
+    (0xfe70, 0xfe70, 3.12), (0xfe71, 0xfe71, 2.03), (0xfe72, 0xfe72, 3.12), (0xfe73, 0xfe73, 3.0),
+    (0xfe74, 0xfe74, 3.12), (0xfe75, 0xfe75, 10.0), (0xfe76, 0xfe76, 3.12), (0xfe77, 0xfe77, 2.03),
+    (0xfe78, 0xfe78, 3.12), (0xfe79, 0xfe79, 2.03), (0xfe7a, 0xfe7a, 3.12), (0xfe7b, 0xfe7b, 2.03),
+    (0xfe7c, 0xfe7c, 3.12), (0xfe7d, 0xfe7d, 2.03), (0xfe7e, 0xfe7e, 3.12), (0xfe7f, 0xfe7f, 2.03),
+    (0xfe80, 0xfe80, 4.69), (0xfe81, 0xfe81, 2.38), (0xfe82, 0xfe82, 2.53), (0xfe83, 0xfe83, 2.17),
+    (0xfe84, 0xfe84, 2.34), (0xfe85, 0xfe86, 4.51), (0xfe87, 0xfe87, 2.17), (0xfe88, 0xfe88, 2.34),
+    (0xfe89, 0xfe89, 6.45), (0xfe8a, 0xfe8a, 5.77), (0xfe8b, 0xfe8c, 2.71), (0xfe8d, 0xfe8d, 2.17),
+    (0xfe8e, 0xfe8e, 2.34), (0xfe8f, 0xfe90, 6.49), (0xfe91, 0xfe92, 2.71), (0xfe93, 0xfe93, 3.43),
+    (0xfe94, 0xfe94, 4.15), (0xfe95, 0xfe96, 6.49), (0xfe97, 0xfe98, 2.71), (0xfe99, 0xfe9a, 6.49),
+    (0xfe9b, 0xfe9c, 2.71), (0xfe9d, 0xfe9d, 5.77), (0xfe9e, 0xfe9e, 5.59), (0xfe9f, 0xfea0, 5.23),
+    (0xfea1, 0xfea1, 5.77), (0xfea2, 0xfea2, 5.59), (0xfea3, 0xfea4, 5.23), (0xfea5, 0xfea5, 5.77),
+    (0xfea6, 0xfea6, 5.59), (0xfea7, 0xfea8, 5.23), (0xfea9, 0xfeac, 3.43), (0xfead, 0xfeb0, 4.33),
+    (0xfeb1, 0xfeb2, 8.99), (0xfeb3, 0xfeb4, 6.08), (0xfeb5, 0xfeb6, 8.99), (0xfeb7, 0xfeb8, 6.08),
+    (0xfeb9, 0xfeba, 10.63), (0xfebb, 0xfebc, 7.71), (0xfebd, 0xfebe, 10.63),
+    (0xfebf, 0xfec0, 7.71), (0xfec1, 0xfec8, 5.23), (0xfec9, 0xfec9, 5.41), (0xfeca, 0xfeca, 4.69),
+    (0xfecb, 0xfecb, 5.41), (0xfecc, 0xfecc, 3.6), (0xfecd, 0xfecd, 5.41), (0xfece, 0xfece, 4.69),
+    (0xfecf, 0xfecf, 5.41), (0xfed0, 0xfed0, 3.6), (0xfed1, 0xfed2, 7.58), (0xfed3, 0xfed3, 3.05),
+    (0xfed4, 0xfed4, 3.24), (0xfed5, 0xfed6, 6.12), (0xfed7, 0xfed7, 3.05), (0xfed8, 0xfed8, 3.24),
+    (0xfed9, 0xfeda, 5.41), (0xfedb, 0xfedc, 3.6), (0xfedd, 0xfede, 4.51), (0xfedf, 0xfee0, 2.34),
+    (0xfee1, 0xfee2, 3.6), (0xfee3, 0xfee4, 4.15), (0xfee5, 0xfee6, 5.41), (0xfee7, 0xfee8, 2.71),
+    (0xfee9, 0xfee9, 3.43), (0xfeea, 0xfeea, 4.15), (0xfeeb, 0xfeeb, 4.69), (0xfeec, 0xfeee, 4.51),
+    (0xfeef, 0xfeef, 6.45), (0xfef0, 0xfef0, 5.54), (0xfef1, 0xfef1, 6.45), (0xfef2, 0xfef2, 5.77),
+    (0xfef3, 0xfef4, 2.71), (0xfef5, 0xfef5, 5.41), (0xfef6, 0xfef6, 5.96), (0xfef7, 0xfef7, 4.86),
+    (0xfef8, 0xfef8, 5.41), (0xfef9, 0xfef9, 4.86), (0xfefa, 0xfefa, 5.41), (0xfefb, 0xfefb, 4.86),
+    (0xfefc, 0xfefc, 5.41), (0xfefd, 0xfefe, 10.0), (0xff00, 0xff60, 10.0), (0xff61, 0xffa0, 5.0),
+    (0xffa1, 0xffa1, 4.83), (0xffa2, 0xffa3, 5.0), (0xffa4, 0xffa4, 4.83), (0xffa5, 0xffa6, 5.0),
+    (0xffa7, 0xffa7, 4.83), (0xffa8, 0xffbe, 5.0), (0xffbf, 0xffc1, 10.0), (0xffc2, 0xffc7, 5.0),
+    (0xffc8, 0xffc9, 10.0), (0xffca, 0xffcf, 5.0), (0xffd0, 0xffd1, 10.0), (0xffd2, 0xffd7, 5.0),
+    (0xffd8, 0xffd9, 10.0), (0xffda, 0xffdc, 5.0), (0xffdd, 0xffe4, 10.0), (0xffe5, 0xffe5, 7.11),
+    (0xffe6, 0xffe6, 11.28), (0xffe7, 0xffe8, 10.0), (0xffe9, 0xffee, 5.0),
+    (0x10100, 0x10100, 7.76), (0x10101, 0x10101, 6.68), (0x10102, 0x10102, 7.76),
+    (0x10103, 0x10103, 6.68), (0x10104, 0x10104, 7.76), (0x10105, 0x10105, 6.68),
+    (0x10106, 0x10106, 7.24), (0x10107, 0x10107, 5.88), (0x10108, 0x10108, 7.24),
+    (0x10109, 0x10109, 5.88), (0x1010a, 0x1010a, 7.24), (0x1010b, 0x1010b, 5.88),
+    (0x1010c, 0x1010c, 7.24), (0x1010d, 0x1010d, 5.88), (0x1010e, 0x1010e, 8.3),
+    (0x1010f, 0x1010f, 8.8), (0x10110, 0x10110, 8.3), (0x10111, 0x10111, 6.99),
+    (0x10112, 0x10112, 6.83), (0x10113, 0x10113, 6.64), (0x10114, 0x10114, 6.83),
+    (0x10115, 0x10115, 6.64), (0x10116, 0x10116, 6.83), (0x10117, 0x10117, 6.64),
+    (0x10118, 0x10118, 6.83), (0x10119, 0x10119, 6.64), (0x1011a, 0x1011a, 6.83),
+    (0x1011b, 0x1011b, 6.64), (0x1011c, 0x1011c, 8.11), (0x1011d, 0x1011d, 6.99),
+    (0x1011e, 0x1011e, 8.11), (0x1011f, 0x1011f, 6.99), (0x10120, 0x10120, 8.11),
+    (0x10121, 0x10121, 6.99), (0x10122, 0x10122, 8.11), (0x10123, 0x10123, 6.99),
+    (0x10124, 0x10124, 8.37), (0x10125, 0x10125, 7.12), (0x10126, 0x10126, 8.37),
+    (0x10127, 0x10127, 7.12), (0x10128, 0x10128, 5.46), (0x10129, 0x10129, 3.42),
+    (0x1012a, 0x1012a, 5.46), (0x1012b, 0x1012b, 3.42), (0x1012c, 0x1012c, 5.46),
+    (0x1012d, 0x1012d, 3.42), (0x1012e, 0x1012e, 5.46), (0x1012f, 0x1012f, 3.42),
+    (0x10130, 0x10130, 5.46), (0x10131, 0x10131, 3.42), (0x10132, 0x10132, 10.07),
+    (0x10133, 0x10133, 7.28), (0x10134, 0x10134, 5.55), (0x10135, 0x10135, 4.03),
+    (0x10136, 0x10136, 7.71), (0x10137, 0x10138, 6.71), (0x10139, 0x10139, 6.37),
+    (0x1013a, 0x1013a, 3.42), (0x1013b, 0x1013b, 6.37), (0x1013c, 0x1013c, 3.42),
+    (0x1013d, 0x1013d, 6.37), (0x1013e, 0x1013e, 5.22), (0x10300, 0x1032e, 0.0),
+    (0x10330, 0x1034e, 0.0), (0x10400, 0x10401, 6.83), (0x10402, 0x10402, 9.11),
+    (0x10403, 0x10403, 6.37), (0x10404, 0x10404, 7.42), (0x10405, 0x10405, 7.1),
+    (0x10406, 0x10407, 5.46), (0x10408, 0x10408, 5.55), (0x10409, 0x10409, 12.22),
+    (0x1040a, 0x1040a, 12.14), (0x1040b, 0x1040b, 9.36), (0x1040c, 0x1040c, 7.71),
+    (0x1040d, 0x1040d, 8.46), (0x1040e, 0x1040e, 7.37), (0x1040f, 0x1040f, 8.37),
+    (0x10410, 0x10410, 7.76), (0x10411, 0x10411, 7.58), (0x10412, 0x10412, 7.62),
+    (0x10413, 0x10413, 6.37), (0x10414, 0x10414, 8.41), (0x10415, 0x10415, 6.83),
+    (0x10416, 0x10416, 11.15), (0x10417, 0x10417, 7.07), (0x10418, 0x10419, 8.46),
+    (0x1041a, 0x1041a, 7.71), (0x1041b, 0x1041b, 8.45), (0x1041c, 0x1041c, 9.48),
+    (0x1041d, 0x1041d, 8.37), (0x1041e, 0x1041e, 8.5), (0x1041f, 0x1041f, 8.37),
+    (0x10420, 0x10420, 7.33), (0x10421, 0x10421, 7.24), (0x10422, 0x10422, 6.82),
+    (0x10423, 0x10423, 7.37), (0x10424, 0x10424, 9.52), (0x10425, 0x10425, 7.64),
+    (0x10426, 0x10426, 8.5), (0x10427, 0x10427, 7.87), (0x10428, 0x10428, 11.64),
+    (0x10429, 0x10429, 11.77), (0x1042a, 0x1042a, 9.07), (0x1042b, 0x1042b, 10.63),
+    (0x1042c, 0x1042c, 7.58), (0x1042d, 0x1042d, 7.42), (0x1042e, 0x1042e, 11.95),
+    (0x1042f, 0x1042f, 7.95), (0x10430, 0x10430, 6.68), (0x10431, 0x10431, 6.97),
+    (0x10432, 0x10432, 6.77), (0x10433, 0x10433, 5.31), (0x10434, 0x10434, 6.91),
+    (0x10435, 0x10435, 6.64), (0x10436, 0x10436, 9.99), (0x10437, 0x10437, 5.88),
+    (0x10438, 0x10439, 7.2), (0x1043a, 0x1043a, 6.71), (0x1043b, 0x1043b, 7.09),
+    (0x1043c, 0x1043c, 8.3), (0x1043d, 0x1043d, 7.2), (0x1043e, 0x1043e, 6.87),
+    (0x1043f, 0x1043f, 7.2), (0x10440, 0x10440, 6.99), (0x10441, 0x10441, 5.98),
+    (0x10442, 0x10442, 5.35), (0x10443, 0x10443, 6.51), (0x10444, 0x10444, 9.66),
+    (0x10445, 0x10445, 6.69), (0x10446, 0x10446, 7.29), (0x10447, 0x10447, 6.84),
+    (0x10448, 0x10448, 10.02), (0x10449, 0x10449, 10.13), (0x1044a, 0x1044a, 7.43),
+    (0x1044b, 0x1044b, 9.37), (0x1044c, 0x1044c, 6.49), (0x1044d, 0x1044d, 6.06),
+    (0x1044e, 0x1044e, 9.94), (0x1d100, 0x1d1fe, 8.65), (0x1d400, 0x1d7a3, 8.65),
+    (0x1d7a4, 0x1d7fe, 10.0),
+];
+
+/// 11px bold Helvetica, the font of the social style
+// Measured widths that happen to be close to 2π are not meant as TAU
+#[allow(clippy::approx_constant)]
+#[rustfmt::skip]
+pub(super) static HELVETICA_11PX_BOLD: &[(u32, u32, f64)] = &[
+    (0x20, 0x21, 3.06), (0x22, 0x22, 5.09), (0x23, 0x24, 6.12), (0x25, 0x25, 11.0),
+    (0x26, 0x26, 7.53), (0x27, 0x27, 3.06), (0x28, 0x29, 3.26), (0x2a, 0x2a, 4.48),
+    (0x2b, 0x2b, 6.6), (0x2c, 0x2c, 3.06), (0x2d, 0x2d, 4.48), (0x2e, 0x2e, 3.06),
+    (0x2f, 0x2f, 4.08), (0x30, 0x39, 6.12), (0x3a, 0x3b, 3.06), (0x3c, 0x3e, 6.6),
+    (0x3f, 0x3f, 6.12), (0x40, 0x40, 8.8), (0x41, 0x41, 7.53), (0x42, 0x42, 7.74),
+    (0x43, 0x44, 8.15), (0x45, 0x45, 7.13), (0x46, 0x46, 6.52), (0x47, 0x47, 8.35),
+    (0x48, 0x48, 8.15), (0x49, 0x49, 3.24), (0x4a, 0x4a, 6.12), (0x4b, 0x4b, 7.94),
+    (0x4c, 0x4c, 6.52), (0x4d, 0x4d, 9.98), (0x4e, 0x4e, 8.15), (0x4f, 0x4f, 8.56),
+    (0x50, 0x50, 7.34), (0x51, 0x51, 8.56), (0x52, 0x52, 7.94), (0x53, 0x53, 7.14),
+    (0x54, 0x54, 6.72), (0x55, 0x55, 8.15), (0x56, 0x56, 6.93), (0x57, 0x57, 10.38),
+    (0x58, 0x59, 7.34), (0x5a, 0x5a, 7.13), (0x5b, 0x5b, 3.66), (0x5c, 0x5c, 4.08),
+    (0x5d, 0x5d, 3.66), (0x5e, 0x5e, 6.6), (0x5f, 0x5f, 5.5), (0x60, 0x60, 2.85),
+    (0x61, 0x61, 6.31), (0x62, 0x62, 6.72), (0x63, 0x63, 6.31), (0x64, 0x64, 6.72),
+    (0x65, 0x65, 6.31), (0x66, 0x66, 3.66), (0x67, 0x67, 6.72), (0x68, 0x68, 6.52),
+    (0x69, 0x69, 2.84), (0x6a, 0x6a, 3.06), (0x6b, 0x6b, 6.31), (0x6c, 0x6c, 2.84),
+    (0x6d, 0x6d, 9.97), (0x6e, 0x6e, 6.52), (0x6f, 0x71, 6.72), (0x72, 0x72, 4.28),
+    (0x73, 0x73, 5.91), (0x74, 0x74, 3.87), (0x75, 0x75, 6.52), (0x76, 0x76, 5.72),
+    (0x77, 0x77, 8.95), (0x78, 0x78, 5.91), (0x79, 0x7a, 5.71), (0x7b, 0x7b, 3.66),
+    (0x7c, 0x7c, 2.45), (0x7d, 0x7d, 3.66), (0x7e, 0x7e, 6.6), (0xa0, 0xa1, 3.06),
+    (0xa2, 0xa5, 6.12), (0xa6, 0xa6, 2.45), (0xa7, 0xa7, 6.12), (0xa8, 0xa8, 2.85),
+    (0xa9, 0xa9, 8.8), (0xaa, 0xaa, 3.78), (0xab, 0xab, 4.88), (0xac, 0xac, 6.6), (0xad, 0xad, 0.0),
+    (0xae, 0xae, 8.8), (0xaf, 0xaf, 2.85), (0xb0, 0xb0, 4.4), (0xb1, 0xb1, 6.6), (0xb2, 0xb3, 4.31),
+    (0xb4, 0xb4, 2.85), (0xb5, 0xb5, 6.52), (0xb6, 0xb6, 6.82), (0xb7, 0xb7, 3.06),
+    (0xb8, 0xb8, 2.85), (0xb9, 0xb9, 4.31), (0xba, 0xba, 4.04), (0xbb, 0xbb, 4.88),
+    (0xbc, 0xbc, 9.81), (0xbd, 0xbe, 9.78), (0xbf, 0xbf, 6.12), (0xc0, 0xc5, 7.53),
+    (0xc6, 0xc6, 10.79), (0xc7, 0xc7, 8.15), (0xc8, 0xcb, 7.13), (0xcc, 0xcf, 3.24),
+    (0xd0, 0xd1, 8.15), (0xd2, 0xd6, 8.56), (0xd7, 0xd7, 6.6), (0xd8, 0xd8, 8.56),
+    (0xd9, 0xdc, 8.15), (0xdd, 0xde, 7.34), (0xdf, 0xdf, 6.72), (0xe0, 0xe5, 6.31),
+    (0xe6, 0xe6, 9.98), (0xe7, 0xeb, 6.31), (0xec, 0xef, 2.84), (0xf0, 0xf0, 6.72),
+    (0xf1, 0xf1, 6.52), (0xf2, 0xf6, 6.72), (0xf7, 0xf7, 6.6), (0xf8, 0xf8, 6.72),
+    (0xf9, 0xfc, 6.52), (0xfd, 0xfd, 5.71), (0xfe, 0xfe, 6.72), (0x100, 0x100, 7.53),
+    (0x101, 0x101, 6.31), (0x102, 0x102, 7.53), (0x103, 0x103, 6.31), (0x104, 0x104, 7.53),
+    (0x105, 0x105, 6.31), (0x106, 0x106, 8.15), (0x107, 0x107, 6.31), (0x108, 0x108, 8.15),
+    (0x109, 0x109, 6.31), (0x10a, 0x10a, 8.15), (0x10b, 0x10b, 6.31), (0x10c, 0x10c, 8.15),
+    (0x10d, 0x10d, 6.31), (0x10e, 0x10e, 8.15), (0x10f, 0x10f, 6.72), (0x110, 0x110, 8.15),
+    (0x111, 0x111, 6.83), (0x112, 0x112, 7.13), (0x113, 0x113, 6.31), (0x114, 0x114, 7.13),
+    (0x115, 0x115, 6.31), (0x116, 0x116, 7.13), (0x117, 0x117, 6.31), (0x118, 0x118, 7.13),
+    (0x119, 0x119, 6.31), (0x11a, 0x11a, 7.13), (0x11b, 0x11b, 6.31), (0x11c, 0x11c, 8.35),
+    (0x11d, 0x11d, 6.72), (0x11e, 0x11e, 8.35), (0x11f, 0x11f, 6.72), (0x120, 0x120, 8.35),
+    (0x121, 0x121, 6.72), (0x122, 0x122, 8.35), (0x123, 0x123, 6.72), (0x124, 0x124, 8.15),
+    (0x125, 0x125, 6.52), (0x126, 0x126, 8.15), (0x127, 0x127, 6.63), (0x128, 0x128, 3.24),
+    (0x129, 0x129, 2.84), (0x12a, 0x12a, 3.24), (0x12b, 0x12b, 2.84), (0x12c, 0x12c, 3.24),
+    (0x12d, 0x12d, 2.84), (0x12e, 0x12e, 3.24), (0x12f, 0x12f, 2.84), (0x130, 0x130, 3.24),
+    (0x131, 0x131, 2.84), (0x132, 0x132, 9.29), (0x133, 0x133, 5.76), (0x134, 0x134, 6.12),
+    (0x135, 0x135, 3.06), (0x136, 0x136, 7.94), (0x137, 0x137, 6.31), (0x138, 0x138, 6.21),
+    (0x139, 0x139, 6.52), (0x13a, 0x13a, 2.84), (0x13b, 0x13b, 6.52), (0x13c, 0x13c, 2.84),
+    (0x13d, 0x13d, 6.52), (0x13e, 0x13e, 4.27), (0x13f, 0x13f, 6.52), (0x140, 0x140, 4.51),
+    (0x141, 0x141, 6.52), (0x142, 0x142, 2.84), (0x143, 0x143, 8.15), (0x144, 0x144, 6.52),
+    (0x145, 0x145, 8.15), (0x146, 0x146, 6.52), (0x147, 0x147, 8.15), (0x148, 0x148, 6.52),
+    (0x149, 0x149, 8.11), (0x14a, 0x14a, 8.15), (0x14b, 0x14b, 6.52), (0x14c, 0x14c, 8.56),
+    (0x14d, 0x14d, 6.72), (0x14e, 0x14e, 8.56), (0x14f, 0x14f, 6.72), (0x150, 0x150, 8.56),
+    (0x151, 0x151, 6.72), (0x152, 0x152, 12.02), (0x153, 0x153, 10.19), (0x154, 0x154, 7.94),
+    (0x155, 0x155, 4.28), (0x156, 0x156, 7.94), (0x157, 0x157, 4.28), (0x158, 0x158, 7.94),
+    (0x159, 0x159, 4.28), (0x15a, 0x15a, 7.14), (0x15b, 0x15b, 5.91), (0x15c, 0x15c, 7.14),
+    (0x15d, 0x15d, 5.91), (0x15e, 0x15e, 7.14), (0x15f, 0x15f, 5.91), (0x160, 0x160, 7.14),
+    (0x161, 0x161, 5.91), (0x162, 0x162, 6.72), (0x163, 0x163, 3.87), (0x164, 0x164, 6.72),
+    (0x165, 0x165, 3.87), (0x166, 0x166, 6.72), (0x167, 0x167, 3.87), (0x168, 0x168, 8.15),
+    (0x169, 0x169, 6.52), (0x16a, 0x16a, 8.15), (0x16b, 0x16b, 6.52), (0x16c, 0x16c, 8.15),
+    (0x16d, 0x16d, 6.52), (0x16e, 0x16e, 8.15), (0x16f, 0x16f, 6.52), (0x170, 0x170, 8.15),
+    (0x171, 0x171, 6.52), (0x172, 0x172, 8.15), (0x173, 0x173, 6.52), (0x174, 0x174, 10.38),
+    (0x175, 0x175, 8.95), (0x176, 0x176, 7.34), (0x177, 0x177, 5.71), (0x178, 0x178, 7.34),
+    (0x179, 0x179, 7.13), (0x17a, 0x17a, 5.71), (0x17b, 0x17b, 7.13), (0x17c, 0x17c, 5.71),
+    (0x17d, 0x17d, 7.13), (0x17e, 0x17e, 5.71), (0x180, 0x180, 6.72), (0x181, 0x181, 8.3),
+    (0x182, 0x182, 7.34), (0x183, 0x183, 6.72), (0x184, 0x184, 7.27), (0x185, 0x185, 6.12),
+    (0x186, 0x187, 7.94), (0x188, 0x188, 4.88), (0x189, 0x189, 7.94), (0x18a, 0x18a, 8.98),
+    (0x18b, 0x18b, 7.34), (0x18c, 0x18c, 6.72), (0x18d, 0x18d, 6.35), (0x18e, 0x18e, 7.34),
+    (0x18f, 0x18f, 8.23), (0x190, 0x190, 6.25), (0x191, 0x191, 6.72), (0x192, 0x192, 6.12),
+    (0x193, 0x193, 8.56), (0x194, 0x194, 7.94), (0x195, 0x195, 8.6), (0x196, 0x196, 3.06),
+    (0x197, 0x197, 4.29), (0x198, 0x198, 8.56), (0x199, 0x199, 6.12), (0x19a, 0x19a, 4.29),
+    (0x19b, 0x19b, 6.04), (0x19c, 0x19c, 10.34), (0x19d, 0x19d, 7.94), (0x19e, 0x19e, 6.72),
+    (0x19f, 0x19f, 8.56), (0x1a0, 0x1a0, 10.41), (0x1a1, 0x1a1, 8.74), (0x1a2, 0x1a2, 10.14),
+    (0x1a3, 0x1a3, 8.4), (0x1a4, 0x1a4, 7.64), (0x1a5, 0x1a5, 6.12), (0x1a6, 0x1a6, 7.15),
+    (0x1a7, 0x1a7, 7.34), (0x1a8, 0x1a8, 6.12), (0x1a9, 0x1a9, 6.54), (0x1aa, 0x1aa, 5.64),
+    (0x1ab, 0x1ab, 3.66), (0x1ac, 0x1ac, 7.34), (0x1ad, 0x1ad, 3.66), (0x1ae, 0x1ae, 6.72),
+    (0x1af, 0x1af, 10.1), (0x1b0, 0x1b0, 8.85), (0x1b1, 0x1b1, 8.52), (0x1b2, 0x1b2, 7.17),
+    (0x1b3, 0x1b3, 8.7), (0x1b4, 0x1b4, 5.5), (0x1b5, 0x1b5, 7.13), (0x1b6, 0x1b6, 5.71),
+    (0x1b7, 0x1b8, 6.6), (0x1b9, 0x1b9, 6.06), (0x1ba, 0x1ba, 4.98), (0x1bb, 0x1bb, 6.73),
+    (0x1bc, 0x1bc, 5.55), (0x1bd, 0x1bd, 4.23), (0x1be, 0x1be, 3.66), (0x1bf, 0x1bf, 6.56),
+    (0x1c0, 0x1c0, 3.08), (0x1c1, 0x1c1, 5.82), (0x1c2, 0x1c2, 6.29), (0x1c3, 0x1c3, 3.66),
+    (0x1c4, 0x1c4, 13.53), (0x1c5, 0x1c5, 12.45), (0x1c6, 0x1c6, 11.66), (0x1c7, 0x1c7, 11.64),
+    (0x1c8, 0x1c8, 9.18), (0x1c9, 0x1c9, 5.73), (0x1ca, 0x1ca, 12.74), (0x1cb, 0x1cb, 9.97),
+    (0x1cc, 0x1cc, 8.8), (0x1cd, 0x1cd, 7.94), (0x1ce, 0x1ce, 6.12), (0x1cf, 0x1d0, 3.06),
+    (0x1d1, 0x1d1, 8.56), (0x1d2, 0x1d2, 6.72), (0x1d3, 0x1d3, 7.94), (0x1d4, 0x1d4, 6.72),
+    (0x1d5, 0x1d5, 7.94), (0x1d6, 0x1d6, 6.72), (0x1d7, 0x1d7, 7.94), (0x1d8, 0x1d8, 6.72),
+    (0x1d9, 0x1d9, 7.94), (0x1da, 0x1da, 6.72), (0x1db, 0x1db, 7.94), (0x1dc, 0x1dc, 6.72),
+    (0x1dd, 0x1dd, 6.12), (0x1de, 0x1de, 7.94), (0x1df, 0x1df, 6.12), (0x1e0, 0x1e0, 7.94),
+    (0x1e1, 0x1e1, 6.12), (0x1e2, 0x1e2, 11.0), (0x1e3, 0x1e3, 9.78), (0x1e4, 0x1e4, 9.07),
+    (0x1e5, 0x1e5, 7.56), (0x1e6, 0x1e6, 8.56), (0x1e7, 0x1e7, 6.72), (0x1e8, 0x1e8, 7.94),
+    (0x1e9, 0x1e9, 6.12), (0x1ea, 0x1ea, 8.56), (0x1eb, 0x1eb, 6.72), (0x1ec, 0x1ec, 8.56),
+    (0x1ed, 0x1ed, 6.72), (0x1ee, 0x1ee, 6.39), (0x1ef, 0x1ef, 5.86), (0x1f0, 0x1f0, 3.06),
+    (0x1f1, 0x1f1, 13.82), (0x1f2, 0x1f2, 12.68), (0x1f3, 0x1f3, 11.91), (0x1f4, 0x1f4, 8.56),
+    (0x1f5, 0x1f5, 6.72), (0x1f6, 0x1f6, 10.67), (0x1f7, 0x1f7, 7.34), (0x1f8, 0x1f8, 7.94),
+    (0x1f9, 0x1f9, 6.72), (0x1fa, 0x1fa, 7.53), (0x1fb, 0x1fb, 6.31), (0x1fc, 0x1fc, 10.79),
+    (0x1fd, 0x1fd, 9.98), (0x1fe, 0x1fe, 8.56), (0x1ff, 0x1ff, 6.72), (0x200, 0x200, 7.94),
+    (0x201, 0x201, 6.12), (0x202, 0x202, 7.94), (0x203, 0x203, 6.12), (0x204, 0x204, 7.34),
+    (0x205, 0x205, 6.12), (0x206, 0x206, 7.34), (0x207, 0x207, 6.12), (0x208, 0x20b, 3.06),
+    (0x20c, 0x20c, 8.56), (0x20d, 0x20d, 6.72), (0x20e, 0x20e, 8.56), (0x20f, 0x20f, 6.72),
+    (0x210, 0x210, 7.94), (0x211, 0x211, 4.28), (0x212, 0x212, 7.94), (0x213, 0x213, 4.28),
+    (0x214, 0x214, 7.94), (0x215, 0x215, 6.72), (0x216, 0x216, 7.94), (0x217, 0x217, 6.72),
+    (0x218, 0x218, 7.14), (0x219, 0x219, 5.91), (0x21a, 0x21a, 6.72), (0x21b, 0x21b, 3.87),
+    (0x21c, 0x21d, 7.3), (0x21e, 0x21e, 7.94), (0x21f, 0x21f, 6.72), (0x220, 0x220, 7.94),
+    (0x221, 0x221, 6.12), (0x222, 0x222, 7.44), (0x223, 0x223, 5.52), (0x224, 0x224, 7.34),
+    (0x225, 0x225, 4.88), (0x226, 0x226, 7.94), (0x227, 0x227, 6.12), (0x228, 0x228, 7.34),
+    (0x229, 0x229, 6.12), (0x22a, 0x22a, 8.56), (0x22b, 0x22b, 6.72), (0x22c, 0x22c, 8.56),
+    (0x22d, 0x22d, 6.72), (0x22e, 0x22e, 8.56), (0x22f, 0x22f, 6.72), (0x230, 0x230, 8.56),
+    (0x231, 0x231, 6.72), (0x232, 0x232, 7.34), (0x233, 0x233, 6.12), (0x234, 0x234, 3.06),
+    (0x235, 0x235, 6.12), (0x236, 0x236, 3.97), (0x237, 0x237, 3.06), (0x238, 0x238, 10.57),
+    (0x239, 0x239, 10.59), (0x23a, 0x23b, 7.94), (0x23c, 0x23c, 6.12), (0x23d, 0x23e, 6.72),
+    (0x23f, 0x23f, 4.28), (0x240, 0x240, 4.88), (0x241, 0x242, 6.72), (0x243, 0x243, 8.39),
+    (0x244, 0x244, 9.15), (0x245, 0x246, 7.34), (0x247, 0x248, 6.12), (0x249, 0x249, 3.06),
+    (0x24a, 0x24a, 9.06), (0x24b, 0x24b, 7.25), (0x24c, 0x24c, 8.49), (0x24d, 0x24d, 4.83),
+    (0x24e, 0x24e, 8.44), (0x370, 0x370, 4.67), (0x371, 0x371, 3.68), (0x372, 0x372, 6.93),
+    (0x373, 0x373, 5.83), (0x374, 0x375, 3.06), (0x376, 0x376, 8.03), (0x377, 0x377, 6.52),
+    (0x378, 0x379, 5.5), (0x37a, 0x37a, 1.53), (0x37b, 0x37d, 6.12), (0x37e, 0x37e, 3.06),
+    (0x37f, 0x37f, 3.66), (0x380, 0x383, 5.5), (0x384, 0x384, 2.65), (0x385, 0x385, 5.63),
+    (0x386, 0x386, 7.94), (0x387, 0x387, 3.06), (0x388, 0x388, 9.28), (0x389, 0x389, 10.31),
+    (0x38a, 0x38a, 5.4), (0x38b, 0x38b, 5.5), (0x38c, 0x38c, 10.21), (0x38d, 0x38d, 5.5),
+    (0x38e, 0x38e, 10.16), (0x38f, 0x38f, 10.22), (0x390, 0x390, 3.08), (0x391, 0x391, 7.53),
+    (0x392, 0x392, 7.74), (0x393, 0x393, 6.28), (0x394, 0x394, 7.49), (0x395, 0x396, 7.13),
+    (0x397, 0x397, 8.15), (0x398, 0x398, 8.56), (0x399, 0x399, 3.24), (0x39a, 0x39a, 7.94),
+    (0x39b, 0x39b, 7.31), (0x39c, 0x39c, 9.98), (0x39d, 0x39d, 8.15), (0x39e, 0x39e, 6.85),
+    (0x39f, 0x39f, 8.56), (0x3a0, 0x3a0, 8.28), (0x3a1, 0x3a1, 7.34), (0x3a2, 0x3a2, 5.5),
+    (0x3a3, 0x3a3, 7.13), (0x3a4, 0x3a4, 6.72), (0x3a5, 0x3a5, 7.34), (0x3a6, 0x3a6, 8.96),
+    (0x3a7, 0x3a7, 7.34), (0x3a8, 0x3a8, 9.25), (0x3a9, 0x3a9, 8.5), (0x3aa, 0x3aa, 3.26),
+    (0x3ab, 0x3ab, 7.23), (0x3ac, 0x3ac, 6.8), (0x3ad, 0x3ad, 5.66), (0x3ae, 0x3ae, 6.52),
+    (0x3af, 0x3af, 3.0), (0x3b0, 0x3b0, 6.25), (0x3b1, 0x3b1, 6.8), (0x3b2, 0x3b2, 6.47),
+    (0x3b3, 0x3b3, 5.59), (0x3b4, 0x3b4, 6.51), (0x3b5, 0x3b5, 5.66), (0x3b6, 0x3b6, 5.21),
+    (0x3b7, 0x3b7, 6.52), (0x3b8, 0x3b8, 6.32), (0x3b9, 0x3b9, 3.0), (0x3ba, 0x3ba, 6.24),
+    (0x3bb, 0x3bb, 6.06), (0x3bc, 0x3bc, 6.52), (0x3bd, 0x3bd, 5.83), (0x3be, 0x3be, 5.11),
+    (0x3bf, 0x3bf, 6.72), (0x3c0, 0x3c0, 7.33), (0x3c1, 0x3c1, 6.57), (0x3c2, 0x3c2, 6.02),
+    (0x3c3, 0x3c3, 6.58), (0x3c4, 0x3c4, 5.49), (0x3c5, 0x3c5, 6.29), (0x3c6, 0x3c6, 8.29),
+    (0x3c7, 0x3c7, 5.91), (0x3c8, 0x3c8, 7.99), (0x3c9, 0x3c9, 8.36), (0x3ca, 0x3ca, 3.08),
+    (0x3cb, 0x3cb, 6.32), (0x3cc, 0x3cc, 6.72), (0x3cd, 0x3cd, 6.32), (0x3ce, 0x3ce, 8.36),
+    (0x3cf, 0x3cf, 6.14), (0x3d0, 0x3d0, 5.8), (0x3d1, 0x3d1, 6.59), (0x3d2, 0x3d2, 7.94),
+    (0x3d3, 0x3d3, 10.16), (0x3d4, 0x3d4, 7.94), (0x3d5, 0x3d5, 6.86), (0x3d6, 0x3d6, 8.04),
+    (0x3d7, 0x3d7, 6.38), (0x3d8, 0x3d8, 8.56), (0x3d9, 0x3d9, 5.5), (0x3da, 0x3da, 7.94),
+    (0x3db, 0x3db, 4.64), (0x3dc, 0x3dc, 6.72), (0x3dd, 0x3dd, 6.01), (0x3de, 0x3de, 6.55),
+    (0x3df, 0x3df, 5.07), (0x3e0, 0x3e0, 8.27), (0x3e1, 0x3e1, 6.1), (0x3e2, 0x3e2, 9.57),
+    (0x3e3, 0x3e3, 9.16), (0x3e4, 0x3e4, 7.98), (0x3e5, 0x3e5, 6.45), (0x3e6, 0x3e6, 8.14),
+    (0x3e7, 0x3e7, 4.88), (0x3e8, 0x3e8, 6.88), (0x3e9, 0x3e9, 6.45), (0x3ea, 0x3ea, 7.5),
+    (0x3eb, 0x3eb, 6.66), (0x3ec, 0x3ec, 7.97), (0x3ed, 0x3ed, 5.67), (0x3ee, 0x3ee, 5.94),
+    (0x3ef, 0x3ef, 4.95), (0x3f0, 0x3f0, 6.38), (0x3f1, 0x3f1, 5.5), (0x3f2, 0x3f2, 6.12),
+    (0x3f3, 0x3f3, 2.83), (0x3f4, 0x3f4, 8.56), (0x3f5, 0x3f6, 5.6), (0x3f7, 0x3f7, 7.34),
+    (0x3f8, 0x3f8, 6.72), (0x3f9, 0x3f9, 7.94), (0x3fa, 0x3fa, 9.16), (0x3fb, 0x3fb, 8.5),
+    (0x3fc, 0x3fc, 7.26), (0x3fd, 0x3fe, 7.94), (0x400, 0x401, 7.13), (0x402, 0x402, 8.84),
+    (0x403, 0x403, 6.31), (0x404, 0x404, 8.1), (0x405, 0x405, 7.14), (0x406, 0x407, 3.24),
+    (0x408, 0x408, 6.12), (0x409, 0x409, 12.38), (0x40a, 0x40a, 12.35), (0x40b, 0x40b, 8.84),
+    (0x40c, 0x40c, 7.94), (0x40d, 0x40d, 8.19), (0x40e, 0x40e, 7.12), (0x40f, 0x40f, 8.05),
+    (0x410, 0x410, 7.53), (0x411, 0x411, 7.72), (0x412, 0x412, 7.74), (0x413, 0x413, 6.31),
+    (0x414, 0x414, 8.49), (0x415, 0x415, 7.13), (0x416, 0x416, 11.67), (0x417, 0x417, 7.12),
+    (0x418, 0x419, 8.19), (0x41a, 0x41a, 7.94), (0x41b, 0x41b, 8.17), (0x41c, 0x41c, 9.98),
+    (0x41d, 0x41d, 8.15), (0x41e, 0x41e, 8.56), (0x41f, 0x41f, 8.05), (0x420, 0x420, 7.34),
+    (0x421, 0x421, 8.15), (0x422, 0x422, 6.72), (0x423, 0x423, 7.12), (0x424, 0x424, 10.15),
+    (0x425, 0x425, 7.34), (0x426, 0x426, 8.27), (0x427, 0x427, 7.48), (0x428, 0x428, 11.45),
+    (0x429, 0x429, 11.67), (0x42a, 0x42a, 8.95), (0x42b, 0x42b, 10.22), (0x42c, 0x42c, 7.45),
+    (0x42d, 0x42d, 8.12), (0x42e, 0x42e, 11.25), (0x42f, 0x42f, 7.97), (0x430, 0x430, 6.31),
+    (0x431, 0x431, 6.68), (0x432, 0x432, 6.39), (0x433, 0x433, 4.98), (0x434, 0x434, 6.86),
+    (0x435, 0x435, 6.31), (0x436, 0x436, 8.93), (0x437, 0x437, 5.96), (0x438, 0x439, 6.6),
+    (0x43a, 0x43a, 6.31), (0x43b, 0x43b, 6.59), (0x43c, 0x43c, 8.12), (0x43d, 0x43d, 6.52),
+    (0x43e, 0x43e, 6.72), (0x43f, 0x43f, 6.41), (0x440, 0x440, 6.72), (0x441, 0x441, 6.31),
+    (0x442, 0x442, 5.52), (0x443, 0x443, 5.71), (0x444, 0x444, 9.42), (0x445, 0x445, 5.91),
+    (0x446, 0x446, 6.63), (0x447, 0x447, 6.31), (0x448, 0x448, 9.01), (0x449, 0x449, 9.17),
+    (0x44a, 0x44a, 7.11), (0x44b, 0x44b, 8.56), (0x44c, 0x44c, 6.03), (0x44d, 0x44d, 6.34),
+    (0x44e, 0x44e, 9.18), (0x44f, 0x44f, 6.36), (0x450, 0x451, 6.31), (0x452, 0x452, 6.74),
+    (0x453, 0x453, 4.98), (0x454, 0x454, 6.34), (0x455, 0x455, 5.91), (0x456, 0x456, 2.84),
+    (0x457, 0x457, 3.3), (0x458, 0x458, 3.06), (0x459, 0x459, 9.87), (0x45a, 0x45a, 9.8),
+    (0x45b, 0x45b, 6.74), (0x45c, 0x45c, 6.31), (0x45d, 0x45d, 6.6), (0x45e, 0x45e, 5.71),
+    (0x45f, 0x45f, 6.41), (0x460, 0x460, 13.76), (0x461, 0x461, 6.81), (0x462, 0x462, 8.29),
+    (0x463, 0x463, 6.47), (0x464, 0x464, 11.19), (0x465, 0x465, 8.08), (0x466, 0x466, 7.94),
+    (0x467, 0x467, 6.12), (0x468, 0x468, 11.05), (0x469, 0x469, 8.6), (0x46a, 0x46a, 10.97),
+    (0x46b, 0x46b, 9.04), (0x46c, 0x46c, 11.74), (0x46d, 0x46d, 9.14), (0x46e, 0x46e, 5.81),
+    (0x46f, 0x46f, 4.42), (0x470, 0x470, 9.17), (0x471, 0x471, 7.94), (0x472, 0x472, 8.56),
+    (0x473, 0x473, 6.72), (0x474, 0x474, 7.37), (0x475, 0x475, 5.94), (0x476, 0x476, 8.14),
+    (0x477, 0x477, 6.72), (0x478, 0x478, 14.67), (0x479, 0x479, 12.84), (0x47a, 0x47a, 9.06),
+    (0x47b, 0x47b, 6.44), (0x47c, 0x47c, 13.6), (0x47d, 0x47d, 8.88), (0x47e, 0x47e, 13.76),
+    (0x47f, 0x47f, 6.81), (0x480, 0x480, 7.94), (0x481, 0x481, 4.88), (0x482, 0x482, 3.72),
+    (0x483, 0x489, 0.0), (0x48a, 0x48a, 8.56), (0x48b, 0x48b, 6.34), (0x48c, 0x48c, 8.29),
+    (0x48d, 0x48d, 6.47), (0x48e, 0x48e, 6.72), (0x48f, 0x48f, 6.12), (0x490, 0x490, 6.31),
+    (0x491, 0x491, 4.98), (0x492, 0x492, 6.9), (0x493, 0x493, 5.44), (0x494, 0x494, 7.94),
+    (0x495, 0x495, 6.34), (0x496, 0x496, 11.9), (0x497, 0x497, 9.15), (0x498, 0x498, 7.12),
+    (0x499, 0x499, 5.96), (0x49a, 0x49a, 8.17), (0x49b, 0x49b, 6.45), (0x49c, 0x49c, 8.27),
+    (0x49d, 0x49d, 6.76), (0x49e, 0x49e, 7.98), (0x49f, 0x49f, 6.34), (0x4a0, 0x4a0, 9.45),
+    (0x4a1, 0x4a1, 7.39), (0x4a2, 0x4a2, 8.37), (0x4a3, 0x4a3, 6.71), (0x4a4, 0x4a4, 11.16),
+    (0x4a5, 0x4a5, 8.7), (0x4a6, 0x4a6, 12.26), (0x4a7, 0x4a7, 9.28), (0x4a8, 0x4a8, 8.02),
+    (0x4a9, 0x4a9, 5.87), (0x4aa, 0x4aa, 8.15), (0x4ab, 0x4ab, 6.31), (0x4ac, 0x4ac, 6.83),
+    (0x4ad, 0x4ad, 5.6), (0x4ae, 0x4ae, 7.34), (0x4af, 0x4af, 5.71), (0x4b0, 0x4b0, 7.34),
+    (0x4b1, 0x4b1, 5.71), (0x4b2, 0x4b2, 7.57), (0x4b3, 0x4b3, 6.08), (0x4b4, 0x4b4, 10.11),
+    (0x4b5, 0x4b5, 7.24), (0x4b6, 0x4b6, 7.69), (0x4b7, 0x4b7, 6.5), (0x4b8, 0x4b8, 7.48),
+    (0x4b9, 0x4b9, 6.31), (0x4ba, 0x4ba, 7.42), (0x4bb, 0x4bb, 6.52), (0x4bc, 0x4bc, 10.17),
+    (0x4bd, 0x4bd, 8.21), (0x4be, 0x4be, 10.17), (0x4bf, 0x4bf, 8.21), (0x4c0, 0x4c0, 3.24),
+    (0x4c1, 0x4c1, 11.67), (0x4c2, 0x4c2, 8.93), (0x4c3, 0x4c3, 8.11), (0x4c4, 0x4c4, 6.53),
+    (0x4c5, 0x4c5, 8.2), (0x4c6, 0x4c6, 6.17), (0x4c7, 0x4c7, 8.07), (0x4c8, 0x4c8, 6.21),
+    (0x4c9, 0x4c9, 8.56), (0x4ca, 0x4ca, 6.34), (0x4cb, 0x4cb, 8.07), (0x4cc, 0x4cc, 6.2),
+    (0x4cd, 0x4cd, 10.38), (0x4ce, 0x4ce, 7.49), (0x4cf, 0x4cf, 3.06), (0x4d0, 0x4d0, 7.53),
+    (0x4d1, 0x4d1, 6.31), (0x4d2, 0x4d2, 7.53), (0x4d3, 0x4d3, 6.31), (0x4d4, 0x4d4, 10.79),
+    (0x4d5, 0x4d5, 9.98), (0x4d6, 0x4d6, 7.13), (0x4d7, 0x4d7, 6.31), (0x4d8, 0x4d8, 8.23),
+    (0x4d9, 0x4d9, 6.31), (0x4da, 0x4da, 8.26), (0x4db, 0x4db, 6.12), (0x4dc, 0x4dc, 11.67),
+    (0x4dd, 0x4dd, 8.93), (0x4de, 0x4de, 7.12), (0x4df, 0x4df, 5.96), (0x4e0, 0x4e0, 6.6),
+    (0x4e1, 0x4e1, 6.06), (0x4e2, 0x4e2, 8.19), (0x4e3, 0x4e3, 6.6), (0x4e4, 0x4e4, 8.19),
+    (0x4e5, 0x4e5, 6.6), (0x4e6, 0x4e6, 8.56), (0x4e7, 0x4e7, 6.72), (0x4e8, 0x4e8, 8.56),
+    (0x4e9, 0x4e9, 6.72), (0x4ea, 0x4ea, 8.71), (0x4eb, 0x4eb, 6.21), (0x4ec, 0x4ec, 8.07),
+    (0x4ed, 0x4ed, 5.6), (0x4ee, 0x4ee, 7.12), (0x4ef, 0x4ef, 5.71), (0x4f0, 0x4f0, 7.12),
+    (0x4f1, 0x4f1, 5.71), (0x4f2, 0x4f2, 7.12), (0x4f3, 0x4f3, 5.71), (0x4f4, 0x4f4, 7.42),
+    (0x4f5, 0x4f5, 6.31), (0x4f6, 0x4f6, 6.67), (0x4f7, 0x4f7, 4.8), (0x4f8, 0x4f8, 10.22),
+    (0x4f9, 0x4f9, 8.56), (0x4fa, 0x4fa, 7.0), (0x4fb, 0x4fb, 5.0), (0x4fc, 0x4fc, 7.94),
+    (0x4fd, 0x4fd, 5.5), (0x4fe, 0x4fe, 7.46), (0x500, 0x500, 6.02), (0x501, 0x501, 5.5),
+    (0x502, 0x502, 9.37), (0x503, 0x503, 8.59), (0x504, 0x504, 8.77), (0x505, 0x505, 7.46),
+    (0x506, 0x506, 6.54), (0x507, 0x507, 5.06), (0x508, 0x508, 10.29), (0x509, 0x509, 8.57),
+    (0x50a, 0x50a, 10.67), (0x50b, 0x50b, 8.73), (0x50c, 0x50c, 8.56), (0x50d, 0x50d, 6.5),
+    (0x50e, 0x50e, 7.87), (0x50f, 0x50f, 6.79), (0x510, 0x510, 7.3), (0x511, 0x511, 5.55),
+    (0x512, 0x512, 8.17), (0x513, 0x513, 6.34), (0x514, 0x514, 8.38), (0x515, 0x515, 7.62),
+    (0x516, 0x516, 9.13), (0x517, 0x517, 7.49), (0x518, 0x518, 11.34), (0x519, 0x519, 8.11),
+    (0x51a, 0x51a, 8.56), (0x51b, 0x51b, 6.12), (0x51c, 0x51c, 11.0), (0x51d, 0x51d, 7.94),
+    (0x51e, 0x51e, 7.53), (0x51f, 0x51f, 6.94), (0x520, 0x520, 10.79), (0x521, 0x521, 8.76),
+    (0x522, 0x522, 12.22), (0x523, 0x523, 9.99), (0x524, 0x524, 7.77), (0x525, 0x525, 6.78),
+    (0x526, 0x526, 7.56), (0x527, 0x527, 6.92), (0x528, 0x528, 4.88), (0x529, 0x529, 4.43),
+    (0x52a, 0x52a, 9.8), (0x52b, 0x52b, 8.94), (0x52c, 0x52c, 6.18), (0x52d, 0x52d, 5.76),
+    (0x52e, 0x52e, 7.49), (0x1d00, 0x1d00, 5.7), (0x1d01, 0x1d01, 8.27), (0x1d02, 0x1d02, 7.94),
+    (0x1d03, 0x1d03, 5.94), (0x1d04, 0x1d04, 5.76), (0x1d05, 0x1d06, 6.35), (0x1d07, 0x1d07, 5.27),
+    (0x1d08, 0x1d08, 5.55), (0x1d09, 0x1d09, 3.06), (0x1d0a, 0x1d0a, 4.38), (0x1d0b, 0x1d0b, 6.75),
+    (0x1d0c, 0x1d0c, 5.32), (0x1d0d, 0x1d0d, 7.49), (0x1d0e, 0x1d0e, 5.82), (0x1d0f, 0x1d0f, 5.5),
+    (0x1d10, 0x1d10, 5.76), (0x1d11, 0x1d11, 6.88), (0x1d12, 0x1d12, 6.86), (0x1d13, 0x1d13, 7.13),
+    (0x1d14, 0x1d14, 10.38), (0x1d15, 0x1d15, 5.52), (0x1d16, 0x1d17, 6.72), (0x1d18, 0x1d1a, 5.86),
+    (0x1d1b, 0x1d1b, 5.4), (0x1d1c, 0x1d1c, 6.01), (0x1d1d, 0x1d1d, 6.81), (0x1d1e, 0x1d1e, 8.84),
+    (0x1d1f, 0x1d1f, 6.82), (0x1d20, 0x1d20, 5.5), (0x1d21, 0x1d21, 7.94), (0x1d22, 0x1d22, 4.88),
+    (0x1d23, 0x1d23, 4.62), (0x1d24, 0x1d24, 5.1), (0x1d25, 0x1d25, 5.98), (0x1d26, 0x1d26, 5.0),
+    (0x1d27, 0x1d27, 5.35), (0x1d28, 0x1d28, 6.34), (0x1d29, 0x1d29, 5.82), (0x1d2a, 0x1d2a, 6.59),
+    (0x1d2b, 0x1d2b, 6.17), (0x1d2c, 0x1d2c, 4.69), (0x1d2d, 0x1d2d, 6.56), (0x1d2e, 0x1d2f, 4.36),
+    (0x1d30, 0x1d30, 4.73), (0x1d31, 0x1d32, 4.29), (0x1d33, 0x1d33, 5.12), (0x1d34, 0x1d34, 5.19),
+    (0x1d35, 0x1d35, 2.6), (0x1d36, 0x1d36, 3.51), (0x1d37, 0x1d37, 5.25), (0x1d38, 0x1d38, 4.37),
+    (0x1d39, 0x1d39, 6.3), (0x1d3a, 0x1d3a, 4.84), (0x1d3b, 0x1d3b, 5.26), (0x1d3c, 0x1d3c, 4.96),
+    (0x1d3d, 0x1d3d, 4.32), (0x1d3e, 0x1d3e, 3.99), (0x1d3f, 0x1d3f, 4.89), (0x1d40, 0x1d40, 4.24),
+    (0x1d41, 0x1d41, 4.82), (0x1d42, 0x1d42, 6.54), (0x1d43, 0x1d44, 4.34), (0x1d45, 0x1d45, 4.62),
+    (0x1d46, 0x1d46, 6.94), (0x1d47, 0x1d48, 4.77), (0x1d49, 0x1d4a, 4.34), (0x1d4b, 0x1d4c, 3.94),
+    (0x1d4d, 0x1d4d, 4.77), (0x1d4e, 0x1d4e, 2.17), (0x1d4f, 0x1d4f, 4.34), (0x1d50, 0x1d50, 6.94),
+    (0x1d51, 0x1d52, 4.77), (0x1d53, 0x1d53, 4.34), (0x1d54, 0x1d56, 4.77), (0x1d57, 0x1d57, 2.6),
+    (0x1d58, 0x1d58, 4.77), (0x1d59, 0x1d59, 4.83), (0x1d5a, 0x1d5a, 6.94), (0x1d5b, 0x1d5b, 4.34),
+    (0x1d5c, 0x1d5c, 3.54), (0x1d5d, 0x1d5d, 4.72), (0x1d5e, 0x1d5e, 4.34), (0x1d5f, 0x1d5f, 4.77),
+    (0x1d60, 0x1d60, 3.93), (0x1d61, 0x1d61, 4.34), (0x1d62, 0x1d62, 2.17), (0x1d63, 0x1d63, 3.04),
+    (0x1d64, 0x1d64, 4.77), (0x1d65, 0x1d65, 4.34), (0x1d66, 0x1d66, 4.72), (0x1d67, 0x1d67, 4.34),
+    (0x1d68, 0x1d68, 4.77), (0x1d69, 0x1d69, 3.93), (0x1d6a, 0x1d6a, 4.34), (0x1d6b, 0x1d6b, 9.82),
+    (0x1d6c, 0x1d6c, 7.11), (0x1d6d, 0x1d6d, 7.35), (0x1d6e, 0x1d6e, 4.3), (0x1d6f, 0x1d6f, 9.16),
+    (0x1d70, 0x1d70, 6.12), (0x1d71, 0x1d71, 7.11), (0x1d72, 0x1d72, 4.74), (0x1d73, 0x1d74, 4.28),
+    (0x1d75, 0x1d75, 4.29), (0x1d76, 0x1d76, 4.88), (0x1d77, 0x1d77, 5.5), (0x1d78, 0x1d78, 3.66),
+    (0x1d79, 0x1d79, 5.71), (0x1d7a, 0x1d7a, 11.81), (0x1d7b, 0x1d7b, 3.16), (0x1d7c, 0x1d7c, 3.41),
+    (0x1d7d, 0x1d7d, 6.12), (0x1d7e, 0x1d7e, 6.01), (0x1e00, 0x1e00, 7.94), (0x1e01, 0x1e01, 6.12),
+    (0x1e02, 0x1e02, 7.94), (0x1e03, 0x1e03, 6.72), (0x1e04, 0x1e04, 7.94), (0x1e05, 0x1e05, 6.72),
+    (0x1e06, 0x1e06, 7.94), (0x1e07, 0x1e07, 6.72), (0x1e08, 0x1e08, 7.94), (0x1e09, 0x1e09, 6.12),
+    (0x1e0a, 0x1e0a, 7.94), (0x1e0b, 0x1e0b, 6.72), (0x1e0c, 0x1e0c, 7.94), (0x1e0d, 0x1e0d, 6.72),
+    (0x1e0e, 0x1e0e, 7.94), (0x1e0f, 0x1e0f, 6.72), (0x1e10, 0x1e10, 7.94), (0x1e11, 0x1e11, 6.72),
+    (0x1e12, 0x1e12, 7.94), (0x1e13, 0x1e13, 6.72), (0x1e14, 0x1e14, 7.34), (0x1e15, 0x1e15, 6.12),
+    (0x1e16, 0x1e16, 7.34), (0x1e17, 0x1e17, 6.12), (0x1e18, 0x1e18, 7.34), (0x1e19, 0x1e19, 6.12),
+    (0x1e1a, 0x1e1a, 7.34), (0x1e1b, 0x1e1b, 6.12), (0x1e1c, 0x1e1c, 7.34), (0x1e1d, 0x1e1d, 6.12),
+    (0x1e1e, 0x1e1e, 6.72), (0x1e1f, 0x1e1f, 3.66), (0x1e20, 0x1e20, 8.56), (0x1e21, 0x1e21, 6.72),
+    (0x1e22, 0x1e22, 7.94), (0x1e23, 0x1e23, 6.72), (0x1e24, 0x1e24, 7.94), (0x1e25, 0x1e25, 6.72),
+    (0x1e26, 0x1e26, 7.94), (0x1e27, 0x1e27, 6.72), (0x1e28, 0x1e28, 7.94), (0x1e29, 0x1e29, 6.72),
+    (0x1e2a, 0x1e2a, 7.94), (0x1e2b, 0x1e2b, 6.72), (0x1e2c, 0x1e2f, 3.06), (0x1e30, 0x1e30, 7.94),
+    (0x1e31, 0x1e31, 6.12), (0x1e32, 0x1e32, 7.94), (0x1e33, 0x1e33, 6.12), (0x1e34, 0x1e34, 7.94),
+    (0x1e35, 0x1e35, 6.12), (0x1e36, 0x1e36, 6.72), (0x1e37, 0x1e37, 3.06), (0x1e38, 0x1e38, 6.72),
+    (0x1e39, 0x1e39, 3.06), (0x1e3a, 0x1e3a, 6.72), (0x1e3b, 0x1e3b, 3.06), (0x1e3c, 0x1e3c, 6.72),
+    (0x1e3d, 0x1e3d, 3.06), (0x1e3e, 0x1e3e, 9.16), (0x1e3f, 0x1e3f, 9.78), (0x1e40, 0x1e40, 9.16),
+    (0x1e41, 0x1e41, 9.78), (0x1e42, 0x1e42, 9.16), (0x1e43, 0x1e43, 9.78), (0x1e44, 0x1e44, 7.94),
+    (0x1e45, 0x1e45, 6.72), (0x1e46, 0x1e46, 7.94), (0x1e47, 0x1e47, 6.72), (0x1e48, 0x1e48, 7.94),
+    (0x1e49, 0x1e49, 6.72), (0x1e4a, 0x1e4a, 7.94), (0x1e4b, 0x1e4b, 6.72), (0x1e4c, 0x1e4c, 8.56),
+    (0x1e4d, 0x1e4d, 6.72), (0x1e4e, 0x1e4e, 8.56), (0x1e4f, 0x1e4f, 6.72), (0x1e50, 0x1e50, 8.56),
+    (0x1e51, 0x1e51, 6.72), (0x1e52, 0x1e52, 8.56), (0x1e53, 0x1e53, 6.72), (0x1e54, 0x1e54, 7.34),
+    (0x1e55, 0x1e55, 6.72), (0x1e56, 0x1e56, 7.34), (0x1e57, 0x1e57, 6.72), (0x1e58, 0x1e58, 7.94),
+    (0x1e59, 0x1e59, 4.28), (0x1e5a, 0x1e5a, 7.94), (0x1e5b, 0x1e5b, 4.28), (0x1e5c, 0x1e5c, 7.94),
+    (0x1e5d, 0x1e5d, 4.28), (0x1e5e, 0x1e5e, 7.94), (0x1e5f, 0x1e5f, 4.28), (0x1e60, 0x1e60, 7.34),
+    (0x1e61, 0x1e61, 6.12), (0x1e62, 0x1e62, 7.34), (0x1e63, 0x1e63, 6.12), (0x1e64, 0x1e64, 7.34),
+    (0x1e65, 0x1e65, 6.12), (0x1e66, 0x1e66, 7.34), (0x1e67, 0x1e67, 6.12), (0x1e68, 0x1e68, 7.34),
+    (0x1e69, 0x1e69, 6.12), (0x1e6a, 0x1e6a, 6.72), (0x1e6b, 0x1e6b, 3.66), (0x1e6c, 0x1e6c, 6.72),
+    (0x1e6d, 0x1e6d, 3.66), (0x1e6e, 0x1e6e, 6.72), (0x1e6f, 0x1e6f, 3.66), (0x1e70, 0x1e70, 6.72),
+    (0x1e71, 0x1e71, 3.66), (0x1e72, 0x1e72, 7.94), (0x1e73, 0x1e73, 6.72), (0x1e74, 0x1e74, 7.94),
+    (0x1e75, 0x1e75, 6.72), (0x1e76, 0x1e76, 7.94), (0x1e77, 0x1e77, 6.72), (0x1e78, 0x1e78, 7.94),
+    (0x1e79, 0x1e79, 6.72), (0x1e7a, 0x1e7a, 7.94), (0x1e7b, 0x1e7b, 6.72), (0x1e7c, 0x1e7c, 7.34),
+    (0x1e7d, 0x1e7d, 6.12), (0x1e7e, 0x1e7e, 7.34), (0x1e7f, 0x1e7f, 6.12), (0x1e80, 0x1e80, 10.38),
+    (0x1e81, 0x1e81, 8.95), (0x1e82, 0x1e82, 10.38), (0x1e83, 0x1e83, 8.95),
+    (0x1e84, 0x1e84, 10.38), (0x1e85, 0x1e85, 8.95), (0x1e86, 0x1e86, 10.38),
+    (0x1e87, 0x1e87, 8.56), (0x1e88, 0x1e88, 10.38), (0x1e89, 0x1e89, 8.56), (0x1e8a, 0x1e8a, 7.34),
+    (0x1e8b, 0x1e8b, 6.12), (0x1e8c, 0x1e8c, 7.34), (0x1e8d, 0x1e8d, 6.12), (0x1e8e, 0x1e8e, 7.34),
+    (0x1e8f, 0x1e8f, 6.12), (0x1e90, 0x1e90, 6.72), (0x1e91, 0x1e91, 5.5), (0x1e92, 0x1e92, 6.72),
+    (0x1e93, 0x1e93, 5.5), (0x1e94, 0x1e94, 6.72), (0x1e95, 0x1e95, 5.5), (0x1e96, 0x1e96, 6.72),
+    (0x1e97, 0x1e97, 3.66), (0x1e98, 0x1e98, 8.56), (0x1e99, 0x1e9a, 6.12), (0x1e9b, 0x1e9b, 3.66),
+    (0x1e9c, 0x1e9c, 4.66), (0x1e9d, 0x1e9d, 4.65), (0x1e9e, 0x1e9e, 8.34), (0x1e9f, 0x1e9f, 6.62),
+    (0x1ea0, 0x1ea0, 7.94), (0x1ea1, 0x1ea1, 6.12), (0x1ea2, 0x1ea2, 7.94), (0x1ea3, 0x1ea3, 6.12),
+    (0x1ea4, 0x1ea4, 8.01), (0x1ea5, 0x1ea5, 6.76), (0x1ea6, 0x1ea6, 8.03), (0x1ea7, 0x1ea7, 6.4),
+    (0x1ea8, 0x1ea8, 7.94), (0x1ea9, 0x1ea9, 6.26), (0x1eaa, 0x1eaa, 7.94), (0x1eab, 0x1eab, 6.12),
+    (0x1eac, 0x1eac, 7.94), (0x1ead, 0x1ead, 6.12), (0x1eae, 0x1eae, 7.94), (0x1eaf, 0x1eaf, 6.12),
+    (0x1eb0, 0x1eb0, 7.94), (0x1eb1, 0x1eb1, 6.12), (0x1eb2, 0x1eb2, 7.94), (0x1eb3, 0x1eb3, 6.12),
+    (0x1eb4, 0x1eb4, 7.94), (0x1eb5, 0x1eb5, 6.12), (0x1eb6, 0x1eb6, 7.94), (0x1eb7, 0x1eb7, 6.12),
+    (0x1eb8, 0x1eb8, 7.34), (0x1eb9, 0x1eb9, 6.12), (0x1eba, 0x1eba, 7.34), (0x1ebb, 0x1ebb, 6.12),
+    (0x1ebc, 0x1ebc, 7.34), (0x1ebd, 0x1ebd, 6.12), (0x1ebe, 0x1ebe, 7.5), (0x1ebf, 0x1ebf, 6.17),
+    (0x1ec0, 0x1ec0, 7.34), (0x1ec1, 0x1ec1, 6.37), (0x1ec2, 0x1ec2, 7.34), (0x1ec3, 0x1ec3, 6.12),
+    (0x1ec4, 0x1ec4, 7.34), (0x1ec5, 0x1ec5, 6.12), (0x1ec6, 0x1ec6, 7.34), (0x1ec7, 0x1ec7, 6.12),
+    (0x1ec8, 0x1ecb, 3.06), (0x1ecc, 0x1ecc, 8.56), (0x1ecd, 0x1ecd, 6.72), (0x1ece, 0x1ece, 8.56),
+    (0x1ecf, 0x1ecf, 6.72), (0x1ed0, 0x1ed0, 8.56), (0x1ed1, 0x1ed1, 6.72), (0x1ed2, 0x1ed2, 8.56),
+    (0x1ed3, 0x1ed3, 6.72), (0x1ed4, 0x1ed4, 8.56), (0x1ed5, 0x1ed5, 6.72), (0x1ed6, 0x1ed6, 8.56),
+    (0x1ed7, 0x1ed7, 6.72), (0x1ed8, 0x1ed8, 8.56), (0x1ed9, 0x1ed9, 6.72), (0x1eda, 0x1eda, 10.41),
+    (0x1edb, 0x1edb, 8.74), (0x1edc, 0x1edc, 10.41), (0x1edd, 0x1edd, 8.74),
+    (0x1ede, 0x1ede, 10.41), (0x1edf, 0x1edf, 8.74), (0x1ee0, 0x1ee0, 10.41),
+    (0x1ee1, 0x1ee1, 8.74), (0x1ee2, 0x1ee2, 10.41), (0x1ee3, 0x1ee3, 8.74), (0x1ee4, 0x1ee4, 7.94),
+    (0x1ee5, 0x1ee5, 6.72), (0x1ee6, 0x1ee6, 7.94), (0x1ee7, 0x1ee7, 6.72), (0x1ee8, 0x1ee8, 10.1),
+    (0x1ee9, 0x1ee9, 8.85), (0x1eea, 0x1eea, 10.1), (0x1eeb, 0x1eeb, 8.85), (0x1eec, 0x1eec, 10.1),
+    (0x1eed, 0x1eed, 8.85), (0x1eee, 0x1eee, 10.1), (0x1eef, 0x1eef, 8.85), (0x1ef0, 0x1ef0, 10.1),
+    (0x1ef1, 0x1ef1, 8.85), (0x1ef2, 0x1ef2, 7.34), (0x1ef3, 0x1ef3, 5.71), (0x1ef4, 0x1ef4, 7.34),
+    (0x1ef5, 0x1ef5, 6.12), (0x1ef6, 0x1ef6, 7.34), (0x1ef7, 0x1ef7, 6.12), (0x1ef8, 0x1ef8, 7.34),
+    (0x1ef9, 0x1ef9, 6.12), (0x1efa, 0x1efa, 10.3), (0x1efb, 0x1efb, 6.48), (0x1efc, 0x1efc, 5.57),
+    (0x1efd, 0x1efd, 4.89), (0x1efe, 0x1efe, 5.04), (0x1f00, 0x1f07, 6.73), (0x1f08, 0x1f08, 8.47),
+    (0x1f09, 0x1f09, 8.57), (0x1f0a, 0x1f0a, 11.59), (0x1f0b, 0x1f0b, 11.15),
+    (0x1f0c, 0x1f0c, 10.38), (0x1f0d, 0x1f0d, 10.91), (0x1f0e, 0x1f0f, 10.38),
+    (0x1f10, 0x1f15, 6.12), (0x1f16, 0x1f17, 5.5), (0x1f18, 0x1f19, 9.78), (0x1f1a, 0x1f1a, 12.1),
+    (0x1f1b, 0x1f1b, 12.06), (0x1f1c, 0x1f1c, 11.8), (0x1f1d, 0x1f1d, 12.03), (0x1f1e, 0x1f1f, 5.5),
+    (0x1f20, 0x1f27, 6.71), (0x1f28, 0x1f29, 10.38), (0x1f2a, 0x1f2a, 12.7),
+    (0x1f2b, 0x1f2b, 12.66), (0x1f2c, 0x1f2c, 12.41), (0x1f2d, 0x1f2d, 12.64),
+    (0x1f2e, 0x1f2f, 10.38), (0x1f30, 0x1f37, 3.06), (0x1f38, 0x1f39, 5.7), (0x1f3a, 0x1f3a, 8.02),
+    (0x1f3b, 0x1f3b, 7.97), (0x1f3c, 0x1f3c, 7.72), (0x1f3d, 0x1f3d, 7.95), (0x1f3e, 0x1f3f, 5.7),
+    (0x1f40, 0x1f45, 6.73), (0x1f46, 0x1f47, 5.5), (0x1f48, 0x1f49, 11.0), (0x1f4a, 0x1f4a, 13.31),
+    (0x1f4b, 0x1f4b, 13.28), (0x1f4c, 0x1f4c, 13.02), (0x1f4d, 0x1f4d, 13.25),
+    (0x1f4e, 0x1f4f, 5.5), (0x1f50, 0x1f57, 6.71), (0x1f58, 0x1f58, 5.5), (0x1f59, 0x1f59, 9.78),
+    (0x1f5a, 0x1f5a, 5.5), (0x1f5b, 0x1f5b, 12.06), (0x1f5c, 0x1f5c, 5.5), (0x1f5d, 0x1f5d, 12.03),
+    (0x1f5e, 0x1f5e, 5.5), (0x1f5f, 0x1f5f, 10.41), (0x1f60, 0x1f67, 7.94), (0x1f68, 0x1f69, 10.89),
+    (0x1f6a, 0x1f6a, 13.21), (0x1f6b, 0x1f6b, 13.17), (0x1f6c, 0x1f6c, 12.91),
+    (0x1f6d, 0x1f6d, 13.14), (0x1f6e, 0x1f6f, 10.89), (0x1f70, 0x1f71, 6.73),
+    (0x1f72, 0x1f73, 6.12), (0x1f74, 0x1f75, 6.71), (0x1f76, 0x1f77, 3.06), (0x1f78, 0x1f79, 6.73),
+    (0x1f7a, 0x1f7b, 6.71), (0x1f7c, 0x1f7d, 7.94), (0x1f7e, 0x1f7f, 5.5), (0x1f80, 0x1f87, 6.73),
+    (0x1f88, 0x1f88, 11.82), (0x1f89, 0x1f89, 11.65), (0x1f8a, 0x1f8a, 14.37),
+    (0x1f8b, 0x1f8b, 14.42), (0x1f8c, 0x1f8c, 13.86), (0x1f8d, 0x1f8d, 14.39),
+    (0x1f8e, 0x1f8f, 13.44), (0x1f90, 0x1f97, 6.71), (0x1f98, 0x1f99, 13.44),
+    (0x1f9a, 0x1f9a, 15.76), (0x1f9b, 0x1f9b, 15.72), (0x1f9c, 0x1f9c, 15.47),
+    (0x1f9d, 0x1f9d, 15.7), (0x1f9e, 0x1f9f, 13.44), (0x1fa0, 0x1fa7, 7.94),
+    (0x1fa8, 0x1fa9, 13.95), (0x1faa, 0x1faa, 16.27), (0x1fab, 0x1fab, 16.22),
+    (0x1fac, 0x1fac, 15.97), (0x1fad, 0x1fad, 16.2), (0x1fae, 0x1faf, 13.95),
+    (0x1fb0, 0x1fb4, 6.73), (0x1fb5, 0x1fb5, 5.5), (0x1fb6, 0x1fb7, 6.73), (0x1fb8, 0x1fb9, 7.94),
+    (0x1fba, 0x1fbb, 9.35), (0x1fbc, 0x1fbc, 10.78), (0x1fbd, 0x1fbd, 2.44), (0x1fbe, 0x1fbe, 3.82),
+    (0x1fbf, 0x1fbf, 2.44), (0x1fc0, 0x1fc1, 2.94), (0x1fc2, 0x1fc4, 6.71), (0x1fc5, 0x1fc5, 5.5),
+    (0x1fc6, 0x1fc7, 6.71), (0x1fc8, 0x1fc8, 10.15), (0x1fc9, 0x1fc9, 10.33),
+    (0x1fca, 0x1fca, 10.83), (0x1fcb, 0x1fcb, 10.71), (0x1fcc, 0x1fcc, 11.03),
+    (0x1fcd, 0x1fcd, 4.76), (0x1fce, 0x1fce, 4.47), (0x1fcf, 0x1fcf, 2.44), (0x1fd0, 0x1fd3, 3.06),
+    (0x1fd4, 0x1fd5, 5.5), (0x1fd6, 0x1fd7, 3.06), (0x1fd8, 0x1fd9, 3.26), (0x1fda, 0x1fda, 5.82),
+    (0x1fdb, 0x1fdb, 5.95), (0x1fdc, 0x1fdc, 5.5), (0x1fdd, 0x1fdd, 4.72), (0x1fde, 0x1fde, 4.7),
+    (0x1fdf, 0x1fdf, 2.44), (0x1fe0, 0x1fe3, 6.71), (0x1fe4, 0x1fe5, 6.72), (0x1fe6, 0x1fe7, 6.71),
+    (0x1fe8, 0x1fe9, 7.34), (0x1fea, 0x1fea, 10.52), (0x1feb, 0x1feb, 10.44),
+    (0x1fec, 0x1fec, 9.78), (0x1fed, 0x1fee, 4.34), (0x1fef, 0x1fef, 2.94), (0x1ff0, 0x1ff1, 5.5),
+    (0x1ff2, 0x1ff4, 7.94), (0x1ff5, 0x1ff5, 5.5), (0x1ff6, 0x1ff7, 7.94), (0x1ff8, 0x1ff8, 10.94),
+    (0x1ff9, 0x1ff9, 11.23), (0x1ffa, 0x1ffa, 11.54), (0x1ffb, 0x1ffb, 11.82),
+    (0x1ffc, 0x1ffc, 11.44), (0x1ffd, 0x1ffd, 2.94), (0x1ffe, 0x1ffe, 2.44), (0x2000, 0x2000, 5.5),
+    (0x2001, 0x2001, 11.0), (0x2002, 0x2002, 3.06), (0x2003, 0x2003, 9.16), (0x2004, 0x2004, 3.66),
+    (0x2005, 0x2005, 3.06), (0x2006, 0x2006, 1.84), (0x2007, 0x2007, 6.12), (0x2008, 0x2008, 1.15),
+    (0x2009, 0x2009, 0.48), (0x200a, 0x200a, 0.16), (0x200b, 0x200f, 0.0), (0x2010, 0x2010, 4.48),
+    (0x2011, 0x2012, 3.66), (0x2013, 0x2013, 5.5), (0x2014, 0x2015, 11.0), (0x2016, 0x2016, 5.82),
+    (0x2017, 0x2017, 3.66), (0x2018, 0x201b, 3.06), (0x201c, 0x201e, 5.09), (0x201f, 0x201f, 5.5),
+    (0x2020, 0x2021, 6.12), (0x2022, 0x2022, 3.85), (0x2023, 0x2023, 7.33), (0x2024, 0x2024, 3.64),
+    (0x2025, 0x2025, 7.41), (0x2026, 0x2026, 11.0), (0x2027, 0x2027, 3.64), (0x2028, 0x202f, 0.0),
+    (0x2030, 0x2030, 12.62), (0x2031, 0x2031, 15.65), (0x2032, 0x2032, 3.66),
+    (0x2033, 0x2033, 6.71), (0x2034, 0x2034, 9.47), (0x2035, 0x2035, 3.66), (0x2036, 0x2036, 6.71),
+    (0x2037, 0x2037, 9.47), (0x2038, 0x2038, 3.66), (0x2039, 0x203a, 2.65), (0x203b, 0x203b, 8.92),
+    (0x203c, 0x203c, 7.33), (0x203d, 0x203d, 6.12), (0x203e, 0x203e, 3.66), (0x203f, 0x2040, 10.55),
+    (0x2041, 0x2041, 6.83), (0x2042, 0x2042, 9.31), (0x2043, 0x2043, 3.66), (0x2044, 0x2044, 1.84),
+    (0x2045, 0x2046, 3.66), (0x2047, 0x2047, 13.44), (0x2048, 0x2049, 10.38),
+    (0x204a, 0x204a, 6.83), (0x204b, 0x204d, 6.12), (0x204e, 0x204e, 4.28), (0x204f, 0x204f, 3.66),
+    (0x2050, 0x2050, 10.55), (0x2051, 0x2051, 4.28), (0x2052, 0x2052, 9.78), (0x2053, 0x2053, 9.64),
+    (0x2054, 0x2054, 10.55), (0x2055, 0x2056, 8.92), (0x2057, 0x2057, 12.18),
+    (0x2058, 0x2059, 8.92), (0x205a, 0x205a, 3.66), (0x205b, 0x205c, 8.92), (0x205d, 0x205e, 3.66),
+    (0x205f, 0x205f, 3.06), (0x2060, 0x206e, 0.0), (0x2070, 0x2070, 4.29), (0x2071, 0x2071, 1.53),
+    (0x2072, 0x2073, 5.5), (0x2074, 0x2079, 4.29), (0x207a, 0x207a, 4.4), (0x207b, 0x207b, 2.97),
+    (0x207c, 0x207c, 4.4), (0x207d, 0x207e, 2.47), (0x207f, 0x2089, 4.29), (0x208a, 0x208a, 4.4),
+    (0x208b, 0x208b, 2.97), (0x208c, 0x208c, 4.4), (0x208d, 0x208e, 2.47), (0x208f, 0x208f, 5.5),
+    (0x2090, 0x2091, 3.06), (0x2092, 0x2092, 3.37), (0x2093, 0x2094, 3.06), (0x2095, 0x209e, 5.5),
+    (0x20a0, 0x20a0, 6.01), (0x20a1, 0x20a2, 7.94), (0x20a3, 0x20a3, 6.12), (0x20a4, 0x20a4, 7.14),
+    (0x20a5, 0x20a5, 9.78), (0x20a6, 0x20a6, 8.9), (0x20a7, 0x20a7, 8.45), (0x20a8, 0x20a8, 6.92),
+    (0x20a9, 0x20a9, 11.26), (0x20aa, 0x20aa, 8.34), (0x20ab, 0x20ab, 6.72), (0x20ac, 0x20ac, 6.12),
+    (0x20ad, 0x20ad, 8.6), (0x20ae, 0x20ae, 6.72), (0x20af, 0x20af, 12.29), (0x20b0, 0x20b0, 5.5),
+    (0x20b1, 0x20b1, 8.57), (0x20b2, 0x20b2, 8.56), (0x20b3, 0x20b3, 7.94), (0x20b4, 0x20b4, 7.01),
+    (0x20b5, 0x20b5, 7.94), (0x20b6, 0x20b6, 7.44), (0x20b7, 0x20b7, 10.33), (0x20b8, 0x20b8, 6.12),
+    (0x20b9, 0x20b9, 6.08), (0x20ba, 0x20ba, 6.12), (0x20bb, 0x20bb, 10.82), (0x20bc, 0x20bd, 6.12),
+    (0x20be, 0x20be, 8.28), (0x20bf, 0x20bf, 7.22), (0x20c0, 0x20ce, 5.5), (0x20d0, 0x20d2, 0.0),
+    (0x20d3, 0x20d3, 6.6), (0x20d4, 0x20d5, 7.7), (0x20d6, 0x20d8, 0.0), (0x20d9, 0x20da, 6.6),
+    (0x20db, 0x20dd, 0.0), (0x20de, 0x20de, 11.0), (0x20df, 0x20df, 9.51), (0x20e0, 0x20e0, 9.9),
+    (0x20e1, 0x20e1, 0.0), (0x20e2, 0x20e2, 5.5), (0x20e3, 0x20f0, 0.0), (0x20f1, 0x20fe, 5.5),
+    (0x2100, 0x2101, 9.17), (0x2102, 0x2102, 7.94), (0x2103, 0x2103, 12.34), (0x2104, 0x2104, 7.78),
+    (0x2105, 0x2106, 9.17), (0x2107, 0x2107, 7.69), (0x2108, 0x2108, 6.98), (0x2109, 0x2109, 11.12),
+    (0x210a, 0x210a, 11.0), (0x210b, 0x210b, 9.96), (0x210c, 0x210c, 9.27), (0x210d, 0x210d, 7.94),
+    (0x210e, 0x210e, 6.34), (0x210f, 0x210f, 7.11), (0x2110, 0x2110, 7.57), (0x2111, 0x2111, 8.69),
+    (0x2112, 0x2112, 7.79), (0x2113, 0x2113, 5.5), (0x2114, 0x2114, 8.56), (0x2115, 0x2115, 6.62),
+    (0x2116, 0x2116, 11.73), (0x2117, 0x2117, 8.8), (0x2118, 0x2118, 9.35), (0x2119, 0x2119, 7.34),
+    (0x211a, 0x211a, 8.56), (0x211b, 0x211b, 10.19), (0x211c, 0x211c, 9.72), (0x211d, 0x211d, 6.62),
+    (0x211e, 0x211e, 7.51), (0x211f, 0x211f, 7.34), (0x2120, 0x2120, 8.25), (0x2121, 0x2121, 10.38),
+    (0x2122, 0x2122, 11.0), (0x2123, 0x2123, 7.94), (0x2124, 0x2124, 6.62), (0x2125, 0x2125, 5.96),
+    (0x2126, 0x2126, 8.5), (0x2127, 0x2127, 8.45), (0x2128, 0x2128, 8.3), (0x2129, 0x2129, 3.06),
+    (0x212a, 0x212b, 7.94), (0x212c, 0x212c, 10.19), (0x212d, 0x212d, 8.5), (0x212e, 0x212e, 8.47),
+    (0x212f, 0x212f, 4.23), (0x2130, 0x2130, 6.77), (0x2131, 0x2131, 7.57), (0x2132, 0x2132, 6.72),
+    (0x2133, 0x2133, 12.27), (0x2134, 0x2134, 4.46), (0x2135, 0x2135, 7.21), (0x2136, 0x2136, 6.64),
+    (0x2137, 0x2137, 5.11), (0x2138, 0x2138, 6.18), (0x2139, 0x2139, 7.26), (0x213a, 0x213a, 8.56),
+    (0x213b, 0x213b, 11.0), (0x213c, 0x213c, 8.84), (0x213d, 0x213d, 7.11), (0x213e, 0x213e, 5.47),
+    (0x213f, 0x213f, 8.45), (0x2140, 0x2140, 10.74), (0x2141, 0x2141, 8.56), (0x2142, 0x2143, 6.72),
+    (0x2144, 0x2144, 7.34), (0x2145, 0x2145, 8.88), (0x2146, 0x2146, 7.63), (0x2147, 0x2147, 6.74),
+    (0x2148, 0x2148, 4.52), (0x2149, 0x2149, 4.65), (0x214a, 0x214a, 7.43), (0x214b, 0x214b, 7.94),
+    (0x214c, 0x214c, 8.91), (0x214d, 0x214d, 9.44), (0x214e, 0x214e, 4.96), (0x2150, 0x2152, 11.0),
+    (0x2153, 0x2154, 9.17), (0x2155, 0x215a, 10.45), (0x215b, 0x215f, 9.17), (0x2160, 0x2160, 3.06),
+    (0x2161, 0x2161, 6.12), (0x2162, 0x2162, 9.16), (0x2163, 0x2163, 10.39), (0x2164, 0x2164, 7.34),
+    (0x2165, 0x2165, 10.39), (0x2166, 0x2166, 13.45), (0x2167, 0x2167, 16.5),
+    (0x2168, 0x2168, 10.39), (0x2169, 0x2169, 7.34), (0x216a, 0x216a, 10.39),
+    (0x216b, 0x216b, 13.45), (0x216c, 0x216c, 6.72), (0x216d, 0x216e, 7.94), (0x216f, 0x216f, 9.16),
+    (0x2170, 0x2170, 3.06), (0x2171, 0x2171, 6.12), (0x2172, 0x2172, 9.16), (0x2173, 0x2173, 9.17),
+    (0x2174, 0x2174, 6.12), (0x2175, 0x2175, 9.17), (0x2176, 0x2176, 12.23),
+    (0x2177, 0x2177, 15.29), (0x2178, 0x2178, 9.17), (0x2179, 0x2179, 6.12), (0x217a, 0x217a, 9.17),
+    (0x217b, 0x217b, 12.23), (0x217c, 0x217c, 3.06), (0x217d, 0x217d, 6.12), (0x217e, 0x217e, 6.72),
+    (0x217f, 0x217f, 9.78), (0x2180, 0x2180, 11.9), (0x2181, 0x2181, 8.72), (0x2182, 0x2182, 13.87),
+    (0x2183, 0x2183, 7.94), (0x2184, 0x2184, 6.12), (0x2185, 0x2185, 7.17), (0x2186, 0x2186, 6.21),
+    (0x2187, 0x2188, 5.5), (0x2189, 0x2189, 11.0), (0x218a, 0x218e, 5.5), (0x2190, 0x2190, 11.0),
+    (0x2191, 0x2191, 5.5), (0x2192, 0x2192, 11.0), (0x2193, 0x2193, 5.5), (0x2194, 0x2194, 11.0),
+    (0x2195, 0x2195, 5.5), (0x2196, 0x2196, 10.07), (0x2197, 0x2197, 11.0), (0x2198, 0x2198, 10.07),
+    (0x2199, 0x219b, 11.0), (0x219c, 0x21a7, 6.62), (0x21a8, 0x21a8, 5.5), (0x21a9, 0x21aa, 11.0),
+    (0x21ab, 0x21ad, 6.62), (0x21ae, 0x21ae, 11.0), (0x21af, 0x21af, 6.62), (0x21b0, 0x21b2, 9.51),
+    (0x21b3, 0x21b3, 11.0), (0x21b4, 0x21b4, 9.51), (0x21b5, 0x21b5, 11.0), (0x21b6, 0x21b7, 9.51),
+    (0x21b8, 0x21b9, 11.0), (0x21ba, 0x21bb, 6.62), (0x21bc, 0x21bc, 9.51), (0x21bd, 0x21bf, 6.62),
+    (0x21c0, 0x21c0, 9.51), (0x21c1, 0x21c3, 6.62), (0x21c4, 0x21c6, 11.0), (0x21c7, 0x21ca, 6.62),
+    (0x21cb, 0x21cc, 11.0), (0x21cd, 0x21cd, 9.68), (0x21ce, 0x21ce, 6.62), (0x21cf, 0x21cf, 9.56),
+    (0x21d0, 0x21d0, 11.0), (0x21d1, 0x21d1, 9.51), (0x21d2, 0x21d2, 11.0), (0x21d3, 0x21d3, 9.51),
+    (0x21d4, 0x21d4, 11.0), (0x21d5, 0x21dd, 6.62), (0x21de, 0x21df, 7.95), (0x21e0, 0x21e0, 11.0),
+    (0x21e1, 0x21e1, 7.95), (0x21e2, 0x21e2, 11.0), (0x21e3, 0x21e3, 7.95), (0x21e4, 0x21e6, 11.0),
+    (0x21e7, 0x21e7, 12.99), (0x21e8, 0x21e9, 11.0), (0x21ea, 0x21ea, 12.99),
+    (0x21eb, 0x21ef, 6.62), (0x21f0, 0x21f0, 9.51), (0x21f1, 0x21f4, 6.62), (0x21f5, 0x21f5, 11.0),
+    (0x21f6, 0x21fe, 6.62), (0x2200, 0x2200, 6.93), (0x2201, 0x2201, 5.16), (0x2202, 0x2202, 6.16),
+    (0x2203, 0x2203, 5.78), (0x2204, 0x2204, 5.88), (0x2205, 0x2205, 7.89), (0x2206, 0x2206, 7.55),
+    (0x2207, 0x2207, 6.73), (0x2208, 0x2208, 6.34), (0x2209, 0x2209, 6.73), (0x220a, 0x220a, 5.6),
+    (0x220b, 0x220b, 6.34), (0x220c, 0x220c, 6.48), (0x220d, 0x220d, 5.6), (0x220e, 0x220e, 5.74),
+    (0x220f, 0x220f, 8.24), (0x2210, 0x2210, 9.05), (0x2211, 0x2211, 6.8), (0x2212, 0x2212, 6.6),
+    (0x2213, 0x2213, 8.75), (0x2214, 0x2214, 6.58), (0x2215, 0x2215, 1.84), (0x2216, 0x2216, 8.84),
+    (0x2217, 0x2217, 5.76), (0x2218, 0x2218, 3.9), (0x2219, 0x2219, 3.06), (0x221a, 0x221a, 6.21),
+    (0x221b, 0x221b, 8.23), (0x221c, 0x221c, 7.97), (0x221d, 0x221d, 5.7), (0x221e, 0x221e, 9.05),
+    (0x221f, 0x221f, 10.77), (0x2220, 0x2220, 6.57), (0x2221, 0x2221, 6.61), (0x2222, 0x2222, 6.66),
+    (0x2223, 0x2223, 2.74), (0x2224, 0x2224, 5.2), (0x2225, 0x2225, 3.8), (0x2226, 0x2226, 5.78),
+    (0x2227, 0x2228, 6.53), (0x2229, 0x2229, 7.91), (0x222a, 0x222a, 5.84), (0x222b, 0x222b, 4.59),
+    (0x222c, 0x222c, 10.06), (0x222d, 0x222d, 12.36), (0x222e, 0x222e, 7.46),
+    (0x222f, 0x222f, 10.16), (0x2230, 0x2230, 12.83), (0x2231, 0x2231, 6.58),
+    (0x2232, 0x2232, 6.91), (0x2233, 0x2233, 6.57), (0x2234, 0x2234, 6.64), (0x2235, 0x2235, 6.57),
+    (0x2236, 0x2236, 3.7), (0x2237, 0x2237, 6.33), (0x2238, 0x2238, 6.19), (0x2239, 0x2239, 6.33),
+    (0x223a, 0x223a, 6.38), (0x223b, 0x223b, 6.16), (0x223c, 0x223d, 6.05), (0x223e, 0x223e, 5.97),
+    (0x223f, 0x223f, 6.42), (0x2240, 0x2240, 4.29), (0x2241, 0x2241, 6.32), (0x2242, 0x2242, 6.38),
+    (0x2243, 0x2243, 6.43), (0x2244, 0x2244, 7.38), (0x2245, 0x2245, 7.66), (0x2246, 0x2246, 6.92),
+    (0x2247, 0x2247, 6.72), (0x2248, 0x2249, 6.6), (0x224a, 0x224a, 6.64), (0x224b, 0x224b, 7.38),
+    (0x224c, 0x224c, 5.97), (0x224d, 0x224d, 6.63), (0x224e, 0x224e, 6.57), (0x224f, 0x224f, 6.52),
+    (0x2250, 0x2250, 6.16), (0x2251, 0x2251, 7.02), (0x2252, 0x2253, 6.96), (0x2254, 0x2255, 8.4),
+    (0x2256, 0x2256, 6.36), (0x2257, 0x2257, 6.55), (0x2258, 0x2258, 6.48), (0x2259, 0x2259, 6.69),
+    (0x225a, 0x225a, 6.81), (0x225b, 0x225b, 7.11), (0x225c, 0x225c, 7.0), (0x225d, 0x225d, 6.19),
+    (0x225e, 0x225e, 6.32), (0x225f, 0x225f, 6.49), (0x2260, 0x2260, 6.6), (0x2261, 0x2262, 6.27),
+    (0x2263, 0x2263, 6.52), (0x2264, 0x2265, 6.6), (0x2266, 0x2266, 6.32), (0x2267, 0x2267, 6.2),
+    (0x2268, 0x2268, 6.84), (0x2269, 0x2269, 6.48), (0x226a, 0x226a, 8.07), (0x226b, 0x226b, 7.71),
+    (0x226c, 0x226c, 3.94), (0x226d, 0x226d, 6.09), (0x226e, 0x2271, 6.6), (0x2272, 0x2272, 6.2),
+    (0x2273, 0x2273, 6.24), (0x2274, 0x2274, 6.41), (0x2275, 0x2275, 6.39), (0x2276, 0x2277, 6.66),
+    (0x2278, 0x2278, 6.17), (0x2279, 0x2279, 6.34), (0x227a, 0x227a, 6.24), (0x227b, 0x227b, 6.36),
+    (0x227c, 0x227c, 6.56), (0x227d, 0x227d, 6.36), (0x227e, 0x227e, 6.44), (0x227f, 0x227f, 6.4),
+    (0x2280, 0x2280, 6.68), (0x2281, 0x2281, 6.36), (0x2282, 0x2283, 6.95), (0x2284, 0x2284, 7.08),
+    (0x2285, 0x2285, 6.94), (0x2286, 0x2287, 7.29), (0x2288, 0x2288, 7.34), (0x2289, 0x2289, 7.14),
+    (0x228a, 0x228b, 6.18), (0x228c, 0x228d, 6.4), (0x228e, 0x228e, 6.7), (0x228f, 0x2290, 7.19),
+    (0x2291, 0x2292, 7.17), (0x2293, 0x2294, 6.72), (0x2295, 0x2295, 7.96), (0x2296, 0x2296, 8.2),
+    (0x2297, 0x2297, 7.83), (0x2298, 0x2298, 7.94), (0x2299, 0x2299, 8.32), (0x229a, 0x229a, 8.09),
+    (0x229b, 0x229b, 7.9), (0x229c, 0x229c, 8.37), (0x229d, 0x229d, 8.31), (0x229e, 0x229e, 8.62),
+    (0x229f, 0x229f, 8.36), (0x22a0, 0x22a0, 8.06), (0x22a1, 0x22a1, 7.87), (0x22a2, 0x22a5, 8.26),
+    (0x22a6, 0x22a6, 3.63), (0x22a7, 0x22a7, 3.67), (0x22a8, 0x22a8, 6.86), (0x22a9, 0x22a9, 8.02),
+    (0x22aa, 0x22aa, 6.85), (0x22ab, 0x22ab, 8.31), (0x22ac, 0x22ac, 8.53), (0x22ad, 0x22ad, 8.56),
+    (0x22ae, 0x22ae, 9.61), (0x22af, 0x22af, 9.2), (0x22b0, 0x22b1, 6.96), (0x22b2, 0x22b3, 8.15),
+    (0x22b4, 0x22b5, 7.73), (0x22b6, 0x22b7, 8.08), (0x22b8, 0x22b8, 6.55), (0x22b9, 0x22b9, 7.51),
+    (0x22ba, 0x22ba, 5.28), (0x22bb, 0x22bc, 7.66), (0x22bd, 0x22bd, 7.42), (0x22be, 0x22be, 8.16),
+    (0x22bf, 0x22bf, 6.94), (0x22c0, 0x22c1, 7.38), (0x22c2, 0x22c3, 7.05), (0x22c4, 0x22c4, 4.19),
+    (0x22c5, 0x22c5, 4.85), (0x22c6, 0x22c6, 4.93), (0x22c7, 0x22c7, 6.94), (0x22c8, 0x22c8, 8.5),
+    (0x22c9, 0x22ca, 8.14), (0x22cb, 0x22cc, 7.96), (0x22cd, 0x22cd, 6.43), (0x22ce, 0x22cf, 6.98),
+    (0x22d0, 0x22d1, 7.13), (0x22d2, 0x22d3, 6.26), (0x22d4, 0x22d4, 6.04), (0x22d5, 0x22d5, 6.75),
+    (0x22d6, 0x22d7, 6.63), (0x22d8, 0x22d9, 10.65), (0x22da, 0x22db, 7.16), (0x22dc, 0x22dd, 7.11),
+    (0x22de, 0x22df, 7.36), (0x22e0, 0x22e0, 7.33), (0x22e1, 0x22e1, 7.21), (0x22e2, 0x22e2, 7.55),
+    (0x22e3, 0x22e3, 7.3), (0x22e4, 0x22e4, 7.38), (0x22e5, 0x22e5, 7.41), (0x22e6, 0x22e6, 7.44),
+    (0x22e7, 0x22e7, 7.55), (0x22e8, 0x22e8, 7.91), (0x22e9, 0x22e9, 7.57), (0x22ea, 0x22ea, 8.23),
+    (0x22eb, 0x22eb, 8.42), (0x22ec, 0x22ec, 8.49), (0x22ed, 0x22ed, 8.54), (0x22ee, 0x22ee, 4.52),
+    (0x22ef, 0x22ef, 11.0), (0x22f0, 0x22f0, 10.12), (0x22f1, 0x22f1, 10.33),
+    (0x22f2, 0x22f2, 7.15), (0x22f3, 0x22f3, 6.34), (0x22f4, 0x22f4, 5.6), (0x22f5, 0x22f6, 6.34),
+    (0x22f7, 0x22f7, 5.6), (0x22f8, 0x22f8, 6.34), (0x22f9, 0x22f9, 6.95), (0x22fa, 0x22fa, 7.15),
+    (0x22fb, 0x22fb, 6.34), (0x22fc, 0x22fc, 5.6), (0x22fd, 0x22fd, 6.34), (0x22fe, 0x22fe, 5.6),
+    (0x2300, 0x2301, 6.62), (0x2302, 0x2302, 6.6), (0x2303, 0x2303, 10.21), (0x2304, 0x2304, 6.62),
+    (0x2305, 0x2305, 8.74), (0x2306, 0x2306, 7.53), (0x2307, 0x2307, 11.0), (0x2308, 0x230f, 6.62),
+    (0x2310, 0x2310, 6.27), (0x2311, 0x2311, 6.62), (0x2312, 0x2312, 11.0), (0x2313, 0x2313, 6.62),
+    (0x2314, 0x2314, 9.51), (0x2315, 0x2315, 6.62), (0x2316, 0x2316, 6.04), (0x2317, 0x2317, 7.15),
+    (0x2318, 0x2318, 10.21), (0x2319, 0x2319, 6.62), (0x231a, 0x231b, 11.0), (0x231c, 0x231f, 6.62),
+    (0x2320, 0x2321, 6.64), (0x2322, 0x2323, 9.51), (0x2324, 0x2324, 8.74), (0x2325, 0x2325, 11.0),
+    (0x2326, 0x2326, 14.38), (0x2327, 0x2327, 11.0), (0x2328, 0x2328, 6.62), (0x2329, 0x232a, 11.0),
+    (0x232b, 0x232b, 14.38), (0x232c, 0x2334, 6.04), (0x2335, 0x2335, 6.62),
+    (0x2336, 0x2336, 10.19), (0x2337, 0x233c, 6.62), (0x233d, 0x233d, 11.0), (0x233e, 0x233e, 6.62),
+    (0x233f, 0x233f, 8.01), (0x2340, 0x2340, 6.6), (0x2341, 0x2344, 6.62), (0x2345, 0x2346, 6.6),
+    (0x2347, 0x2349, 6.62), (0x234a, 0x234a, 6.6), (0x234b, 0x234d, 6.62), (0x234e, 0x234f, 6.6),
+    (0x2350, 0x2350, 6.62), (0x2351, 0x2351, 6.6), (0x2352, 0x2354, 6.62), (0x2355, 0x2356, 6.6),
+    (0x2357, 0x235c, 6.62), (0x235d, 0x235d, 6.6), (0x235e, 0x2360, 6.62), (0x2361, 0x2362, 6.6),
+    (0x2363, 0x2365, 6.62), (0x2366, 0x2367, 6.6), (0x2368, 0x2369, 6.62), (0x236a, 0x236a, 6.6),
+    (0x236b, 0x2370, 6.62), (0x2371, 0x2372, 6.6), (0x2373, 0x237a, 6.62), (0x237b, 0x237b, 7.36),
+    (0x237c, 0x237c, 10.3), (0x237d, 0x237d, 3.51), (0x237e, 0x237e, 10.33), (0x237f, 0x237f, 4.08),
+    (0x2380, 0x2383, 6.62), (0x2384, 0x2384, 8.63), (0x2385, 0x2385, 7.52), (0x2386, 0x2386, 7.42),
+    (0x2387, 0x2388, 11.0), (0x2389, 0x238a, 6.62), (0x238b, 0x238b, 9.81), (0x238c, 0x238c, 8.88),
+    (0x238d, 0x238d, 8.65), (0x238e, 0x238e, 8.22), (0x238f, 0x2393, 7.24), (0x2394, 0x2394, 8.17),
+    (0x2395, 0x2395, 6.62), (0x2396, 0x2396, 5.19), (0x2397, 0x2398, 8.67), (0x2399, 0x239a, 8.97),
+    (0x239b, 0x23ad, 11.0), (0x23ae, 0x23ae, 6.62), (0x23af, 0x23af, 3.46), (0x23b0, 0x23b1, 11.0),
+    (0x23b2, 0x23b3, 16.37), (0x23b4, 0x23b5, 6.77), (0x23b6, 0x23b6, 7.96), (0x23b7, 0x23b7, 7.07),
+    (0x23b8, 0x23bd, 5.54), (0x23be, 0x23cc, 11.0), (0x23cd, 0x23cd, 10.97),
+    (0x23ce, 0x23ce, 12.03), (0x23cf, 0x23cf, 9.43), (0x23d0, 0x23d0, 5.62), (0x23d1, 0x23d1, 5.96),
+    (0x23d2, 0x23d3, 5.97), (0x23d4, 0x23d5, 11.34), (0x23d6, 0x23d6, 10.54),
+    (0x23d7, 0x23d9, 10.73), (0x23da, 0x23db, 11.0), (0x23dc, 0x23df, 6.79), (0x23e0, 0x23e0, 6.77),
+    (0x23e1, 0x23e1, 11.0), (0x23e2, 0x23e2, 12.58), (0x23e3, 0x23e3, 10.01),
+    (0x23e4, 0x23e4, 12.03), (0x23e5, 0x23e5, 11.2), (0x23e6, 0x23e6, 10.19),
+    (0x23e7, 0x23e7, 8.78), (0x23e8, 0x23e8, 5.5), (0x23e9, 0x23f3, 11.0), (0x23f4, 0x23f7, 5.5),
+    (0x23f8, 0x23fa, 11.0), (0x23fb, 0x23fe, 5.5), (0x2400, 0x241f, 11.0), (0x2420, 0x2420, 6.6),
+    (0x2421, 0x2421, 11.0), (0x2422, 0x2422, 6.6), (0x2423, 0x2423, 6.63), (0x2424, 0x2424, 6.6),
+    (0x2425, 0x2425, 8.71), (0x2426, 0x2426, 4.44), (0x2427, 0x243e, 5.5), (0x2440, 0x2445, 6.64),
+    (0x2446, 0x2449, 8.25), (0x244a, 0x244a, 6.64), (0x244b, 0x245e, 5.5), (0x2460, 0x24c4, 11.0),
+    (0x24c5, 0x24c5, 9.24), (0x24c6, 0x24fe, 11.0), (0x2500, 0x251b, 11.0), (0x251c, 0x251c, 7.79),
+    (0x251d, 0x2523, 11.0), (0x2524, 0x2524, 7.79), (0x2525, 0x252b, 11.0), (0x252c, 0x252c, 7.79),
+    (0x252d, 0x2533, 11.0), (0x2534, 0x2534, 7.79), (0x2535, 0x253b, 11.0), (0x253c, 0x253c, 7.79),
+    (0x253d, 0x254b, 11.0), (0x254c, 0x254f, 6.8), (0x2550, 0x256c, 7.79), (0x256d, 0x2574, 11.0),
+    (0x2575, 0x257e, 6.8), (0x2580, 0x2580, 7.79), (0x2581, 0x2583, 11.0), (0x2584, 0x2584, 7.79),
+    (0x2585, 0x258b, 11.0), (0x258c, 0x258c, 7.79), (0x258d, 0x258f, 11.0), (0x2590, 0x2592, 7.79),
+    (0x2593, 0x2593, 8.02), (0x2594, 0x2595, 11.0), (0x2596, 0x259e, 6.62), (0x25a0, 0x25a1, 6.64),
+    (0x25a2, 0x25a2, 11.0), (0x25a3, 0x25a9, 9.51), (0x25aa, 0x25ab, 3.9), (0x25ac, 0x25ac, 11.0),
+    (0x25ad, 0x25ad, 9.51), (0x25ae, 0x25b0, 6.62), (0x25b1, 0x25b1, 11.0), (0x25b2, 0x25b2, 10.89),
+    (0x25b3, 0x25b3, 11.0), (0x25b4, 0x25b4, 6.96), (0x25b5, 0x25b5, 9.51), (0x25b6, 0x25b6, 9.68),
+    (0x25b7, 0x25b7, 11.0), (0x25b8, 0x25b9, 5.83), (0x25ba, 0x25ba, 10.89), (0x25bb, 0x25bb, 6.62),
+    (0x25bc, 0x25bc, 10.89), (0x25bd, 0x25bd, 11.0), (0x25be, 0x25bf, 7.03), (0x25c0, 0x25c0, 11.0),
+    (0x25c1, 0x25c1, 8.74), (0x25c2, 0x25c2, 6.62), (0x25c3, 0x25c3, 9.51), (0x25c4, 0x25c4, 10.89),
+    (0x25c5, 0x25c5, 6.62), (0x25c6, 0x25c6, 7.95), (0x25c7, 0x25c7, 6.76), (0x25c8, 0x25c8, 9.51),
+    (0x25c9, 0x25c9, 11.0), (0x25ca, 0x25ca, 6.6), (0x25cb, 0x25cb, 6.64), (0x25cc, 0x25cc, 6.54),
+    (0x25cd, 0x25cd, 6.62), (0x25ce, 0x25ce, 11.0), (0x25cf, 0x25cf, 6.85), (0x25d0, 0x25d3, 11.0),
+    (0x25d4, 0x25d7, 6.62), (0x25d8, 0x25d9, 6.64), (0x25da, 0x25e1, 6.62), (0x25e2, 0x25e5, 11.0),
+    (0x25e6, 0x25e6, 3.9), (0x25e7, 0x25ee, 6.62), (0x25ef, 0x25ef, 11.0), (0x25f0, 0x25fa, 6.62),
+    (0x25fb, 0x25fe, 11.0), (0x2600, 0x2603, 11.0), (0x2604, 0x2604, 6.62), (0x2605, 0x2606, 11.0),
+    (0x2607, 0x2608, 6.62), (0x2609, 0x2609, 11.0), (0x260a, 0x260d, 6.62), (0x260e, 0x260e, 11.0),
+    (0x260f, 0x260f, 9.51), (0x2610, 0x2612, 9.13), (0x2613, 0x2613, 6.62), (0x2614, 0x2617, 11.0),
+    (0x2618, 0x261b, 6.62), (0x261c, 0x261f, 11.0), (0x2620, 0x262e, 6.62), (0x262f, 0x262f, 9.51),
+    (0x2630, 0x2637, 9.9), (0x2638, 0x2638, 6.62), (0x2639, 0x2639, 8.11), (0x263a, 0x263a, 11.23),
+    (0x263b, 0x263b, 8.11), (0x263c, 0x263c, 10.08), (0x263d, 0x263f, 6.62), (0x2640, 0x2640, 8.25),
+    (0x2641, 0x2641, 6.62), (0x2642, 0x2642, 8.25), (0x2643, 0x2647, 6.62), (0x2648, 0x2653, 11.0),
+    (0x2654, 0x265f, 6.62), (0x2660, 0x2660, 5.84), (0x2661, 0x2662, 11.0), (0x2663, 0x2663, 7.22),
+    (0x2664, 0x2664, 11.0), (0x2665, 0x2665, 6.53), (0x2666, 0x2666, 5.61), (0x2667, 0x2669, 11.0),
+    (0x266a, 0x266a, 6.01), (0x266b, 0x266b, 8.25), (0x266c, 0x266c, 11.0), (0x266d, 0x266d, 5.49),
+    (0x266e, 0x266e, 11.0), (0x266f, 0x266f, 6.04), (0x2670, 0x2671, 6.62), (0x2672, 0x267d, 11.0),
+    (0x267e, 0x267e, 6.62), (0x267f, 0x267f, 11.0), (0x2680, 0x268b, 6.62), (0x268c, 0x268c, 9.67),
+    (0x268d, 0x268d, 9.49), (0x268e, 0x268e, 9.45), (0x268f, 0x268f, 9.86), (0x2690, 0x2692, 6.62),
+    (0x2693, 0x2693, 11.0), (0x2694, 0x269c, 6.62), (0x269d, 0x269f, 5.5), (0x26a0, 0x26a1, 11.0),
+    (0x26a2, 0x26a2, 9.81), (0x26a3, 0x26a3, 9.87), (0x26a4, 0x26a4, 9.71), (0x26a5, 0x26a5, 7.26),
+    (0x26a6, 0x26a6, 8.15), (0x26a7, 0x26a7, 10.16), (0x26a8, 0x26a8, 5.86),
+    (0x26a9, 0x26a9, 10.12), (0x26aa, 0x26ab, 11.0), (0x26ac, 0x26ac, 7.1), (0x26ad, 0x26ad, 8.54),
+    (0x26ae, 0x26ae, 11.61), (0x26af, 0x26af, 12.3), (0x26b0, 0x26b1, 6.62), (0x26b2, 0x26b2, 6.63),
+    (0x26b3, 0x26bc, 5.5), (0x26bd, 0x26be, 11.0), (0x26bf, 0x26c3, 5.5), (0x26c4, 0x26c5, 11.0),
+    (0x26c6, 0x26c7, 5.5), (0x26c8, 0x26c8, 11.0), (0x26c9, 0x26ca, 5.5), (0x26cb, 0x26cb, 11.0),
+    (0x26cc, 0x26cd, 5.5), (0x26ce, 0x26cf, 11.0), (0x26d0, 0x26d0, 5.5), (0x26d1, 0x26d1, 11.0),
+    (0x26d2, 0x26d2, 5.5), (0x26d3, 0x26d4, 11.0), (0x26d5, 0x26e1, 5.5), (0x26e2, 0x26e2, 7.7),
+    (0x26e3, 0x26e8, 5.5), (0x26e9, 0x26ea, 11.0), (0x26eb, 0x26ef, 5.5), (0x26f0, 0x26f5, 11.0),
+    (0x26f6, 0x26f6, 5.5), (0x26f7, 0x26fa, 11.0), (0x26fb, 0x26fc, 5.5), (0x26fd, 0x26fd, 11.0),
+    (0x26fe, 0x26fe, 5.5), (0x2700, 0x2700, 5.5), (0x2701, 0x2701, 10.72), (0x2702, 0x2702, 10.57),
+    (0x2703, 0x2703, 10.72), (0x2704, 0x2704, 10.78), (0x2705, 0x2705, 11.0),
+    (0x2706, 0x2706, 8.68), (0x2707, 0x2707, 8.69), (0x2708, 0x2708, 8.7), (0x2709, 0x2709, 7.59),
+    (0x270a, 0x270d, 11.0), (0x270e, 0x270e, 8.72), (0x270f, 0x270f, 10.26),
+    (0x2710, 0x2710, 10.02), (0x2711, 0x2711, 10.39), (0x2712, 0x2712, 10.72),
+    (0x2713, 0x2713, 8.41), (0x2714, 0x2714, 9.31), (0x2715, 0x2715, 8.38), (0x2716, 0x2716, 8.37),
+    (0x2717, 0x2717, 6.28), (0x2718, 0x2718, 7.44), (0x2719, 0x2719, 8.4), (0x271a, 0x271a, 8.36),
+    (0x271b, 0x271b, 8.35), (0x271c, 0x271c, 8.29), (0x271d, 0x271d, 5.44), (0x271e, 0x271e, 6.07),
+    (0x271f, 0x271f, 5.91), (0x2720, 0x2720, 6.35), (0x2721, 0x2721, 7.61), (0x2722, 0x2722, 8.65),
+    (0x2723, 0x2724, 8.67), (0x2725, 0x2725, 8.69), (0x2726, 0x2726, 8.72), (0x2727, 0x2727, 8.73),
+    (0x2728, 0x2728, 11.0), (0x2729, 0x2729, 9.06), (0x272a, 0x272a, 8.68), (0x272b, 0x272b, 9.25),
+    (0x272c, 0x272c, 9.06), (0x272d, 0x272d, 9.16), (0x272e, 0x272e, 8.98), (0x272f, 0x272f, 9.14),
+    (0x2730, 0x2730, 10.15), (0x2731, 0x2731, 8.19), (0x2732, 0x2732, 7.95), (0x2733, 0x2733, 8.24),
+    (0x2734, 0x2734, 8.69), (0x2735, 0x2735, 8.71), (0x2736, 0x2736, 7.64), (0x2737, 0x2737, 8.53),
+    (0x2738, 0x2738, 8.45), (0x2739, 0x2739, 8.71), (0x273a, 0x273a, 8.35), (0x273b, 0x273b, 7.78),
+    (0x273c, 0x273c, 7.79), (0x273d, 0x273d, 7.5), (0x273e, 0x273e, 7.71), (0x273f, 0x273f, 9.09),
+    (0x2740, 0x2740, 8.96), (0x2741, 0x2742, 8.68), (0x2743, 0x2743, 7.78), (0x2744, 0x2744, 7.56),
+    (0x2745, 0x2745, 7.65), (0x2746, 0x2746, 7.58), (0x2747, 0x2747, 8.65), (0x2748, 0x2748, 8.66),
+    (0x2749, 0x2749, 7.84), (0x274a, 0x274a, 8.7), (0x274b, 0x274b, 8.64), (0x274c, 0x274c, 11.0),
+    (0x274d, 0x274d, 9.6), (0x274e, 0x274e, 11.0), (0x274f, 0x2750, 8.38), (0x2751, 0x2752, 8.35),
+    (0x2753, 0x2755, 11.0), (0x2756, 0x2756, 8.63), (0x2757, 0x2757, 11.0), (0x2758, 0x2758, 3.31),
+    (0x2759, 0x2759, 3.05), (0x275a, 0x275a, 4.57), (0x275b, 0x275c, 4.31), (0x275d, 0x275e, 7.35),
+    (0x275f, 0x2760, 5.5), (0x2761, 0x2761, 8.05), (0x2762, 0x2763, 5.98), (0x2764, 0x2764, 10.01),
+    (0x2765, 0x2765, 7.34), (0x2766, 0x2767, 8.36), (0x2768, 0x2769, 4.29), (0x276a, 0x276b, 3.49),
+    (0x276c, 0x276d, 3.03), (0x276e, 0x276f, 5.6), (0x2770, 0x2771, 4.51), (0x2772, 0x2773, 2.57),
+    (0x2774, 0x2775, 3.67), (0x2776, 0x2793, 8.67), (0x2794, 0x2794, 9.83), (0x2795, 0x2797, 11.0),
+    (0x2798, 0x2798, 8.23), (0x2799, 0x2799, 10.16), (0x279a, 0x279a, 8.23), (0x279b, 0x279b, 10.1),
+    (0x279c, 0x279c, 10.19), (0x279d, 0x279e, 10.21), (0x279f, 0x279f, 9.17), (0x27a0, 0x27a0, 9.6),
+    (0x27a1, 0x27a1, 9.11), (0x27a2, 0x27a3, 10.16), (0x27a4, 0x27a4, 10.09),
+    (0x27a5, 0x27a5, 10.23), (0x27a6, 0x27a6, 10.24), (0x27a7, 0x27a7, 5.09),
+    (0x27a8, 0x27a8, 9.71), (0x27a9, 0x27aa, 9.2), (0x27ab, 0x27ac, 9.54), (0x27ad, 0x27ae, 7.65),
+    (0x27af, 0x27af, 9.61), (0x27b0, 0x27b0, 11.0), (0x27b1, 0x27b1, 9.61), (0x27b2, 0x27b2, 8.36),
+    (0x27b3, 0x27b3, 10.4), (0x27b4, 0x27b4, 8.48), (0x27b5, 0x27b5, 9.52), (0x27b6, 0x27b6, 8.48),
+    (0x27b7, 0x27b7, 9.77), (0x27b8, 0x27b8, 10.63), (0x27b9, 0x27b9, 9.77), (0x27ba, 0x27ba, 9.14),
+    (0x27bb, 0x27bb, 9.6), (0x27bc, 0x27bc, 10.19), (0x27bd, 0x27bd, 10.67), (0x27be, 0x27be, 10.1),
+    (0x27c0, 0x27c0, 6.03), (0x27c1, 0x27c1, 10.43), (0x27c2, 0x27c2, 7.58), (0x27c3, 0x27c4, 6.21),
+    (0x27c5, 0x27c6, 6.62), (0x27c7, 0x27c7, 6.82), (0x27c8, 0x27c9, 10.1), (0x27ca, 0x27ca, 3.74),
+    (0x27cb, 0x27cb, 9.22), (0x27cc, 0x27cc, 3.57), (0x27cd, 0x27cd, 9.22), (0x27ce, 0x27cf, 5.5),
+    (0x27d0, 0x27d0, 8.21), (0x27d1, 0x27d1, 6.82), (0x27d2, 0x27d2, 6.57), (0x27d3, 0x27d4, 5.97),
+    (0x27d5, 0x27d6, 9.93), (0x27d7, 0x27d7, 12.26), (0x27d8, 0x27d9, 7.58), (0x27da, 0x27da, 9.57),
+    (0x27db, 0x27db, 9.42), (0x27dc, 0x27dc, 11.6), (0x27dd, 0x27de, 9.33), (0x27df, 0x27df, 4.36),
+    (0x27e0, 0x27e0, 6.06), (0x27e1, 0x27e1, 7.7), (0x27e2, 0x27e3, 9.64), (0x27e4, 0x27e5, 10.41),
+    (0x27e6, 0x27e7, 4.64), (0x27e8, 0x27e9, 4.02), (0x27ea, 0x27eb, 5.1), (0x27ec, 0x27ed, 5.37),
+    (0x27ee, 0x27ee, 2.56), (0x27f0, 0x27f1, 10.8), (0x27f2, 0x27f3, 10.48),
+    (0x27f4, 0x27f4, 13.71), (0x27f5, 0x27fe, 12.97), (0x2800, 0x28fe, 7.52),
+    (0x2900, 0x2901, 10.29), (0x2902, 0x2903, 9.52), (0x2904, 0x2904, 11.39),
+    (0x2905, 0x2905, 10.29), (0x2906, 0x2907, 9.52), (0x2908, 0x2909, 5.25), (0x290a, 0x290b, 7.89),
+    (0x290c, 0x290f, 9.7), (0x2910, 0x2910, 11.47), (0x2911, 0x2911, 9.22), (0x2912, 0x2913, 8.33),
+    (0x2914, 0x2915, 9.51), (0x2916, 0x2918, 11.47), (0x2919, 0x291c, 9.51), (0x291d, 0x2920, 9.7),
+    (0x2921, 0x2922, 8.21), (0x2923, 0x2926, 7.15), (0x2927, 0x2927, 8.57), (0x2928, 0x2928, 8.13),
+    (0x2929, 0x2929, 8.57), (0x292a, 0x292a, 8.13), (0x292b, 0x292c, 7.68), (0x292d, 0x2930, 8.13),
+    (0x2931, 0x2932, 8.57), (0x2933, 0x2933, 9.31), (0x2934, 0x2935, 11.0), (0x2936, 0x2937, 9.51),
+    (0x2938, 0x2938, 4.48), (0x2939, 0x2939, 9.51), (0x293a, 0x293d, 8.89), (0x293e, 0x293f, 7.21),
+    (0x2940, 0x2941, 8.39), (0x2942, 0x2947, 9.22), (0x2948, 0x2948, 10.13), (0x2949, 0x2949, 5.36),
+    (0x294a, 0x294b, 9.22), (0x294c, 0x294d, 5.44), (0x294e, 0x294e, 9.22), (0x294f, 0x294f, 4.76),
+    (0x2950, 0x2950, 9.22), (0x2951, 0x2951, 4.76), (0x2952, 0x2953, 9.49), (0x2954, 0x2955, 4.76),
+    (0x2956, 0x2957, 9.49), (0x2958, 0x2959, 4.76), (0x295a, 0x295b, 9.59), (0x295c, 0x295d, 4.76),
+    (0x295e, 0x295f, 9.59), (0x2960, 0x2961, 4.76), (0x2962, 0x2965, 9.51), (0x2966, 0x296d, 9.22),
+    (0x296e, 0x296f, 7.85), (0x2970, 0x2975, 9.22), (0x2976, 0x2976, 7.95), (0x2977, 0x2977, 11.47),
+    (0x2978, 0x2978, 7.95), (0x2979, 0x2979, 8.41), (0x297a, 0x297a, 10.13), (0x297b, 0x297b, 8.41),
+    (0x297c, 0x297d, 6.12), (0x297e, 0x297e, 6.57), (0x2980, 0x2980, 4.01), (0x2981, 0x2981, 4.18),
+    (0x2982, 0x2982, 3.45), (0x2983, 0x2984, 4.11), (0x2985, 0x2986, 4.92), (0x2987, 0x2988, 3.68),
+    (0x2989, 0x298a, 4.35), (0x298b, 0x298c, 3.31), (0x298d, 0x2990, 3.3), (0x2991, 0x2991, 4.02),
+    (0x2992, 0x2992, 4.04), (0x2993, 0x2994, 7.25), (0x2995, 0x2996, 7.65), (0x2997, 0x2998, 7.13),
+    (0x2999, 0x2999, 2.48), (0x299a, 0x299a, 3.05), (0x299b, 0x299b, 6.53), (0x299c, 0x299d, 6.51),
+    (0x299e, 0x299e, 7.27), (0x299f, 0x299f, 6.03), (0x29a0, 0x29a1, 6.53), (0x29a2, 0x29a5, 6.03),
+    (0x29a6, 0x29a7, 8.28), (0x29a8, 0x29af, 6.53), (0x29b0, 0x29b4, 7.73), (0x29b5, 0x29b5, 9.56),
+    (0x29b6, 0x29b6, 7.7), (0x29b7, 0x29b7, 7.72), (0x29b8, 0x29b8, 7.94), (0x29b9, 0x29b9, 7.72),
+    (0x29ba, 0x29ba, 7.73), (0x29bb, 0x29bb, 7.74), (0x29bc, 0x29c1, 7.72), (0x29c2, 0x29c2, 9.41),
+    (0x29c3, 0x29c3, 9.61), (0x29c4, 0x29c8, 8.22), (0x29c9, 0x29c9, 8.75), (0x29ca, 0x29cc, 8.23),
+    (0x29cd, 0x29cd, 10.38), (0x29ce, 0x29ce, 7.15), (0x29cf, 0x29d0, 8.34), (0x29d1, 0x29d3, 7.56),
+    (0x29d4, 0x29d5, 7.17), (0x29d6, 0x29d7, 8.44), (0x29d8, 0x29d9, 3.05), (0x29da, 0x29db, 4.66),
+    (0x29dc, 0x29dc, 7.01), (0x29dd, 0x29dd, 7.12), (0x29de, 0x29de, 7.01), (0x29df, 0x29df, 7.41),
+    (0x29e0, 0x29e0, 8.22), (0x29e1, 0x29e1, 7.19), (0x29e2, 0x29e2, 7.51), (0x29e3, 0x29e5, 7.3),
+    (0x29e6, 0x29e6, 7.81), (0x29e7, 0x29e7, 6.99), (0x29e8, 0x29e9, 8.23), (0x29ea, 0x29ea, 7.89),
+    (0x29eb, 0x29eb, 5.2), (0x29ec, 0x29ed, 9.89), (0x29ee, 0x29ef, 6.57), (0x29f0, 0x29f1, 7.79),
+    (0x29f2, 0x29f3, 7.72), (0x29f4, 0x29f4, 9.0), (0x29f5, 0x29f5, 4.08), (0x29f6, 0x29f6, 6.34),
+    (0x29f7, 0x29f9, 5.59), (0x29fa, 0x29fb, 7.43), (0x29fc, 0x29fd, 5.91), (0x29fe, 0x29fe, 5.2),
+    (0x2a00, 0x2a01, 9.89), (0x2a02, 0x2a02, 9.92), (0x2a03, 0x2a04, 5.99), (0x2a05, 0x2a06, 6.55),
+    (0x2a07, 0x2a08, 8.43), (0x2a09, 0x2a09, 7.33), (0x2a0a, 0x2a0a, 9.41), (0x2a0b, 0x2a0b, 7.18),
+    (0x2a0c, 0x2a0c, 16.56), (0x2a0d, 0x2a0f, 6.72), (0x2a10, 0x2a10, 6.52), (0x2a11, 0x2a11, 8.01),
+    (0x2a12, 0x2a12, 6.74), (0x2a13, 0x2a13, 6.52), (0x2a14, 0x2a14, 7.42), (0x2a15, 0x2a15, 6.52),
+    (0x2a16, 0x2a16, 6.72), (0x2a17, 0x2a17, 7.43), (0x2a18, 0x2a1a, 6.72), (0x2a1b, 0x2a1b, 7.02),
+    (0x2a1c, 0x2a1c, 6.79), (0x2a1d, 0x2a1d, 7.56), (0x2a1e, 0x2a1e, 4.35), (0x2a1f, 0x2a1f, 3.67),
+    (0x2a20, 0x2a20, 8.92), (0x2a21, 0x2a21, 3.59), (0x2a22, 0x2a23, 5.95), (0x2a24, 0x2a24, 5.99),
+    (0x2a25, 0x2a25, 5.95), (0x2a26, 0x2a26, 5.99), (0x2a27, 0x2a27, 7.12), (0x2a28, 0x2a28, 7.02),
+    (0x2a29, 0x2a2a, 5.99), (0x2a2b, 0x2a2c, 5.97), (0x2a2d, 0x2a2e, 8.37), (0x2a2f, 0x2a2f, 5.04),
+    (0x2a30, 0x2a30, 5.82), (0x2a31, 0x2a31, 5.96), (0x2a32, 0x2a32, 5.74), (0x2a33, 0x2a33, 8.85),
+    (0x2a34, 0x2a35, 7.86), (0x2a36, 0x2a38, 9.89), (0x2a39, 0x2a3b, 8.23), (0x2a3c, 0x2a3d, 5.63),
+    (0x2a3e, 0x2a3e, 3.67), (0x2a3f, 0x2a3f, 6.77), (0x2a40, 0x2a45, 5.99), (0x2a46, 0x2a47, 4.27),
+    (0x2a48, 0x2a49, 5.96), (0x2a4a, 0x2a4b, 5.62), (0x2a4c, 0x2a4d, 8.13), (0x2a4e, 0x2a4f, 6.55),
+    (0x2a50, 0x2a50, 8.13), (0x2a51, 0x2a52, 6.71), (0x2a53, 0x2a54, 7.17), (0x2a55, 0x2a56, 8.28),
+    (0x2a57, 0x2a58, 7.17), (0x2a59, 0x2a59, 6.67), (0x2a5a, 0x2a5b, 7.17), (0x2a5c, 0x2a5d, 8.12),
+    (0x2a5e, 0x2a60, 6.71), (0x2a61, 0x2a61, 5.97), (0x2a62, 0x2a63, 6.71), (0x2a64, 0x2a65, 7.34),
+    (0x2a66, 0x2a69, 5.96), (0x2a6a, 0x2a6d, 5.98), (0x2a6e, 0x2a6e, 5.33), (0x2a6f, 0x2a70, 5.98),
+    (0x2a71, 0x2a72, 5.97), (0x2a73, 0x2a73, 5.99), (0x2a74, 0x2a74, 9.33), (0x2a75, 0x2a75, 10.82),
+    (0x2a76, 0x2a76, 16.03), (0x2a77, 0x2a77, 6.0), (0x2a78, 0x2a78, 6.55), (0x2a79, 0x2a7a, 7.25),
+    (0x2a7b, 0x2a7c, 5.63), (0x2a7d, 0x2a7f, 5.97), (0x2a80, 0x2a80, 5.98), (0x2a81, 0x2a84, 5.97),
+    (0x2a85, 0x2a86, 5.98), (0x2a87, 0x2a87, 6.06), (0x2a88, 0x2a88, 6.27), (0x2a89, 0x2a8a, 5.98),
+    (0x2a8b, 0x2a8c, 5.97), (0x2a8d, 0x2a8d, 6.06), (0x2a8e, 0x2a8e, 6.03), (0x2a8f, 0x2a90, 5.99),
+    (0x2a91, 0x2a97, 5.97), (0x2a98, 0x2a9a, 5.98), (0x2a9b, 0x2a9c, 5.97), (0x2a9d, 0x2a9e, 5.98),
+    (0x2a9f, 0x2a9f, 6.07), (0x2aa0, 0x2aa0, 6.0), (0x2aa1, 0x2aa2, 7.51), (0x2aa3, 0x2aa3, 8.87),
+    (0x2aa4, 0x2aa4, 5.99), (0x2aa5, 0x2aa5, 10.29), (0x2aa6, 0x2aa9, 6.75), (0x2aaa, 0x2aaa, 5.63),
+    (0x2aab, 0x2aab, 5.76), (0x2aac, 0x2aac, 6.06), (0x2aad, 0x2aad, 6.11), (0x2aae, 0x2aae, 5.97),
+    (0x2aaf, 0x2ab1, 6.08), (0x2ab2, 0x2ab2, 6.06), (0x2ab3, 0x2ab6, 6.08), (0x2ab7, 0x2ab7, 6.02),
+    (0x2ab8, 0x2ab8, 6.03), (0x2ab9, 0x2ab9, 6.02), (0x2aba, 0x2aba, 6.03), (0x2abb, 0x2abc, 9.76),
+    (0x2abd, 0x2abe, 6.21), (0x2abf, 0x2ac6, 5.05), (0x2ac7, 0x2aca, 6.0), (0x2acb, 0x2acc, 5.05),
+    (0x2acd, 0x2ace, 10.48), (0x2acf, 0x2ad0, 6.21), (0x2ad1, 0x2ad2, 6.2), (0x2ad3, 0x2ad6, 5.05),
+    (0x2ad7, 0x2ad8, 9.35), (0x2ad9, 0x2adb, 6.57), (0x2adc, 0x2adc, 6.71), (0x2add, 0x2add, 6.57),
+    (0x2ade, 0x2ade, 4.32), (0x2adf, 0x2ae1, 7.58), (0x2ae2, 0x2ae2, 7.55), (0x2ae3, 0x2ae3, 7.52),
+    (0x2ae4, 0x2ae4, 7.55), (0x2ae5, 0x2ae5, 9.21), (0x2ae6, 0x2ae6, 7.54), (0x2ae7, 0x2ae9, 7.58),
+    (0x2aea, 0x2aeb, 7.62), (0x2aec, 0x2aed, 5.63), (0x2aee, 0x2aee, 3.44), (0x2aef, 0x2af0, 3.13),
+    (0x2af1, 0x2af1, 3.29), (0x2af2, 0x2af2, 4.29), (0x2af3, 0x2af3, 5.98), (0x2af4, 0x2af4, 3.84),
+    (0x2af5, 0x2af5, 5.26), (0x2af6, 0x2af6, 2.48), (0x2af7, 0x2af8, 10.68), (0x2af9, 0x2afa, 5.97),
+    (0x2afb, 0x2afb, 5.48), (0x2afc, 0x2afc, 3.9), (0x2afd, 0x2afd, 4.52), (0x2afe, 0x2afe, 3.23),
+    (0x2e80, 0x2e99, 11.0), (0x2e9a, 0x2e9a, 5.5), (0x2e9b, 0x2ef3, 11.0), (0x2ef4, 0x2efe, 5.5),
+    (0x2ff0, 0x2ffb, 11.0), (0x2ffc, 0x2ffe, 5.5), (0x3000, 0x302d, 11.0), (0x302e, 0x302f, 17.6),
+    (0x3030, 0x303e, 11.0), (0x3200, 0x321b, 9.51), (0x321c, 0x321c, 9.56), (0x321d, 0x321f, 5.5),
+    (0x3220, 0x324f, 11.0), (0x3250, 0x3250, 5.5), (0x3251, 0x325f, 11.0), (0x3260, 0x327b, 9.51),
+    (0x327c, 0x327e, 5.5), (0x327f, 0x327f, 9.51), (0x3280, 0x32cb, 11.0), (0x32cc, 0x32cf, 5.5),
+    (0x32d0, 0x32fe, 11.0), (0x3300, 0x3376, 11.0), (0x3377, 0x337a, 5.5), (0x337b, 0x337f, 11.0),
+    (0x3380, 0x3384, 9.51), (0x3385, 0x3389, 11.0), (0x338a, 0x338c, 9.51), (0x338d, 0x3390, 11.0),
+    (0x3391, 0x3395, 9.51), (0x3396, 0x3398, 11.0), (0x3399, 0x339a, 9.51), (0x339b, 0x33a6, 11.0),
+    (0x33a7, 0x33af, 9.51), (0x33b0, 0x33b3, 11.0), (0x33b4, 0x33c1, 9.51), (0x33c2, 0x33c2, 11.0),
+    (0x33c3, 0x33c3, 9.51), (0x33c4, 0x33c4, 11.0), (0x33c5, 0x33c7, 9.51), (0x33c8, 0x33c8, 11.0),
+    (0x33c9, 0x33ca, 9.51), (0x33cb, 0x33ce, 11.0), (0x33cf, 0x33d0, 9.51), (0x33d1, 0x33d2, 11.0),
+    (0x33d3, 0x33d3, 9.51), (0x33d4, 0x33d5, 11.0), (0x33d6, 0x33d6, 9.51), (0x33d7, 0x33da, 11.0),
+    (0x33db, 0x33dd, 9.51), (0x33de, 0x33df, 5.5), (0x33e0, 0x33fe, 11.0), (0x3400, 0x4db5, 11.0),
+    (0x4db6, 0x4dbe, 5.5), (0x4e00, 0x9fc2, 11.0), (0x9fc3, 0x9fc3, 5.5), (0x9fc4, 0x9fc4, 11.0),
+    (0x9fc5, 0x9fc5, 5.5), (0x9fc6, 0x9fd0, 11.0), (0x9fd1, 0x9ffe, 5.5), (0xf900, 0xfa2d, 11.0),
+    (0xfa2e, 0xfa2f, 5.5), (0xfa30, 0xfa6d, 11.0), (0xfa6e, 0xfafe, 5.5), (0xfe30, 0xfe4e, 11.0),
+    (0xfe70, 0xfe70, 3.43), (0xfe71, 0xfe71, 2.23), (0xfe72, 0xfe72, 3.43), (0xfe73, 0xfe73, 3.3),
+    (0xfe74, 0xfe74, 3.43), (0xfe75, 0xfe75, 5.5), (0xfe76, 0xfe76, 3.43), (0xfe77, 0xfe77, 2.23),
+    (0xfe78, 0xfe78, 3.43), (0xfe79, 0xfe79, 2.23), (0xfe7a, 0xfe7a, 3.43), (0xfe7b, 0xfe7b, 2.23),
+    (0xfe7c, 0xfe7c, 3.43), (0xfe7d, 0xfe7d, 2.23), (0xfe7e, 0xfe7e, 3.43), (0xfe7f, 0xfe7f, 2.23),
+    (0xfe80, 0xfe80, 5.16), (0xfe81, 0xfe81, 2.62), (0xfe82, 0xfe82, 2.79), (0xfe83, 0xfe83, 2.38),
+    (0xfe84, 0xfe84, 2.58), (0xfe85, 0xfe86, 4.96), (0xfe87, 0xfe87, 2.38), (0xfe88, 0xfe88, 2.58),
+    (0xfe89, 0xfe89, 7.09), (0xfe8a, 0xfe8a, 6.35), (0xfe8b, 0xfe8c, 2.99), (0xfe8d, 0xfe8d, 2.38),
+    (0xfe8e, 0xfe8e, 2.58), (0xfe8f, 0xfe90, 7.14), (0xfe91, 0xfe92, 2.99), (0xfe93, 0xfe93, 3.77),
+    (0xfe94, 0xfe94, 4.57), (0xfe95, 0xfe96, 7.14), (0xfe97, 0xfe98, 2.99), (0xfe99, 0xfe9a, 7.14),
+    (0xfe9b, 0xfe9c, 2.99), (0xfe9d, 0xfe9d, 6.35), (0xfe9e, 0xfe9e, 6.14), (0xfe9f, 0xfea0, 5.76),
+    (0xfea1, 0xfea1, 6.35), (0xfea2, 0xfea2, 6.14), (0xfea3, 0xfea4, 5.76), (0xfea5, 0xfea5, 6.35),
+    (0xfea6, 0xfea6, 6.14), (0xfea7, 0xfea8, 5.76), (0xfea9, 0xfeac, 3.77), (0xfead, 0xfeb0, 4.76),
+    (0xfeb1, 0xfeb2, 9.89), (0xfeb3, 0xfeb4, 6.69), (0xfeb5, 0xfeb6, 9.89), (0xfeb7, 0xfeb8, 6.69),
+    (0xfeb9, 0xfeba, 11.69), (0xfebb, 0xfebc, 8.49), (0xfebd, 0xfebe, 11.69),
+    (0xfebf, 0xfec0, 8.49), (0xfec1, 0xfec8, 5.76), (0xfec9, 0xfec9, 5.95), (0xfeca, 0xfeca, 5.16),
+    (0xfecb, 0xfecb, 5.95), (0xfecc, 0xfecc, 3.96), (0xfecd, 0xfecd, 5.95), (0xfece, 0xfece, 5.16),
+    (0xfecf, 0xfecf, 5.95), (0xfed0, 0xfed0, 3.96), (0xfed1, 0xfed2, 8.34), (0xfed3, 0xfed3, 3.35),
+    (0xfed4, 0xfed4, 3.57), (0xfed5, 0xfed6, 6.74), (0xfed7, 0xfed7, 3.35), (0xfed8, 0xfed8, 3.57),
+    (0xfed9, 0xfeda, 5.95), (0xfedb, 0xfedc, 3.96), (0xfedd, 0xfede, 4.96), (0xfedf, 0xfee0, 2.58),
+    (0xfee1, 0xfee2, 3.96), (0xfee3, 0xfee4, 4.57), (0xfee5, 0xfee6, 5.95), (0xfee7, 0xfee8, 2.99),
+    (0xfee9, 0xfee9, 3.77), (0xfeea, 0xfeea, 4.57), (0xfeeb, 0xfeeb, 5.16), (0xfeec, 0xfeee, 4.96),
+    (0xfeef, 0xfeef, 7.09), (0xfef0, 0xfef0, 6.1), (0xfef1, 0xfef1, 7.09), (0xfef2, 0xfef2, 6.35),
+    (0xfef3, 0xfef4, 2.99), (0xfef5, 0xfef5, 5.95), (0xfef6, 0xfef6, 6.55), (0xfef7, 0xfef7, 5.35),
+    (0xfef8, 0xfef8, 5.95), (0xfef9, 0xfef9, 5.35), (0xfefa, 0xfefa, 5.95), (0xfefb, 0xfefb, 5.35),
+    (0xfefc, 0xfefc, 5.95), (0xfefd, 0xfefe, 5.5), (0xff00, 0xff00, 5.5), (0xff01, 0xff60, 11.0),
+    (0xff61, 0xffa0, 5.5), (0xffa1, 0xffa1, 5.22), (0xffa2, 0xffa3, 5.5), (0xffa4, 0xffa4, 5.22),
+    (0xffa5, 0xffa6, 5.5), (0xffa7, 0xffa7, 5.22), (0xffa8, 0xffdf, 5.5), (0xffe0, 0xffe6, 11.0),
+    (0xffe7, 0xffe7, 5.5), (0xffe8, 0xffe8, 11.0), (0xffe9, 0xffee, 5.5), (0x10100, 0x10100, 7.53),
+    (0x10101, 0x10101, 6.31), (0x10102, 0x10102, 7.53), (0x10103, 0x10103, 6.31),
+    (0x10104, 0x10104, 7.53), (0x10105, 0x10105, 6.31), (0x10106, 0x10106, 8.15),
+    (0x10107, 0x10107, 6.31), (0x10108, 0x10108, 8.15), (0x10109, 0x10109, 6.31),
+    (0x1010a, 0x1010a, 8.15), (0x1010b, 0x1010b, 6.31), (0x1010c, 0x1010c, 8.15),
+    (0x1010d, 0x1010d, 6.31), (0x1010e, 0x1010e, 8.15), (0x1010f, 0x1010f, 6.72),
+    (0x10110, 0x10110, 8.15), (0x10111, 0x10111, 6.83), (0x10112, 0x10112, 7.13),
+    (0x10113, 0x10113, 6.31), (0x10114, 0x10114, 7.13), (0x10115, 0x10115, 6.31),
+    (0x10116, 0x10116, 7.13), (0x10117, 0x10117, 6.31), (0x10118, 0x10118, 7.13),
+    (0x10119, 0x10119, 6.31), (0x1011a, 0x1011a, 7.13), (0x1011b, 0x1011b, 6.31),
+    (0x1011c, 0x1011c, 8.35), (0x1011d, 0x1011d, 6.72), (0x1011e, 0x1011e, 8.35),
+    (0x1011f, 0x1011f, 6.72), (0x10120, 0x10120, 8.35), (0x10121, 0x10121, 6.72),
+    (0x10122, 0x10122, 8.35), (0x10123, 0x10123, 6.72), (0x10124, 0x10124, 8.15),
+    (0x10125, 0x10125, 6.52), (0x10126, 0x10126, 8.15), (0x10127, 0x10127, 6.63),
+    (0x10128, 0x10128, 3.24), (0x10129, 0x10129, 2.84), (0x1012a, 0x1012a, 3.24),
+    (0x1012b, 0x1012b, 2.84), (0x1012c, 0x1012c, 3.24), (0x1012d, 0x1012d, 2.84),
+    (0x1012e, 0x1012e, 3.24), (0x1012f, 0x1012f, 2.84), (0x10130, 0x10130, 3.24),
+    (0x10131, 0x10131, 2.84), (0x10132, 0x10132, 9.29), (0x10133, 0x10133, 5.76),
+    (0x10134, 0x10134, 6.12), (0x10135, 0x10135, 3.06), (0x10136, 0x10136, 7.94),
+    (0x10137, 0x10137, 6.31), (0x10138, 0x10138, 6.21), (0x10139, 0x10139, 6.52),
+    (0x1013a, 0x1013a, 2.84), (0x1013b, 0x1013b, 6.52), (0x1013c, 0x1013c, 2.84),
+    (0x1013d, 0x1013d, 6.52), (0x1013e, 0x1013e, 4.27), (0x10300, 0x1032e, 0.0),
+    (0x10330, 0x1034e, 0.0), (0x10400, 0x10401, 7.13), (0x10402, 0x10402, 8.84),
+    (0x10403, 0x10403, 6.31), (0x10404, 0x10404, 8.1), (0x10405, 0x10405, 7.14),
+    (0x10406, 0x10407, 3.24), (0x10408, 0x10408, 6.12), (0x10409, 0x10409, 12.38),
+    (0x1040a, 0x1040a, 12.35), (0x1040b, 0x1040b, 8.84), (0x1040c, 0x1040c, 7.94),
+    (0x1040d, 0x1040d, 8.19), (0x1040e, 0x1040e, 7.12), (0x1040f, 0x1040f, 8.05),
+    (0x10410, 0x10410, 7.53), (0x10411, 0x10411, 7.72), (0x10412, 0x10412, 7.74),
+    (0x10413, 0x10413, 6.31), (0x10414, 0x10414, 8.49), (0x10415, 0x10415, 7.13),
+    (0x10416, 0x10416, 11.67), (0x10417, 0x10417, 7.12), (0x10418, 0x10419, 8.19),
+    (0x1041a, 0x1041a, 7.94), (0x1041b, 0x1041b, 8.17), (0x1041c, 0x1041c, 9.98),
+    (0x1041d, 0x1041d, 8.15), (0x1041e, 0x1041e, 8.56), (0x1041f, 0x1041f, 8.05),
+    (0x10420, 0x10420, 7.34), (0x10421, 0x10421, 8.15), (0x10422, 0x10422, 6.72),
+    (0x10423, 0x10423, 7.12), (0x10424, 0x10424, 10.15), (0x10425, 0x10425, 7.34),
+    (0x10426, 0x10426, 8.27), (0x10427, 0x10427, 7.48), (0x10428, 0x10428, 11.45),
+    (0x10429, 0x10429, 11.67), (0x1042a, 0x1042a, 8.95), (0x1042b, 0x1042b, 10.22),
+    (0x1042c, 0x1042c, 7.45), (0x1042d, 0x1042d, 8.12), (0x1042e, 0x1042e, 11.25),
+    (0x1042f, 0x1042f, 7.97), (0x10430, 0x10430, 6.31), (0x10431, 0x10431, 6.68),
+    (0x10432, 0x10432, 6.39), (0x10433, 0x10433, 4.98), (0x10434, 0x10434, 6.86),
+    (0x10435, 0x10435, 6.31), (0x10436, 0x10436, 8.93), (0x10437, 0x10437, 5.96),
+    (0x10438, 0x10439, 6.6), (0x1043a, 0x1043a, 6.31), (0x1043b, 0x1043b, 6.59),
+    (0x1043c, 0x1043c, 8.12), (0x1043d, 0x1043d, 6.52), (0x1043e, 0x1043e, 6.72),
+    (0x1043f, 0x1043f, 6.41), (0x10440, 0x10440, 6.72), (0x10441, 0x10441, 6.31),
+    (0x10442, 0x10442, 5.52), (0x10443, 0x10443, 5.71), (0x10444, 0x10444, 9.42),
+    (0x10445, 0x10445, 5.91), (0x10446, 0x10446, 6.63), (0x10447, 0x10447, 6.31),
+    (0x10448, 0x10448, 9.01), (0x10449, 0x10449, 9.17), (0x1044a, 0x1044a, 7.11),
+    (0x1044b, 0x1044b, 8.56), (0x1044c, 0x1044c, 6.03), (0x1044d, 0x1044d, 6.34),
+    (0x1044e, 0x1044e, 9.18), (0x1d100, 0x1d1fe, 9.51), (0x1d400, 0x1d7a3, 9.51),
+    (0x1d7a4, 0x1d7fe, 5.5),
+];
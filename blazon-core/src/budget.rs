@@ -0,0 +1,96 @@
+//! Limits on metric values, for gating CI on bloat
+
+use crate::{Metrics, format_size};
+use std::fmt;
+
+/// Upper limits on metrics; `None` leaves a metric unchecked
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Budgets {
+    /// Maximum number of unique dependencies
+    pub deps: Option<usize>,
+    /// Maximum binary size in bytes
+    pub binary_size: Option<u64>,
+    /// Maximum target directory size in bytes
+    pub target_dir: Option<u64>,
+}
+
+/// A metric over its budget
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetViolation {
+    /// Identifier of the metric, matching the id of its badge
+    pub id: &'static str,
+    /// Human-readable name of the metric
+    pub name: &'static str,
+    /// The measured value
+    pub actual: u64,
+    /// The budget it exceeds
+    pub limit: u64,
+}
+
+impl Budgets {
+    /// Whether no metric has a budget
+    pub fn is_empty(&self) -> bool {
+        self == &Budgets::default()
+    }
+
+    /// The metrics that exceed their budgets, in README order
+    pub fn check(&self, metrics: &Metrics) -> Vec<BudgetViolation> {
+        let checks = [
+            (
+                "deps",
+                "dependency count",
+                metrics.dep_count as u64,
+                self.deps.map(|d| d as u64),
+            ),
+            (
+                "size",
+                "binary size",
+                metrics.binary_size_bytes,
+                self.binary_size,
+            ),
+            (
+                "target-dir",
+                "target dir size",
+                metrics.target_dir_bytes,
+                self.target_dir,
+            ),
+        ];
+
+        checks
+            .into_iter()
+            .filter_map(|(id, name, actual, limit)| {
+                let limit = limit?;
+                (actual > limit).then_some(BudgetViolation {
+                    id,
+                    name,
+                    actual,
+                    limit,
+                })
+            })
+            .collect()
+    }
+}
+
+impl BudgetViolation {
+    /// Format a value of this metric the way its badge shows it
+    pub fn format_value(&self, value: u64) -> String {
+        if self.id == "deps" {
+            value.to_string()
+        } else {
+            format_size(value)
+        }
+    }
+}
+
+impl fmt::Display for BudgetViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} exceeds the budget of {} (by {})",
+            self.name,
+            self.format_value(self.actual),
+            self.format_value(self.limit),
+            self.format_value(self.actual - self.limit)
+        )
+    }
+}
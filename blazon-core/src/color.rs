@@ -0,0 +1,189 @@
+//! Badge colours chosen from metric values
+//!
+//! A [`ColorScale`] turns a metric value into a badge colour, so a badge can signal a
+//! regression by changing colour rather than only by changing its number.
+
+use crate::badge::svg_color;
+use crate::{ParseError, parse_size};
+use std::str::FromStr;
+
+/// How a badge colour is chosen from a metric value
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColorScale {
+    /// The same colour whatever the value
+    Fixed(String),
+    /// The colour of the first step whose bound the value is under, else `above`
+    ///
+    /// Steps are sorted by strictly increasing bound.
+    Thresholds {
+        steps: Vec<(u64, String)>,
+        above: String,
+    },
+    /// A linear blend between two colours over a range of values, clamped at either end
+    Gradient { from: (u64, Rgb), to: (u64, Rgb) },
+}
+
+/// An sRGB colour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl ColorScale {
+    /// The badge colour for `value`, as a shields.io colour name or `#rrggbb` hex code
+    pub fn color_for(&self, value: u64) -> String {
+        match self {
+            ColorScale::Fixed(color) => color.clone(),
+            ColorScale::Thresholds { steps, above } => steps
+                .iter()
+                .find(|(bound, _)| value < *bound)
+                .map_or(above, |(_, color)| color)
+                .clone(),
+            ColorScale::Gradient {
+                from: (low, start),
+                to: (high, end),
+            } => {
+                let t = if value <= *low {
+                    0.0
+                } else if value >= *high {
+                    1.0
+                } else {
+                    (value - low) as f64 / (high - low) as f64
+                };
+                start.blend(*end, t).to_string()
+            }
+        }
+    }
+}
+
+/// Parse a colour scale
+///
+/// - `blue` or `#e05d44`: a fixed colour
+/// - `green<2M,yellow<5M,red`: thresholds, colouring values under 2M green, under 5M yellow and
+///   anything else red
+/// - `green@2M..red@10M`: a gradient from green at 2M (and below) to red at 10M (and above)
+///
+/// Bounds are sizes as accepted by [`parse_size`], so plain counts work too (`blue<100,red`).
+impl FromStr for ColorScale {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some((start, end)) = s.split_once("..") {
+            let from = parse_gradient_stop(start)?;
+            let to = parse_gradient_stop(end)?;
+            if from.0 >= to.0 {
+                return Err(invalid(
+                    "colour gradient",
+                    s,
+                    "the first value must be lower than the second",
+                ));
+            }
+            return Ok(ColorScale::Gradient { from, to });
+        }
+
+        if !s.contains(',') {
+            if s.is_empty() || s.contains('<') {
+                return Err(invalid("colour scale", s, NO_FINAL_COLOUR));
+            }
+            return Ok(ColorScale::Fixed(s.to_string()));
+        }
+
+        let mut parts: Vec<&str> = s.split(',').map(str::trim).collect();
+        let above = parts.pop().unwrap_or_default();
+        if above.is_empty() || above.contains('<') {
+            return Err(invalid("colour scale", s, NO_FINAL_COLOUR));
+        }
+
+        let mut steps: Vec<(u64, String)> = Vec::with_capacity(parts.len());
+        for part in parts {
+            let (color, bound) = part.split_once('<').ok_or_else(|| {
+                invalid(
+                    "colour threshold",
+                    part,
+                    "expected COLOUR<VALUE, e.g. green<2M",
+                )
+            })?;
+            let bound = parse_size(bound)?;
+            if steps.last().is_some_and(|(previous, _)| *previous >= bound) {
+                return Err(invalid("colour scale", s, "thresholds must increase"));
+            }
+            steps.push((bound, color.trim().to_string()));
+        }
+
+        Ok(ColorScale::Thresholds {
+            steps,
+            above: above.to_string(),
+        })
+    }
+}
+
+/// The problem with a colour scale whose last part is a threshold, or missing
+const NO_FINAL_COLOUR: &str = "expected a final colour for values above the thresholds";
+
+/// An error for the `part` of a colour scale `text`, with the `problem` that it has
+fn invalid(part: &'static str, text: &str, problem: &'static str) -> ParseError {
+    ParseError::ColorScale {
+        part,
+        text: text.to_string(),
+        problem,
+    }
+}
+
+fn parse_gradient_stop(stop: &str) -> Result<(u64, Rgb), ParseError> {
+    let (color, value) = stop.split_once('@').ok_or_else(|| {
+        invalid(
+            "gradient stop",
+            stop,
+            "expected COLOUR@VALUE, e.g. green@2M",
+        )
+    })?;
+    Ok((parse_size(value)?, color.trim().parse()?))
+}
+
+impl Rgb {
+    /// Linear interpolation towards `other`, with `t` from 0 (self) to 1 (other)
+    pub fn blend(self, other: Rgb, t: f64) -> Rgb {
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+}
+
+/// Parse a shields.io colour name or a 3 or 6 digit hex code
+impl FromStr for Rgb {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Not the lightgrey an empty badge colour falls back to
+        if s.trim().is_empty() {
+            return Err(ParseError::Color {
+                text: s.to_string(),
+            });
+        }
+        let color = svg_color(s.trim());
+        let hex = color.trim_start_matches('#');
+        let digit = |i: usize, len: usize| {
+            let value = u8::from_str_radix(hex.get(i * len..(i + 1) * len)?, 16).ok()?;
+            Some(if len == 1 { value * 17 } else { value })
+        };
+        let rgb = match hex.len() {
+            3 => (digit(0, 1), digit(1, 1), digit(2, 1)),
+            6 => (digit(0, 2), digit(1, 2), digit(2, 2)),
+            _ => (None, None, None),
+        };
+        match rgb {
+            (Some(r), Some(g), Some(b)) => Ok(Rgb(r, g, b)),
+            _ => Err(ParseError::Color {
+                text: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for Rgb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
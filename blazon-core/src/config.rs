@@ -0,0 +1,487 @@
+//! Settings read from `blazon.toml` or the `blazon` metadata tables of Cargo.toml
+//!
+//! A package is configured by, from highest precedence to lowest:
+//!
+//! 1. `blazon.toml` in the package directory
+//! 2. `[package.metadata.blazon]` in the package's Cargo.toml
+//! 3. `blazon.toml` in the workspace root
+//! 4. `[workspace.metadata.blazon]` in the workspace's Cargo.toml
+//!
+//! Layers are merged key by key, so a package can override one colour and inherit the rest.
+//! Paths are relative to the file that sets them. Every setting has the same shape in each
+//! layer:
+//!
+//! ```toml
+//! readmes = ["README.md"]
+//! binary = "my-binary"
+//! crate = "my-crate"
+//! metrics = ["deps", "size"]
+//! style = "flat-square"
+//!
+//! [labels]
+//! deps = "dependencies"
+//!
+//! [colors]
+//! size = "green<2M,yellow<5M,red"
+//!
+//! [budgets]
+//! deps = 100
+//! size = "2M"
+//!
+//! [deps]
+//! count = "versions"
+//! depth = "direct"
+//! build = true
+//! dev = true
+//! proc-macros = false
+//! workspace = false
+//! ```
+//!
+//! Unknown keys and invalid values are errors pointing at the offending key or value, so a typo
+//! is not silently ignored.
+
+use crate::badge::BadgeStyle;
+use crate::budget::Budgets;
+use crate::color::ColorScale;
+use crate::deps::{DepOptions, Depth, Identity};
+use crate::error::InvalidConfig;
+use crate::file::relative_to_cwd;
+use crate::metadata::{Metadata, Package};
+use crate::{BadgeOptions, BlazonError, METRICS, ParseError, parse_size};
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use toml_edit::{Document, Item, TableLike};
+
+/// Name of the standalone config file
+pub const CONFIG_FILE: &str = "blazon.toml";
+
+/// The keys allowed at the top level of a config
+const KEYS: &[&str] = &[
+    "readmes", "binary", "crate", "metrics", "style", "labels", "colors", "budgets", "deps",
+];
+
+/// The keys allowed in the `deps` table
+const DEP_KEYS: &[&str] = &["count", "depth", "build", "dev", "proc-macros", "workspace"];
+
+/// Settings for one package; `None` and empty maps leave the default or CLI value in place
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    /// READMEs to update, resolved against the directory of the file that lists them
+    pub readmes: Option<Vec<PathBuf>>,
+    /// Binary to measure
+    pub binary: Option<String>,
+    /// Crate the badges link to, set by the `crate` key
+    pub crate_name: Option<String>,
+    /// Ids of the metrics to show badges for, in order
+    pub metrics: Option<Vec<String>>,
+    /// Badge labels by metric id
+    pub labels: BTreeMap<String, String>,
+    /// Colour scales by metric id
+    pub colors: BTreeMap<String, ColorScale>,
+    /// Badge style
+    pub style: Option<BadgeStyle>,
+    /// Budgets, set by metric id under `[budgets]`
+    pub budgets: Budgets,
+    /// How dependencies are counted, set under `[deps]`
+    pub deps: DepSettings,
+}
+
+/// How dependencies are counted; `None` leaves the default or CLI value in place
+///
+/// Each setting is one of [`DepOptions`], with `proc-macros` and `workspace` saying whether
+/// those crates count rather than whether they are excluded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DepSettings {
+    /// How crates are told apart, as `count`
+    pub count: Option<Identity>,
+    /// How far down the tree to count, as `depth`
+    pub depth: Option<Depth>,
+    /// Whether build dependencies count
+    pub build: Option<bool>,
+    /// Whether dev dependencies count
+    pub dev: Option<bool>,
+    /// Whether proc-macros, and what only they depend on, count
+    pub proc_macros: Option<bool>,
+    /// Whether other workspace members count
+    pub workspace: Option<bool>,
+}
+
+impl DepSettings {
+    /// These settings, with any left unset taken from `fallback`
+    pub fn or(self, fallback: DepSettings) -> DepSettings {
+        DepSettings {
+            count: self.count.or(fallback.count),
+            depth: self.depth.or(fallback.depth),
+            build: self.build.or(fallback.build),
+            dev: self.dev.or(fallback.dev),
+            proc_macros: self.proc_macros.or(fallback.proc_macros),
+            workspace: self.workspace.or(fallback.workspace),
+        }
+    }
+
+    /// The options these settings select, with the defaults for any left unset
+    pub fn options(&self) -> DepOptions {
+        DepOptions {
+            identity: self.count.unwrap_or_default(),
+            depth: self.depth.unwrap_or_default(),
+            build: self.build.unwrap_or(false),
+            dev: self.dev.unwrap_or(false),
+            exclude_proc_macros: !self.proc_macros.unwrap_or(true),
+            exclude_workspace: !self.workspace.unwrap_or(true),
+        }
+    }
+}
+
+impl Config {
+    /// Load and merge the config layers of the package owning `dir`, or of the workspace alone
+    /// when `dir` is only inside a virtual workspace root
+    pub fn load(metadata: &Metadata, dir: &Path) -> Result<Self, BlazonError> {
+        Self::load_layers(metadata, metadata.package_for_dir(dir), false)
+    }
+
+    /// Load the config of `package` when it is selected by name, or every member is measured
+    ///
+    /// Like [`Config::load`], except that the workspace layers cannot set `readmes`, `binary`
+    /// or `crate`: those name the package the workspace is measured as, so a package selected
+    /// out of it keeps its own.
+    pub fn load_package(metadata: &Metadata, package: &Package) -> Result<Self, BlazonError> {
+        Self::load_layers(metadata, Some(package), true)
+    }
+
+    /// Merge the layers of `package` and the workspace, in order of precedence
+    fn load_layers(
+        metadata: &Metadata,
+        package: Option<&Package>,
+        selected: bool,
+    ) -> Result<Self, BlazonError> {
+        let root = &metadata.workspace_root;
+        let mut layers = Vec::new();
+        if let Some(package) = package {
+            layers.push((package.dir().join(CONFIG_FILE), &[][..]));
+            layers.push((
+                package.manifest_path.clone(),
+                &["package", "metadata", "blazon"][..],
+            ));
+        }
+        let package_layers = layers.len();
+        let root_file = root.join(CONFIG_FILE);
+        if !layers.iter().any(|(path, _)| path == &root_file) {
+            layers.push((root_file, &[][..]));
+        }
+        layers.push((
+            root.join("Cargo.toml"),
+            &["workspace", "metadata", "blazon"][..],
+        ));
+
+        let mut config = Config::default();
+        for (index, (path, table)) in layers.into_iter().enumerate() {
+            let content = match std::fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(BlazonError::io("read", path, e)),
+            };
+            if let Some(mut layer) = Config::parse(&path, &content, table)? {
+                if selected && index >= package_layers {
+                    layer.readmes = None;
+                    layer.binary = None;
+                    layer.crate_name = None;
+                }
+                config = config.or(layer);
+            }
+        }
+        Ok(config)
+    }
+
+    /// Parse the config in the table at `table` of the TOML file at `path`
+    ///
+    /// An empty `table` is the whole file, as in `blazon.toml`. Returns `None` if the file has
+    /// no such table.
+    pub fn parse(path: &Path, content: &str, table: &[&str]) -> Result<Option<Self>, BlazonError> {
+        let parser = Parser { path, content };
+        let document = Document::parse(content.to_string())
+            .map_err(|e| parser.error(e.span(), e.message(), None))?;
+
+        let mut settings = document.as_table() as &dyn TableLike;
+        for name in table {
+            match settings.get(name) {
+                Some(item) => settings = parser.table(item, name)?,
+                None => return Ok(None),
+            }
+        }
+        parser.check_keys(settings, KEYS, "key")?;
+
+        let base = path.parent().unwrap_or(Path::new(""));
+        let mut config = Config::default();
+        for (key, item) in settings.iter() {
+            match key {
+                "readmes" => {
+                    let readmes = parser.strings(item, key)?;
+                    config.readmes = Some(
+                        readmes
+                            .into_iter()
+                            .map(|(readme, _)| relative_to_cwd(&base.join(readme)))
+                            .collect(),
+                    );
+                }
+                "binary" => config.binary = Some(parser.string(item, key)?.to_string()),
+                "crate" => config.crate_name = Some(parser.string(item, key)?.to_string()),
+                "metrics" => {
+                    let mut metrics = Vec::new();
+                    for (id, span) in parser.strings(item, key)? {
+                        if !METRICS.iter().any(|(known, _)| *known == id) {
+                            return Err(parser.unknown(span, "metric", &id, ids()));
+                        }
+                        metrics.push(id);
+                    }
+                    config.metrics = Some(metrics);
+                }
+                "style" => config.style = Some(parser.parsed(item, key)?),
+                "labels" => {
+                    for (id, item) in parser.metric_table(item, key)? {
+                        let label = parser.string(item, &id)?.to_string();
+                        config.labels.insert(id, label);
+                    }
+                }
+                "colors" => {
+                    for (id, item) in parser.metric_table(item, key)? {
+                        let scale = parser.parsed(item, &id)?;
+                        config.colors.insert(id, scale);
+                    }
+                }
+                "budgets" => {
+                    for (id, item) in parser.metric_table(item, key)? {
+                        match id.as_str() {
+                            "deps" => config.budgets.deps = Some(parser.count(item, &id)?),
+                            "size" => config.budgets.binary_size = Some(parser.size(item, &id)?),
+                            _ => config.budgets.target_dir = Some(parser.size(item, &id)?),
+                        }
+                    }
+                }
+                "deps" => {
+                    let table = parser.table(item, key)?;
+                    parser.check_keys(table, DEP_KEYS, "key")?;
+                    let deps = &mut config.deps;
+                    for (key, item) in table.iter() {
+                        match key {
+                            "count" => deps.count = Some(parser.parsed(item, key)?),
+                            "depth" => deps.depth = Some(parser.parsed(item, key)?),
+                            "build" => deps.build = Some(parser.boolean(item, key)?),
+                            "dev" => deps.dev = Some(parser.boolean(item, key)?),
+                            "proc-macros" => deps.proc_macros = Some(parser.boolean(item, key)?),
+                            _ => deps.workspace = Some(parser.boolean(item, key)?),
+                        }
+                    }
+                }
+                _ => unreachable!("keys are checked against KEYS"),
+            }
+        }
+        Ok(Some(config))
+    }
+
+    /// These settings, with any left unset taken from `fallback`
+    pub fn or(self, fallback: Config) -> Config {
+        let mut labels = fallback.labels;
+        labels.extend(self.labels);
+        let mut colors = fallback.colors;
+        colors.extend(self.colors);
+        Config {
+            readmes: self.readmes.or(fallback.readmes),
+            binary: self.binary.or(fallback.binary),
+            crate_name: self.crate_name.or(fallback.crate_name),
+            metrics: self.metrics.or(fallback.metrics),
+            labels,
+            colors,
+            style: self.style.or(fallback.style),
+            budgets: Budgets {
+                deps: self.budgets.deps.or(fallback.budgets.deps),
+                binary_size: self.budgets.binary_size.or(fallback.budgets.binary_size),
+                target_dir: self.budgets.target_dir.or(fallback.budgets.target_dir),
+            },
+            deps: self.deps.or(fallback.deps),
+        }
+    }
+
+    /// The default badge options with these settings applied
+    pub fn badge_options(&self) -> BadgeOptions {
+        let mut options = BadgeOptions::default();
+        if let Some(style) = self.style {
+            options.style = style;
+        }
+        if let Some(metrics) = &self.metrics {
+            options.metrics = metrics.clone();
+        }
+        for (id, label) in &self.labels {
+            if let Some(slot) = options.labels.get_mut(id) {
+                *slot = label.clone();
+            }
+        }
+        for (id, scale) in &self.colors {
+            if let Some(slot) = options.colors.get_mut(id) {
+                *slot = scale.clone();
+            }
+        }
+        options
+    }
+}
+
+/// The metric ids, as listed in help text
+fn ids() -> Vec<&'static str> {
+    METRICS.iter().map(|(id, _)| *id).collect()
+}
+
+/// A string value with its span in the file
+type Spanned = (String, Option<Range<usize>>);
+
+/// Reads settings out of one TOML file, reporting errors against its content
+struct Parser<'a> {
+    path: &'a Path,
+    content: &'a str,
+}
+
+impl Parser<'_> {
+    /// An error at `span` of the file
+    fn error(
+        &self,
+        span: Option<Range<usize>>,
+        message: &str,
+        help: Option<String>,
+    ) -> BlazonError {
+        BlazonError::Config(Box::new(InvalidConfig {
+            path: relative_to_cwd(self.path).display().to_string(),
+            content: self.content.to_string(),
+            span,
+            message: message.to_string(),
+            help,
+        }))
+    }
+
+    /// An error for a value of `item` that is not of the type `expected`
+    fn mismatch(&self, item: &Item, key: &str, expected: &str) -> BlazonError {
+        self.error(
+            item.span(),
+            &format!(
+                "Expected {} for '{}', found {}",
+                expected,
+                key,
+                item.type_name()
+            ),
+            None,
+        )
+    }
+
+    /// An error for an unknown `kind` of name, listing the `known` ones
+    fn unknown(
+        &self,
+        span: Option<Range<usize>>,
+        kind: &str,
+        name: &str,
+        known: Vec<&str>,
+    ) -> BlazonError {
+        self.error(
+            span,
+            &format!("Unknown {} '{}'", kind, name),
+            Some(format!("expected one of: {}", known.join(", "))),
+        )
+    }
+
+    /// The table `item`, the value of `key`
+    fn table<'t>(&self, item: &'t Item, key: &str) -> Result<&'t dyn TableLike, BlazonError> {
+        item.as_table_like()
+            .ok_or_else(|| self.mismatch(item, key, "a table"))
+    }
+
+    /// Fail on the first key of `table` not in `known`, calling it an unknown `kind`
+    fn check_keys(
+        &self,
+        table: &dyn TableLike,
+        known: &[&str],
+        kind: &str,
+    ) -> Result<(), BlazonError> {
+        for (key, _) in table.iter() {
+            if !known.contains(&key) {
+                let span = table.get_key_value(key).and_then(|(key, _)| key.span());
+                return Err(self.unknown(span, kind, key, known.to_vec()));
+            }
+        }
+        Ok(())
+    }
+
+    /// The entries of the table `item`, each keyed by a metric id
+    fn metric_table<'t>(
+        &self,
+        item: &'t Item,
+        key: &str,
+    ) -> Result<Vec<(String, &'t Item)>, BlazonError> {
+        let table = self.table(item, key)?;
+        self.check_keys(table, &ids(), "metric")?;
+        Ok(table
+            .iter()
+            .map(|(id, item)| (id.to_string(), item))
+            .collect())
+    }
+
+    /// The string `item`, the value of `key`
+    fn string<'t>(&self, item: &'t Item, key: &str) -> Result<&'t str, BlazonError> {
+        item.as_str()
+            .ok_or_else(|| self.mismatch(item, key, "a string"))
+    }
+
+    /// The strings of the array `item` with their spans, the value of `key`
+    fn strings(&self, item: &Item, key: &str) -> Result<Vec<Spanned>, BlazonError> {
+        let array = item
+            .as_array()
+            .ok_or_else(|| self.mismatch(item, key, "an array of strings"))?;
+        array
+            .iter()
+            .map(|value| match value.as_str() {
+                Some(text) => Ok((text.to_string(), value.span())),
+                None => Err(self.error(
+                    value.span(),
+                    &format!(
+                        "Expected a string in '{}', found {}",
+                        key,
+                        value.type_name()
+                    ),
+                    None,
+                )),
+            })
+            .collect()
+    }
+
+    /// The string `item` parsed as a `T`, the value of `key`
+    fn parsed<T: FromStr<Err = ParseError>>(
+        &self,
+        item: &Item,
+        key: &str,
+    ) -> Result<T, BlazonError> {
+        self.string(item, key)?
+            .parse()
+            .map_err(|e: ParseError| self.error(item.span(), &e.to_string(), None))
+    }
+
+    /// The boolean `item`, the value of `key`
+    fn boolean(&self, item: &Item, key: &str) -> Result<bool, BlazonError> {
+        item.as_bool()
+            .ok_or_else(|| self.mismatch(item, key, "a boolean"))
+    }
+
+    /// The non-negative integer `item`, the value of `key`
+    fn count(&self, item: &Item, key: &str) -> Result<usize, BlazonError> {
+        item.as_integer()
+            .and_then(|count| usize::try_from(count).ok())
+            .ok_or_else(|| self.mismatch(item, key, "a non-negative integer"))
+    }
+
+    /// The size `item`, as a number of bytes or a string such as `2M`, the value of `key`
+    fn size(&self, item: &Item, key: &str) -> Result<u64, BlazonError> {
+        if item.is_integer() {
+            return self.count(item, key).map(|bytes| bytes as u64);
+        }
+        let text = item
+            .as_str()
+            .ok_or_else(|| self.mismatch(item, key, "a size such as \"2M\""))?;
+        parse_size(text).map_err(|e| self.error(item.span(), &e.to_string(), None))
+    }
+}
@@ -0,0 +1,238 @@
+//! Counting the dependencies of a package from `cargo tree`
+//!
+//! The count is of the crates in `cargo tree --package <package> --edges normal`, that is the
+//! crates the package links against when built with its default features for the host:
+//!
+//! - Only the measured package's tree is read. Without a package, cargo prints the tree of every
+//!   default member of a virtual workspace and the count is of their union.
+//! - Build and dev dependencies are left out, as are their own dependencies unless the package
+//!   also depends on them normally. [`DepOptions::build`] and [`DepOptions::dev`] follow those
+//!   edges too.
+//! - Proc-macros count like any other crate, unless [`DepOptions::exclude_proc_macros`] is set,
+//!   in which case their own dependencies go with them.
+//! - A crate counts once however many times it appears in the tree, so shared dependencies are
+//!   not counted per dependent. Crates are told apart by name, or by name and version with
+//!   [`Identity::Versions`], where two versions of a crate count twice.
+//! - The package itself is the root of its tree and is never counted. Without a package, no
+//!   root is counted, even where one member depends on another.
+//! - [`Depth::Direct`] counts only the crates the package names in its manifest, and
+//!   [`Depth::Transitive`] only the rest. A crate that is both is direct.
+//! - Other members of the workspace count like any other dependency, unless
+//!   [`DepOptions::exclude_workspace`] is set. Excluding them leaves their own dependencies in
+//!   the count, as the package still links against those.
+
+use crate::ParseError;
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+/// Which crates of a package's tree count as its dependencies
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DepOptions {
+    /// How crates are told apart
+    pub identity: Identity,
+    /// How far down the tree to count
+    pub depth: Depth,
+    /// Follow build dependencies
+    pub build: bool,
+    /// Follow dev dependencies
+    pub dev: bool,
+    /// Leave proc-macros, and what only they depend on, out of the count
+    pub exclude_proc_macros: bool,
+    /// Leave the other members of the workspace out of the count
+    pub exclude_workspace: bool,
+}
+
+impl DepOptions {
+    /// The `--edges` argument to `cargo tree` for these options
+    pub fn edges(&self) -> String {
+        let mut edges = vec!["normal"];
+        if self.build {
+            edges.push("build");
+        }
+        if self.dev {
+            edges.push("dev");
+        }
+        if self.exclude_proc_macros {
+            edges.push("no-proc-macro");
+        }
+        edges.join(",")
+    }
+
+    /// Qualify a badge label with how the dependencies were counted, e.g. `deps (direct)`
+    ///
+    /// The default options leave the label as it is.
+    pub fn qualify(&self, label: &str) -> String {
+        let mut modes = Vec::new();
+        match self.depth {
+            Depth::All => {}
+            Depth::Direct => modes.push("direct"),
+            Depth::Transitive => modes.push("transitive"),
+        }
+        if self.identity == Identity::Versions {
+            modes.push("by version");
+        }
+        if self.build {
+            modes.push("+build");
+        }
+        if self.dev {
+            modes.push("+dev");
+        }
+        if self.exclude_proc_macros {
+            modes.push("no proc-macros");
+        }
+        if self.exclude_workspace {
+            modes.push("no workspace");
+        }
+
+        if modes.is_empty() {
+            label.to_string()
+        } else {
+            format!("{} ({})", label, modes.join(", "))
+        }
+    }
+}
+
+/// How the crates of a tree are told apart
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Identity {
+    /// By name, so every version of a crate counts once
+    #[default]
+    Names,
+    /// By name and version, so each version of a crate counts
+    Versions,
+}
+
+impl FromStr for Identity {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "names" => Ok(Self::Names),
+            "versions" => Ok(Self::Versions),
+            _ => Err(ParseError::Unknown {
+                kind: "count",
+                name: s.to_string(),
+                expected: vec!["names", "versions"],
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Names => write!(f, "names"),
+            Self::Versions => write!(f, "versions"),
+        }
+    }
+}
+
+/// How far down the tree to count
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Depth {
+    /// Every crate in the tree
+    #[default]
+    All,
+    /// Only the package's own dependencies
+    Direct,
+    /// Only the dependencies of its dependencies
+    Transitive,
+}
+
+impl FromStr for Depth {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            "direct" => Ok(Self::Direct),
+            "transitive" => Ok(Self::Transitive),
+            _ => Err(ParseError::Unknown {
+                kind: "depth",
+                name: s.to_string(),
+                expected: vec!["all", "direct", "transitive"],
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Depth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All => write!(f, "all"),
+            Self::Direct => write!(f, "direct"),
+            Self::Transitive => write!(f, "transitive"),
+        }
+    }
+}
+
+/// A crate in `cargo tree --prefix depth` output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeEntry<'a> {
+    /// Distance from the root, which is at depth 0
+    pub depth: usize,
+    pub name: &'a str,
+    pub version: &'a str,
+}
+
+impl<'a> TreeEntry<'a> {
+    /// Parse a line such as `2serde v1.0.0 (proc-macro) (*)`
+    pub fn parse(line: &'a str) -> Option<Self> {
+        let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let depth = line[..digits].parse().ok()?;
+        let mut words = line[digits..].split_whitespace();
+        let name = words.next()?;
+        let version = words.next().unwrap_or_default();
+        Some(Self {
+            depth,
+            name,
+            version: version.strip_prefix('v').unwrap_or(version),
+        })
+    }
+
+    /// What tells this crate apart from others under `identity`
+    fn key(&self, identity: Identity) -> (&'a str, &'a str) {
+        match identity {
+            Identity::Names => (self.name, ""),
+            Identity::Versions => (self.name, self.version),
+        }
+    }
+}
+
+/// Count the dependencies in `tree`, the output of `cargo tree --prefix depth`
+///
+/// `members` names the workspace members, which are left out of the count when `options`
+/// excludes them. The roots of the tree, at depth 0, are never counted, even where one depends on
+/// another, but with [`Identity::Versions`] another version of a root counts like any crate.
+pub fn count_tree(tree: &str, options: &DepOptions, members: &[String]) -> usize {
+    let entries: Vec<TreeEntry> = tree.lines().filter_map(TreeEntry::parse).collect();
+    let roots: HashSet<_> = entries
+        .iter()
+        .filter(|entry| entry.depth == 0)
+        .map(|entry| entry.key(options.identity))
+        .collect();
+    let direct: HashSet<_> = entries
+        .iter()
+        .filter(|entry| entry.depth == 1)
+        .map(|entry| entry.key(options.identity))
+        .collect();
+
+    let mut unique = HashSet::new();
+    for entry in &entries {
+        let key = entry.key(options.identity);
+        let sibling = members.iter().any(|m| m == entry.name);
+        if roots.contains(&key) || (options.exclude_workspace && sibling) {
+            continue;
+        }
+        let counted = match options.depth {
+            Depth::All => true,
+            Depth::Direct => entry.depth == 1,
+            Depth::Transitive => !direct.contains(&key),
+        };
+        if counted {
+            unique.insert(key);
+        }
+    }
+    unique.len()
+}
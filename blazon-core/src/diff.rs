@@ -0,0 +1,150 @@
+//! Previewing README changes as line diffs
+//!
+//! [`diff_readme`] computes what [`update_readme`](crate::update_readme) would write without
+//! touching the file, so callers can show or check the change first.
+
+use crate::region::RegionContent;
+use crate::{BlazonError, read_and_patch};
+use imara_diff::{Algorithm, Diff, InternedInput};
+use std::ops::Range;
+
+/// A README before and after patching in badges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadmeDiff {
+    /// The current content
+    pub before: String,
+    /// The content with badges patched in
+    pub after: String,
+    /// The changed line ranges, in order
+    pub hunks: Vec<Hunk>,
+}
+
+/// A run of changed lines, as 0-based line ranges into each side
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    /// Lines of [`ReadmeDiff::before`] that are removed
+    pub before: Range<u32>,
+    /// Lines of [`ReadmeDiff::after`] that replace them
+    pub after: Range<u32>,
+}
+
+impl ReadmeDiff {
+    /// Diff two versions of a README line by line
+    pub fn new(before: String, after: String) -> Self {
+        let input = InternedInput::new(before.as_str(), after.as_str());
+        let hunks = compute(&input)
+            .hunks()
+            .map(|hunk| Hunk {
+                before: hunk.before,
+                after: hunk.after,
+            })
+            .collect();
+        Self {
+            before,
+            after,
+            hunks,
+        }
+    }
+
+    /// Whether patching leaves the README as it is
+    pub fn is_unchanged(&self) -> bool {
+        self.hunks.is_empty()
+    }
+
+    /// Render as a unified diff with three lines of context, labelling both sides with `path`
+    ///
+    /// Empty when nothing changes.
+    pub fn unified(&self, path: &str) -> String {
+        if self.is_unchanged() {
+            return String::new();
+        }
+
+        let before: Vec<&str> = self.before.split_inclusive('\n').collect();
+        let after: Vec<&str> = self.after.split_inclusive('\n').collect();
+        let mut diff = format!("--- {path}\n+++ {path}\n");
+
+        // Hunks whose context would touch or overlap are printed under one header
+        let mut groups: Vec<&[Hunk]> = Vec::new();
+        let mut start = 0;
+        for i in 1..=self.hunks.len() {
+            if i == self.hunks.len()
+                || self.hunks[i].before.start - self.hunks[i - 1].before.end > 2 * CONTEXT
+            {
+                groups.push(&self.hunks[start..i]);
+                start = i;
+            }
+        }
+
+        for group in groups {
+            let (first, last) = (&group[0], &group[group.len() - 1]);
+            let before_start = first.before.start.saturating_sub(CONTEXT);
+            let before_end = (last.before.end + CONTEXT).min(before.len() as u32);
+            let after_start = first.after.start - (first.before.start - before_start);
+            let after_end = last.after.end + (before_end - last.before.end);
+
+            diff.push_str(&format!(
+                "@@ -{} +{} @@\n",
+                header_range(before_start, before_end),
+                header_range(after_start, after_end)
+            ));
+
+            let mut pos = before_start;
+            for hunk in group {
+                push_lines(
+                    &mut diff,
+                    ' ',
+                    &before[pos as usize..hunk.before.start as usize],
+                );
+                push_lines(&mut diff, '-', &before[range(&hunk.before)]);
+                push_lines(&mut diff, '+', &after[range(&hunk.after)]);
+                pos = hunk.before.end;
+            }
+            push_lines(&mut diff, ' ', &before[pos as usize..before_end as usize]);
+        }
+
+        diff
+    }
+}
+
+/// Lines of context around each hunk
+const CONTEXT: u32 = 3;
+
+fn range(lines: &Range<u32>) -> Range<usize> {
+    lines.start as usize..lines.end as usize
+}
+
+/// A `start,len` hunk header range, 1-based unless empty as in GNU diff
+fn header_range(start: u32, end: u32) -> String {
+    match end - start {
+        0 => format!("{},0", start),
+        len => format!("{},{}", start + 1, len),
+    }
+}
+
+fn push_lines(diff: &mut String, prefix: char, lines: &[&str]) {
+    for line in lines {
+        diff.push(prefix);
+        diff.push_str(line);
+        if !line.ends_with('\n') {
+            diff.push_str("\n\\ No newline at end of file\n");
+        }
+    }
+}
+
+fn compute(input: &InternedInput<&str>) -> Diff {
+    let mut diff = Diff::compute(Algorithm::Histogram, input);
+    diff.postprocess_lines(input);
+    diff
+}
+
+/// Diff the README at `readme_path` against the result of patching in `badges`
+///
+/// Nothing is written; [`ReadmeDiff::after`] holds what [`update_readme`](crate::update_readme)
+/// would write, with `\n` line breaks and no BOM whatever the file's own encoding.
+pub fn diff_readme<C: RegionContent + ?Sized>(
+    readme_path: &str,
+    badges: &C,
+) -> Result<ReadmeDiff, BlazonError> {
+    let (before, after) = read_and_patch(readme_path, badges)?;
+    Ok(ReadmeDiff::new(before.content, after))
+}
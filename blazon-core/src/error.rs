@@ -0,0 +1,390 @@
+//! The error type of blazon-core
+//!
+//! [`BlazonError`] tells apart the failures a caller may want to handle differently: cargo
+//! missing or failing, no binary to measure, a binary that has not been built, file I/O, README
+//! markers that cannot be patched, and invalid config. With the `miette` feature it is also a
+//! [`miette::Diagnostic`], with help text and, for marker and config problems, a labelled span
+//! into the README or config file.
+//!
+//! [`ParseError`] is the error of the settings parsed from text, such as sizes, colour scales
+//! and badge styles, whether they come from flags or from config.
+
+use crate::region::MarkerError;
+use std::fmt;
+use std::io;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::process::ExitStatus;
+
+/// An error from blazon-core
+#[derive(Debug)]
+pub enum BlazonError {
+    /// A cargo command could not be started, usually because cargo is not installed
+    CargoNotFound {
+        /// The cargo subcommand, e.g. `tree`
+        command: &'static str,
+        source: io::Error,
+    },
+    /// A cargo command exited unsuccessfully
+    CargoFailed {
+        /// The cargo subcommand, e.g. `tree`
+        command: &'static str,
+        status: ExitStatus,
+        /// What cargo printed to stderr, if it was captured
+        stderr: String,
+    },
+    /// A cargo command printed output that could not be parsed
+    CargoOutput {
+        /// The cargo subcommand, e.g. `metadata`
+        command: &'static str,
+        message: String,
+    },
+    /// The workspace has no binary target to measure
+    NoBinary,
+    /// The workspace has several binary targets and none is preferred
+    AmbiguousBinary {
+        /// The names of the candidate binaries
+        candidates: Vec<String>,
+    },
+    /// The binary to measure is missing, e.g. because it has not been built
+    BinaryNotBuilt { path: PathBuf, source: io::Error },
+    /// A file or directory could not be read, written or measured
+    Io {
+        /// What was being done, e.g. `read`
+        action: &'static str,
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// A README's markers cannot be patched
+    Markers(Box<InvalidReadme>),
+    /// A config file, or the `blazon` table of a Cargo.toml, holds an invalid setting
+    Config(Box<InvalidConfig>),
+}
+
+/// A README whose markers cannot be patched, with the content the error points into
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidReadme {
+    /// The README path, as given
+    pub path: String,
+    /// The README content that [`MarkerError::span`] points into
+    pub content: String,
+    pub error: MarkerError,
+}
+
+/// An invalid setting, with the config file content the error points into
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidConfig {
+    /// The config file path, as shown to users
+    pub path: String,
+    /// The config file content that `span` points into
+    pub content: String,
+    /// Byte range of the offending key or value, where known
+    pub span: Option<Range<usize>>,
+    pub message: String,
+    /// A line suggesting how to fix the setting, e.g. the keys allowed where a key is unknown
+    pub help: Option<String>,
+}
+
+/// A setting that does not parse
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// A name that is not one of those allowed, e.g. an unknown badge style
+    Unknown {
+        /// What is named, e.g. `badge style`
+        kind: &'static str,
+        name: String,
+        /// The names allowed
+        expected: Vec<&'static str>,
+    },
+    /// A size whose unit is not one of B, K, M and G
+    SizeUnit { text: String, unit: String },
+    /// A size that is not a number followed by a unit
+    SizeNumber { text: String },
+    /// A colour scale, or a part of one, that does not follow the syntax
+    ColorScale {
+        /// Which part, e.g. `colour threshold`
+        part: &'static str,
+        text: String,
+        /// What is wrong with it
+        problem: &'static str,
+    },
+    /// A colour to blend that is neither a shields.io colour name nor a hex code
+    Color { text: String },
+    /// A region whose name lists a metric that does not exist
+    Region {
+        /// The region's start marker
+        marker: String,
+        metric: String,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Unknown {
+                kind,
+                name,
+                expected,
+            } => write!(
+                f,
+                "Unknown {} '{}' (expected one of: {})",
+                kind,
+                name,
+                expected.join(", ")
+            ),
+            ParseError::SizeUnit { text, unit } => {
+                write!(f, "Invalid size '{}': unknown unit '{}'", text, unit)
+            }
+            ParseError::SizeNumber { text } => {
+                write!(f, "Invalid size '{}': expected a number", text)
+            }
+            ParseError::ColorScale {
+                part,
+                text,
+                problem,
+            } => write!(f, "Invalid {} '{}': {}", part, text, problem),
+            ParseError::Color { text } => write!(
+                f,
+                "Invalid colour '{}': gradients need a shields.io colour name or hex code",
+                text
+            ),
+            ParseError::Region { marker, metric } => {
+                let ids: Vec<&str> = crate::METRICS.iter().map(|(id, _)| *id).collect();
+                write!(
+                    f,
+                    "Invalid region '{}': unknown metric '{}' (expected one of: {})",
+                    marker,
+                    metric,
+                    ids.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl BlazonError {
+    /// An I/O error while doing `action` to `path`
+    pub(crate) fn io(action: &'static str, path: impl Into<PathBuf>, source: io::Error) -> Self {
+        BlazonError::Io {
+            action,
+            path: path.into(),
+            source,
+        }
+    }
+
+    /// A cargo command that could not be started
+    pub(crate) fn cargo_not_found(command: &'static str) -> impl FnOnce(io::Error) -> Self {
+        move |source| BlazonError::CargoNotFound { command, source }
+    }
+
+    /// A cargo command that exited with `output`'s unsuccessful status
+    pub(crate) fn cargo_failed(command: &'static str, output: &std::process::Output) -> Self {
+        BlazonError::CargoFailed {
+            command,
+            status: output.status,
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }
+    }
+
+    /// The marker error, if this is one
+    pub fn marker_error(&self) -> Option<&MarkerError> {
+        match self {
+            BlazonError::Markers(readme) => Some(&readme.error),
+            _ => None,
+        }
+    }
+
+    /// A line suggesting how to fix the error, where there is one
+    pub fn help(&self) -> Option<String> {
+        match self {
+            BlazonError::CargoNotFound { .. } => Some(
+                "install Rust with rustup, or make sure cargo is on PATH or CARGO points to it"
+                    .to_string(),
+            ),
+            BlazonError::NoBinary => Some("add a [[bin]] target to Cargo.toml".to_string()),
+            BlazonError::AmbiguousBinary { .. } => {
+                Some("choose one of the binaries explicitly".to_string())
+            }
+            BlazonError::BinaryNotBuilt { .. } => {
+                Some("build the binary before measuring it".to_string())
+            }
+            BlazonError::Markers(readme) => readme.error.help(),
+            BlazonError::Config(config) => config.help.clone(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BlazonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BlazonError::CargoNotFound { command, source } => {
+                write!(f, "Failed to run cargo {}: {}", command, source)
+            }
+            BlazonError::CargoFailed {
+                command,
+                status,
+                stderr,
+            } => {
+                write!(f, "cargo {} failed ({})", command, status)?;
+                if !stderr.is_empty() {
+                    write!(f, ":\n{}", stderr)?;
+                }
+                Ok(())
+            }
+            BlazonError::CargoOutput { command, message } => {
+                write!(f, "Failed to parse cargo {} output: {}", command, message)
+            }
+            BlazonError::NoBinary => write!(f, "No binary target found"),
+            BlazonError::AmbiguousBinary { candidates } => {
+                write!(
+                    f,
+                    "Multiple binary targets found: {}",
+                    candidates.join(", ")
+                )
+            }
+            BlazonError::BinaryNotBuilt { path, source } => {
+                write!(f, "Failed to get size for {}: {}", path.display(), source)
+            }
+            BlazonError::Io {
+                action,
+                path,
+                source,
+            } => write!(f, "Failed to {} {}: {}", action, path.display(), source),
+            BlazonError::Markers(readme) => write!(f, "{} in {}", readme.error, readme.path),
+            BlazonError::Config(config) => write!(f, "{} in {}", config.message, config.path),
+        }
+    }
+}
+
+impl std::error::Error for BlazonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BlazonError::CargoNotFound { source, .. }
+            | BlazonError::BinaryNotBuilt { source, .. }
+            | BlazonError::Io { source, .. } => Some(source),
+            BlazonError::Markers(readme) => Some(&readme.error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "miette")]
+mod diagnostic {
+    use super::*;
+    use crate::region::MarkerErrorKind;
+    use miette::{
+        Diagnostic, LabeledSpan, MietteError, MietteSpanContents, SourceCode, SourceSpan,
+        SpanContents,
+    };
+
+    impl Diagnostic for BlazonError {
+        fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+            let code = match self {
+                BlazonError::CargoNotFound { .. } => "blazon::cargo_not_found",
+                BlazonError::CargoFailed { .. } => "blazon::cargo_failed",
+                BlazonError::CargoOutput { .. } => "blazon::cargo_output",
+                BlazonError::NoBinary => "blazon::no_binary",
+                BlazonError::AmbiguousBinary { .. } => "blazon::ambiguous_binary",
+                BlazonError::BinaryNotBuilt { .. } => "blazon::binary_not_built",
+                BlazonError::Io { .. } => "blazon::io",
+                BlazonError::Markers(readme) => match readme.error.kind {
+                    MarkerErrorKind::NoMarkers => "blazon::no_markers",
+                    MarkerErrorKind::MissingStart => "blazon::missing_start_marker",
+                    MarkerErrorKind::MissingEnd => "blazon::missing_end_marker",
+                    MarkerErrorKind::EndBeforeStart => "blazon::end_before_start_marker",
+                    MarkerErrorKind::Duplicate { .. } => "blazon::duplicate_marker",
+                    MarkerErrorKind::Invalid(_) => "blazon::invalid_region",
+                },
+                BlazonError::Config(_) => "blazon::config",
+            };
+            Some(Box::new(code))
+        }
+
+        fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+            self.help()
+                .map(|help| Box::new(help) as Box<dyn fmt::Display>)
+        }
+
+        fn source_code(&self) -> Option<&dyn SourceCode> {
+            match self {
+                BlazonError::Markers(readme) => Some(readme.as_ref()),
+                BlazonError::Config(config) => Some(config.as_ref()),
+                _ => None,
+            }
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            if let BlazonError::Config(config) = self {
+                let span = config.span.clone()?;
+                let label = LabeledSpan::new_with_span(Some("here".to_string()), span);
+                return Some(Box::new(std::iter::once(label)));
+            }
+            let error = self.marker_error()?;
+            let (label, related) = match error.kind {
+                MarkerErrorKind::NoMarkers => return None,
+                MarkerErrorKind::MissingStart => ("this end marker has no start", None),
+                MarkerErrorKind::MissingEnd => ("this start marker is never closed", None),
+                MarkerErrorKind::EndBeforeStart => ("closed here", Some("opened here")),
+                MarkerErrorKind::Duplicate { .. } => ("repeated here", Some("first here")),
+                MarkerErrorKind::Invalid(_) => ("in this region", None),
+            };
+            let span = |range: &std::ops::Range<usize>, label: &str| {
+                LabeledSpan::new_with_span(Some(label.to_string()), range.clone())
+            };
+            let mut labels = vec![span(&error.span, label)];
+            if let (Some(range), Some(label)) = (&error.related, related) {
+                labels.push(span(range, label));
+            }
+            Some(Box::new(labels.into_iter()))
+        }
+    }
+
+    /// The README content, named by its path in reports
+    impl SourceCode for InvalidReadme {
+        fn read_span<'a>(
+            &'a self,
+            span: &SourceSpan,
+            context_lines_before: usize,
+            context_lines_after: usize,
+        ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
+            let contents =
+                self.content
+                    .read_span(span, context_lines_before, context_lines_after)?;
+            let named = MietteSpanContents::new_named(
+                self.path.clone(),
+                contents.data(),
+                *contents.span(),
+                contents.line(),
+                contents.column(),
+                contents.line_count(),
+            );
+            Ok(Box::new(named.with_language("markdown")))
+        }
+    }
+
+    /// The config file content, named by its path in reports
+    impl SourceCode for InvalidConfig {
+        fn read_span<'a>(
+            &'a self,
+            span: &SourceSpan,
+            context_lines_before: usize,
+            context_lines_after: usize,
+        ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
+            let contents =
+                self.content
+                    .read_span(span, context_lines_before, context_lines_after)?;
+            let named = MietteSpanContents::new_named(
+                self.path.clone(),
+                contents.data(),
+                *contents.span(),
+                contents.line(),
+                contents.column(),
+                contents.line_count(),
+            );
+            Ok(Box::new(named.with_language("toml")))
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Reading and atomically rewriting text files
+//!
+//! A [`TextFile`] remembers the line endings and byte order mark of the file it was read from,
+//! so content patched in with `\n` line breaks is written back in the file's own encoding.
+//! Writes go through a temporary file renamed over the original, so a crash never leaves a
+//! truncated file, and a symlink is followed to the file it points to rather than replaced.
+
+use crate::BlazonError;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const BOM: &str = "\u{feff}";
+
+/// How many names [`create_temp`] tries before giving up
+const TEMP_ATTEMPTS: usize = 100;
+
+/// Numbers the temporary files of this process, so no two share a name
+static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The line break used throughout a file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`, also used for files with no line breaks or a mix of both
+    #[default]
+    Lf,
+    /// `\r\n` on every line
+    Crlf,
+}
+
+impl LineEnding {
+    /// The line ending of `content`: CRLF only if every line break is `\r\n`
+    pub fn of(content: &str) -> Self {
+        let breaks = content.matches('\n').count();
+        if breaks > 0 && content.matches("\r\n").count() == breaks {
+            LineEnding::Crlf
+        } else {
+            LineEnding::Lf
+        }
+    }
+}
+
+/// A UTF-8 text file, read with `\n` line breaks and no byte order mark
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextFile {
+    /// The file written to, with any symlinks resolved
+    pub path: PathBuf,
+    /// The content, with CRLF line breaks normalised to `\n` and the BOM removed
+    pub content: String,
+    /// The file's line ending
+    pub line_ending: LineEnding,
+    /// Whether the file starts with a UTF-8 byte order mark
+    pub bom: bool,
+}
+
+impl TextFile {
+    /// Read the file at `path`, following symlinks
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, BlazonError> {
+        let path = path.as_ref();
+        let raw = fs::read_to_string(path).map_err(|e| BlazonError::io("read", path, e))?;
+        let resolved = fs::canonicalize(path).map_err(|e| BlazonError::io("resolve", path, e))?;
+        Ok(Self::parse(resolved, &raw))
+    }
+
+    /// A file at `path` whose raw text is `raw`
+    pub fn parse(path: PathBuf, raw: &str) -> Self {
+        let (bom, text) = match raw.strip_prefix(BOM) {
+            Some(text) => (true, text),
+            None => (false, raw),
+        };
+        let line_ending = LineEnding::of(text);
+        let content = match line_ending {
+            LineEnding::Lf => text.to_string(),
+            LineEnding::Crlf => text.replace("\r\n", "\n"),
+        };
+        Self {
+            path,
+            content,
+            line_ending,
+            bom,
+        }
+    }
+
+    /// `content` in this file's encoding: its line ending and BOM restored
+    pub fn encode(&self, content: &str) -> String {
+        let mut encoded = String::with_capacity(content.len() + BOM.len());
+        if self.bom {
+            encoded.push_str(BOM);
+        }
+        match self.line_ending {
+            LineEnding::Lf => encoded.push_str(content),
+            LineEnding::Crlf => encoded.push_str(&content.replace('\n', "\r\n")),
+        }
+        encoded
+    }
+
+    /// Atomically replace the file with `content`, encoded as the file was read
+    pub fn write(&self, content: &str) -> Result<(), BlazonError> {
+        write_atomic(&self.path, self.encode(content).as_bytes())
+    }
+}
+
+/// Replace the file at `path` with `bytes` via a temporary file in the same directory
+///
+/// A symlink at `path` is followed, and an existing file keeps its permissions.
+pub fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), BlazonError> {
+    let path = match fs::canonicalize(path) {
+        Ok(resolved) => resolved,
+        Err(_) => path.to_path_buf(),
+    };
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let file_name = path.file_name().ok_or_else(|| {
+        let source = std::io::Error::new(std::io::ErrorKind::InvalidInput, "not a file path");
+        BlazonError::io("write", &path, source)
+    })?;
+    let (temp_path, mut temp) = create_temp(dir, &file_name.to_string_lossy())
+        .map_err(|e| BlazonError::io("write", &path, e))?;
+
+    let result = (|| {
+        temp.write_all(bytes)?;
+        if let Ok(metadata) = fs::metadata(&path) {
+            temp.set_permissions(metadata.permissions())?;
+        }
+        temp.sync_all()?;
+        fs::rename(&temp_path, &path)
+    })();
+
+    result.map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        BlazonError::io("write", path, e)
+    })
+}
+
+/// Create a new file in `dir` named after `file_name`, returning its path
+///
+/// The file must not exist yet, so nothing already at the path, such as a symlink, is written
+/// through or overwritten; a name that is taken is skipped for the next.
+fn create_temp(dir: &Path, file_name: &str) -> io::Result<(PathBuf, fs::File)> {
+    for _ in 0..TEMP_ATTEMPTS {
+        let temp_path = dir.join(format!(
+            ".{}.{}.{}.blazon.tmp",
+            file_name,
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        match fs::File::options()
+            .write(true)
+            .create_new(true)
+            .open(&temp_path)
+        {
+            Ok(file) => return Ok((temp_path, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "no free name for a temporary file",
+    ))
+}
+
+/// `path` relative to the current directory when it is inside it, so messages stay short
+pub fn relative_to_cwd(path: &Path) -> PathBuf {
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf())
+}
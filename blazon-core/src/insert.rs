@@ -0,0 +1,172 @@
+//! Adding a marker pair to a README that has none
+//!
+//! [`insert_markers`] picks where badges belong: at the end of the badge row near the top of the
+//! README, so blazon's badges join it, or else on the line after the first heading.
+
+use crate::region::Region;
+use std::fmt;
+
+/// Where markers were inserted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    /// At the end of the badge row, inline with the other badges
+    BadgeRow,
+    /// On their own lines after the first heading
+    Heading,
+    /// On their own lines at the top of a README with no badges or headings
+    Start,
+}
+
+/// Where markers were inserted into a README
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Insertion {
+    /// What the markers were placed after
+    pub anchor: Anchor,
+    /// The 1-based line holding the start marker
+    pub line: usize,
+}
+
+impl fmt::Display for Insertion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.anchor {
+            Anchor::BadgeRow => write!(f, "at the end of the badge row on line {}", self.line),
+            Anchor::Heading => write!(
+                f,
+                "on line {}, after the heading on line {}",
+                self.line,
+                self.line - 1
+            ),
+            Anchor::Start => write!(f, "on line {}, at the top", self.line),
+        }
+    }
+}
+
+/// Insert a `<!-- blazon -->` marker pair into `content`, unless it already has a marker
+///
+/// Returns `None` when `content` already has markers, even only an end marker whose start is
+/// missing, otherwise the new content and where the markers went.
+pub fn insert_markers(content: &str) -> Option<(String, Insertion)> {
+    if !crate::region::find_markers(content).is_empty() {
+        return None;
+    }
+
+    let region = Region { name: None };
+    let pair = format!("{}{}", region.start_marker(), region.end_marker());
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+
+    if let Some(row_end) = badge_row_end(&lines) {
+        let line = lines[row_end];
+        let text = line.trim_end_matches(['\r', '\n']);
+        let mut inserted = lines[..row_end].concat();
+        inserted.push_str(text);
+        inserted.push_str(&pair);
+        inserted.push_str(&line[text.len()..]);
+        inserted.push_str(&lines[row_end + 1..].concat());
+        let insertion = Insertion {
+            anchor: Anchor::BadgeRow,
+            line: row_end + 1,
+        };
+        return Some((inserted, insertion));
+    }
+
+    let (at, anchor) = match first_heading_end(&lines) {
+        Some(heading_end) => (heading_end + 1, Anchor::Heading),
+        None => (0, Anchor::Start),
+    };
+    let mut inserted = lines[..at].concat();
+    if !inserted.is_empty() && !inserted.ends_with('\n') {
+        inserted.push('\n');
+    }
+    inserted.push_str(&format!(
+        "{}\n{}\n",
+        region.start_marker(),
+        region.end_marker()
+    ));
+    // Keep the badges out of the following paragraph
+    if lines.get(at).is_some_and(|next| !next.trim().is_empty()) {
+        inserted.push('\n');
+    }
+    inserted.push_str(&lines[at..].concat());
+    let insertion = Insertion {
+        anchor,
+        line: at + 1,
+    };
+    Some((inserted, insertion))
+}
+
+/// The index of the last line of the first badge row, if it comes before any prose
+///
+/// Headings, blank lines and HTML lines may precede the row.
+fn badge_row_end(lines: &[&str]) -> Option<usize> {
+    let start = lines.iter().position(|line| {
+        let line = line.trim();
+        !(line.is_empty() || line.starts_with('#') || line.starts_with('<') || is_underline(line))
+    })?;
+    if !is_badge_line(lines[start]) {
+        return None;
+    }
+    let len = lines[start..]
+        .iter()
+        .take_while(|line| is_badge_line(line))
+        .count();
+    Some(start + len - 1)
+}
+
+/// The index of the last line of the first heading: a `#` line, or text underlined by `=`/`-`
+fn first_heading_end(lines: &[&str]) -> Option<usize> {
+    lines.iter().enumerate().find_map(|(i, line)| {
+        let line = line.trim();
+        if line.starts_with('#') {
+            Some(i)
+        } else if !line.is_empty()
+            && lines
+                .get(i + 1)
+                .is_some_and(|next| is_underline(next.trim()))
+        {
+            Some(i + 1)
+        } else {
+            None
+        }
+    })
+}
+
+/// Whether `line` is a setext heading underline
+fn is_underline(line: &str) -> bool {
+    !line.is_empty() && (line.chars().all(|c| c == '=') || line.chars().all(|c| c == '-'))
+}
+
+/// Whether `line` holds only images, optionally linked, such as `[![ci](ci.svg)](ci)`
+fn is_badge_line(line: &str) -> bool {
+    let mut rest = line.trim();
+    if rest.is_empty() {
+        return false;
+    }
+    while !rest.is_empty() {
+        match skip_badge(rest) {
+            Some(after) => rest = after.trim_start(),
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Skip the image or linked image starting `text`, returning the rest
+fn skip_badge(text: &str) -> Option<&str> {
+    match text.strip_prefix("[![") {
+        Some(linked) => skip_target(skip_image(linked)?.strip_prefix(']')?),
+        None => skip_image(text.strip_prefix("![")?),
+    }
+}
+
+/// Skip `alt](src)` after `![`, returning the rest
+fn skip_image(text: &str) -> Option<&str> {
+    let close = text.find("](")?;
+    skip_target(&text[close + 1..])
+}
+
+/// Skip a `(target)`, returning the rest
+fn skip_target(text: &str) -> Option<&str> {
+    let text = text.strip_prefix('(')?;
+    let close = text.find(')')?;
+    Some(&text[close + 1..])
+}
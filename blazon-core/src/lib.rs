@@ -1,109 +1,390 @@
 //! blazon-core: badge generation for Rust project metrics
 
+pub mod badge;
+pub mod budget;
+pub mod color;
+pub mod config;
 pub mod debug;
-
+pub mod deps;
+pub mod diff;
+pub mod error;
+pub mod file;
+pub mod insert;
+pub mod metadata;
+pub mod region;
+
+use badge::{Badge, BadgeStyle};
+use color::ColorScale;
+use error::InvalidReadme;
+pub use error::{BlazonError, ParseError};
+use facet::Facet;
+use metadata::Metadata;
+use region::{Layout, MarkerError, MarkerErrorKind, RegionContent, RenderedBadge, RenderedBadges};
 use std::collections::HashSet;
-use std::process::Command;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 
 /// Project metrics
+#[derive(Debug, Clone, Default, Facet)]
 pub struct Metrics {
     pub dep_count: usize,
     pub binary_size_bytes: u64,
+    pub target_dir_bytes: u64,
+    pub target_dir_profiles: TargetDirProfiles,
+}
+
+/// Per-profile breakdown of the target directory size in bytes
+///
+/// Each field is the size of the matching top-level subdirectory of the target directory, so
+/// the sum can be less than [`Metrics::target_dir_bytes`] (which also counts everything else,
+/// e.g. custom profiles and `--target` triple subdirectories).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Facet)]
+pub struct TargetDirProfiles {
+    pub debug: u64,
+    pub release: u64,
+    pub doc: u64,
 }
 
-/// Count unique dependencies using cargo tree
-pub fn count_dependencies() -> Result<usize, String> {
-    let output = Command::new("cargo")
-        .args(["tree", "--edges", "normal", "--prefix", "none"])
+/// A command running cargo: the cargo that invoked blazon if there is one, otherwise `cargo`
+///
+/// Cargo sets `CARGO` for the subcommands it runs, so `cargo +nightly blazon` measures with the
+/// nightly toolchain rather than whichever cargo is first on `PATH`.
+pub fn cargo() -> Command {
+    Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+}
+
+/// Count the dependencies of the package of `options` using cargo tree
+///
+/// The tree is that of the build [`build`] makes, for the same target and with the same features,
+/// so a binary's `required-features` count their dependencies too. The profile of `options` is
+/// not used. See [`deps`] for what is counted.
+pub fn count_dependencies(options: &BuildOptions) -> Result<usize, BlazonError> {
+    let output = options
+        .cargo("tree")
+        .args(["--edges", &options.deps.edges(), "--prefix", "depth"])
         .output()
-        .map_err(|e| format!("Failed to run cargo tree: {}", e))?;
+        .map_err(BlazonError::cargo_not_found("tree"))?;
 
     if !output.status.success() {
-        return Err("cargo tree failed".to_string());
+        return Err(BlazonError::cargo_failed("tree", &output));
     }
 
+    let members: Vec<String> = if options.deps.exclude_workspace {
+        Metadata::load_manifest(options.manifest_path.as_deref())?
+            .members()
+            .map(|p| p.name.clone())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut unique_deps: HashSet<String> = HashSet::new();
+    Ok(deps::count_tree(&stdout, &options.deps, &members))
+}
 
-    for line in stdout.lines() {
-        if let Some(dep) = line.split_whitespace().next()
-            && !dep.is_empty()
-        {
-            unique_deps.insert(dep.to_string());
+/// Get the main binary name from Cargo.toml
+///
+/// See [`Metadata::infer_binary`] for how the binary is chosen.
+pub fn get_binary_name() -> Result<String, BlazonError> {
+    let cwd = std::env::current_dir().map_err(|e| BlazonError::io("resolve", ".", e))?;
+    let metadata = Metadata::load()?;
+    Ok(metadata.infer_binary(&cwd)?.target.name.clone())
+}
+
+/// Options controlling which build of the binary is measured
+#[derive(Debug, Clone)]
+pub struct BuildOptions {
+    /// Cargo profile to build and measure
+    pub profile: String,
+    /// Target triple to build for, if not the host
+    pub target: Option<String>,
+    /// Features to enable, e.g. the `required-features` of the measured binary
+    pub features: Vec<String>,
+    /// Cargo.toml to run cargo against, instead of the one cargo finds from the current directory
+    pub manifest_path: Option<PathBuf>,
+    /// Package to build and count the dependencies of, as `cargo -p`
+    pub package: Option<String>,
+    /// Which dependencies of the package are counted
+    pub deps: deps::DepOptions,
+}
+
+impl Default for BuildOptions {
+    fn default() -> Self {
+        Self {
+            profile: "release".to_string(),
+            target: None,
+            features: Vec::new(),
+            manifest_path: None,
+            package: None,
+            deps: deps::DepOptions::default(),
+        }
+    }
+}
+
+impl BuildOptions {
+    /// Name of the directory cargo puts this profile's artifacts in
+    ///
+    /// The built-in `dev` and `test` profiles share `debug`, and `bench` shares `release`; custom
+    /// profiles use their own name.
+    pub fn profile_dir(&self) -> &str {
+        match self.profile.as_str() {
+            "dev" | "test" => "debug",
+            "bench" => "release",
+            profile => profile,
+        }
+    }
+
+    /// A `cargo` command running `subcommand` on this manifest and package, for this target and
+    /// with these features
+    fn cargo(&self, subcommand: &str) -> Command {
+        let mut command = cargo();
+        command.arg(subcommand);
+        if let Some(manifest_path) = &self.manifest_path {
+            command.arg("--manifest-path").arg(manifest_path);
         }
+        if let Some(package) = &self.package {
+            command.args(["--package", package]);
+        }
+        if let Some(target) = &self.target {
+            command.args(["--target", target]);
+        }
+        if !self.features.is_empty() {
+            command.args(["--features", &self.features.join(",")]);
+        }
+        command
     }
+}
 
-    Ok(unique_deps.len())
+/// A binary produced by a cargo build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinaryArtifact {
+    pub name: String,
+    pub path: PathBuf,
 }
 
-/// Get the main binary name from Cargo.toml
-pub fn get_binary_name() -> Result<String, String> {
-    let output = Command::new("cargo")
-        .args(["metadata", "--format-version", "1", "--no-deps"])
+/// A line of `cargo build --message-format=json` output, keeping only the fields we read
+#[derive(Facet)]
+struct CargoMessage {
+    reason: String,
+    #[facet(default)]
+    target: Option<MessageTarget>,
+    #[facet(default)]
+    executable: Option<String>,
+}
+
+#[derive(Facet)]
+struct MessageTarget {
+    name: String,
+    kind: Vec<String>,
+}
+
+/// Build with the given options, returning the binaries cargo reports having produced
+pub fn build(options: &BuildOptions) -> Result<Vec<BinaryArtifact>, BlazonError> {
+    let output = options
+        .cargo("build")
+        .args(["--quiet", "--message-format=json-render-diagnostics"])
+        .args(["--profile", &options.profile])
+        .stderr(Stdio::inherit())
         .output()
-        .map_err(|e| format!("Failed to run cargo metadata: {}", e))?;
+        .map_err(BlazonError::cargo_not_found("build"))?;
 
     if !output.status.success() {
-        return Err("cargo metadata failed".to_string());
+        return Err(BlazonError::cargo_failed("build", &output));
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
+    parse_artifacts(&String::from_utf8_lossy(&output.stdout))
+}
 
-    // Simple string search for binary target
-    for line in stdout.lines() {
-        if (line.contains(r#""kind":["bin"]"#) || line.contains(r#""kind": ["bin"]"#))
-            && let Some(name_line) = stdout
-                .lines()
-                .skip_while(|l| {
-                    !l.contains(r#""kind":["bin"]"#) && !l.contains(r#""kind": ["bin"]"#)
-                })
-                .find(|l| l.contains(r#""name":"#))
-            && let Some(start) = name_line.find(r#""name":""#)
+/// Build in release mode
+pub fn build_release() -> Result<Vec<BinaryArtifact>, BlazonError> {
+    build(&BuildOptions::default())
+}
+
+/// Extract the binary artifacts from `cargo build --message-format=json` output
+pub fn parse_artifacts(messages: &str) -> Result<Vec<BinaryArtifact>, BlazonError> {
+    let mut artifacts = Vec::new();
+
+    for line in messages.lines().filter(|l| l.starts_with('{')) {
+        let message: CargoMessage =
+            facet_json::from_str(line).map_err(|e| BlazonError::CargoOutput {
+                command: "build",
+                message: e.to_string(),
+            })?;
+
+        if message.reason != "compiler-artifact" {
+            continue;
+        }
+        if let (Some(target), Some(executable)) = (message.target, message.executable)
+            && target.kind.iter().any(|k| k == "bin")
         {
-            let name_start = start + r#""name":""#.len();
-            if let Some(end) = name_line[name_start..].find('"') {
-                return Ok(name_line[name_start..name_start + end].to_string());
-            }
+            artifacts.push(BinaryArtifact {
+                name: target.name,
+                path: PathBuf::from(executable),
+            });
         }
     }
 
-    Err("No binary target found".to_string())
+    Ok(artifacts)
 }
 
-/// Build in release mode
-pub fn build_release() -> Result<(), String> {
-    let status = Command::new("cargo")
-        .args(["build", "--release", "--quiet"])
-        .status()
-        .map_err(|e| format!("Failed to run cargo build: {}", e))?;
+/// Get the path cargo writes a binary to for the given build options
+pub fn get_binary_path(target_dir: &Path, binary_name: &str, options: &BuildOptions) -> PathBuf {
+    let mut path = target_dir.to_path_buf();
+    if let Some(target) = &options.target {
+        path.push(target);
+    }
+    path.push(options.profile_dir());
 
-    if !status.success() {
-        return Err("cargo build failed".to_string());
+    let windows = match &options.target {
+        Some(target) => target.contains("windows"),
+        None => cfg!(windows),
+    };
+    if windows {
+        path.push(format!("{}.exe", binary_name));
+    } else {
+        path.push(binary_name);
     }
+    path
+}
 
-    Ok(())
+/// Get the path cargo writes a library's rlib to for the given build options
+///
+/// `lib_name` is the name of the library target, whose dashes cargo turns into underscores.
+pub fn get_library_path(target_dir: &Path, lib_name: &str, options: &BuildOptions) -> PathBuf {
+    let mut path = target_dir.to_path_buf();
+    if let Some(target) = &options.target {
+        path.push(target);
+    }
+    path.push(options.profile_dir());
+    path.push(format!("lib{}.rlib", lib_name.replace('-', "_")));
+    path
 }
 
 /// Get binary size in bytes
-pub fn get_binary_size(binary_name: &str) -> Result<u64, String> {
-    let path = format!("target/release/{}", binary_name);
-    std::fs::metadata(&path)
+pub fn get_binary_size(binary_path: &Path) -> Result<u64, BlazonError> {
+    std::fs::metadata(binary_path)
         .map(|m| m.len())
-        .map_err(|e| format!("Failed to get size for {}: {}", path, e))
+        .map_err(|source| BlazonError::BinaryNotBuilt {
+            path: binary_path.to_path_buf(),
+            source,
+        })
+}
+
+/// Get the target directory cargo builds into
+///
+/// This is read from `cargo metadata` rather than assumed to be `./target`, so it respects
+/// `CARGO_TARGET_DIR`, `build.target-dir` in `.cargo/config.toml` and workspace roots.
+pub fn get_target_dir() -> Result<PathBuf, BlazonError> {
+    Metadata::load().map(|m| m.target_directory)
+}
+
+/// Get the total size of the target directory, with a per-profile breakdown
+///
+/// Sizes are apparent sizes, the sum of file lengths, rather than the disk space the files take
+/// up. Cargo hardlinks each final artifact to its copy in `deps`, so on unix a file with several
+/// links is counted once. A target directory that does not exist yet (e.g. a fresh checkout) has
+/// size zero.
+pub fn get_target_dir_size(target_dir: &Path) -> Result<(u64, TargetDirProfiles), BlazonError> {
+    let size_of = |path: &Path| {
+        dir_size(path, &mut HashSet::new()).map_err(|e| BlazonError::io("measure", path, e))
+    };
+
+    let profiles = TargetDirProfiles {
+        debug: size_of(&target_dir.join("debug"))?,
+        release: size_of(&target_dir.join("release"))?,
+        doc: size_of(&target_dir.join("doc"))?,
+    };
+
+    Ok((size_of(target_dir)?, profiles))
+}
+
+/// Sum the lengths of all files under `path`, without following symlinks
+///
+/// On unix, `seen` holds the `(device, inode)` of each hardlinked file already counted, so its
+/// other links add nothing.
+fn dir_size(path: &Path, seen: &mut HashSet<(u64, u64)>) -> std::io::Result<u64> {
+    let metadata = match std::fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+
+    if !metadata.is_dir() {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            if metadata.nlink() > 1 && !seen.insert((metadata.dev(), metadata.ino())) {
+                return Ok(0);
+            }
+        }
+        return Ok(metadata.len());
+    }
+
+    let mut total = 0;
+    for entry in std::fs::read_dir(path)? {
+        total += dir_size(&entry?.path(), seen)?;
+    }
+    Ok(total)
 }
 
 /// Collect all metrics
-pub fn collect_metrics(binary_name: &str, should_build: bool) -> Result<Metrics, String> {
+///
+/// When building, the binary is measured at the path cargo reports for it; otherwise its path is
+/// resolved from the target directory and build options.
+pub fn collect_metrics(
+    binary_name: &str,
+    should_build: bool,
+    options: &BuildOptions,
+) -> Result<Metrics, BlazonError> {
+    let artifacts = if should_build {
+        build(options)?
+    } else {
+        Vec::new()
+    };
+
+    let metadata = Metadata::load_manifest(options.manifest_path.as_deref())?;
+    let target_dir = metadata.target_directory;
+    let binary_path = match artifacts.into_iter().find(|a| a.name == binary_name) {
+        Some(artifact) => artifact.path,
+        None => get_binary_path(&target_dir, binary_name, options),
+    };
+
+    measure(&binary_path, &target_dir, options)
+}
+
+/// Collect all metrics for a library, measuring its rlib in place of a binary
+///
+/// This is how workspace members without a binary are measured.
+pub fn collect_library_metrics(
+    lib_name: &str,
+    should_build: bool,
+    options: &BuildOptions,
+) -> Result<Metrics, BlazonError> {
     if should_build {
-        build_release()?;
+        build(options)?;
     }
 
-    let dep_count = count_dependencies()?;
-    let binary_size_bytes = get_binary_size(binary_name)?;
+    let target_dir = Metadata::load_manifest(options.manifest_path.as_deref())?.target_directory;
+    let library_path = get_library_path(&target_dir, lib_name, options);
+
+    measure(&library_path, &target_dir, options)
+}
+
+/// Count the dependencies and measure the artifact at `artifact_path` and the target directory
+fn measure(
+    artifact_path: &Path,
+    target_dir: &Path,
+    options: &BuildOptions,
+) -> Result<Metrics, BlazonError> {
+    let dep_count = count_dependencies(options)?;
+    let binary_size_bytes = get_binary_size(artifact_path)?;
+    let (target_dir_bytes, target_dir_profiles) = get_target_dir_size(target_dir)?;
 
     Ok(Metrics {
         dep_count,
         binary_size_bytes,
+        target_dir_bytes,
+        target_dir_profiles,
     })
 }
 
@@ -113,64 +394,496 @@ pub fn format_size(bytes: u64) -> String {
         format!("{}B", bytes)
     } else if bytes < 1024 * 1024 {
         format!("{:.1}K", bytes as f64 / 1024.0)
-    } else {
+    } else if bytes < 1024 * 1024 * 1024 {
         format!("{:.1}M", bytes as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{:.1}G", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+    }
+}
+
+/// Parse a human-readable size such as `512`, `2M` or `1.5GiB` into bytes
+///
+/// Accepts the suffixes [`format_size`] prints (1024-based), optionally followed by `B` or `iB`.
+pub fn parse_size(text: &str) -> Result<u64, ParseError> {
+    let trimmed = text.trim();
+    let number_end = trimmed
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(number_end);
+
+    let multiplier: u64 = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" | "KIB" => 1024,
+        "M" | "MB" | "MIB" => 1024 * 1024,
+        "G" | "GB" | "GIB" => 1024 * 1024 * 1024,
+        _ => {
+            return Err(ParseError::SizeUnit {
+                text: text.to_string(),
+                unit: unit.to_string(),
+            });
+        }
+    };
+    let number: f64 = number.parse().map_err(|_| ParseError::SizeNumber {
+        text: text.to_string(),
+    })?;
+
+    Ok((number * multiplier as f64).round() as u64)
+}
+
+/// Colour scale of each metric's badge
+#[derive(Debug, Clone)]
+pub struct MetricColors {
+    pub deps: ColorScale,
+    pub size: ColorScale,
+    pub target_dir: ColorScale,
+}
+
+impl Default for MetricColors {
+    fn default() -> Self {
+        Self {
+            deps: ColorScale::Fixed("blue".to_string()),
+            size: ColorScale::Fixed("green".to_string()),
+            target_dir: ColorScale::Fixed("orange".to_string()),
+        }
     }
 }
 
+impl MetricColors {
+    /// The colour scale of the metric with id `id`, if there is one
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut ColorScale> {
+        match id {
+            "deps" => Some(&mut self.deps),
+            "size" => Some(&mut self.size),
+            "target-dir" => Some(&mut self.target_dir),
+            _ => None,
+        }
+    }
+}
+
+/// Text on the left of each metric's badge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetricLabels {
+    pub deps: String,
+    pub size: String,
+    pub target_dir: String,
+}
+
+impl Default for MetricLabels {
+    fn default() -> Self {
+        Self {
+            deps: "cargo tree".to_string(),
+            size: Artifact::Binary.size_label().to_string(),
+            target_dir: "target dir".to_string(),
+        }
+    }
+}
+
+impl MetricLabels {
+    /// The label of the metric with id `id`, if there is one
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut String> {
+        match id {
+            "deps" => Some(&mut self.deps),
+            "size" => Some(&mut self.size),
+            "target-dir" => Some(&mut self.target_dir),
+            _ => None,
+        }
+    }
+}
+
+/// How badges are presented, independent of the metrics they show
+#[derive(Debug, Clone)]
+pub struct BadgeOptions {
+    /// Visual style of every badge
+    pub style: BadgeStyle,
+    /// Colour scale of each metric, applied to its value
+    pub colors: MetricColors,
+    /// Label of each metric's badge
+    pub labels: MetricLabels,
+    /// Ids of the metrics to show badges for, in order
+    pub metrics: Vec<String>,
+    /// What the size metric measures, which names it in the alt text of its badge
+    pub artifact: Artifact,
+}
+
+/// The kind of artifact the size metric measures
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Artifact {
+    /// A binary, as for a package with a binary target
+    #[default]
+    Binary,
+    /// A library's rlib, as for a workspace member without a binary
+    Library,
+}
+
+impl Artifact {
+    /// The name of its size in badge alt text, e.g. `Binary Size`
+    pub fn size_name(self) -> &'static str {
+        match self {
+            Artifact::Binary => "Binary Size",
+            Artifact::Library => "Library Size",
+        }
+    }
+
+    /// The name of its size in table rows, e.g. `Binary size`
+    pub fn size_row_name(self) -> &'static str {
+        match self {
+            Artifact::Binary => "Binary size",
+            Artifact::Library => "Library size",
+        }
+    }
+
+    /// The default label of the size badge, e.g. `build size`
+    pub fn size_label(self) -> &'static str {
+        match self {
+            Artifact::Binary => "build size",
+            Artifact::Library => "library size",
+        }
+    }
+}
+
+impl BadgeOptions {
+    /// The name of the metric with id `id` in table rows, from [`METRICS`] but for the artifact
+    /// measured by the size metric
+    pub fn metric_name(&self, id: &str) -> String {
+        match METRICS.iter().find(|(known, _)| *known == id) {
+            Some(("size", _)) => self.artifact.size_row_name().to_string(),
+            Some((_, name)) => name.to_string(),
+            None => id.to_string(),
+        }
+    }
+}
+
+impl Default for BadgeOptions {
+    fn default() -> Self {
+        Self {
+            style: BadgeStyle::default(),
+            colors: MetricColors::default(),
+            labels: MetricLabels::default(),
+            metrics: METRICS.iter().map(|(id, _)| id.to_string()).collect(),
+            artifact: Artifact::default(),
+        }
+    }
+}
+
+/// Id and display name of each metric, in README order
+///
+/// Ids name the metric's badge, its SVG file and its marker region (`<!-- blazon:size -->`).
+pub const METRICS: &[(&str, &str)] = &[
+    ("deps", "Dependencies"),
+    ("size", "Binary size"),
+    ("target-dir", "Target dir"),
+];
+
+/// The badges of the metrics in [`BadgeOptions::metrics`], in that order
+pub fn metric_badges(metrics: &Metrics, options: &BadgeOptions) -> Vec<Badge> {
+    let size_formatted = format_size(metrics.binary_size_bytes);
+    let target_dir_formatted = format_size(metrics.target_dir_bytes);
+
+    let badges = [
+        Badge {
+            id: "deps".to_string(),
+            alt: format!("Dependencies: {}", metrics.dep_count),
+            label: options.labels.deps.clone(),
+            value: metrics.dep_count.to_string(),
+            color: options.colors.deps.color_for(metrics.dep_count as u64),
+            style: options.style,
+        },
+        Badge {
+            id: "size".to_string(),
+            alt: format!("{}: {}", options.artifact.size_name(), size_formatted),
+            label: options.labels.size.clone(),
+            value: size_formatted,
+            color: options.colors.size.color_for(metrics.binary_size_bytes),
+            style: options.style,
+        },
+        Badge {
+            id: "target-dir".to_string(),
+            alt: format!("Target Dir: {}", target_dir_formatted),
+            label: options.labels.target_dir.clone(),
+            value: target_dir_formatted,
+            color: options
+                .colors
+                .target_dir
+                .color_for(metrics.target_dir_bytes),
+            style: options.style,
+        },
+    ];
+
+    options
+        .metrics
+        .iter()
+        .filter_map(|id| badges.iter().find(|badge| &badge.id == id).cloned())
+        .collect()
+}
+
+/// Render each metric's badge as markdown showing its img.shields.io image
+pub fn render_badges(
+    metrics: &Metrics,
+    crate_name: &str,
+    options: &BadgeOptions,
+) -> RenderedBadges {
+    let crates_io_url = format!("https://crates.io/crates/{}", crate_name);
+
+    RenderedBadges(
+        metric_badges(metrics, options)
+            .into_iter()
+            .map(|badge| RenderedBadge {
+                markdown: badge.markdown(&badge.shields_url(), &crates_io_url),
+                name: options.metric_name(&badge.id),
+                badge,
+            })
+            .collect(),
+    )
+}
+
 /// Generate shields.io badge URLs and markdown
-pub fn generate_badges(metrics: &Metrics, crate_name: &str) -> String {
+pub fn generate_badges(metrics: &Metrics, crate_name: &str, options: &BadgeOptions) -> String {
+    render_badges(metrics, crate_name, options).markdown()
+}
+
+/// SVG badges rendered in memory, with the markdown that links them from the README
+#[derive(Debug, Clone)]
+pub struct SvgBadges {
+    /// Markdown for the README, linking each SVG by its relative path
+    pub badges: RenderedBadges,
+    /// Path and content of each SVG file
+    pub files: Vec<(PathBuf, String)>,
+}
+
+impl SvgBadges {
+    /// Write the stale SVG files, creating their directories as needed
+    ///
+    /// Files that already hold their content are left untouched. Returns the paths written.
+    pub fn write(&self) -> Result<Vec<&Path>, BlazonError> {
+        let stale = self.stale_files();
+        for (path, svg) in self
+            .files
+            .iter()
+            .filter(|(path, _)| stale.contains(&path.as_path()))
+        {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).map_err(|e| BlazonError::io("create", dir, e))?;
+            }
+            file::write_atomic(path, svg.as_bytes())?;
+        }
+        Ok(stale)
+    }
+
+    /// The SVG files that are missing or differ from their rendered content
+    pub fn stale_files(&self) -> Vec<&Path> {
+        self.files
+            .iter()
+            .filter(|(path, svg)| std::fs::read_to_string(path).ok().as_ref() != Some(svg))
+            .map(|(path, _)| path.as_path())
+            .collect()
+    }
+}
+
+/// Render SVG badges for `svg_dir` and the markdown referencing them, without writing anything
+///
+/// Each badge belongs at `<svg_dir>/<id>.svg` and is linked by its path relative to the
+/// directory containing `readme_path`, so the README renders without img.shields.io.
+pub fn render_svg_badges(
+    metrics: &Metrics,
+    crate_name: &str,
+    svg_dir: &Path,
+    readme_path: &Path,
+    options: &BadgeOptions,
+) -> Result<SvgBadges, BlazonError> {
     let crates_io_url = format!("https://crates.io/crates/{}", crate_name);
-    let size_formatted = format_size(metrics.binary_size_bytes);
+    let readme_dir = match readme_path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
 
-    let deps_badge = format!(
-        "[![Dependencies: {}](https://img.shields.io/badge/cargo%20tree-{}-blue)]({})",
-        metrics.dep_count, metrics.dep_count, crates_io_url
-    );
+    let mut badges = Vec::new();
+    let mut files = Vec::new();
+    for badge in metric_badges(metrics, options) {
+        let svg_path = svg_dir.join(format!("{}.svg", badge.id));
+        let image = relative_path(readme_dir, &svg_path)?;
+        files.push((svg_path, badge.to_svg()));
+        badges.push(RenderedBadge {
+            markdown: badge.markdown(&image, &crates_io_url),
+            name: options.metric_name(&badge.id),
+            badge,
+        });
+    }
+
+    Ok(SvgBadges {
+        badges: RenderedBadges(badges),
+        files,
+    })
+}
 
-    let size_badge = format!(
-        "[![Binary Size: {}](https://img.shields.io/badge/build%20size-{}-green)]({})",
-        size_formatted, size_formatted, crates_io_url
+/// Write SVG badges into `svg_dir` and generate markdown referencing them from the README
+///
+/// See [`render_svg_badges`] for the layout.
+pub fn generate_svg_badges(
+    metrics: &Metrics,
+    crate_name: &str,
+    svg_dir: &Path,
+    readme_path: &Path,
+    options: &BadgeOptions,
+) -> Result<String, BlazonError> {
+    let badges = render_svg_badges(metrics, crate_name, svg_dir, readme_path, options)?;
+    badges.write()?;
+    Ok(badges.badges.markdown())
+}
+
+/// Express `path` relative to existing `base`, with `/` separators as used in markdown
+///
+/// `path` need not exist yet: its deepest existing ancestor is resolved instead.
+fn relative_path(base: &Path, path: &Path) -> Result<String, BlazonError> {
+    let base = base
+        .canonicalize()
+        .map_err(|e| BlazonError::io("resolve", base, e))?;
+    let path = resolve_ancestor(
+        &std::path::absolute(path).map_err(|e| BlazonError::io("resolve", path, e))?,
     );
 
-    format!("{}\n{}", deps_badge, size_badge)
+    let common = base
+        .components()
+        .zip(path.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let parts: Vec<String> =
+        std::iter::repeat_n("..".to_string(), base.components().count() - common)
+            .chain(
+                path.components()
+                    .skip(common)
+                    .map(|c| c.as_os_str().to_string_lossy().into_owned()),
+            )
+            .collect();
+
+    Ok(parts.join("/"))
 }
 
-/// Update README file with generated badges using textum
-pub fn update_readme(readme_path: &str, badge_content: &str) -> Result<(), String> {
+/// Canonicalize the deepest existing ancestor of absolute `path` and re-append the rest
+fn resolve_ancestor(path: &Path) -> PathBuf {
+    match path.canonicalize() {
+        Ok(resolved) => resolved,
+        Err(_) => match (path.parent(), path.file_name()) {
+            (Some(parent), Some(name)) => resolve_ancestor(parent).join(name),
+            _ => path.to_path_buf(),
+        },
+    }
+}
+
+/// Replace each marker region of README `content` with its rendering of `badges`
+///
+/// Each region keeps its [`Layout`](region::Layout), so patching is idempotent. The markers
+/// are checked first, so a README without any, or with an unclosed, repeated or misordered
+/// marker, is left alone and the [`MarkerError`] points at the marker at fault.
+pub fn patch_readme<C: RegionContent + ?Sized>(
+    content: &str,
+    badges: &C,
+) -> Result<String, MarkerError> {
     use textum::{Boundary, BoundaryMode, Patch, Snippet, Target};
 
-    let start = Boundary::new(
-        Target::Literal("<!-- blazon -->".to_string()),
-        BoundaryMode::Exclude,
-    );
-    let end = Boundary::new(
-        Target::Literal("<!-- /blazon -->".to_string()),
-        BoundaryMode::Exclude,
-    );
+    let mut patched = content.to_string();
+    for (region, span) in region::check_markers(content)? {
+        let invalid = |message: String| MarkerError {
+            kind: MarkerErrorKind::Invalid(message),
+            region: region.clone(),
+            span: span.clone(),
+            related: None,
+        };
+
+        let start = Boundary::new(
+            Target::Literal(region.start_marker()),
+            BoundaryMode::Exclude,
+        );
+        let end = Boundary::new(Target::Literal(region.end_marker()), BoundaryMode::Exclude);
+
+        let snippet = Snippet::Between { start, end };
+
+        let layout = Layout::of(content, &region);
+        let patch = Patch {
+            file: None,
+            snippet,
+            replacement: layout.wrap(&badges.render(&region, layout).map_err(invalid)?),
+        };
+
+        patched = patch
+            .apply_to_string(&patched)
+            .map_err(|e| invalid(format!("Failed to apply patch: {:?}", e)))?;
+    }
 
-    let snippet = Snippet::Between { start, end };
+    Ok(patched)
+}
 
-    let patch = Patch {
-        file: Some(readme_path.to_string()),
-        snippet,
-        replacement: format!("\n{}", badge_content),
-    };
+/// Read the README at `readme_path` and patch `badges` into it, returning both
+fn read_and_patch<C: RegionContent + ?Sized>(
+    readme_path: &str,
+    badges: &C,
+) -> Result<(file::TextFile, String), BlazonError> {
+    let readme = file::TextFile::read(readme_path)?;
+    match patch_readme(&readme.content, badges) {
+        Ok(patched) => Ok((readme, patched)),
+        Err(error) => Err(BlazonError::Markers(Box::new(InvalidReadme {
+            path: readme_path.to_string(),
+            content: readme.content,
+            error,
+        }))),
+    }
+}
 
-    // Read the file content
-    let content = std::fs::read_to_string(readme_path)
-        .map_err(|e| format!("Failed to read {}: {}", readme_path, e))?;
+/// Whether a file was rewritten
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteStatus {
+    /// The file already held the new content, so it was not written
+    Unchanged,
+    /// The file was written with new content
+    Updated,
+}
 
-    // Apply patch to string
-    let updated = patch
-        .apply_to_string(&content)
-        .map_err(|e| format!("Failed to apply patch: {:?}", e))?;
+impl WriteStatus {
+    /// The status as shown to users: `unchanged` or `updated`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WriteStatus::Unchanged => "unchanged",
+            WriteStatus::Updated => "updated",
+        }
+    }
+}
+
+impl std::fmt::Display for WriteStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Update README file with generated badges using textum
+///
+/// Every marker region is filled in; see [`region`] for the region names. The file is only
+/// written when its content changes, so its mtime is left alone when the badges are current.
+/// Writes are atomic and keep the file's line endings, BOM and permissions; see [`file`].
+pub fn update_readme<C: RegionContent + ?Sized>(
+    readme_path: &str,
+    badges: &C,
+) -> Result<WriteStatus, BlazonError> {
+    let (readme, updated) = read_and_patch(readme_path, badges)?;
+    if updated == readme.content {
+        return Ok(WriteStatus::Unchanged);
+    }
 
-    // Write back
-    std::fs::write(readme_path, updated)
-        .map_err(|e| format!("Failed to write {}: {}", readme_path, e))?;
+    readme.write(&updated)?;
 
-    Ok(())
+    Ok(WriteStatus::Updated)
+}
+
+/// Add a marker pair to the README at `readme_path` if it has no regions
+///
+/// Returns where the markers were inserted, or `None` if the README already had markers and
+/// was left untouched. See [`insert`] for where markers go.
+pub fn insert_readme_markers(readme_path: &str) -> Result<Option<insert::Insertion>, BlazonError> {
+    let readme = file::TextFile::read(readme_path)?;
+    let Some((inserted, insertion)) = insert::insert_markers(&readme.content) else {
+        return Ok(None);
+    };
+    readme.write(&inserted)?;
+    Ok(Some(insertion))
 }
 
 /// Macro for debug output
@@ -0,0 +1,238 @@
+//! Typed model of `cargo metadata` output
+//!
+//! Only the fields blazon reads are modelled; everything else in the JSON is ignored, so the
+//! model keeps working as cargo adds fields.
+
+use crate::BlazonError;
+use facet::Facet;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// The workspace as described by `cargo metadata --no-deps`
+#[derive(Facet, Debug, Clone)]
+pub struct Metadata {
+    pub packages: Vec<Package>,
+    pub workspace_members: Vec<String>,
+    #[facet(default)]
+    pub workspace_default_members: Vec<String>,
+    pub workspace_root: PathBuf,
+    pub target_directory: PathBuf,
+}
+
+/// A workspace package
+#[derive(Facet, Debug, Clone)]
+pub struct Package {
+    pub name: String,
+    pub version: String,
+    pub id: String,
+    pub manifest_path: PathBuf,
+    pub targets: Vec<Target>,
+    pub features: BTreeMap<String, Vec<String>>,
+    #[facet(default)]
+    pub readme: Option<PathBuf>,
+}
+
+/// A build target (lib, bin, test, example, ...) of a package
+#[derive(Facet, Debug, Clone)]
+pub struct Target {
+    pub name: String,
+    pub kind: Vec<String>,
+    #[facet(default)]
+    pub crate_types: Vec<String>,
+    pub src_path: PathBuf,
+    #[facet(default, rename = "required-features")]
+    pub required_features: Vec<String>,
+}
+
+/// A binary target together with the package that owns it
+#[derive(Debug, Clone, Copy)]
+pub struct BinaryTarget<'a> {
+    pub package: &'a Package,
+    pub target: &'a Target,
+}
+
+impl Metadata {
+    /// Run `cargo metadata` for the current directory and parse its output
+    pub fn load() -> Result<Self, BlazonError> {
+        Self::load_manifest(None)
+    }
+
+    /// Run `cargo metadata` for `manifest_path`, or the current directory, and parse its output
+    pub fn load_manifest(manifest_path: Option<&Path>) -> Result<Self, BlazonError> {
+        let mut command = crate::cargo();
+        command.args(["metadata", "--format-version", "1", "--no-deps"]);
+        if let Some(manifest_path) = manifest_path {
+            command.arg("--manifest-path").arg(manifest_path);
+        }
+        let output = command
+            .output()
+            .map_err(BlazonError::cargo_not_found("metadata"))?;
+
+        if !output.status.success() {
+            return Err(BlazonError::cargo_failed("metadata", &output));
+        }
+
+        Self::parse(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Parse the JSON printed by `cargo metadata --format-version 1`
+    pub fn parse(json: &str) -> Result<Self, BlazonError> {
+        facet_json::from_str(json).map_err(|e| BlazonError::CargoOutput {
+            command: "metadata",
+            message: e.to_string(),
+        })
+    }
+
+    /// Iterate over the workspace members
+    pub fn members(&self) -> impl Iterator<Item = &Package> {
+        self.packages
+            .iter()
+            .filter(|p| self.workspace_members.contains(&p.id))
+    }
+
+    /// Iterate over the members cargo builds by default at the workspace root
+    pub fn default_members(&self) -> impl Iterator<Item = &Package> {
+        let ids = if self.workspace_default_members.is_empty() {
+            &self.workspace_members
+        } else {
+            &self.workspace_default_members
+        };
+        self.packages.iter().filter(|p| ids.contains(&p.id))
+    }
+
+    /// Find the member package whose directory contains `dir`
+    ///
+    /// Returns `None` when `dir` is only inside a virtual workspace root.
+    pub fn package_for_dir(&self, dir: &Path) -> Option<&Package> {
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        self.members()
+            .filter(|p| p.manifest_path.parent().is_some_and(|d| dir.starts_with(d)))
+            .max_by_key(|p| p.manifest_path.components().count())
+    }
+
+    /// Find a workspace member by name
+    pub fn find_package(&self, name: &str) -> Option<&Package> {
+        self.members().find(|p| p.name == name)
+    }
+
+    /// Find a binary target by name among the workspace members
+    pub fn find_binary(&self, name: &str) -> Option<BinaryTarget<'_>> {
+        self.members()
+            .flat_map(Package::binaries)
+            .find(|b| b.target.name == name)
+    }
+
+    /// Infer the crate name badges should link to when measuring `binary_name` from `dir`
+    ///
+    /// This is the package that owns the binary, or failing that the package owning `dir`.
+    pub fn infer_crate_name(&self, binary_name: &str, dir: &Path) -> Option<&str> {
+        self.find_binary(binary_name)
+            .map(|b| b.package)
+            .or_else(|| self.package_for_dir(dir))
+            .map(|p| p.name.as_str())
+    }
+
+    /// Infer the binary to measure when running from `dir`
+    ///
+    /// Candidates are the binaries of the package owning `dir`, or of the default workspace
+    /// members when `dir` is a virtual workspace root. Binaries that build with default features
+    /// are preferred, then a binary named after its package.
+    pub fn infer_binary(&self, dir: &Path) -> Result<BinaryTarget<'_>, BlazonError> {
+        let candidates: Vec<BinaryTarget> = match self.package_for_dir(dir) {
+            Some(package) => package.binaries().collect(),
+            None => self.default_members().flat_map(Package::binaries).collect(),
+        };
+        choose_binary(candidates)
+    }
+}
+
+/// The preferred binary among `candidates`, as described for [`Metadata::infer_binary`]
+fn choose_binary(mut candidates: Vec<BinaryTarget<'_>>) -> Result<BinaryTarget<'_>, BlazonError> {
+    if candidates.is_empty() {
+        return Err(BlazonError::NoBinary);
+    }
+    if candidates.iter().any(|b| b.missing_features().is_empty()) {
+        candidates.retain(|b| b.missing_features().is_empty());
+    }
+    if let Some(named) = candidates.iter().find(|b| b.target.name == b.package.name)
+        && candidates.len() > 1
+    {
+        candidates = vec![*named];
+    }
+
+    match candidates.as_slice() {
+        [binary] => Ok(*binary),
+        _ => Err(BlazonError::AmbiguousBinary {
+            candidates: candidates.iter().map(|b| b.target.name.clone()).collect(),
+        }),
+    }
+}
+
+impl Package {
+    /// Iterate over the binary targets of this package
+    pub fn binaries(&self) -> impl Iterator<Item = BinaryTarget<'_>> {
+        self.targets
+            .iter()
+            .filter(|t| t.is_bin())
+            .map(move |target| BinaryTarget {
+                package: self,
+                target,
+            })
+    }
+
+    /// The binary to measure for this package, preferred as by [`Metadata::infer_binary`]
+    pub fn main_binary(&self) -> Result<BinaryTarget<'_>, BlazonError> {
+        choose_binary(self.binaries().collect())
+    }
+
+    /// The library target of this package, if it has one that builds an rlib
+    pub fn library(&self) -> Option<&Target> {
+        self.targets
+            .iter()
+            .find(|t| t.kind.iter().any(|k| k == "lib" || k == "rlib"))
+    }
+
+    /// The directory holding this package's Cargo.toml
+    pub fn dir(&self) -> &Path {
+        self.manifest_path.parent().unwrap_or(Path::new("."))
+    }
+
+    /// The features enabled when this package is built with its default features
+    pub fn default_features(&self) -> BTreeSet<&str> {
+        let mut enabled = BTreeSet::new();
+        let mut pending = vec!["default"];
+
+        while let Some(feature) = pending.pop() {
+            if let Some(implied) = self.features.get(feature)
+                && enabled.insert(feature)
+            {
+                pending.extend(implied.iter().map(String::as_str));
+            }
+        }
+
+        enabled.remove("default");
+        enabled
+    }
+}
+
+impl Target {
+    /// Whether this is a binary target
+    pub fn is_bin(&self) -> bool {
+        self.kind.iter().any(|k| k == "bin")
+    }
+}
+
+impl BinaryTarget<'_> {
+    /// Required features of the binary that its package's default features do not enable
+    ///
+    /// These are qualified as `package/feature`, ready to pass to `cargo build --features`.
+    pub fn missing_features(&self) -> Vec<String> {
+        let enabled = self.package.default_features();
+        self.target
+            .required_features
+            .iter()
+            .filter(|f| !enabled.contains(f.as_str()))
+            .map(|f| format!("{}/{}", self.package.name, f))
+            .collect()
+    }
+}
@@ -0,0 +1,454 @@
+//! Marker regions of a README that blazon fills in
+//!
+//! `<!-- blazon -->` ... `<!-- /blazon -->` holds every badge. Named regions such as
+//! `<!-- blazon:size -->` ... `<!-- /blazon:size -->` hold their own selection, so badges can be
+//! split across a README and all updated in one run:
+//!
+//! - `blazon:size`, `blazon:deps,size`: the badges of the listed metrics
+//! - `blazon:table`, `blazon:table:deps,size`: a markdown table of all or the listed metrics
+//!
+//! A region keeps its [`Layout`]: markers within a line get badges on that line, markers on
+//! separate lines get one badge per line between them.
+
+use crate::badge::Badge;
+use crate::{METRICS, ParseError};
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// A marker region, identified by the name in its markers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// The name after `blazon:`, or `None` for the plain `<!-- blazon -->` region
+    pub name: Option<String>,
+}
+
+/// Which metrics a region shows, and how
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    /// Metric ids, in the order they are shown
+    pub ids: Vec<String>,
+    pub format: Format,
+}
+
+/// How a region presents its metrics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One badge per metric
+    Badges,
+    /// A markdown table with a row per metric
+    Table,
+}
+
+/// Where a region's content sits relative to its markers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// Both markers on one line, e.g. after other badges in a badge row; badges are separated
+    /// by spaces
+    Inline,
+    /// Markers on separate lines, with one badge per line between them
+    Block,
+}
+
+impl Layout {
+    /// The layout of `region` in README `content`
+    ///
+    /// A region is inline when its start marker follows other text on the same line (as in a
+    /// badge row) or when nothing between its markers spans a line break.
+    pub fn of(content: &str, region: &Region) -> Self {
+        let Some(start) = content.find(&region.start_marker()) else {
+            return Layout::Inline;
+        };
+        let line_start = content[..start].rfind('\n').map_or(0, |i| i + 1);
+        let after = &content[start + region.start_marker().len()..];
+        let inner = after
+            .find(&region.end_marker())
+            .map_or(after, |end| &after[..end]);
+        if !content[line_start..start].trim().is_empty() || !inner.contains('\n') {
+            Layout::Inline
+        } else {
+            Layout::Block
+        }
+    }
+
+    /// Wrap rendered content to sit between the markers in this layout
+    pub fn wrap(self, content: &str) -> String {
+        match self {
+            Layout::Inline => content.to_string(),
+            Layout::Block if content.is_empty() => "\n".to_string(),
+            Layout::Block => format!("\n{}\n", content),
+        }
+    }
+}
+
+impl Region {
+    /// The comment opening this region
+    pub fn start_marker(&self) -> String {
+        match &self.name {
+            Some(name) => format!("<!-- blazon:{} -->", name),
+            None => "<!-- blazon -->".to_string(),
+        }
+    }
+
+    /// The comment closing this region
+    pub fn end_marker(&self) -> String {
+        match &self.name {
+            Some(name) => format!("<!-- /blazon:{} -->", name),
+            None => "<!-- /blazon -->".to_string(),
+        }
+    }
+
+    /// The metrics this region shows, from its name
+    pub fn selection(&self) -> Result<Selection, ParseError> {
+        match &self.name {
+            Some(name) => name.parse().map_err(|e| match e {
+                ParseError::Unknown { name, .. } => ParseError::Region {
+                    marker: self.start_marker(),
+                    metric: name,
+                },
+                e => e,
+            }),
+            None => Ok(Selection::all(Format::Badges)),
+        }
+    }
+}
+
+impl Selection {
+    /// Every metric, in README order
+    pub fn all(format: Format) -> Self {
+        Self {
+            ids: METRICS.iter().map(|(id, _)| id.to_string()).collect(),
+            format,
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (format, list) = match s.strip_prefix("table") {
+            Some("") => return Ok(Selection::all(Format::Table)),
+            Some(rest) => match rest.strip_prefix(':') {
+                Some(list) => (Format::Table, list),
+                None => (Format::Badges, s),
+            },
+            None => (Format::Badges, s),
+        };
+
+        let ids = list
+            .split(',')
+            .map(|id| {
+                let id = id.trim();
+                if METRICS.iter().any(|(known, _)| *known == id) {
+                    Ok(id.to_string())
+                } else {
+                    Err(ParseError::Unknown {
+                        kind: "metric",
+                        name: id.to_string(),
+                        expected: METRICS.iter().map(|(id, _)| *id).collect(),
+                    })
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Selection { ids, format })
+    }
+}
+
+/// A marker comment in a README
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Marker {
+    /// The region the marker belongs to
+    pub region: Region,
+    /// Whether this is the end marker, `<!-- /blazon... -->`
+    pub end: bool,
+    /// The byte range of the comment
+    pub span: Range<usize>,
+}
+
+/// Find every start and end marker of a README, in order
+pub fn find_markers(content: &str) -> Vec<Marker> {
+    content
+        .match_indices("<!-- ")
+        .filter_map(|(start, open)| {
+            let rest = &content[start + open.len()..];
+            let (end, rest) = match rest.strip_prefix('/') {
+                Some(rest) => (true, rest),
+                None => (false, rest),
+            };
+            let rest = rest.strip_prefix("blazon")?;
+            let name = if rest.starts_with(" -->") {
+                None
+            } else {
+                let rest = rest.strip_prefix(':')?;
+                let name = &rest[..rest.find(" -->")?];
+                if name.is_empty() || name.contains(char::is_whitespace) {
+                    return None;
+                }
+                Some(name.to_string())
+            };
+
+            let region = Region { name };
+            let len = match end {
+                true => region.end_marker().len(),
+                false => region.start_marker().len(),
+            };
+            Some(Marker {
+                region,
+                end,
+                span: start..start + len,
+            })
+        })
+        .collect()
+}
+
+/// Find the marker regions of a README, in order of first appearance
+///
+/// Only opening markers are found; use [`check_markers`] to find those that are not closed.
+pub fn find_regions(content: &str) -> Vec<Region> {
+    let mut regions: Vec<Region> = Vec::new();
+    for marker in find_markers(content) {
+        if !marker.end && !regions.contains(&marker.region) {
+            regions.push(marker.region);
+        }
+    }
+    regions
+}
+
+/// Find the marker regions of a README, checking each has one start marker before one end marker
+///
+/// Returns each region with the byte range of its start marker, in order of appearance.
+pub fn check_markers(content: &str) -> Result<Vec<(Region, Range<usize>)>, MarkerError> {
+    let markers = find_markers(content);
+    if markers.is_empty() {
+        return Err(MarkerError {
+            kind: MarkerErrorKind::NoMarkers,
+            region: Region { name: None },
+            span: 0..0,
+            related: None,
+        });
+    }
+
+    let mut regions = Vec::new();
+    for marker in &markers {
+        if regions.iter().any(|(region, _)| *region == marker.region) {
+            continue;
+        }
+        let spans = |end: bool| {
+            markers
+                .iter()
+                .filter(|m| m.region == marker.region && m.end == end)
+                .map(|m| m.span.clone())
+                .collect::<Vec<_>>()
+        };
+        let (starts, ends) = (spans(false), spans(true));
+        let error = |kind, span: &Range<usize>, related: Option<&Range<usize>>| MarkerError {
+            kind,
+            region: marker.region.clone(),
+            span: span.clone(),
+            related: related.cloned(),
+        };
+
+        match (starts.as_slice(), ends.as_slice()) {
+            ([first, second, ..], _) => {
+                let kind = MarkerErrorKind::Duplicate { end: false };
+                return Err(error(kind, second, Some(first)));
+            }
+            (_, [first, second, ..]) => {
+                let kind = MarkerErrorKind::Duplicate { end: true };
+                return Err(error(kind, second, Some(first)));
+            }
+            ([], [end]) => return Err(error(MarkerErrorKind::MissingStart, end, None)),
+            ([start], []) => return Err(error(MarkerErrorKind::MissingEnd, start, None)),
+            ([start], [end]) if end.start < start.start => {
+                return Err(error(MarkerErrorKind::EndBeforeStart, end, Some(start)));
+            }
+            ([start], _) => regions.push((marker.region.clone(), start.clone())),
+            ([], []) => unreachable!("every region comes from a marker"),
+        }
+    }
+    Ok(regions)
+}
+
+/// A README whose markers cannot be patched
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkerError {
+    /// What is wrong
+    pub kind: MarkerErrorKind,
+    /// The region at fault
+    pub region: Region,
+    /// The byte range of the offending marker, empty when there are no markers at all
+    pub span: Range<usize>,
+    /// The byte range of the marker it conflicts with: the first of a duplicate, or the start
+    /// marker that an end marker precedes
+    pub related: Option<Range<usize>>,
+}
+
+/// What is wrong with a README's markers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkerErrorKind {
+    /// The README has no markers at all
+    NoMarkers,
+    /// An end marker without a start marker
+    MissingStart,
+    /// A start marker without an end marker
+    MissingEnd,
+    /// An end marker before its start marker
+    EndBeforeStart,
+    /// A second start marker, or a second end marker, for the same region
+    Duplicate {
+        /// Whether the end marker is repeated
+        end: bool,
+    },
+    /// A region whose name or layout cannot be filled in
+    Invalid(String),
+}
+
+impl MarkerError {
+    /// A line suggesting the markers to add or move
+    pub fn help(&self) -> Option<String> {
+        let (start, end) = (self.region.start_marker(), self.region.end_marker());
+        match self.kind {
+            MarkerErrorKind::NoMarkers => {
+                Some(format!("add {}{} where the badges should go", start, end))
+            }
+            MarkerErrorKind::MissingStart => Some(format!("add {} before {}", start, end)),
+            MarkerErrorKind::MissingEnd => Some(format!("add {} after {}", end, start)),
+            MarkerErrorKind::EndBeforeStart => Some(format!("move {} after {}", end, start)),
+            MarkerErrorKind::Duplicate { .. } => Some(format!(
+                "remove one of them: a region has exactly one {} and one {}",
+                start, end
+            )),
+            MarkerErrorKind::Invalid(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for MarkerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            MarkerErrorKind::NoMarkers => write!(f, "No blazon markers found"),
+            MarkerErrorKind::MissingStart => {
+                write!(f, "Missing start marker '{}'", self.region.start_marker())
+            }
+            MarkerErrorKind::MissingEnd => {
+                write!(f, "Missing end marker '{}'", self.region.end_marker())
+            }
+            MarkerErrorKind::EndBeforeStart => write!(
+                f,
+                "End marker '{}' comes before its start marker",
+                self.region.end_marker()
+            ),
+            MarkerErrorKind::Duplicate { end } => {
+                let marker = match end {
+                    true => self.region.end_marker(),
+                    false => self.region.start_marker(),
+                };
+                write!(f, "Duplicate marker '{}'", marker)
+            }
+            MarkerErrorKind::Invalid(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for MarkerError {}
+
+/// Content to patch into marker regions
+pub trait RegionContent {
+    /// The content of `region` in `layout`, without its markers or surrounding newlines
+    fn render(&self, region: &Region, layout: Layout) -> Result<String, String>;
+}
+
+/// The same text in every region, its lines joined by spaces in inline regions
+impl RegionContent for str {
+    fn render(&self, _region: &Region, layout: Layout) -> Result<String, String> {
+        Ok(match layout {
+            Layout::Inline => self.lines().map(str::trim).collect::<Vec<_>>().join(" "),
+            Layout::Block => self.trim_end_matches('\n').to_string(),
+        })
+    }
+}
+
+/// The same text in every region, its lines joined by spaces in inline regions
+impl RegionContent for String {
+    fn render(&self, region: &Region, layout: Layout) -> Result<String, String> {
+        self.as_str().render(region, layout)
+    }
+}
+
+/// A badge with the markdown that shows it, and the name of its metric in table rows
+#[derive(Debug, Clone)]
+pub struct RenderedBadge {
+    pub badge: Badge,
+    pub markdown: String,
+    pub name: String,
+}
+
+/// Badges for each metric, rendered into each region according to its selection
+#[derive(Debug, Clone)]
+pub struct RenderedBadges(pub Vec<RenderedBadge>);
+
+impl RenderedBadges {
+    /// Markdown for every badge, one per line
+    pub fn markdown(&self) -> String {
+        self.0
+            .iter()
+            .map(|b| b.markdown.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Render the metrics of `selection`, with badges separated to suit `layout`
+    ///
+    /// Tables always span several lines, whatever the layout.
+    pub fn render_selection(&self, selection: &Selection, layout: Layout) -> String {
+        let selected = selection
+            .ids
+            .iter()
+            .filter_map(|id| self.0.iter().find(|b| &b.badge.id == id));
+
+        match selection.format {
+            Format::Badges => selected
+                .map(|b| b.markdown.as_str())
+                .collect::<Vec<_>>()
+                .join(match layout {
+                    Layout::Inline => " ",
+                    Layout::Block => "\n",
+                }),
+            Format::Table => {
+                let mut table = "| Metric | Value |\n| --- | --- |".to_string();
+                for rendered in selected {
+                    table.push_str(&format!(
+                        "\n| {} | {} |",
+                        table_escape(&rendered.name),
+                        table_escape(&rendered.badge.value)
+                    ));
+                }
+                table
+            }
+        }
+    }
+}
+
+impl RegionContent for RenderedBadges {
+    fn render(&self, region: &Region, layout: Layout) -> Result<String, String> {
+        let mut selection = region.selection().map_err(|e| e.to_string())?;
+        // Regions listing no metrics show every rendered badge, in the order rendered
+        if matches!(region.name.as_deref(), None | Some("table")) {
+            selection.ids = self.0.iter().map(|b| b.badge.id.clone()).collect();
+        }
+        if selection.format == Format::Table && layout == Layout::Inline {
+            return Err(format!(
+                "Region '{}' holds a table, so its markers must be on separate lines",
+                region.start_marker()
+            ));
+        }
+        Ok(self.render_selection(&selection, layout))
+    }
+}
+
+/// Escape `|` so text stays within its table cell
+fn table_escape(text: &str) -> String {
+    text.replace('|', "\\|")
+}
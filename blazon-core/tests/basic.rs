@@ -1,4 +1,9 @@
-use blazon_core::{Metrics, format_size, generate_badges};
+use blazon_core::badge::BadgeStyle;
+use blazon_core::{
+    BadgeOptions, BinaryArtifact, BuildOptions, Metrics, ParseError, TargetDirProfiles,
+    format_size, generate_badges, get_binary_path, get_library_path, parse_artifacts, parse_size,
+};
+use std::path::{Path, PathBuf};
 
 #[test]
 fn test_format_size() {
@@ -8,22 +13,50 @@ fn test_format_size() {
     assert_eq!(format_size(1024 * 1024), "1.0M");
     assert_eq!(format_size(2 * 1024 * 1024), "2.0M");
     assert_eq!(format_size(1536 * 1024), "1.5M");
+    assert_eq!(format_size(3 * 1024 * 1024 * 1024), "3.0G");
+}
+
+#[test]
+fn test_parse_size() {
+    assert_eq!(parse_size("500"), Ok(500));
+    assert_eq!(parse_size("500B"), Ok(500));
+    assert_eq!(parse_size("2K"), Ok(2048));
+    assert_eq!(parse_size("1.5M"), Ok(1536 * 1024));
+    assert_eq!(parse_size("2 MiB"), Ok(2 * 1024 * 1024));
+    assert_eq!(parse_size("3gb"), Ok(3 * 1024 * 1024 * 1024));
+    // Round-trips what format_size prints
+    assert_eq!(parse_size(&format_size(2048)), Ok(2048));
+    assert_eq!(
+        parse_size("2X"),
+        Err(ParseError::SizeUnit {
+            text: "2X".to_string(),
+            unit: "X".to_string(),
+        })
+    );
+    assert_eq!(
+        parse_size("M").unwrap_err().to_string(),
+        "Invalid size 'M': expected a number"
+    );
 }
 
 #[test]
 fn test_generate_badges() {
     let metrics = Metrics {
         dep_count: 42,
-        binary_size_bytes: 2 * 1024 * 1024, // 2MB
+        binary_size_bytes: 2 * 1024 * 1024,  // 2MB
+        target_dir_bytes: 512 * 1024 * 1024, // 512MB
+        target_dir_profiles: TargetDirProfiles::default(),
     };
 
-    let badges = generate_badges(&metrics, "test-crate");
+    let badges = generate_badges(&metrics, "test-crate", &BadgeOptions::default());
 
     // Check the badges contain expected elements
     assert!(badges.contains("Dependencies: 42"));
     assert!(badges.contains("Binary Size: 2.0M"));
     assert!(badges.contains("https://img.shields.io/badge/cargo%20tree-42-blue"));
     assert!(badges.contains("https://img.shields.io/badge/build%20size-2.0M-green"));
+    assert!(badges.contains("Target Dir: 512.0M"));
+    assert!(badges.contains("https://img.shields.io/badge/target%20dir-512.0M-orange"));
     assert!(badges.contains("https://crates.io/crates/test-crate"));
 }
 
@@ -32,15 +65,124 @@ fn test_badge_format() {
     let metrics = Metrics {
         dep_count: 123,
         binary_size_bytes: 1500000, // ~1.4M
+        ..Default::default()
     };
 
-    let badges = generate_badges(&metrics, "my-crate");
+    let badges = generate_badges(&metrics, "my-crate", &BadgeOptions::default());
 
-    // Should have two badge lines
+    // Should have three badge lines
     let lines: Vec<&str> = badges.lines().collect();
-    assert_eq!(lines.len(), 2);
+    assert_eq!(lines.len(), 3);
 
     // Each line should be a complete markdown badge
     assert!(lines[0].starts_with("[![Dependencies:"));
     assert!(lines[1].starts_with("[![Binary Size:"));
+    assert!(lines[2].starts_with("[![Target Dir:"));
+}
+
+#[test]
+fn test_badge_style_query() {
+    let metrics = Metrics {
+        dep_count: 42,
+        ..Default::default()
+    };
+    let options = BadgeOptions {
+        style: BadgeStyle::FlatSquare,
+        ..Default::default()
+    };
+
+    let badges = generate_badges(&metrics, "my-crate", &options);
+    assert!(badges.contains("https://img.shields.io/badge/cargo%20tree-42-blue?style=flat-square"));
+
+    // The default style is left implicit
+    let badges = generate_badges(&metrics, "my-crate", &BadgeOptions::default());
+    assert!(!badges.contains("?style="));
+}
+
+#[test]
+fn test_badge_style_names() {
+    for style in BadgeStyle::ALL {
+        assert_eq!(style.as_str().parse::<BadgeStyle>(), Ok(style));
+    }
+    assert_eq!("for-the-badge".parse(), Ok(BadgeStyle::ForTheBadge));
+    assert!(matches!(
+        "rounded".parse::<BadgeStyle>(),
+        Err(ParseError::Unknown { expected, .. }) if expected.contains(&"flat-square")
+    ));
+}
+
+#[test]
+fn test_parse_artifacts() {
+    let messages = r#"{"reason":"compiler-artifact","package_id":"path+file:///ws/foo#0.1.0","manifest_path":"/ws/foo/Cargo.toml","target":{"kind":["lib"],"crate_types":["lib"],"name":"foo","src_path":"/ws/foo/src/lib.rs","edition":"2024","doc":true,"doctest":true,"test":true},"profile":{"opt_level":"3","debuginfo":0,"debug_assertions":false,"overflow_checks":false,"test":false},"features":[],"filenames":["/ws/target/release/libfoo.rlib"],"executable":null,"fresh":true}
+{"reason":"compiler-artifact","package_id":"path+file:///ws/foo#0.1.0","manifest_path":"/ws/foo/Cargo.toml","target":{"kind":["bin"],"crate_types":["bin"],"name":"foo-cli","src_path":"/ws/foo/src/main.rs","edition":"2024","doc":true,"doctest":false,"test":true},"profile":{"opt_level":"3","debuginfo":0,"debug_assertions":false,"overflow_checks":false,"test":false},"features":[],"filenames":["/ws/target/x86_64-unknown-linux-musl/dist/foo-cli"],"executable":"/ws/target/x86_64-unknown-linux-musl/dist/foo-cli","fresh":false}
+{"reason":"compiler-artifact","package_id":"path+file:///ws/foo#0.1.0","manifest_path":"/ws/foo/Cargo.toml","target":{"kind":["example"],"crate_types":["bin"],"name":"demo","src_path":"/ws/foo/examples/demo.rs","edition":"2024","doc":false,"doctest":false,"test":false},"profile":{"opt_level":"3","debuginfo":0,"debug_assertions":false,"overflow_checks":false,"test":false},"features":[],"filenames":["/ws/target/release/examples/demo"],"executable":"/ws/target/release/examples/demo","fresh":true}
+{"reason":"build-finished","success":true}
+"#;
+
+    let artifacts = parse_artifacts(messages).unwrap();
+
+    assert_eq!(
+        artifacts,
+        vec![BinaryArtifact {
+            name: "foo-cli".to_string(),
+            path: PathBuf::from("/ws/target/x86_64-unknown-linux-musl/dist/foo-cli"),
+        }]
+    );
+}
+
+#[test]
+fn test_get_binary_path() {
+    let target_dir = Path::new("/ws/target");
+
+    let release = BuildOptions::default();
+    let dev = BuildOptions {
+        profile: "dev".to_string(),
+        ..Default::default()
+    };
+    let cross = BuildOptions {
+        profile: "dist".to_string(),
+        target: Some("aarch64-unknown-linux-gnu".to_string()),
+        ..Default::default()
+    };
+    let windows = BuildOptions {
+        profile: "release".to_string(),
+        target: Some("x86_64-pc-windows-msvc".to_string()),
+        ..Default::default()
+    };
+
+    let exe = std::env::consts::EXE_SUFFIX;
+    assert_eq!(
+        get_binary_path(target_dir, "foo", &release),
+        PathBuf::from(format!("/ws/target/release/foo{exe}"))
+    );
+    assert_eq!(
+        get_binary_path(target_dir, "foo", &dev),
+        PathBuf::from(format!("/ws/target/debug/foo{exe}"))
+    );
+    assert_eq!(
+        get_binary_path(target_dir, "foo", &cross),
+        PathBuf::from("/ws/target/aarch64-unknown-linux-gnu/dist/foo")
+    );
+    assert_eq!(
+        get_binary_path(target_dir, "foo", &windows),
+        PathBuf::from("/ws/target/x86_64-pc-windows-msvc/release/foo.exe")
+    );
+}
+
+#[test]
+fn test_get_library_path() {
+    let target_dir = Path::new("/ws/target");
+    let cross = BuildOptions {
+        target: Some("aarch64-unknown-linux-gnu".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        get_library_path(target_dir, "foo-core", &BuildOptions::default()),
+        PathBuf::from("/ws/target/release/libfoo_core.rlib")
+    );
+    assert_eq!(
+        get_library_path(target_dir, "foo_core", &cross),
+        PathBuf::from("/ws/target/aarch64-unknown-linux-gnu/release/libfoo_core.rlib")
+    );
 }
@@ -0,0 +1,66 @@
+use blazon_core::Metrics;
+use blazon_core::budget::{BudgetViolation, Budgets};
+
+const MB: u64 = 1024 * 1024;
+
+fn metrics() -> Metrics {
+    Metrics {
+        dep_count: 42,
+        binary_size_bytes: 3 * MB,
+        target_dir_bytes: 512 * MB,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_no_budgets() {
+    let budgets = Budgets::default();
+    assert!(budgets.is_empty());
+    assert!(budgets.check(&metrics()).is_empty());
+}
+
+#[test]
+fn test_within_budgets() {
+    let budgets = Budgets {
+        deps: Some(42),
+        binary_size: Some(3 * MB),
+        target_dir: Some(1024 * MB),
+    };
+    assert!(budgets.check(&metrics()).is_empty());
+}
+
+#[test]
+fn test_exceeded_budgets() {
+    let budgets = Budgets {
+        deps: Some(30),
+        binary_size: Some(2 * MB),
+        target_dir: None,
+    };
+
+    let violations = budgets.check(&metrics());
+    assert_eq!(
+        violations,
+        [
+            BudgetViolation {
+                id: "deps",
+                name: "dependency count",
+                actual: 42,
+                limit: 30,
+            },
+            BudgetViolation {
+                id: "size",
+                name: "binary size",
+                actual: 3 * MB,
+                limit: 2 * MB,
+            },
+        ]
+    );
+    assert_eq!(
+        violations[0].to_string(),
+        "dependency count 42 exceeds the budget of 30 (by 12)"
+    );
+    assert_eq!(
+        violations[1].to_string(),
+        "binary size 3.0M exceeds the budget of 2.0M (by 1.0M)"
+    );
+}
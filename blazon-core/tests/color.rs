@@ -0,0 +1,106 @@
+use blazon_core::color::{ColorScale, Rgb};
+use blazon_core::{BadgeOptions, Metrics, metric_badges};
+
+const MB: u64 = 1024 * 1024;
+
+#[test]
+fn test_parse_fixed() {
+    assert_eq!(
+        "blue".parse::<ColorScale>(),
+        Ok(ColorScale::Fixed("blue".to_string()))
+    );
+    assert_eq!(
+        "#e05d44".parse::<ColorScale>().unwrap().color_for(0),
+        "#e05d44"
+    );
+}
+
+#[test]
+fn test_thresholds() {
+    let scale: ColorScale = "green<2M, yellow<5M, red".parse().unwrap();
+    assert_eq!(
+        scale,
+        ColorScale::Thresholds {
+            steps: vec![
+                (2 * MB, "green".to_string()),
+                (5 * MB, "yellow".to_string())
+            ],
+            above: "red".to_string(),
+        }
+    );
+
+    assert_eq!(scale.color_for(MB), "green");
+    assert_eq!(scale.color_for(2 * MB - 1), "green");
+    assert_eq!(scale.color_for(2 * MB), "yellow");
+    assert_eq!(scale.color_for(5 * MB), "red");
+    assert_eq!(scale.color_for(40 * MB), "red");
+}
+
+#[test]
+fn test_thresholds_on_counts() {
+    let scale: ColorScale = "brightgreen<50,orange<100,red".parse().unwrap();
+    assert_eq!(scale.color_for(12), "brightgreen");
+    assert_eq!(scale.color_for(75), "orange");
+    assert_eq!(scale.color_for(100), "red");
+}
+
+#[test]
+fn test_gradient() {
+    let scale: ColorScale = "green@2M..red@10M".parse().unwrap();
+    assert_eq!(
+        scale,
+        ColorScale::Gradient {
+            from: (2 * MB, Rgb(0x97, 0xca, 0x00)),
+            to: (10 * MB, Rgb(0xe0, 0x5d, 0x44)),
+        }
+    );
+
+    // Clamped outside the range
+    assert_eq!(scale.color_for(0), "#97ca00");
+    assert_eq!(scale.color_for(2 * MB), "#97ca00");
+    assert_eq!(scale.color_for(10 * MB), "#e05d44");
+    assert_eq!(scale.color_for(40 * MB), "#e05d44");
+    // Halfway blends each channel
+    assert_eq!(scale.color_for(6 * MB), "#bc9422");
+}
+
+#[test]
+fn test_invalid_scales() {
+    let error = |s: &str| s.parse::<ColorScale>().unwrap_err().to_string();
+
+    assert!(error("").contains("final colour"));
+    assert!(error("green<2M").contains("final colour"));
+    assert!(error("green<2M,").contains("final colour"));
+    assert!(error("green,red").contains("expected COLOUR<VALUE"));
+    assert!(error("yellow<5M,green<2M,red").contains("thresholds must increase"));
+    assert!(error("green<2X,red").contains("unknown unit"));
+    assert!(error("red@10M..green@2M").contains("must be lower"));
+    assert!(error("green..red").contains("expected COLOUR@VALUE"));
+    assert!(error("hotpink@1..red@2").contains("Invalid colour 'hotpink'"));
+}
+
+#[test]
+fn test_metric_badges_use_scales() {
+    let metrics = Metrics {
+        dep_count: 120,
+        binary_size_bytes: 40 * MB,
+        target_dir_bytes: 100 * MB,
+        ..Default::default()
+    };
+    let mut options = BadgeOptions::default();
+    options.colors.deps = "green<100,red".parse().unwrap();
+    options.colors.size = "green<2M,yellow<5M,red".parse().unwrap();
+
+    let colors: Vec<String> = metric_badges(&metrics, &options)
+        .into_iter()
+        .map(|badge| badge.color)
+        .collect();
+    assert_eq!(colors, ["red", "red", "orange"]);
+
+    // Fixed colours are unchanged by default
+    let colors: Vec<String> = metric_badges(&metrics, &BadgeOptions::default())
+        .into_iter()
+        .map(|badge| badge.color)
+        .collect();
+    assert_eq!(colors, ["blue", "green", "orange"]);
+}
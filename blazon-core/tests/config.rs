@@ -0,0 +1,327 @@
+use blazon_core::badge::BadgeStyle;
+use blazon_core::color::ColorScale;
+use blazon_core::config::{Config, DepSettings};
+use blazon_core::deps::{DepOptions, Depth, Identity};
+use blazon_core::metadata::Metadata;
+use blazon_core::{BlazonError, Metrics, metric_badges};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+const MB: u64 = 1024 * 1024;
+
+/// Parse `content` as a whole `blazon.toml` in `/project`
+fn parse(content: &str) -> Result<Config, BlazonError> {
+    Config::parse(Path::new("/project/blazon.toml"), content, &[]).map(Option::unwrap)
+}
+
+/// The config error of parsing `content`, with the text its span points at
+fn parse_error(content: &str) -> (String, Option<String>, Option<&str>) {
+    let err = parse(content).unwrap_err();
+    let BlazonError::Config(invalid) = &err else {
+        panic!("expected Config, got {:?}", err);
+    };
+    let spanned = invalid.span.clone().map(|span| &content[span]);
+    (err.to_string(), err.help(), spanned)
+}
+
+#[test]
+fn test_parse_every_setting() {
+    let config = parse(
+        r#"
+readmes = ["README.md", "docs/index.md"]
+binary = "tool"
+crate = "tool-cli"
+metrics = ["size", "deps"]
+style = "flat-square"
+
+[labels]
+deps = "dependencies"
+
+[colors]
+size = "green<2M,red"
+
+[budgets]
+deps = 100
+size = "2M"
+target-dir = 1024
+
+[deps]
+count = "versions"
+depth = "direct"
+proc-macros = false
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.readmes,
+        Some(vec![
+            PathBuf::from("/project/README.md"),
+            PathBuf::from("/project/docs/index.md")
+        ])
+    );
+    assert_eq!(config.binary.as_deref(), Some("tool"));
+    assert_eq!(config.crate_name.as_deref(), Some("tool-cli"));
+    assert_eq!(config.metrics, Some(vec!["size".into(), "deps".into()]));
+    assert_eq!(config.style, Some(BadgeStyle::FlatSquare));
+    assert_eq!(config.labels["deps"], "dependencies");
+    assert_eq!(config.colors["size"], "green<2M,red".parse().unwrap());
+    assert_eq!(config.budgets.deps, Some(100));
+    assert_eq!(config.budgets.binary_size, Some(2 * MB));
+    assert_eq!(config.budgets.target_dir, Some(1024));
+    assert_eq!(
+        config.deps,
+        DepSettings {
+            count: Some(Identity::Versions),
+            depth: Some(Depth::Direct),
+            proc_macros: Some(false),
+            ..DepSettings::default()
+        }
+    );
+}
+
+#[test]
+fn test_metadata_table() {
+    let manifest = r#"
+[package]
+name = "tool"
+
+[package.metadata.blazon]
+binary = "tool"
+"#;
+    let path = Path::new("/project/Cargo.toml");
+    let table = ["package", "metadata", "blazon"];
+
+    let config = Config::parse(path, manifest, &table).unwrap().unwrap();
+    assert_eq!(config.binary.as_deref(), Some("tool"));
+
+    let workspace = ["workspace", "metadata", "blazon"];
+    assert_eq!(Config::parse(path, manifest, &workspace).unwrap(), None);
+}
+
+#[test]
+fn test_badge_options() {
+    let config = parse(
+        r#"
+metrics = ["size", "deps"]
+style = "plastic"
+labels = { deps = "dependencies" }
+colors = { deps = "red" }
+"#,
+    )
+    .unwrap();
+    let options = config.badge_options();
+    let metrics = Metrics {
+        dep_count: 7,
+        ..Default::default()
+    };
+
+    let badges = metric_badges(&metrics, &options);
+    let ids: Vec<&str> = badges.iter().map(|b| b.id.as_str()).collect();
+    assert_eq!(ids, ["size", "deps"]);
+    assert_eq!(badges[0].label, "build size");
+    assert_eq!(badges[1].label, "dependencies");
+    assert_eq!(badges[1].color, "red");
+    assert_eq!(badges[1].style, BadgeStyle::Plastic);
+}
+
+#[test]
+fn test_merge_prefers_self() {
+    let package = parse("binary = \"tool\"\n[colors]\ndeps = \"red\"\n").unwrap();
+    let workspace = parse(
+        "binary = \"other\"\ncrate = \"shared\"\n[colors]\ndeps = \"blue\"\nsize = \"green\"\n",
+    )
+    .unwrap();
+
+    let config = package.or(workspace);
+    assert_eq!(config.binary.as_deref(), Some("tool"));
+    assert_eq!(config.crate_name.as_deref(), Some("shared"));
+    assert_eq!(config.colors["deps"], ColorScale::Fixed("red".into()));
+    assert_eq!(config.colors["size"], ColorScale::Fixed("green".into()));
+}
+
+#[test]
+fn test_dep_settings() {
+    let flags = DepSettings {
+        build: Some(true),
+        ..DepSettings::default()
+    };
+    let config =
+        parse("[deps]\nbuild = false\ndepth = \"transitive\"\nworkspace = false\n").unwrap();
+
+    assert_eq!(
+        flags.or(config.deps).options(),
+        DepOptions {
+            depth: Depth::Transitive,
+            build: true,
+            exclude_workspace: true,
+            ..DepOptions::default()
+        }
+    );
+    assert_eq!(DepSettings::default().options(), DepOptions::default());
+
+    let (message, _, spanned) = parse_error("[deps]\ndev = \"yes\"\n");
+    assert_eq!(
+        message,
+        "Expected a boolean for 'dev', found string in /project/blazon.toml"
+    );
+    assert_eq!(spanned, Some("\"yes\""));
+}
+
+#[test]
+fn test_unknown_key() {
+    let (message, help, spanned) = parse_error("binary = \"tool\"\nbinnary = \"tool\"\n");
+    assert_eq!(message, "Unknown key 'binnary' in /project/blazon.toml");
+    assert_eq!(
+        help.as_deref(),
+        Some(
+            "expected one of: readmes, binary, crate, metrics, style, labels, colors, budgets, deps"
+        )
+    );
+    assert_eq!(spanned, Some("binnary"));
+}
+
+#[test]
+fn test_unknown_metric() {
+    let (message, help, spanned) = parse_error("metrics = [\"deps\", \"sizes\"]\n");
+    assert_eq!(message, "Unknown metric 'sizes' in /project/blazon.toml");
+    assert_eq!(
+        help.as_deref(),
+        Some("expected one of: deps, size, target-dir")
+    );
+    assert_eq!(spanned, Some("\"sizes\""));
+
+    let (message, _, spanned) = parse_error("[labels]\ndep = \"dependencies\"\n");
+    assert_eq!(message, "Unknown metric 'dep' in /project/blazon.toml");
+    assert_eq!(spanned, Some("dep"));
+}
+
+#[test]
+fn test_invalid_values() {
+    let (message, _, spanned) = parse_error("binary = 1\n");
+    assert_eq!(
+        message,
+        "Expected a string for 'binary', found integer in /project/blazon.toml"
+    );
+    assert_eq!(spanned, Some("1"));
+
+    let (message, _, spanned) = parse_error("[colors]\nsize = \"green<2M\"\n");
+    assert!(message.starts_with("Invalid colour scale 'green<2M'"));
+    assert_eq!(spanned, Some("\"green<2M\""));
+
+    let (message, _, spanned) = parse_error("[budgets]\nsize = \"2X\"\n");
+    assert!(message.starts_with("Invalid size '2X'"));
+    assert_eq!(spanned, Some("\"2X\""));
+
+    let (_, _, spanned) = parse_error("[budgets]\ndeps = -1\n");
+    assert_eq!(spanned, Some("-1"));
+}
+
+#[test]
+fn test_syntax_error() {
+    let (message, _, spanned) = parse_error("binary = \"tool\n");
+    assert!(message.ends_with(" in /project/blazon.toml"));
+    assert!(spanned.is_some());
+}
+
+#[test]
+fn test_load_layers() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::write(
+        root.join("Cargo.toml"),
+        r#"[workspace]
+members = ["tool"]
+
+[workspace.metadata.blazon]
+crate = "shared"
+style = "plastic"
+readmes = ["README.md"]
+"#,
+    )
+    .unwrap();
+    fs::write(root.join("blazon.toml"), "style = \"flat-square\"\n").unwrap();
+    fs::create_dir_all(root.join("tool/src")).unwrap();
+    fs::write(
+        root.join("tool/Cargo.toml"),
+        r#"[package]
+name = "tool"
+version = "0.1.0"
+edition = "2021"
+
+[package.metadata.blazon]
+binary = "tool"
+"#,
+    )
+    .unwrap();
+    fs::write(root.join("tool/src/main.rs"), "fn main() {}").unwrap();
+    fs::write(
+        root.join("tool/blazon.toml"),
+        "readmes = [\"../docs.md\"]\n",
+    )
+    .unwrap();
+
+    let metadata = Metadata::load_manifest(Some(&root.join("Cargo.toml"))).unwrap();
+    let root = root.canonicalize().unwrap();
+
+    let config = Config::load(&metadata, &root.join("tool")).unwrap();
+    assert_eq!(config.binary.as_deref(), Some("tool"));
+    assert_eq!(config.crate_name.as_deref(), Some("shared"));
+    assert_eq!(config.style, Some(BadgeStyle::FlatSquare));
+    assert_eq!(config.readmes, Some(vec![root.join("tool/../docs.md")]));
+
+    let config = Config::load(&metadata, &root).unwrap();
+    assert_eq!(config.binary, None);
+    assert_eq!(config.readmes, Some(vec![root.join("README.md")]));
+
+    // Members only take settings naming a package from their own layers
+    fs::remove_file(root.join("tool/blazon.toml")).unwrap();
+    let tool = metadata.find_package("tool").unwrap();
+    let config = Config::load_package(&metadata, tool).unwrap();
+    assert_eq!(config.binary.as_deref(), Some("tool"));
+    assert_eq!(config.crate_name, None);
+    assert_eq!(config.readmes, None);
+    assert_eq!(config.style, Some(BadgeStyle::FlatSquare));
+}
+
+#[test]
+fn test_load_selected_package() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::write(
+        root.join("Cargo.toml"),
+        r#"[workspace]
+members = ["app", "core"]
+
+[workspace.metadata.blazon]
+binary = "app"
+crate = "app"
+readmes = ["README.md"]
+metrics = ["deps"]
+"#,
+    )
+    .unwrap();
+    for (name, file) in [("app", "src/main.rs"), ("core", "src/lib.rs")] {
+        fs::create_dir_all(root.join(name).join("src")).unwrap();
+        fs::write(
+            root.join(name).join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n"),
+        )
+        .unwrap();
+        fs::write(root.join(name).join(file), "").unwrap();
+    }
+    let metadata = Metadata::load_manifest(Some(&root.join("Cargo.toml"))).unwrap();
+
+    // The workspace is measured as app
+    let config = Config::load(&metadata, &metadata.workspace_root).unwrap();
+    assert_eq!(config.binary.as_deref(), Some("app"));
+
+    // Selecting core leaves app's binary, crate and README behind, but not the shared settings
+    let core = metadata.find_package("core").unwrap();
+    let config = Config::load_package(&metadata, core).unwrap();
+    assert_eq!(config.binary, None);
+    assert_eq!(config.crate_name, None);
+    assert_eq!(config.readmes, None);
+    assert_eq!(config.metrics, Some(vec!["deps".into()]));
+}
@@ -0,0 +1,237 @@
+use blazon_core::deps::{DepOptions, Depth, Identity, TreeEntry, count_tree};
+use blazon_core::metadata::Metadata;
+use blazon_core::{BuildOptions, ParseError, count_dependencies};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+use walkdir::WalkDir;
+
+/// A copy of the fixtures in a temporary directory, so cargo writes its lockfile there rather
+/// than into the source tree
+fn copy_fixtures() -> TempDir {
+    let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let temp_dir = TempDir::new().unwrap();
+    for entry in WalkDir::new(&source) {
+        let entry = entry.unwrap();
+        let dest = temp_dir
+            .path()
+            .join(entry.path().strip_prefix(&source).unwrap());
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(dest).unwrap();
+        } else if entry.file_name() != "Cargo.lock" {
+            fs::copy(entry.path(), dest).unwrap();
+        }
+    }
+    temp_dir
+}
+
+/// The manifest of the fixture workspace in `fixtures`: `app` depends on its sibling
+/// `fixture-core`, on `helper` 0.1 and on the proc-macro `fixture-macros`, which depends on
+/// `helper` 0.2. It also has the build dependency `builder` and the dev dependency `tester`, and
+/// its binary `app-cli` requires the feature `cli`, which adds the dependency `extra`.
+fn fixture_manifest(fixtures: &TempDir) -> PathBuf {
+    fixtures.path().join("workspace/Cargo.toml")
+}
+
+/// Count the dependencies of `package` in the fixture workspace, or of all its members
+fn count_fixture(package: Option<&str>, deps: DepOptions) -> usize {
+    let fixtures = copy_fixtures();
+    let options = BuildOptions {
+        manifest_path: Some(fixture_manifest(&fixtures)),
+        package: package.map(str::to_string),
+        deps,
+        ..Default::default()
+    };
+    count_dependencies(&options).unwrap()
+}
+
+const EXCLUDE_WORKSPACE: DepOptions = DepOptions {
+    identity: Identity::Names,
+    depth: Depth::All,
+    build: false,
+    dev: false,
+    exclude_proc_macros: false,
+    exclude_workspace: true,
+};
+
+const TREE: &str = "\
+0app v0.1.0 (/ws/app)
+1core v0.1.0 (/ws/core)
+2log v0.4.0
+1derive v1.0.0 (proc-macro)
+2log v0.3.0
+1log v0.4.0
+";
+
+#[test]
+fn test_tree_entry() {
+    let entry = TreeEntry::parse("12serde_derive v1.0.219 (proc-macro) (*)").unwrap();
+    assert_eq!(entry.depth, 12);
+    assert_eq!(entry.name, "serde_derive");
+    assert_eq!(entry.version, "1.0.219");
+    assert_eq!(TreeEntry::parse(""), None);
+    assert_eq!(TreeEntry::parse("serde v1.0.0"), None);
+}
+
+#[test]
+fn test_count_tree() {
+    let members = ["app".to_string(), "core".to_string()];
+
+    // The root does not count, and a crate seen twice counts once
+    assert_eq!(count_tree(TREE, &DepOptions::default(), &members), 3);
+    assert_eq!(count_tree(TREE, &EXCLUDE_WORKSPACE, &members), 2);
+    assert_eq!(count_tree("", &DepOptions::default(), &members), 0);
+}
+
+#[test]
+fn test_count_tree_modes() {
+    let count = |options: DepOptions| count_tree(TREE, &options, &[]);
+    let versions = DepOptions {
+        identity: Identity::Versions,
+        ..Default::default()
+    };
+    assert_eq!(count(versions.clone()), 4);
+
+    let direct = DepOptions {
+        depth: Depth::Direct,
+        ..Default::default()
+    };
+    assert_eq!(count(direct), 3);
+
+    // log is a direct dependency by name, but only log 0.3 is not one by version
+    let transitive = DepOptions {
+        depth: Depth::Transitive,
+        ..Default::default()
+    };
+    assert_eq!(count(transitive.clone()), 0);
+    let transitive_versions = DepOptions {
+        identity: Identity::Versions,
+        ..transitive
+    };
+    assert_eq!(count(transitive_versions), 1);
+}
+
+#[test]
+fn test_count_tree_versioned_root() {
+    // The root is log 0.4, which depends on log 0.3 through derive
+    let tree = "\
+0log v0.4.0 (/ws/log)
+1derive v1.0.0 (proc-macro)
+2log v0.3.0
+";
+    let versions = DepOptions {
+        identity: Identity::Versions,
+        ..Default::default()
+    };
+    assert_eq!(count_tree(tree, &DepOptions::default(), &[]), 1);
+    assert_eq!(count_tree(tree, &versions, &[]), 2);
+}
+
+#[test]
+fn test_edges_and_label() {
+    let default = DepOptions::default();
+    assert_eq!(default.edges(), "normal");
+    assert_eq!(default.qualify("deps"), "deps");
+
+    let options = DepOptions {
+        depth: Depth::Direct,
+        build: true,
+        dev: true,
+        exclude_proc_macros: true,
+        ..Default::default()
+    };
+    assert_eq!(options.edges(), "normal,build,dev,no-proc-macro");
+    assert_eq!(
+        options.qualify("deps"),
+        "deps (direct, +build, +dev, no proc-macros)"
+    );
+    assert_eq!(EXCLUDE_WORKSPACE.qualify("deps"), "deps (no workspace)");
+}
+
+#[test]
+fn test_parse_modes() {
+    assert_eq!("versions".parse(), Ok(Identity::Versions));
+    assert_eq!("transitive".parse(), Ok(Depth::Transitive));
+    assert_eq!(
+        "version".parse::<Identity>(),
+        Err(ParseError::Unknown {
+            kind: "count",
+            name: "version".to_string(),
+            expected: vec!["names", "versions"],
+        })
+    );
+    assert!("indirect".parse::<Depth>().is_err());
+}
+
+#[test]
+fn test_count_fixture_package() {
+    assert_eq!(count_fixture(Some("app"), DepOptions::default()), 3);
+    assert_eq!(
+        count_fixture(Some("fixture-core"), DepOptions::default()),
+        1
+    );
+}
+
+#[test]
+fn test_count_fixture_required_features() {
+    // The features a binary requires are counted with the binary
+    let fixtures = copy_fixtures();
+    let metadata = Metadata::load_manifest(Some(&fixture_manifest(&fixtures))).unwrap();
+    let binary = metadata.find_binary("app-cli").unwrap();
+    let options = BuildOptions {
+        manifest_path: Some(fixture_manifest(&fixtures)),
+        package: Some("app".to_string()),
+        features: binary.missing_features(),
+        ..Default::default()
+    };
+    assert_eq!(options.features, ["app/cli"]);
+    assert_eq!(count_dependencies(&options).unwrap(), 4);
+}
+
+#[test]
+fn test_count_fixture_excluding_workspace() {
+    // The sibling goes, but not the helper it depends on
+    assert_eq!(count_fixture(Some("app"), EXCLUDE_WORKSPACE), 2);
+    // The measured package is never counted, so never excluded either
+    assert_eq!(count_fixture(Some("fixture-core"), EXCLUDE_WORKSPACE), 1);
+}
+
+#[test]
+fn test_count_fixture_workspace_union() {
+    // Without a package, the trees of every member are counted together, but not the members
+    // that are roots of them
+    assert_eq!(count_fixture(None, DepOptions::default()), 2);
+}
+
+#[test]
+fn test_count_fixture_modes() {
+    let app = |options: DepOptions| count_fixture(Some("app"), options);
+
+    // Both versions of helper
+    let versions = DepOptions {
+        identity: Identity::Versions,
+        ..Default::default()
+    };
+    assert_eq!(app(versions), 4);
+
+    // Without fixture-macros goes the helper 0.2 only it depends on
+    let no_proc_macros = DepOptions {
+        identity: Identity::Versions,
+        exclude_proc_macros: true,
+        ..Default::default()
+    };
+    assert_eq!(app(no_proc_macros), 2);
+
+    let build_and_dev = DepOptions {
+        build: true,
+        dev: true,
+        ..Default::default()
+    };
+    assert_eq!(app(build_and_dev), 5);
+
+    let direct = DepOptions {
+        depth: Depth::Direct,
+        ..Default::default()
+    };
+    assert_eq!(app(direct), 3);
+}
@@ -0,0 +1,110 @@
+use blazon_core::diff::{Hunk, ReadmeDiff, diff_readme};
+use insta::assert_snapshot;
+use std::fs;
+use tempfile::TempDir;
+
+const README: &str = "# Title
+
+<!-- blazon -->
+[![Dependencies: 1](deps.svg)](https://crates.io/crates/x)
+[![Binary Size: 1.0M](size.svg)](https://crates.io/crates/x)
+<!-- /blazon -->
+
+Some text.
+More text.
+";
+
+#[test]
+fn test_hunks() {
+    let after = README.replace("Binary Size: 1.0M", "Binary Size: 1.2M");
+    let diff = ReadmeDiff::new(README.to_string(), after.clone());
+
+    assert!(!diff.is_unchanged());
+    assert_eq!(diff.after, after);
+    assert_eq!(
+        diff.hunks,
+        [Hunk {
+            before: 4..5,
+            after: 4..5,
+        }]
+    );
+}
+
+#[test]
+fn test_unchanged() {
+    let diff = ReadmeDiff::new(README.to_string(), README.to_string());
+
+    assert!(diff.is_unchanged());
+    assert!(diff.hunks.is_empty());
+    assert_eq!(diff.unified("README.md"), "");
+}
+
+#[test]
+fn test_unified_diff() {
+    let after = README.replace(
+        "[![Binary Size: 1.0M](size.svg)](https://crates.io/crates/x)\n",
+        "[![Binary Size: 1.2M](size.svg)](https://crates.io/crates/x)\n[![Target Dir: 9.0M](target-dir.svg)](https://crates.io/crates/x)\n",
+    );
+    let diff = ReadmeDiff::new(README.to_string(), after);
+
+    assert_snapshot!(diff.unified("README.md"));
+}
+
+#[test]
+fn test_diff_readme_does_not_write() {
+    let temp_dir = TempDir::new().unwrap();
+    let readme_path = temp_dir.path().join("README.md");
+    let original = "Intro\n<!-- blazon -->\nold\n<!-- /blazon -->\n";
+    fs::write(&readme_path, original).unwrap();
+
+    let diff = diff_readme(readme_path.to_str().unwrap(), "NEW\n").unwrap();
+
+    assert_eq!(diff.before, original);
+    assert_eq!(
+        diff.after,
+        "Intro\n<!-- blazon -->\nNEW\n<!-- /blazon -->\n"
+    );
+    assert_eq!(
+        diff.hunks,
+        [Hunk {
+            before: 2..3,
+            after: 2..3,
+        }]
+    );
+    assert_eq!(fs::read_to_string(&readme_path).unwrap(), original);
+}
+
+#[test]
+fn test_diff_readme_crlf() {
+    let temp_dir = TempDir::new().unwrap();
+    let readme_path = temp_dir.path().join("README.md");
+    fs::write(
+        &readme_path,
+        "Intro\r\n<!-- blazon -->\r\n<!-- /blazon -->\r\n",
+    )
+    .unwrap();
+
+    let diff = diff_readme(readme_path.to_str().unwrap(), "NEW").unwrap();
+
+    // Compared with plain line breaks, so only the patched line differs
+    assert_eq!(diff.before, "Intro\n<!-- blazon -->\n<!-- /blazon -->\n");
+    assert_eq!(
+        diff.hunks,
+        [Hunk {
+            before: 2..2,
+            after: 2..3,
+        }]
+    );
+}
+
+#[test]
+fn test_unified_diff_separate_hunks() {
+    let before: String = (1..=20).map(|i| format!("line {i}\n")).collect();
+    let after = before
+        .replace("line 2\n", "line two\n")
+        .replace("line 19\nline 20\n", "line 19\nline twenty");
+    let diff = ReadmeDiff::new(before, after);
+
+    assert_eq!(diff.hunks.len(), 2);
+    assert_snapshot!(diff.unified("README.md"));
+}
@@ -0,0 +1,46 @@
+use blazon_core::region::MarkerErrorKind;
+use blazon_core::{BlazonError, update_readme};
+use std::error::Error;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_missing_readme_is_io_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let readme_path = temp_dir.path().join("README.md");
+
+    let err = update_readme(readme_path.to_str().unwrap(), "NEW").unwrap_err();
+    match &err {
+        BlazonError::Io { action, path, .. } => {
+            assert_eq!(*action, "read");
+            assert_eq!(path, &readme_path);
+        }
+        other => panic!("expected Io, got {:?}", other),
+    }
+    assert!(err.to_string().starts_with("Failed to read "));
+    assert!(err.source().is_some());
+}
+
+#[test]
+fn test_marker_error_keeps_readme() {
+    let temp_dir = TempDir::new().unwrap();
+    let readme_path = temp_dir.path().join("README.md");
+    fs::write(&readme_path, "# Title\n<!-- blazon -->\n").unwrap();
+    let readme = readme_path.to_str().unwrap();
+
+    let err = update_readme(readme, "NEW").unwrap_err();
+    let BlazonError::Markers(invalid) = &err else {
+        panic!("expected Markers, got {:?}", err);
+    };
+    assert_eq!(invalid.path, readme);
+    assert_eq!(invalid.content, "# Title\n<!-- blazon -->\n");
+    assert_eq!(invalid.error.kind, MarkerErrorKind::MissingEnd);
+    assert_eq!(
+        err.to_string(),
+        format!("Missing end marker '<!-- /blazon -->' in {}", readme)
+    );
+    assert_eq!(
+        err.help().as_deref(),
+        Some("add <!-- /blazon --> after <!-- blazon -->")
+    );
+}
@@ -0,0 +1,140 @@
+use blazon_core::badge::{Badge, create_badge, shields_escape, xml_escape};
+use proptest::prelude::*;
+
+/// Decode a badge path field the way shields.io does
+fn shields_unescape(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut decoded = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap();
+                decoded.push(u8::from_str_radix(hex, 16).unwrap());
+                i += 3;
+            }
+            b => {
+                decoded.push(b);
+                i += 1;
+            }
+        }
+    }
+
+    let decoded = String::from_utf8(decoded).unwrap();
+    let mut text = String::new();
+    let mut chars = decoded.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('_', Some('_')) | ('-', Some('-')) => text.push(chars.next().unwrap()),
+            ('_', _) => text.push(' '),
+            (c, _) => text.push(c),
+        }
+    }
+    text
+}
+
+/// Whether XML 1.0 allows `c` in a document
+fn is_xml_char(c: char) -> bool {
+    !matches!(c, '\0'..='\x08' | '\x0b' | '\x0c' | '\x0e'..='\x1f' | '\u{fffe}' | '\u{ffff}')
+}
+
+#[test]
+fn test_shields_escape() {
+    assert_eq!(
+        shields_escape("build size (stripped)"),
+        "build%20size%20%28stripped%29"
+    );
+    assert_eq!(shields_escape("x86_64-linux"), "x86__64--linux");
+    assert_eq!(shields_escape("1.3M"), "1.3M");
+    assert_eq!(shields_escape("a/b?c#d%"), "a%2Fb%3Fc%23d%25");
+    assert_eq!(shields_escape("größe"), "gr%C3%B6%C3%9Fe");
+}
+
+#[test]
+fn test_xml_escape() {
+    assert_eq!(
+        xml_escape("deps <dev & build>"),
+        "deps &lt;dev &amp; build&gt;"
+    );
+    assert_eq!(
+        xml_escape(r#"say "hi" 'there'"#),
+        "say &quot;hi&quot; &apos;there&apos;"
+    );
+    assert_eq!(xml_escape("a\tb\r\nc"), "a&#9;b&#13;&#10;c");
+    assert_eq!(xml_escape("bell\x07 nul\0 \u{fffe}"), "bell nul ");
+}
+
+#[test]
+fn test_shields_url_escapes_fields() {
+    let badge = Badge {
+        id: "size".to_string(),
+        alt: "Binary Size [stripped]: 1.2M".to_string(),
+        label: "build size (stripped)".to_string(),
+        value: "1.2M-ish".to_string(),
+        color: "#e05d44".to_string(),
+        style: Default::default(),
+    };
+
+    assert_eq!(
+        badge.shields_url(),
+        "https://img.shields.io/badge/build%20size%20%28stripped%29-1.2M--ish-e05d44"
+    );
+    assert_eq!(
+        badge.markdown(&badge.shields_url(), "https://crates.io/crates/x"),
+        "[![Binary Size \\[stripped\\]: 1.2M](https://img.shields.io/badge/build%20size%20%28stripped%29-1.2M--ish-e05d44)](https://crates.io/crates/x)"
+    );
+}
+
+#[test]
+fn test_svg_escapes_text() {
+    let svg = create_badge("<deps> & co", "\"42\"", "blue");
+    assert!(svg.contains(">&lt;deps&gt; &amp; co<"));
+    assert!(svg.contains("aria-label=\"&lt;deps&gt; &amp; co: &quot;42&quot;\""));
+    assert!(!svg.contains("<deps>"));
+}
+
+proptest! {
+    #[test]
+    fn shields_escape_roundtrips(text in any::<String>()) {
+        prop_assert_eq!(shields_unescape(&shields_escape(&text)), text);
+    }
+
+    #[test]
+    fn shields_escape_is_one_url_field(text in any::<String>()) {
+        let escaped = shields_escape(&text);
+        prop_assert!(escaped.chars().all(|c| c.is_ascii_alphanumeric() || "-_.~%".contains(c)));
+        // Every dash is doubled, so the field cannot be split into label/value/colour
+        prop_assert!(!escaped.replace("--", "").contains('-'));
+    }
+
+    #[test]
+    fn xml_escape_roundtrips(text in any::<String>()) {
+        let escaped = xml_escape(&text);
+        prop_assert!(!escaped.contains(['<', '>', '"', '\'']));
+
+        let xml = format!("<t a=\"{escaped}\">{escaped}</t>");
+        let document = roxmltree::Document::parse(&xml);
+        prop_assert!(document.is_ok(), "{:?} does not parse: {:?}", xml, document.err());
+        let document = document.unwrap();
+        let element = document.root_element();
+        let expected: String = text.chars().filter(|&c| is_xml_char(c)).collect();
+        prop_assert_eq!(element.attribute("a"), Some(expected.as_str()));
+        prop_assert_eq!(element.text().unwrap_or_default(), expected);
+    }
+
+    #[test]
+    fn svg_parses_as_xml(label in any::<String>(), value in any::<String>(), color in any::<String>()) {
+        let svg = create_badge(&label, &value, &color);
+        let document = roxmltree::Document::parse(&svg);
+        prop_assert!(document.is_ok(), "{:?} does not parse: {:?}", svg, document.err());
+    }
+
+    #[test]
+    fn svg_contains_no_raw_markup_from_text(label in "[<>&\"' a-z]{0,12}", value in "[<>&\"' 0-9]{0,12}") {
+        let svg = create_badge(&label, &value, "blue");
+        let text_nodes = svg.split("<text").skip(1).map(|t| &t[t.find('>').unwrap() + 1..t.find("</text>").unwrap()]);
+        for node in text_nodes {
+            prop_assert!(!node.contains(['<', '>', '"', '\'']));
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use blazon_core::file::{LineEnding, TextFile, write_atomic};
+use blazon_core::{WriteStatus, update_readme};
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_line_ending_of() {
+    assert_eq!(LineEnding::of(""), LineEnding::Lf);
+    assert_eq!(LineEnding::of("one line"), LineEnding::Lf);
+    assert_eq!(LineEnding::of("a\nb\n"), LineEnding::Lf);
+    assert_eq!(LineEnding::of("a\r\nb\r\n"), LineEnding::Crlf);
+    // Mixed files are left as they are
+    assert_eq!(LineEnding::of("a\r\nb\n"), LineEnding::Lf);
+}
+
+#[test]
+fn test_text_file_roundtrip() {
+    for raw in [
+        "a\nb\n",
+        "a\r\nb\r\n",
+        "\u{feff}a\r\nb",
+        "\u{feff}a\nb\n",
+        "a\r\nb\n",
+    ] {
+        let file = TextFile::parse("README.md".into(), raw);
+        assert!(!file.content.contains('\u{feff}'));
+        assert_eq!(file.encode(&file.content), raw);
+    }
+
+    let file = TextFile::parse("README.md".into(), "\u{feff}a\r\nb\r\n");
+    assert_eq!(file.content, "a\nb\n");
+    assert_eq!(file.encode("a\nx\ny\n"), "\u{feff}a\r\nx\r\ny\r\n");
+}
+
+#[test]
+fn test_update_readme_keeps_crlf_and_bom() {
+    let temp_dir = TempDir::new().unwrap();
+    let readme_path = temp_dir.path().join("README.md");
+    fs::write(
+        &readme_path,
+        "\u{feff}# Title\r\n<!-- blazon -->\r\nold\r\n<!-- /blazon -->\r\nRest\r\n",
+    )
+    .unwrap();
+    let readme = readme_path.to_str().unwrap();
+
+    assert_eq!(
+        update_readme(readme, "one\ntwo").unwrap(),
+        WriteStatus::Updated
+    );
+    assert_eq!(
+        fs::read_to_string(&readme_path).unwrap(),
+        "\u{feff}# Title\r\n<!-- blazon -->\r\none\r\ntwo\r\n<!-- /blazon -->\r\nRest\r\n"
+    );
+    assert_eq!(
+        update_readme(readme, "one\ntwo").unwrap(),
+        WriteStatus::Unchanged
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_update_readme_follows_symlink() {
+    let temp_dir = TempDir::new().unwrap();
+    let target = temp_dir.path().join("docs/README.md");
+    fs::create_dir_all(target.parent().unwrap()).unwrap();
+    fs::write(&target, "<!-- blazon --><!-- /blazon -->\n").unwrap();
+    let link = temp_dir.path().join("README.md");
+    std::os::unix::fs::symlink(&target, &link).unwrap();
+
+    update_readme(link.to_str().unwrap(), "NEW").unwrap();
+
+    assert!(
+        fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink()
+    );
+    assert_eq!(
+        fs::read_to_string(&target).unwrap(),
+        "<!-- blazon -->NEW<!-- /blazon -->\n"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_write_atomic_keeps_mode() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("README.md");
+    fs::write(&path, "old").unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+    write_atomic(&path, b"new").unwrap();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+    let mode = fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o640);
+}
+
+#[test]
+fn test_write_atomic_leaves_no_temp_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("badge.svg");
+
+    write_atomic(&path, b"<svg/>").unwrap();
+
+    let names: Vec<_> = fs::read_dir(temp_dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(names, ["badge.svg"]);
+
+    // A failed write cleans up too
+    assert!(write_atomic(&temp_dir.path().join("missing/badge.svg"), b"<svg/>").is_err());
+    assert!(!temp_dir.path().join("missing").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_write_atomic_skips_planted_temp_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("README.md");
+    let victim = temp_dir.path().join("victim");
+    fs::write(&victim, "untouched").unwrap();
+
+    // Symlinks at the names the temporary file could take are neither followed nor replaced
+    let dir = temp_dir.path().canonicalize().unwrap();
+    for n in 0..20 {
+        let name = format!(".README.md.{}.{}.blazon.tmp", std::process::id(), n);
+        std::os::unix::fs::symlink(&victim, dir.join(name)).unwrap();
+    }
+    write_atomic(&path, b"new").unwrap();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+    assert_eq!(fs::read_to_string(&victim).unwrap(), "untouched");
+    assert!(
+        fs::symlink_metadata(dir.join(format!(".README.md.{}.0.blazon.tmp", std::process::id())))
+            .unwrap()
+            .file_type()
+            .is_symlink()
+    );
+}
@@ -0,0 +1,110 @@
+use blazon_core::insert::{Anchor, Insertion, insert_markers};
+use blazon_core::{insert_readme_markers, patch_readme};
+use std::fs;
+use tempfile::TempDir;
+
+fn insert(content: &str) -> (String, Insertion) {
+    insert_markers(content).unwrap()
+}
+
+#[test]
+fn test_insert_after_badge_row() {
+    let readme =
+        "# my-crate\n\n[![crates.io](a.svg)](a) ![ci](b.svg)\n[![docs](c.svg)](c)\n\nIntro.\n";
+
+    let (inserted, insertion) = insert(readme);
+    assert_eq!(
+        inserted,
+        "# my-crate\n\n[![crates.io](a.svg)](a) ![ci](b.svg)\n[![docs](c.svg)](c)<!-- blazon --><!-- /blazon -->\n\nIntro.\n"
+    );
+    assert_eq!(
+        insertion,
+        Insertion {
+            anchor: Anchor::BadgeRow,
+            line: 4
+        }
+    );
+    assert_eq!(
+        insertion.to_string(),
+        "at the end of the badge row on line 4"
+    );
+
+    // The badges join the row
+    let patched = patch_readme(&inserted, "[![x](x.svg)](x)").unwrap();
+    assert!(patched.contains("(c)<!-- blazon -->[![x](x.svg)](x)<!-- /blazon -->\n"));
+}
+
+#[test]
+fn test_insert_after_heading() {
+    let (inserted, insertion) = insert("# my-crate\nIntro.\n");
+    assert_eq!(
+        inserted,
+        "# my-crate\n<!-- blazon -->\n<!-- /blazon -->\n\nIntro.\n"
+    );
+    assert_eq!(
+        insertion.to_string(),
+        "on line 2, after the heading on line 1"
+    );
+
+    let (inserted, _) = insert("Title\n=====\n\nIntro.\n");
+    assert_eq!(
+        inserted,
+        "Title\n=====\n<!-- blazon -->\n<!-- /blazon -->\n\nIntro.\n"
+    );
+
+    let (inserted, _) = insert("# my-crate");
+    assert_eq!(inserted, "# my-crate\n<!-- blazon -->\n<!-- /blazon -->\n");
+}
+
+#[test]
+fn test_insert_prose_before_badges() {
+    // Images after the first paragraph are not a badge row
+    let (_, insertion) = insert("# my-crate\nIntro.\n\n![diagram](d.svg)\n");
+    assert_eq!(insertion.anchor, Anchor::Heading);
+    assert_eq!(insertion.line, 2);
+}
+
+#[test]
+fn test_insert_at_start() {
+    let (inserted, insertion) = insert("Just text.\n");
+    assert_eq!(
+        inserted,
+        "<!-- blazon -->\n<!-- /blazon -->\n\nJust text.\n"
+    );
+    assert_eq!(insertion.to_string(), "on line 1, at the top");
+
+    let (inserted, _) = insert("");
+    assert_eq!(inserted, "<!-- blazon -->\n<!-- /blazon -->\n");
+}
+
+#[test]
+fn test_insert_keeps_existing_markers() {
+    assert_eq!(
+        insert_markers("# x\n<!-- blazon:size --><!-- /blazon:size -->\n"),
+        None
+    );
+}
+
+#[test]
+fn test_insert_keeps_orphan_end_marker() {
+    // The README is left for the missing start marker to be reported, rather than given a
+    // second end marker
+    assert_eq!(insert_markers("# x\nIntro.\n<!-- /blazon -->\n"), None);
+}
+
+#[test]
+fn test_insert_readme_markers() {
+    let temp_dir = TempDir::new().unwrap();
+    let readme_path = temp_dir.path().join("README.md");
+    fs::write(&readme_path, "# Title\r\nIntro.\r\n").unwrap();
+    let readme = readme_path.to_str().unwrap();
+
+    let insertion = insert_readme_markers(readme).unwrap().unwrap();
+    assert_eq!(insertion.line, 2);
+    assert_eq!(
+        fs::read_to_string(&readme_path).unwrap(),
+        "# Title\r\n<!-- blazon -->\r\n<!-- /blazon -->\r\n\r\nIntro.\r\n"
+    );
+
+    assert_eq!(insert_readme_markers(readme).unwrap(), None);
+}
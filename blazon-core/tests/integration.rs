@@ -9,9 +9,9 @@ fn test_update_readme() {
     // Create a README with markers
     let initial_content = r#"# My Project
 
-<!-- auto-generated badges -->
+<!-- blazon -->
 old badge content here
-<!-- /auto-generated badges -->
+<!-- /blazon -->
 
 Some other content.
 "#;
@@ -20,7 +20,8 @@ Some other content.
 
     // Update with new badges
     let new_badges = "[![test](https://example.com)](https://example.com)";
-    blazon_core::update_readme(readme_path.to_str().unwrap(), new_badges).unwrap();
+    let status = blazon_core::update_readme(readme_path.to_str().unwrap(), new_badges).unwrap();
+    assert_eq!(status, blazon_core::WriteStatus::Updated);
 
     // Read back and verify
     let updated = fs::read_to_string(&readme_path).unwrap();
@@ -40,8 +41,8 @@ fn test_update_readme_preserves_structure() {
 
 Before badges.
 
-<!-- auto-generated badges -->
-<!-- /auto-generated badges -->
+<!-- blazon -->
+<!-- /blazon -->
 
 After badges.
 "#;
@@ -58,6 +59,169 @@ After badges.
     assert!(updated.contains("NEW"));
 
     // Check markers are still there
-    assert!(updated.contains("<!-- auto-generated badges -->"));
-    assert!(updated.contains("<!-- /auto-generated badges -->"));
+    assert!(updated.contains("<!-- blazon -->"));
+    assert!(updated.contains("<!-- /blazon -->"));
+}
+
+#[test]
+fn test_target_dir_size() {
+    let temp_dir = TempDir::new().unwrap();
+    let target_dir = temp_dir.path().join("target");
+
+    fs::create_dir_all(target_dir.join("debug/deps")).unwrap();
+    fs::create_dir_all(target_dir.join("release")).unwrap();
+    fs::create_dir_all(target_dir.join("doc")).unwrap();
+    fs::create_dir_all(target_dir.join("x86_64-unknown-linux-gnu/release")).unwrap();
+
+    fs::write(target_dir.join("debug/deps/libfoo.rlib"), vec![0; 100]).unwrap();
+    fs::write(target_dir.join("debug/foo"), vec![0; 50]).unwrap();
+    fs::write(target_dir.join("release/foo"), vec![0; 20]).unwrap();
+    fs::write(target_dir.join("doc/index.html"), vec![0; 5]).unwrap();
+    fs::write(
+        target_dir.join("x86_64-unknown-linux-gnu/release/foo"),
+        vec![0; 7],
+    )
+    .unwrap();
+    fs::write(target_dir.join("CACHEDIR.TAG"), vec![0; 1]).unwrap();
+
+    let (total, profiles) = blazon_core::get_target_dir_size(&target_dir).unwrap();
+
+    assert_eq!(profiles.debug, 150);
+    assert_eq!(profiles.release, 20);
+    assert_eq!(profiles.doc, 5);
+    assert_eq!(total, 183);
+}
+
+#[test]
+fn test_target_dir_size_missing() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let (total, profiles) =
+        blazon_core::get_target_dir_size(&temp_dir.path().join("target")).unwrap();
+
+    assert_eq!(total, 0);
+    assert_eq!(profiles, blazon_core::TargetDirProfiles::default());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_target_dir_size_hardlinks() {
+    let temp_dir = TempDir::new().unwrap();
+    let target_dir = temp_dir.path().join("target");
+    fs::create_dir_all(target_dir.join("release/deps")).unwrap();
+
+    // As cargo links the final binary to its copy in deps
+    fs::write(target_dir.join("release/deps/foo-0123"), vec![0; 40]).unwrap();
+    fs::hard_link(
+        target_dir.join("release/deps/foo-0123"),
+        target_dir.join("release/foo"),
+    )
+    .unwrap();
+
+    let (total, profiles) = blazon_core::get_target_dir_size(&target_dir).unwrap();
+
+    assert_eq!(profiles.release, 40);
+    assert_eq!(total, 40);
+}
+
+#[test]
+fn test_generate_svg_badges() {
+    let temp_dir = TempDir::new().unwrap();
+    let member_dir = temp_dir.path().join("member");
+    fs::create_dir_all(&member_dir).unwrap();
+    let readme_path = member_dir.join("README.md");
+    let svg_dir = temp_dir.path().join(".github/badges");
+
+    let metrics = blazon_core::Metrics {
+        dep_count: 7,
+        binary_size_bytes: 3 * 1024,
+        ..Default::default()
+    };
+
+    let badges = blazon_core::generate_svg_badges(
+        &metrics,
+        "my-crate",
+        &svg_dir,
+        &readme_path,
+        &Default::default(),
+    )
+    .unwrap();
+
+    assert!(badges.contains(
+        "[![Dependencies: 7](../.github/badges/deps.svg)](https://crates.io/crates/my-crate)"
+    ));
+    assert!(badges.contains("(../.github/badges/size.svg)"));
+    assert!(badges.contains("(../.github/badges/target-dir.svg)"));
+    assert!(!badges.contains("img.shields.io"));
+
+    let deps_svg = fs::read_to_string(svg_dir.join("deps.svg")).unwrap();
+    assert!(deps_svg.starts_with("<svg"));
+    assert!(deps_svg.contains(">cargo tree<"));
+    assert!(deps_svg.contains(">7<"));
+    let size_svg = fs::read_to_string(svg_dir.join("size.svg")).unwrap();
+    assert!(size_svg.contains(">3.0K<"));
+}
+
+#[test]
+fn test_patch_readme() {
+    let content = "# Title\n<!-- blazon -->\nold\n<!-- /blazon -->\nRest\n";
+
+    let patched = blazon_core::patch_readme(content, "NEW").unwrap();
+    assert_eq!(
+        patched,
+        "# Title\n<!-- blazon -->\nNEW\n<!-- /blazon -->\nRest\n"
+    );
+
+    assert!(blazon_core::patch_readme("no markers", "NEW").is_err());
+}
+
+#[test]
+fn test_render_svg_badges_writes_nothing() {
+    let temp_dir = TempDir::new().unwrap();
+    let readme_path = temp_dir.path().join("README.md");
+    let svg_dir = temp_dir.path().join("not/yet/created");
+
+    let badges = blazon_core::render_svg_badges(
+        &blazon_core::Metrics::default(),
+        "my-crate",
+        &svg_dir,
+        &readme_path,
+        &Default::default(),
+    )
+    .unwrap();
+
+    assert!(
+        badges
+            .badges
+            .markdown()
+            .contains("(not/yet/created/deps.svg)")
+    );
+    assert!(!svg_dir.exists());
+    assert_eq!(badges.stale_files().len(), 3);
+
+    assert_eq!(badges.write().unwrap().len(), 3);
+    assert!(badges.stale_files().is_empty());
+    assert!(badges.write().unwrap().is_empty());
+}
+
+#[test]
+fn test_update_readme_unchanged() {
+    let temp_dir = TempDir::new().unwrap();
+    let readme_path = temp_dir.path().join("README.md");
+    fs::write(&readme_path, "<!-- blazon -->\nNEW\n<!-- /blazon -->\n").unwrap();
+    let readme = readme_path.to_str().unwrap();
+
+    // Backdated, so a rewrite would show up as a newer mtime
+    let past = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+    let file = fs::File::options().write(true).open(&readme_path).unwrap();
+    file.set_modified(past).unwrap();
+    drop(file);
+
+    let status = blazon_core::update_readme(readme, "NEW").unwrap();
+    assert_eq!(status, blazon_core::WriteStatus::Unchanged);
+    assert_eq!(status.to_string(), "unchanged");
+    assert_eq!(
+        fs::metadata(&readme_path).unwrap().modified().unwrap(),
+        past
+    );
 }
@@ -0,0 +1,216 @@
+use blazon_core::BlazonError;
+use blazon_core::metadata::Metadata;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Write a package with the given manifest body and binary sources under `dir`
+fn create_package(dir: &Path, manifest: &str, bins: &[&str]) {
+    fs::create_dir_all(dir.join("src/bin")).unwrap();
+    fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    fs::write(dir.join("src/lib.rs"), "").unwrap();
+    for bin in bins {
+        fs::write(dir.join(format!("src/bin/{}.rs", bin)), "fn main() {}").unwrap();
+    }
+}
+
+/// Run `cargo metadata` in `dir` and parse it
+fn load_metadata(dir: &Path) -> Metadata {
+    let output = Command::new("cargo")
+        .args(["metadata", "--format-version", "1", "--no-deps"])
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "cargo metadata failed");
+    Metadata::parse(&String::from_utf8_lossy(&output.stdout)).unwrap()
+}
+
+/// A virtual workspace with a library-only member and a CLI member whose binary needs a feature
+fn create_workspace() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"core\", \"cli\"]\nresolver = \"2\"\n",
+    )
+    .unwrap();
+    create_package(
+        &root.join("core"),
+        "[package]\nname = \"foo-core\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        &[],
+    );
+    create_package(
+        &root.join("cli"),
+        r#"[package]
+name = "foo-cli"
+version = "0.2.0"
+edition = "2021"
+readme = "README.md"
+
+[features]
+default = []
+cli = []
+
+[[bin]]
+name = "foo"
+path = "src/bin/foo.rs"
+required-features = ["cli"]
+"#,
+        &["foo"],
+    );
+
+    temp_dir
+}
+
+#[test]
+fn test_parse_workspace() {
+    let temp_dir = create_workspace();
+    let metadata = load_metadata(temp_dir.path());
+
+    let mut names: Vec<&str> = metadata.members().map(|p| p.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, ["foo-cli", "foo-core"]);
+    assert!(metadata.target_directory.is_absolute());
+
+    let cli = metadata.members().find(|p| p.name == "foo-cli").unwrap();
+    assert_eq!(cli.version, "0.2.0");
+    assert_eq!(cli.readme.as_deref(), Some(Path::new("README.md")));
+    assert!(cli.features.contains_key("cli"));
+}
+
+#[test]
+fn test_member_artifacts() {
+    let temp_dir = create_workspace();
+    let metadata = load_metadata(temp_dir.path());
+
+    let core = metadata.find_package("foo-core").unwrap();
+    assert!(matches!(core.main_binary(), Err(BlazonError::NoBinary)));
+    assert_eq!(core.library().unwrap().name, "foo_core");
+    assert!(core.dir().ends_with("core"));
+
+    let cli = metadata.find_package("foo-cli").unwrap();
+    assert_eq!(cli.main_binary().unwrap().target.name, "foo");
+}
+
+#[test]
+fn test_infer_binary_from_workspace_root() {
+    let temp_dir = create_workspace();
+    let metadata = load_metadata(temp_dir.path());
+
+    let binary = metadata.infer_binary(temp_dir.path()).unwrap();
+    assert_eq!(binary.target.name, "foo");
+    assert_eq!(binary.package.name, "foo-cli");
+    assert_eq!(binary.missing_features(), ["foo-cli/cli"]);
+}
+
+#[test]
+fn test_infer_binary_from_member_subdirectory() {
+    let temp_dir = create_workspace();
+    let metadata = load_metadata(temp_dir.path());
+
+    let binary = metadata
+        .infer_binary(&temp_dir.path().join("cli/src"))
+        .unwrap();
+    assert_eq!(binary.target.name, "foo");
+
+    let err = metadata
+        .infer_binary(&temp_dir.path().join("core"))
+        .unwrap_err();
+    assert!(matches!(err, BlazonError::NoBinary));
+    assert_eq!(err.to_string(), "No binary target found");
+}
+
+#[test]
+fn test_infer_binary_prefers_default_features() {
+    let temp_dir = TempDir::new().unwrap();
+    create_package(
+        temp_dir.path(),
+        r#"[package]
+name = "tool"
+version = "0.1.0"
+edition = "2021"
+
+[features]
+default = ["std"]
+std = ["extra"]
+extra = []
+gui = []
+
+[[bin]]
+name = "tool-gui"
+path = "src/bin/tool-gui.rs"
+required-features = ["gui"]
+
+[[bin]]
+name = "tool-cli"
+path = "src/bin/tool-cli.rs"
+required-features = ["extra"]
+"#,
+        &["tool-gui", "tool-cli"],
+    );
+    let metadata = load_metadata(temp_dir.path());
+
+    let binary = metadata.infer_binary(temp_dir.path()).unwrap();
+    assert_eq!(binary.target.name, "tool-cli");
+    assert!(binary.missing_features().is_empty());
+    assert_eq!(
+        metadata.find_binary("tool-gui").unwrap().missing_features(),
+        ["tool/gui"]
+    );
+}
+
+#[test]
+fn test_infer_binary_ambiguous() {
+    let temp_dir = TempDir::new().unwrap();
+    create_package(
+        temp_dir.path(),
+        "[package]\nname = \"multi\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        &["alpha", "beta"],
+    );
+    let metadata = load_metadata(temp_dir.path());
+
+    let err = metadata.infer_binary(temp_dir.path()).unwrap_err();
+    match &err {
+        BlazonError::AmbiguousBinary { candidates } => assert_eq!(candidates, &["alpha", "beta"]),
+        other => panic!("expected AmbiguousBinary, got {:?}", other),
+    }
+    assert!(err.to_string().starts_with("Multiple binary targets found"));
+}
+
+#[test]
+fn test_infer_crate_name() {
+    let temp_dir = create_workspace();
+    let metadata = load_metadata(temp_dir.path());
+
+    assert_eq!(
+        metadata.infer_crate_name("foo", temp_dir.path()),
+        Some("foo-cli")
+    );
+    assert_eq!(
+        metadata.infer_crate_name("unknown", &temp_dir.path().join("core")),
+        Some("foo-core")
+    );
+    assert_eq!(metadata.infer_crate_name("unknown", temp_dir.path()), None);
+}
+
+#[test]
+fn test_load_manifest() {
+    let temp_dir = create_workspace();
+
+    let metadata = Metadata::load_manifest(Some(&temp_dir.path().join("Cargo.toml"))).unwrap();
+    let names: Vec<&str> = metadata.members().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["foo-core", "foo-cli"]);
+
+    let package = metadata.find_package("foo-cli").unwrap();
+    assert_eq!(
+        package.manifest_path,
+        temp_dir
+            .path()
+            .canonicalize()
+            .unwrap()
+            .join("cli/Cargo.toml")
+    );
+    assert!(metadata.find_package("foo").is_none());
+}
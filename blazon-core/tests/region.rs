@@ -0,0 +1,315 @@
+use blazon_core::region::{
+    Format, Layout, MarkerError, MarkerErrorKind, Region, Selection, check_markers, find_markers,
+    find_regions,
+};
+use blazon_core::{BadgeOptions, Metrics, ParseError, patch_readme, render_badges};
+use insta::assert_snapshot;
+
+fn named(name: &str) -> Region {
+    Region {
+        name: Some(name.to_string()),
+    }
+}
+
+#[test]
+fn test_find_regions() {
+    let content = "\
+[![ci](ci.svg)](ci)<!-- blazon:size --><!-- /blazon:size -->
+<!-- blazon -->
+<!-- /blazon -->
+## Performance
+<!-- blazon:table -->
+<!-- /blazon:table -->
+<!-- blazon:size --> again <!-- /blazon:size -->
+<!-- blazon: spaced --> <!-- blazonry -->
+";
+
+    assert_eq!(
+        find_regions(content),
+        [named("size"), Region { name: None }, named("table")]
+    );
+}
+
+#[test]
+fn test_markers() {
+    assert_eq!(Region { name: None }.start_marker(), "<!-- blazon -->");
+    assert_eq!(
+        named("deps,size").start_marker(),
+        "<!-- blazon:deps,size -->"
+    );
+    assert_eq!(
+        named("deps,size").end_marker(),
+        "<!-- /blazon:deps,size -->"
+    );
+}
+
+#[test]
+fn test_selection() {
+    let ids = |s: &[&str]| s.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(
+        Region { name: None }.selection(),
+        Ok(Selection {
+            ids: ids(&["deps", "size", "target-dir"]),
+            format: Format::Badges,
+        })
+    );
+    assert_eq!(
+        named("size,deps").selection(),
+        Ok(Selection {
+            ids: ids(&["size", "deps"]),
+            format: Format::Badges,
+        })
+    );
+    assert_eq!(
+        named("table").selection(),
+        Ok(Selection::all(Format::Table))
+    );
+    assert_eq!(
+        named("table:target-dir").selection(),
+        Ok(Selection {
+            ids: ids(&["target-dir"]),
+            format: Format::Table,
+        })
+    );
+    let error = named("bloat").selection().unwrap_err();
+    assert_eq!(
+        error,
+        ParseError::Region {
+            marker: "<!-- blazon:bloat -->".to_string(),
+            metric: "bloat".to_string(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Invalid region '<!-- blazon:bloat -->': unknown metric 'bloat' (expected one of: deps, size, target-dir)"
+    );
+}
+
+#[test]
+fn test_patch_named_regions() {
+    let readme = "\
+# my-crate
+[![ci](ci.svg)](ci)<!-- blazon:size --><!-- /blazon:size -->
+
+Intro.
+
+## Performance
+
+<!-- blazon:table -->
+<!-- /blazon:table -->
+";
+    let metrics = Metrics {
+        dep_count: 42,
+        binary_size_bytes: 3 * 1024 * 1024,
+        target_dir_bytes: 512 * 1024 * 1024,
+        ..Default::default()
+    };
+    let badges = render_badges(&metrics, "my-crate", &BadgeOptions::default());
+
+    let patched = patch_readme(readme, &badges).unwrap();
+    assert_snapshot!(patched);
+
+    // Repeated runs leave the README as it is
+    assert_eq!(patch_readme(&patched, &badges).unwrap(), patched);
+}
+
+#[test]
+fn test_patch_invalid_region() {
+    let badges = render_badges(&Metrics::default(), "x", &BadgeOptions::default());
+    let error = patch_readme("<!-- blazon:sise --><!-- /blazon:sise -->", &badges).unwrap_err();
+    assert!(error.to_string().contains("unknown metric 'sise'"));
+    assert_eq!(error.span, 0..20);
+}
+
+fn badges() -> blazon_core::region::RenderedBadges {
+    let metrics = Metrics {
+        dep_count: 7,
+        binary_size_bytes: 1024,
+        ..Default::default()
+    };
+    render_badges(&metrics, "x", &BadgeOptions::default())
+}
+
+#[test]
+fn test_layout_of() {
+    let region = Region { name: None };
+    let layout = |content| Layout::of(content, &region);
+
+    assert_eq!(layout("<!-- blazon --><!-- /blazon -->"), Layout::Inline);
+    assert_eq!(
+        layout("<!-- blazon -->[![a](a)](a) [![b](b)](b)<!-- /blazon -->"),
+        Layout::Inline
+    );
+    assert_eq!(layout("<!-- blazon -->\n<!-- /blazon -->"), Layout::Block);
+    assert_eq!(
+        layout("# x\n  <!-- blazon -->\n[![a](a)](a)\n<!-- /blazon -->"),
+        Layout::Block
+    );
+    // After other badges, even if an earlier version spread the badges over several lines
+    assert_eq!(
+        layout("[![ci](ci)](ci)<!-- blazon -->\n[![a](a)](a)<!-- /blazon -->"),
+        Layout::Inline
+    );
+}
+
+#[test]
+fn test_inline_region() {
+    let readme = "[![ci](ci.svg)](ci) <!-- blazon:deps,size --><!-- /blazon:deps,size -->\nText\n";
+
+    let patched = patch_readme(readme, &badges()).unwrap();
+    assert_eq!(
+        patched,
+        "[![ci](ci.svg)](ci) <!-- blazon:deps,size -->\
+[![Dependencies: 7](https://img.shields.io/badge/cargo%20tree-7-blue)](https://crates.io/crates/x) \
+[![Binary Size: 1.0K](https://img.shields.io/badge/build%20size-1.0K-green)](https://crates.io/crates/x)\
+<!-- /blazon:deps,size -->\nText\n"
+    );
+    assert_eq!(patch_readme(&patched, &badges()).unwrap(), patched);
+}
+
+#[test]
+fn test_block_region() {
+    let readme = "# x\n<!-- blazon:deps,size -->\nold\n<!-- /blazon:deps,size -->\nText\n";
+
+    let patched = patch_readme(readme, &badges()).unwrap();
+    assert_eq!(
+        patched,
+        "# x\n<!-- blazon:deps,size -->
+[![Dependencies: 7](https://img.shields.io/badge/cargo%20tree-7-blue)](https://crates.io/crates/x)
+[![Binary Size: 1.0K](https://img.shields.io/badge/build%20size-1.0K-green)](https://crates.io/crates/x)
+<!-- /blazon:deps,size -->\nText\n"
+    );
+    assert_eq!(patch_readme(&patched, &badges()).unwrap(), patched);
+}
+
+#[test]
+fn test_empty_block_region() {
+    let readme = "<!-- blazon:size -->\n<!-- /blazon:size -->\n";
+
+    let patched = patch_readme(readme, &badges()).unwrap();
+    assert!(patched.starts_with("<!-- blazon:size -->\n[![Binary Size"));
+    assert!(patched.ends_with(")\n<!-- /blazon:size -->\n"));
+}
+
+#[test]
+fn test_text_layout() {
+    assert_eq!(
+        patch_readme("a<!-- blazon -->old<!-- /blazon -->b", "one\ntwo\n").unwrap(),
+        "a<!-- blazon -->one two<!-- /blazon -->b"
+    );
+    assert_eq!(
+        patch_readme("<!-- blazon -->\n<!-- /blazon -->", "one\ntwo\n").unwrap(),
+        "<!-- blazon -->\none\ntwo\n<!-- /blazon -->"
+    );
+}
+
+#[test]
+fn test_inline_table_region() {
+    let error = patch_readme("<!-- blazon:table --><!-- /blazon:table -->", &badges()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Region '<!-- blazon:table -->' holds a table, so its markers must be on separate lines"
+    );
+}
+
+fn marker_error(content: &str) -> MarkerError {
+    check_markers(content).unwrap_err()
+}
+
+#[test]
+fn test_find_markers() {
+    let markers = find_markers("a<!-- blazon:size -->b<!-- /blazon:size --><!-- blazon x -->");
+    assert_eq!(markers.len(), 2);
+    assert_eq!(markers[0].region, named("size"));
+    assert!(!markers[0].end);
+    assert_eq!(markers[0].span, 1..21);
+    assert!(markers[1].end);
+    assert_eq!(markers[1].span, 22..43);
+}
+
+#[test]
+fn test_check_markers() {
+    let regions = check_markers(
+        "<!-- blazon --><!-- /blazon -->\n<!-- blazon:size -->\n<!-- /blazon:size -->",
+    )
+    .unwrap();
+    assert_eq!(
+        regions,
+        [(Region { name: None }, 0..15), (named("size"), 32..52)]
+    );
+}
+
+#[test]
+fn test_no_markers() {
+    let error = marker_error("# Title\n");
+    assert_eq!(error.kind, MarkerErrorKind::NoMarkers);
+    assert_eq!(error.to_string(), "No blazon markers found");
+    assert_eq!(
+        error.help().unwrap(),
+        "add <!-- blazon --><!-- /blazon --> where the badges should go"
+    );
+}
+
+#[test]
+fn test_missing_start_marker() {
+    let error = marker_error("# Title\n<!-- /blazon:size -->\n");
+    assert_eq!(error.kind, MarkerErrorKind::MissingStart);
+    assert_eq!(error.region, named("size"));
+    assert_eq!(error.span, 8..29);
+    assert_eq!(
+        error.to_string(),
+        "Missing start marker '<!-- blazon:size -->'"
+    );
+    assert_eq!(
+        error.help().unwrap(),
+        "add <!-- blazon:size --> before <!-- /blazon:size -->"
+    );
+}
+
+#[test]
+fn test_missing_end_marker() {
+    let error = marker_error("<!-- blazon -->\n<!-- blazon:size --><!-- /blazon:size -->");
+    assert_eq!(error.kind, MarkerErrorKind::MissingEnd);
+    assert_eq!(error.span, 0..15);
+    assert_eq!(error.to_string(), "Missing end marker '<!-- /blazon -->'");
+    assert_eq!(
+        error.help().unwrap(),
+        "add <!-- /blazon --> after <!-- blazon -->"
+    );
+}
+
+#[test]
+fn test_end_before_start_marker() {
+    let error = marker_error("<!-- /blazon -->\n<!-- blazon -->");
+    assert_eq!(error.kind, MarkerErrorKind::EndBeforeStart);
+    assert_eq!(error.span, 0..16);
+    assert_eq!(error.related, Some(17..32));
+    assert_eq!(
+        error.to_string(),
+        "End marker '<!-- /blazon -->' comes before its start marker"
+    );
+    assert_eq!(
+        error.help().unwrap(),
+        "move <!-- /blazon --> after <!-- blazon -->"
+    );
+}
+
+#[test]
+fn test_duplicate_marker() {
+    let error = marker_error("<!-- blazon --><!-- /blazon -->\n<!-- blazon --><!-- /blazon -->");
+    assert_eq!(error.kind, MarkerErrorKind::Duplicate { end: false });
+    assert_eq!(error.span, 32..47);
+    assert_eq!(error.related, Some(0..15));
+    assert_eq!(error.to_string(), "Duplicate marker '<!-- blazon -->'");
+
+    let error = marker_error("<!-- blazon --><!-- /blazon --><!-- /blazon -->");
+    assert_eq!(error.kind, MarkerErrorKind::Duplicate { end: true });
+    assert_eq!(error.to_string(), "Duplicate marker '<!-- /blazon -->'");
+}
+
+#[test]
+fn test_patch_readme_checks_markers() {
+    let error = patch_readme("<!-- blazon:size -->\nText", "NEW").unwrap_err();
+    assert_eq!(error.kind, MarkerErrorKind::MissingEnd);
+}
@@ -0,0 +1,135 @@
+use blazon_core::badge::{BadgeStyle, create_badge, create_styled_badge};
+use insta::assert_snapshot;
+
+/// Read the label and value section widths and the total width out of a rendered badge
+fn section_widths(svg: &str) -> (f64, f64, f64) {
+    let width_after = |from: usize| -> f64 {
+        let value = svg[from..].split("width=\"").nth(1).unwrap();
+        value[..value.find('"').unwrap()].parse().unwrap()
+    };
+    // Every style draws its sections in the first group, after any gradients and clip paths
+    let sections = svg.find("<g ").unwrap();
+    (
+        width_after(sections),
+        width_after(svg.find(r#"<rect x=""#).unwrap()),
+        width_after(0),
+    )
+}
+
+/// Section widths of flat img.shields.io badges for the same text
+///
+/// The flat-square and plastic styles share this geometry.
+const SHIELDS_FLAT: &[(&str, &str, (f64, f64, f64))] = &[
+    ("build", "passing", (37.0, 51.0, 88.0)),
+    ("cargo tree", "42", (67.0, 23.0, 90.0)),
+    ("build size", "1.3M", (61.0, 37.0, 98.0)),
+    ("target dir", "512.0M", (61.0, 51.0, 112.0)),
+    ("größe", "1,5 MiB", (41.0, 51.0, 92.0)),
+    ("日本語", "ラベル", (43.0, 43.0, 86.0)),
+    ("emoji", "🦀 ok", (41.0, 37.0, 78.0)),
+    ("WWW", "iii", (43.0, 19.0, 62.0)),
+];
+
+/// Section widths of for-the-badge img.shields.io badges, which are fractional
+const SHIELDS_FOR_THE_BADGE: &[(&str, &str, (f64, f64, f64))] = &[
+    ("build", "passing", (61.25, 83.75, 145.0)),
+    ("cargo tree", "42", (101.5, 40.5, 142.0)),
+    ("build size", "1.3M", (95.5, 56.0, 151.5)),
+    ("target dir", "512.0M", (98.5, 72.5, 171.0)),
+    ("größe", "1,5 MiB", (73.5, 75.75, 149.25)),
+    ("日本語", "ラベル", (57.75, 58.75, 116.5)),
+    ("emoji", "🦀 ok", (61.25, 60.25, 121.5)),
+    ("WWW", "iii", (56.75, 43.75, 100.5)),
+];
+
+/// Button and bubble widths of social img.shields.io badges
+const SHIELDS_SOCIAL: &[(&str, &str, (f64, f64, f64))] = &[
+    ("build", "passing", (37.0, 49.0, 93.0)),
+    ("cargo tree", "42", (67.0, 21.0, 95.0)),
+    ("build size", "1.3M", (61.0, 33.0, 101.0)),
+    ("target dir", "512.0M", (61.0, 45.0, 113.0)),
+    ("größe", "1,5 MiB", (43.0, 47.0, 97.0)),
+    ("日本語", "ラベル", (43.0, 37.0, 87.0)),
+    ("emoji", "🦀 ok", (39.0, 35.0, 81.0)),
+    ("WWW", "iii", (41.0, 17.0, 65.0)),
+];
+
+fn assert_layout(style: BadgeStyle, expected: &[(&str, &str, (f64, f64, f64))]) {
+    for &(label, value, widths) in expected {
+        let svg = create_styled_badge(label, value, "blue", style);
+        assert_eq!(section_widths(&svg), widths, "{style} {label}: {value}");
+    }
+}
+
+#[test]
+fn test_layout_matches_shields() {
+    assert_layout(BadgeStyle::Flat, SHIELDS_FLAT);
+    assert_layout(BadgeStyle::FlatSquare, SHIELDS_FLAT);
+    assert_layout(BadgeStyle::Plastic, SHIELDS_FLAT);
+    assert_layout(BadgeStyle::ForTheBadge, SHIELDS_FOR_THE_BADGE);
+    assert_layout(BadgeStyle::Social, SHIELDS_SOCIAL);
+}
+
+#[test]
+fn test_style_heights() {
+    let height = |style| {
+        let svg = create_styled_badge("build", "passing", "blue", style);
+        let value = svg.split("height=\"").nth(1).unwrap();
+        value[..value.find('"').unwrap()].to_string()
+    };
+    assert_eq!(height(BadgeStyle::Flat), "20");
+    assert_eq!(height(BadgeStyle::FlatSquare), "20");
+    assert_eq!(height(BadgeStyle::Plastic), "18");
+    assert_eq!(height(BadgeStyle::ForTheBadge), "28");
+    assert_eq!(height(BadgeStyle::Social), "20");
+}
+
+#[test]
+fn test_flat_badge_snapshot() {
+    assert_snapshot!(create_badge("build", "passing", "blue"));
+}
+
+#[test]
+fn test_unicode_badge_snapshot() {
+    assert_snapshot!(create_badge("größe", "1,5 MiB", "yellow"));
+}
+
+#[test]
+fn test_flat_square_badge_snapshot() {
+    assert_snapshot!(create_styled_badge(
+        "build",
+        "passing",
+        "blue",
+        BadgeStyle::FlatSquare
+    ));
+}
+
+#[test]
+fn test_plastic_badge_snapshot() {
+    assert_snapshot!(create_styled_badge(
+        "build",
+        "passing",
+        "blue",
+        BadgeStyle::Plastic
+    ));
+}
+
+#[test]
+fn test_for_the_badge_snapshot() {
+    assert_snapshot!(create_styled_badge(
+        "build",
+        "passing",
+        "blue",
+        BadgeStyle::ForTheBadge
+    ));
+}
+
+#[test]
+fn test_social_badge_snapshot() {
+    assert_snapshot!(create_styled_badge(
+        "build",
+        "passing",
+        "blue",
+        BadgeStyle::Social
+    ));
+}
@@ -0,0 +1,13 @@
+//! `cargo blazon`: blazon as a cargo subcommand
+//!
+//! Cargo runs `cargo-blazon blazon ARGS...` for `cargo blazon ARGS...`, and sets `CARGO` to
+//! itself, which blazon uses for every cargo command it runs.
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    // Also runnable directly as `cargo-blazon ARGS...`
+    if args.first().is_some_and(|arg| arg == "blazon") {
+        args.remove(0);
+    }
+    std::process::exit(blazon::cli::run(args));
+}
@@ -1,85 +1,857 @@
-#[cfg(feature = "cli")]
-pub mod cli {
-    pub mod args;
-    pub mod report;
-
-    use args::{Args, print_usage};
-    use blazon_core::{collect_metrics, generate_badges, get_binary_name, update_readme};
-    use std::io;
-
-    pub fn main() -> io::Result<()> {
-        // Install miette handler for nice error displays
-        if let Err(e) = report::install_handler() {
-            eprintln!("Warning: Failed to install error handler: {}", e);
-        }
-
-        let args: Args = facet_args::from_std_args().map_err(|e| {
-            eprintln!("{}", report::DiagnosticDisplay(&e));
-            io::Error::new(io::ErrorKind::InvalidInput, "Argument parsing failed")
-        })?;
-
-        if args.help {
-            print_usage();
-            std::process::exit(0);
-        }
-
-        let binary_name = match &args.binary {
-            Some(name) => name.clone(),
-            None => get_binary_name().unwrap_or_else(|e| {
-                eprintln!("Error: {}", e);
-                eprintln!("Please specify --binary NAME");
-                std::process::exit(1);
-            }),
-        };
+//! The blazon command line: argument parsing, help, diagnostics and the commands themselves
+//!
+//! [`run`] is shared by the `blazon` and `cargo-blazon` binaries, which only differ in how
+//! they receive their arguments.
 
-        if args.verbose {
-            eprintln!("Binary: {}", binary_name);
-            eprintln!("README: {}", args.readme);
-            eprintln!();
+pub mod args;
+mod diff;
+mod help;
+pub mod parse;
+pub mod report;
+
+use args::{BadgeArgs, Command, InitArgs, Invocation, MetricsArgs, SyncArgs, WriteArgs};
+use blazon_core::badge::Badge;
+use blazon_core::budget::Budgets;
+use blazon_core::config::{CONFIG_FILE, Config};
+use blazon_core::deps::DepOptions;
+use blazon_core::diff::diff_readme;
+use blazon_core::file::{relative_to_cwd, write_atomic};
+use blazon_core::metadata::Metadata;
+use blazon_core::region::MarkerErrorKind;
+use blazon_core::{
+    Artifact, BadgeOptions, BlazonError, BuildOptions, Metrics, ParseError, WriteStatus,
+    collect_library_metrics, collect_metrics, format_size, insert_readme_markers, parse_size,
+    render_badges, render_svg_badges, update_readme,
+};
+use facet::Facet;
+use parse::ArgsError;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Exit status when the README is out of date (`check`) or a metric is over budget
+pub const EXIT_FAILED: i32 = 1;
+/// Exit status when files were written and `--exit-code` asks to tell, distinct from success
+/// with nothing to do (0)
+pub const EXIT_UPDATED: i32 = 2;
+/// Exit status for invalid arguments
+pub const EXIT_USAGE: i32 = 3;
+/// Exit status when the README's markers are missing or malformed
+pub const EXIT_MARKERS: i32 = 4;
+/// Exit status when cargo is missing, fails, or prints output blazon cannot read
+pub const EXIT_CARGO: i32 = 5;
+/// Exit status when there is no binary to measure, or it has not been built
+pub const EXIT_BINARY: i32 = 6;
+/// Exit status when a file cannot be read or written
+pub const EXIT_IO: i32 = 7;
+
+/// The README updated when neither the arguments nor the config name one
+const DEFAULT_README: &str = "README.md";
+
+/// The starter config `blazon init` writes, every setting commented out at its default
+const CONFIG_TEMPLATE: &str = r#"# blazon configuration: uncomment a setting to override its default
+
+# READMEs to update
+# readmes = ["README.md"]
+
+# Binary to measure, and the crate the badges link to
+# binary = "my-binary"
+# crate = "my-crate"
+
+# Badges to show, in order
+# metrics = ["deps", "size", "target-dir"]
+
+# Badge style: flat, flat-square, plastic, for-the-badge or social
+# style = "flat"
+
+# [labels]
+# deps = "dependencies"
+
+# [colors]
+# deps = "blue"
+# size = "green<2M,yellow<5M,red"
+# target-dir = "orange"
+
+# [budgets]
+# deps = 100
+# size = "2M"
+# target-dir = "10G"
+
+# How dependencies are counted
+# [deps]
+# count = "names"
+# depth = "all"
+# build = false
+# dev = false
+# proc-macros = true
+# workspace = true
+"#;
+
+/// Run blazon with `std_args`, the arguments after the program name, returning its exit status
+pub fn run(std_args: Vec<String>) -> i32 {
+    // Install miette handler for nice error displays
+    if let Err(e) = report::install_handler() {
+        eprintln!("Warning: Failed to install error handler: {}", e);
+    }
+
+    let std_args: Vec<&str> = std_args.iter().map(String::as_str).collect();
+
+    let command = match Command::parse(&std_args) {
+        Ok(Invocation::Run(command)) => command,
+        Ok(Invocation::Help) => {
+            print!(
+                "{}",
+                help::overview("blazon", Command::SHAPE, args::EXIT_STATUS)
+            );
+            return 0;
+        }
+        Ok(Invocation::CommandHelp(name)) => {
+            let footer = match name.as_str() {
+                "update" | "check" => args::COLOUR_SCALES,
+                _ => "",
+            };
+            print!("{}", help::command("blazon", Command::SHAPE, &name, footer));
+            return 0;
         }
+        Err(e) => return Error::Args(e).report(),
+    };
 
-        let metrics = collect_metrics(&binary_name, !args.no_build).unwrap_or_else(|e| {
-            eprintln!("Error: {}", e);
-            std::process::exit(1);
-        });
+    let exit_code = command.exit_code();
+    let result = match *command {
+        Command::Update(args) => update(args.sync, args.write),
+        Command::Check(args) => {
+            let write = WriteArgs {
+                check: true,
+                ..WriteArgs::default()
+            };
+            update(args.sync, write)
+        }
+        Command::Metrics(args) => metrics(args),
+        Command::Badge(args) => badge(args),
+        Command::Init(args) => init(args),
+    };
+    match result {
+        Ok(WriteStatus::Updated) if exit_code => EXIT_UPDATED,
+        Ok(_) => 0,
+        Err(error) => error.report(),
+    }
+}
 
-        if args.verbose {
-            eprintln!("Dependencies: {}", metrics.dep_count);
-            eprintln!("Binary size: {} bytes", metrics.binary_size_bytes);
-            eprintln!();
+/// Why a command failed, each reported with its own exit status
+enum Error {
+    /// Arguments that do not match the options of their command
+    Args(ArgsError),
+    /// Arguments that do not make sense, alone or together
+    Usage(String),
+    /// A failure of blazon-core
+    Blazon(BlazonError),
+    /// The current directory could not be read
+    Io(io::Error),
+    /// The README is out of date (`check`) or a metric is over budget, as already reported
+    Failed,
+}
+
+impl Error {
+    /// Report the error, unless it already was, and return its exit status
+    fn report(self) -> i32 {
+        match self {
+            Error::Args(error) => {
+                eprintln!("{}", report::DiagnosticDisplay(&error));
+                EXIT_USAGE
+            }
+            Error::Usage(message) => {
+                eprintln!("Error: {}", message);
+                EXIT_USAGE
+            }
+            Error::Blazon(error) => {
+                eprintln!("{}", report::DiagnosticDisplay(&report::Failure(&error)));
+                exit_code(&error)
+            }
+            Error::Io(error) => {
+                eprintln!("Error: {}", error);
+                EXIT_IO
+            }
+            Error::Failed => EXIT_FAILED,
         }
+    }
+}
+
+impl From<BlazonError> for Error {
+    fn from(error: BlazonError) -> Self {
+        Error::Blazon(error)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Usage(error.to_string())
+    }
+}
 
-        let crate_name = match &args.crate_name {
-            Some(name) => name.clone(),
-            None => binary_name.clone(), // Use binary name as fallback
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+/// `blazon update`: measure, then update the READMEs and SVG badges, or check them
+fn update(args: SyncArgs, write: WriteArgs) -> Result<WriteStatus, Error> {
+    if write.insert && (write.check || write.dry_run) {
+        return Err(usage_error(
+            "--insert writes the README, so it cannot be used with --check or --dry-run",
+        ));
+    }
+    if args.workspace {
+        let conflicts = [
+            ("a README argument", args.readme.is_some()),
+            ("--package", args.build.package.is_some()),
+            ("--binary", args.build.binary.is_some()),
+            ("--crate-name", args.crate_name.is_some()),
+        ];
+        if let Some((conflict, _)) = conflicts.iter().find(|(_, given)| *given) {
+            return Err(usage_error(format!(
+                "--workspace measures every member, so it cannot be used with {}",
+                conflict
+            )));
+        }
+    }
+
+    // Flags are read before cargo runs, so an invalid one fails fast
+    let parse_budget = |arg: &Option<String>| arg.as_deref().map(parse_size).transpose();
+    let colors = [
+        ("deps", parse_flag(&args.deps_color)?),
+        ("size", parse_flag(&args.size_color)?),
+        ("target-dir", parse_flag(&args.target_dir_color)?),
+    ];
+    let flags = Config {
+        readmes: args
+            .readme
+            .as_ref()
+            .map(|readme| vec![PathBuf::from(readme)]),
+        binary: args.build.binary.clone(),
+        crate_name: args.crate_name.clone(),
+        style: parse_flag(&args.style)?,
+        colors: colors
+            .into_iter()
+            .filter_map(|(id, scale)| Some((id.to_string(), scale?)))
+            .collect(),
+        budgets: Budgets {
+            deps: args.max_deps,
+            binary_size: parse_budget(&args.max_size)?,
+            target_dir: parse_budget(&args.max_target_dir)?,
+        },
+        deps: args.deps.dep_settings()?,
+        ..Config::default()
+    };
+
+    let project = Project::load(&args.build.manifest_path, &args.build.package)?;
+    if args.workspace {
+        return update_workspace(&args, &write, flags, &project);
+    }
+    let config = flags.or(project.config()?);
+    let dep_options = config.deps.options();
+    let mut badge_options = config.badge_options();
+    badge_options.labels.deps = dep_options.qualify(&badge_options.labels.deps);
+    let binary_name = project.binary(&config.binary)?;
+    let readmes: Vec<String> = match &config.readmes {
+        Some(readmes) => readmes.iter().map(|r| r.display().to_string()).collect(),
+        None => vec![
+            relative_to_cwd(&project.dir.join(DEFAULT_README))
+                .display()
+                .to_string(),
+        ],
+    };
+
+    let crate_name = match &config.crate_name {
+        Some(name) => name.clone(),
+        None => {
+            let name = project
+                .metadata
+                .infer_crate_name(&binary_name, &project.dir)
+                .unwrap_or(&binary_name)
+                .to_string();
+            if name != binary_name {
+                eprintln!(
+                    "Warning: binary '{}' belongs to package '{}', linking badges to crates.io/crates/{} (use --crate-name to change)",
+                    binary_name, name, name
+                );
+            }
+            name
+        }
+    };
+
+    if args.verbose {
+        eprintln!("Binary: {}", binary_name);
+        eprintln!("Crate: {}", crate_name);
+        eprintln!("README: {}", readmes.join(", "));
+        eprintln!();
+    }
+
+    let build_options = project.build_options(
+        &binary_name,
+        &args.build.profile,
+        &args.build.target,
+        dep_options,
+    );
+    let metrics = collect_metrics(&binary_name, !args.build.no_build, &build_options)?;
+
+    if args.verbose {
+        eprintln!("Dependencies: {}", metrics.dep_count);
+        eprintln!("Binary size: {} bytes", metrics.binary_size_bytes);
+        eprintln!(
+            "Target dir: {} bytes (debug: {}, release: {}, doc: {})",
+            metrics.target_dir_bytes,
+            metrics.target_dir_profiles.debug,
+            metrics.target_dir_profiles.release,
+            metrics.target_dir_profiles.doc
+        );
+        eprintln!();
+    }
+
+    let mut run = Run::default();
+    let svg_dir = args.svg_dir.as_ref().map(PathBuf::from);
+    for readme in &readmes {
+        let badges = Measured {
+            metrics: &metrics,
+            crate_name: &crate_name,
+            options: &badge_options,
         };
+        sync_readme(&args, &write, readme, svg_dir.as_deref(), &badges, &mut run)?;
+    }
 
-        let badges = generate_badges(&metrics, &crate_name);
+    // Checked after writing, so the READMEs show the numbers that broke the budget
+    check_budgets(&config.budgets, &metrics)?;
+    run.finish()
+}
+
+/// `blazon update --workspace`: measure each member and update its own READMEs
+///
+/// A member's READMEs are those of its own config, else the `readme` of its Cargo.toml,
+/// else its README.md. Members without a binary are measured by their library's rlib, and its
+/// badge is labelled as a library size unless the config sets the label.
+fn update_workspace(
+    args: &SyncArgs,
+    write: &WriteArgs,
+    flags: Config,
+    project: &Project,
+) -> Result<WriteStatus, Error> {
+    let mut run = Run::default();
+    let mut rows = Vec::new();
+    let mut over_budget = Vec::new();
+
+    for package in project.metadata.members() {
+        let config = flags
+            .clone()
+            .or(Config::load_package(&project.metadata, package)?);
+        let dep_options = config.deps.options();
+        let mut badge_options = config.badge_options();
+        badge_options.labels.deps = dep_options.qualify(&badge_options.labels.deps);
 
+        let binary = match &config.binary {
+            Some(name) => Some(name.clone()),
+            None => match package.main_binary() {
+                Ok(binary) => Some(binary.target.name.clone()),
+                Err(BlazonError::NoBinary) => None,
+                Err(e) => return Err(e.into()),
+            },
+        };
+        let (artifact, metrics) = match (&binary, package.library()) {
+            (Some(name), _) => {
+                let mut build_options = project.build_options(
+                    name,
+                    &args.build.profile,
+                    &args.build.target,
+                    dep_options.clone(),
+                );
+                build_options.package = Some(package.name.clone());
+                (
+                    name.clone(),
+                    collect_metrics(name, !args.build.no_build, &build_options),
+                )
+            }
+            (None, Some(library)) => {
+                badge_options.artifact = Artifact::Library;
+                if !config.labels.contains_key("size") {
+                    badge_options.labels.size = Artifact::Library.size_label().to_string();
+                }
+                let mut build_options = project.build_options(
+                    &library.name,
+                    &args.build.profile,
+                    &args.build.target,
+                    dep_options.clone(),
+                );
+                build_options.package = Some(package.name.clone());
+                let metrics =
+                    collect_library_metrics(&library.name, !args.build.no_build, &build_options);
+                (format!("lib{}.rlib", library.name), metrics)
+            }
+            (None, None) => {
+                eprintln!("Skipping {}: no binary or library to measure", package.name);
+                continue;
+            }
+        };
+        let metrics = metrics?;
+        let crate_name = config.crate_name.as_ref().unwrap_or(&package.name);
+
+        let readmes = match &config.readmes {
+            Some(readmes) => readmes.clone(),
+            None => vec![relative_to_cwd(
+                &package.dir().join(
+                    package
+                        .readme
+                        .as_deref()
+                        .unwrap_or(Path::new(DEFAULT_README)),
+                ),
+            )],
+        };
         if args.verbose {
-            eprintln!("Generated badges:");
-            eprintln!("{}", badges);
+            eprintln!("Package: {}", package.name);
+            eprintln!("Artifact: {}", artifact);
+            eprintln!("Crate: {}", crate_name);
+            eprintln!("Dependencies: {}", metrics.dep_count);
+            eprintln!("Size: {} bytes", metrics.binary_size_bytes);
             eprintln!();
         }
 
-        eprintln!("Updating {}...", args.readme);
-        update_readme(&args.readme, &badges).unwrap_or_else(|e| {
-            eprintln!("Error: {}", e);
-            std::process::exit(1);
-        });
+        let svg_dir = args
+            .svg_dir
+            .as_ref()
+            .map(|dir| Path::new(dir).join(&package.name));
+        for readme in &readmes {
+            let readme = readme.display().to_string();
+            let status = if !Path::new(&readme).exists() {
+                "no README"
+            } else {
+                let badges = Measured {
+                    metrics: &metrics,
+                    crate_name,
+                    options: &badge_options,
+                };
+                match sync_readme(args, write, &readme, svg_dir.as_deref(), &badges, &mut run) {
+                    Ok(status) => status,
+                    Err(e)
+                        if e.marker_error()
+                            .is_some_and(|e| e.kind == MarkerErrorKind::NoMarkers) =>
+                    {
+                        "no markers"
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            };
+            rows.push([
+                package.name.clone(),
+                artifact.clone(),
+                metrics.dep_count.to_string(),
+                format_size(metrics.binary_size_bytes),
+                readme,
+                status.to_string(),
+            ]);
+        }
 
-        eprintln!("✓ Successfully updated {}", args.readme);
-        Ok(())
+        let violations = config.budgets.check(&metrics);
+        if !violations.is_empty() {
+            over_budget.push((package.name.clone(), violations));
+        }
+    }
+
+    print_summary(&rows);
+    for (package, violations) in &over_budget {
+        eprintln!("In {}:", package);
+        eprintln!(
+            "{}",
+            report::DiagnosticDisplay(&report::BudgetExceeded::new(violations.clone()))
+        );
+    }
+    if !over_budget.is_empty() {
+        return Err(Error::Failed);
     }
+    run.finish()
+}
+
+/// The measurements a README's badges are rendered from
+struct Measured<'a> {
+    metrics: &'a Metrics,
+    crate_name: &'a str,
+    options: &'a BadgeOptions,
+}
+
+/// What an update or check found and wrote across the READMEs it visited
+#[derive(Default)]
+struct Run {
+    /// Whether a README or SVG badge was out of date, when checking
+    out_of_date: bool,
+    /// Whether any file was written
+    updated: bool,
+    /// SVG badges already reported stale, as READMEs can share them
+    reported: Vec<PathBuf>,
 }
 
-#[cfg(not(feature = "cli"))]
-pub mod cli {
-    pub fn main() {
-        eprintln!("Please build with the cli feature");
-        eprintln!("Example: cargo install blazon --features cli");
-        std::process::exit(1);
+impl Run {
+    /// Whether files were written, or an error if any were out of date
+    fn finish(self) -> Result<WriteStatus, Error> {
+        if self.out_of_date {
+            eprintln!("Run blazon update to update");
+            return Err(Error::Failed);
+        }
+        Ok(match self.updated {
+            true => WriteStatus::Updated,
+            false => WriteStatus::Unchanged,
+        })
+    }
+}
+
+/// Update, check or dry-run one README and its SVG badges in `svg_dir`
+///
+/// Returns the README's status as shown in the workspace summary.
+fn sync_readme(
+    args: &SyncArgs,
+    write: &WriteArgs,
+    readme: &str,
+    svg_dir: Option<&Path>,
+    measured: &Measured,
+    run: &mut Run,
+) -> Result<&'static str, BlazonError> {
+    let svg_badges = match svg_dir {
+        Some(svg_dir) => Some(render_svg_badges(
+            measured.metrics,
+            measured.crate_name,
+            svg_dir,
+            Path::new(readme),
+            measured.options,
+        )?),
+        None => None,
+    };
+    let badges = match &svg_badges {
+        Some(svg_badges) => svg_badges.badges.clone(),
+        None => render_badges(measured.metrics, measured.crate_name, measured.options),
+    };
+
+    if args.verbose {
+        eprintln!("Generated badges:");
+        eprintln!("{}", badges.markdown());
+        eprintln!();
+    }
+
+    if write.check || write.dry_run {
+        let readme_diff = diff_readme(readme, &badges)?;
+        diff::print(&readme_diff.unified(readme));
+        let stale_files: Vec<&Path> = svg_badges
+            .iter()
+            .flat_map(|b| b.stale_files())
+            .filter(|path| !run.reported.iter().any(|seen| seen == path))
+            .collect();
+        run.reported
+            .extend(stale_files.iter().map(|path| path.to_path_buf()));
+
+        if write.dry_run {
+            for path in &stale_files {
+                eprintln!("Would write {}", path.display());
+            }
+            if readme_diff.is_unchanged() {
+                eprintln!("{} would be unchanged", readme);
+                return Ok("unchanged");
+            }
+            eprintln!("Would update {} (dry run, nothing written)", readme);
+            return Ok("would update");
+        }
+
+        let mut current = true;
+        if !readme_diff.is_unchanged() {
+            eprintln!("✗ {} is out of date", readme);
+            current = false;
+        }
+        for path in &stale_files {
+            eprintln!("✗ {} is out of date", path.display());
+            current = false;
+        }
+        if current {
+            eprintln!("✓ {} is up to date", readme);
+            return Ok("up to date");
+        }
+        run.out_of_date = true;
+        return Ok("out of date");
+    }
+
+    let mut status = WriteStatus::Unchanged;
+    if let Some(svg_badges) = &svg_badges {
+        for path in &svg_badges.write()? {
+            eprintln!("Wrote {}", path.display());
+            status = WriteStatus::Updated;
+        }
+    }
+
+    if write.insert && insert_markers(readme)? == WriteStatus::Updated {
+        status = WriteStatus::Updated;
+    }
+
+    match update_readme(readme, &badges)? {
+        WriteStatus::Unchanged => eprintln!("✓ {} is unchanged", readme),
+        WriteStatus::Updated => {
+            eprintln!("✓ Updated {}", readme);
+            status = WriteStatus::Updated;
+        }
+    }
+    if status == WriteStatus::Updated {
+        run.updated = true;
+    }
+    Ok(status.as_str())
+}
+
+/// Print the workspace summary: one row per member README
+fn print_summary(rows: &[[String; 6]]) {
+    let header = ["Package", "Artifact", "Deps", "Size", "README", "Status"].map(String::from);
+    let mut widths = [0; 6];
+    for row in core::iter::once(&header).chain(rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in core::iter::once(&header).chain(rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:width$}", cell))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    }
+}
+
+/// `blazon metrics`: measure and print the metrics to stdout
+fn metrics(args: MetricsArgs) -> Result<WriteStatus, Error> {
+    let flags = Config {
+        binary: args.build.binary.clone(),
+        deps: args.deps.dep_settings()?,
+        ..Config::default()
+    };
+    let project = Project::load(&args.build.manifest_path, &args.build.package)?;
+    let config = flags.or(project.config()?);
+    let binary_name = project.binary(&config.binary)?;
+    let build_options = project.build_options(
+        &binary_name,
+        &args.build.profile,
+        &args.build.target,
+        config.deps.options(),
+    );
+    let metrics = collect_metrics(&binary_name, !args.build.no_build, &build_options)?;
+
+    if args.json {
+        println!("{}", facet_json::to_string(&metrics));
+        return Ok(WriteStatus::Unchanged);
+    }
+    let profiles = &metrics.target_dir_profiles;
+    println!("Dependencies: {}", metrics.dep_count);
+    println!(
+        "Binary size:  {} ({} bytes)",
+        format_size(metrics.binary_size_bytes),
+        metrics.binary_size_bytes
+    );
+    println!(
+        "Target dir:   {} (debug: {}, release: {}, doc: {})",
+        format_size(metrics.target_dir_bytes),
+        format_size(profiles.debug),
+        format_size(profiles.release),
+        format_size(profiles.doc)
+    );
+    Ok(WriteStatus::Unchanged)
+}
+
+/// `blazon badge`: render one badge as SVG or an img.shields.io URL
+fn badge(args: BadgeArgs) -> Result<WriteStatus, Error> {
+    let style = parse_flag(&args.style)?.unwrap_or_default();
+    let (Some(label), Some(value)) = (args.label, args.value) else {
+        return Err(usage_error("badge needs both --label and --value"));
+    };
+    let badge = Badge {
+        id: "badge".to_string(),
+        alt: format!("{}: {}", label, value),
+        label,
+        value,
+        color: args.color,
+        style,
+    };
+
+    if args.url {
+        if args.output.is_some() {
+            return Err(usage_error(
+                "--url prints the URL, so it cannot be used with --output",
+            ));
+        }
+        println!("{}", badge.shields_url());
+        return Ok(WriteStatus::Unchanged);
+    }
+    let svg = badge.to_svg();
+    let Some(output) = &args.output else {
+        println!("{}", svg);
+        return Ok(WriteStatus::Unchanged);
+    };
+    if std::fs::read_to_string(output).is_ok_and(|existing| existing == svg) {
+        eprintln!("✓ {} is unchanged", output);
+        return Ok(WriteStatus::Unchanged);
     }
+    write_atomic(Path::new(output), svg.as_bytes())?;
+    eprintln!("Wrote {}", output);
+    Ok(WriteStatus::Updated)
 }
 
-pub use cli::main;
+/// `blazon init`: add markers to the README and write a starter config
+fn init(args: InitArgs) -> Result<WriteStatus, Error> {
+    let mut status = insert_markers(&args.readme)?;
+    if !args.no_config {
+        let config = Path::new(CONFIG_FILE);
+        if config.exists() {
+            eprintln!("✓ {} already exists", CONFIG_FILE);
+        } else {
+            write_atomic(config, CONFIG_TEMPLATE.as_bytes())?;
+            eprintln!("✓ Wrote {}", CONFIG_FILE);
+            status = WriteStatus::Updated;
+        }
+    }
+    Ok(status)
+}
+
+/// The package or workspace to measure
+struct Project {
+    metadata: Metadata,
+    /// Where the binary and crate name are inferred from: the package's directory with
+    /// `--package`, the manifest's with `--manifest-path`, otherwise the current directory
+    dir: PathBuf,
+    manifest_path: Option<PathBuf>,
+    package: Option<String>,
+}
+
+impl Project {
+    /// Load the workspace of `manifest_path`, or of the current directory
+    fn load(manifest_path: &Option<String>, package: &Option<String>) -> Result<Self, Error> {
+        let manifest_path = manifest_path.as_ref().map(PathBuf::from);
+        let metadata = Metadata::load_manifest(manifest_path.as_deref())?;
+        let dir = match (package, &manifest_path) {
+            (Some(name), _) => match metadata.find_package(name) {
+                Some(package) => package.manifest_path.parent().unwrap().to_path_buf(),
+                None => {
+                    return Err(usage_error(format!(
+                        "No package '{}' in the workspace",
+                        name
+                    )));
+                }
+            },
+            (None, Some(manifest_path)) => match manifest_path.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+                _ => std::env::current_dir()?,
+            },
+            (None, None) => std::env::current_dir()?,
+        };
+        Ok(Self {
+            metadata,
+            dir,
+            manifest_path,
+            package: package.clone(),
+        })
+    }
+
+    /// The merged config of the package or workspace
+    ///
+    /// With `--package`, the workspace's `readmes`, `binary` and `crate` do not apply, as they
+    /// name the package the workspace is measured as rather than the one selected.
+    fn config(&self) -> Result<Config, BlazonError> {
+        match self
+            .package
+            .as_deref()
+            .and_then(|name| self.metadata.find_package(name))
+        {
+            Some(package) => Config::load_package(&self.metadata, package),
+            None => Config::load(&self.metadata, &self.dir),
+        }
+    }
+
+    /// The binary to measure: `binary` if given, otherwise the one inferred from `dir`
+    fn binary(&self, binary: &Option<String>) -> Result<String, BlazonError> {
+        match binary {
+            Some(name) => Ok(name.clone()),
+            None => self
+                .metadata
+                .infer_binary(&self.dir)
+                .map(|b| b.target.name.clone()),
+        }
+    }
+
+    /// How to build `binary_name`, enabling any features it requires
+    ///
+    /// Without `--package`, the package owning the binary is built and its dependencies
+    /// counted, rather than every member of the workspace.
+    fn build_options(
+        &self,
+        binary_name: &str,
+        profile: &Option<String>,
+        target: &Option<String>,
+        deps: DepOptions,
+    ) -> BuildOptions {
+        let mut build_options = BuildOptions::default();
+        if let Some(profile) = profile {
+            build_options.profile = profile.clone();
+        }
+        build_options.target = target.clone();
+        build_options.manifest_path = self.manifest_path.clone();
+        build_options.package = self.package.clone();
+        build_options.deps = deps;
+        if let Some(binary) = self.metadata.find_binary(binary_name) {
+            build_options.features = binary.missing_features();
+            if build_options.package.is_none() {
+                build_options.package = Some(binary.package.name.clone());
+            }
+        }
+        build_options
+    }
+}
+
+/// Add markers to a README without any, reporting where they went
+fn insert_markers(readme: &str) -> Result<WriteStatus, BlazonError> {
+    Ok(match insert_readme_markers(readme)? {
+        Some(insertion) => {
+            eprintln!("✓ Inserted markers into {} {}", readme, insertion);
+            WriteStatus::Updated
+        }
+        None => {
+            eprintln!("✓ {} already has markers", readme);
+            WriteStatus::Unchanged
+        }
+    })
+}
+
+/// The exit status for `error`
+pub fn exit_code(error: &BlazonError) -> i32 {
+    match error {
+        BlazonError::CargoNotFound { .. }
+        | BlazonError::CargoFailed { .. }
+        | BlazonError::CargoOutput { .. } => EXIT_CARGO,
+        BlazonError::NoBinary
+        | BlazonError::AmbiguousBinary { .. }
+        | BlazonError::BinaryNotBuilt { .. } => EXIT_BINARY,
+        BlazonError::Io { .. } => EXIT_IO,
+        BlazonError::Markers(_) => EXIT_MARKERS,
+        BlazonError::Config(_) => EXIT_USAGE,
+    }
+}
+
+/// An error for arguments that do not make sense
+fn usage_error(message: impl Into<String>) -> Error {
+    Error::Usage(message.into())
+}
+
+/// The value of a flag, if it was given
+fn parse_flag<T: core::str::FromStr<Err = ParseError>>(
+    arg: &Option<String>,
+) -> Result<Option<T>, ParseError> {
+    arg.as_deref().map(str::parse).transpose()
+}
+
+/// Report metrics over budget, failing if there are any
+fn check_budgets(budgets: &Budgets, metrics: &Metrics) -> Result<(), Error> {
+    let violations = budgets.check(metrics);
+    if !violations.is_empty() {
+        eprintln!(
+            "{}",
+            report::DiagnosticDisplay(&report::BudgetExceeded::new(violations))
+        );
+        return Err(Error::Failed);
+    }
+    Ok(())
+}
@@ -1,15 +1,200 @@
+//! The commands and options blazon accepts, as facet shapes the parser and help both read
+
+use super::parse::{self, ArgsError};
+use super::parse_flag;
+use blazon_core::ParseError;
+use blazon_core::config::DepSettings;
 use facet::Facet;
 
+/// Generate and update badge metadata in README files
 #[derive(Facet)]
-pub struct Args {
-    /// Path to README file to update
-    #[facet(positional, default = "README.md".to_string())]
-    pub readme: String,
+#[repr(u8)]
+pub enum Command {
+    /// Measure the project and update the README's badges (the default)
+    Update(UpdateArgs),
+    /// Verify the README and SVG badges are up to date, without writing
+    Check(CheckArgs),
+    /// Measure the project and print the metrics
+    Metrics(MetricsArgs),
+    /// Render a single badge from a label and value
+    Badge(BadgeArgs),
+    /// Add markers to the README and write a starter blazon.toml
+    Init(InitArgs),
+}
+
+/// What to do, parsed from the command line
+pub enum Invocation {
+    /// Run a command
+    Run(Box<Command>),
+    /// Print the overview of all commands
+    Help,
+    /// Print the help of the command with this name
+    CommandHelp(String),
+}
+
+impl Command {
+    /// The names of the commands, as typed on the command line
+    pub fn names() -> Vec<String> {
+        parse::variants(Self::SHAPE)
+            .iter()
+            .map(parse::command_name)
+            .collect()
+    }
+
+    /// Parse arguments without the program name
+    ///
+    /// A first argument naming a command selects it, otherwise the arguments are `update`'s, so
+    /// `blazon [OPTIONS] [README]` keeps working.
+    pub fn parse(args: &[&str]) -> Result<Invocation, ArgsError> {
+        let names = Self::names();
+        let named = |arg: &str| names.iter().find(|name| *name == arg).cloned();
+        let (name, rest) = match args.split_first() {
+            Some((&"-h" | &"--help", _)) => return Ok(Invocation::Help),
+            Some((&"help", [])) => return Ok(Invocation::Help),
+            Some((&"help", [command, ..])) => {
+                return Ok(match named(command) {
+                    Some(name) => Invocation::CommandHelp(name),
+                    None => Invocation::Help,
+                });
+            }
+            Some((first, rest)) => match named(first) {
+                Some(name) => (name, rest),
+                None => ("update".to_string(), args),
+            },
+            None => ("update".to_string(), args),
+        };
+        if rest.iter().any(|arg| matches!(*arg, "-h" | "--help")) {
+            return Ok(Invocation::CommandHelp(name));
+        }
+
+        let command = parse::parse_command(&name, rest).expect("commands are named by variant")?;
+        Ok(Invocation::Run(Box::new(command)))
+    }
+
+    /// Whether writing files should exit with status 2, as `--exit-code` asks
+    pub fn exit_code(&self) -> bool {
+        match self {
+            Command::Update(args) => args.write.exit_code,
+            Command::Badge(args) => args.exit_code,
+            Command::Init(args) => args.exit_code,
+            Command::Check(_) | Command::Metrics(_) => false,
+        }
+    }
+}
+
+/// Measure the project and update the README's badges
+#[derive(Facet)]
+pub struct UpdateArgs {
+    /// The options shared with `check`
+    #[facet(flatten)]
+    pub sync: SyncArgs,
+
+    /// How to write
+    #[facet(flatten)]
+    pub write: WriteArgs,
+}
+
+/// How `update` writes, beyond what `check` shares with it
+#[derive(Facet, Default)]
+pub struct WriteArgs {
+    /// Verify the README is up to date without writing it, as `blazon check` does
+    #[facet(named, long, default)]
+    pub check: bool,
+
+    /// Print the changes as a diff without writing anything
+    #[facet(named, long, default)]
+    pub dry_run: bool,
+
+    /// Add markers after the badge row or first heading if the README has none
+    #[facet(named, long, default)]
+    pub insert: bool,
 
-    /// Crate name for crates.io link (default: infer from Cargo.toml)
+    /// Exit with status 2 if any file was written
+    #[facet(named, long, default)]
+    pub exit_code: bool,
+}
+
+/// Verify the README and SVG badges are up to date, without writing
+///
+/// Prints a diff of any changes `blazon update` would make, and fails if there are some.
+#[derive(Facet)]
+pub struct CheckArgs {
+    /// The options shared with `update`
+    #[facet(flatten)]
+    pub sync: SyncArgs,
+}
+
+/// The options of `update` and `check`: what to measure and how to render its badges
+#[derive(Facet)]
+pub struct SyncArgs {
+    /// Path to README file (default: the config's readmes, or the README.md of the package)
+    #[facet(positional, default)]
+    pub readme: Option<String>,
+
+    /// Crate name for crates.io link (default: package that owns the binary)
     #[facet(named, short = 'c', long, default)]
     pub crate_name: Option<String>,
 
+    /// Measure every workspace member and sync its own README, then print a summary
+    #[facet(named, long, default)]
+    pub workspace: bool,
+
+    /// What to build and measure
+    #[facet(flatten)]
+    pub build: BuildArgs,
+
+    /// How to count dependencies
+    #[facet(flatten)]
+    pub deps: DepArgs,
+
+    /// Write SVG badges to this directory and link them instead of img.shields.io
+    #[facet(named, long, default)]
+    pub svg_dir: Option<String>,
+
+    /// Badge style: flat, flat-square, plastic, for-the-badge or social (default: flat)
+    #[facet(named, long, default)]
+    pub style: Option<String>,
+
+    /// Colour scale of the dependency badge, e.g. `green<100,red` (default: blue)
+    #[facet(named, long, default)]
+    pub deps_color: Option<String>,
+
+    /// Colour scale of the binary size badge, e.g. `green<2M,yellow<5M,red` (default: green)
+    #[facet(named, long, default)]
+    pub size_color: Option<String>,
+
+    /// Colour scale of the target dir badge, e.g. `green@1G..red@10G` (default: orange)
+    #[facet(named, long, default)]
+    pub target_dir_color: Option<String>,
+
+    /// Fail if the dependency count exceeds this
+    #[facet(named, long, default)]
+    pub max_deps: Option<usize>,
+
+    /// Fail if the binary is larger than this, e.g. `2M`
+    #[facet(named, long, default)]
+    pub max_size: Option<String>,
+
+    /// Fail if the target directory is larger than this, e.g. `10G`
+    #[facet(named, long, default)]
+    pub max_target_dir: Option<String>,
+
+    /// Show verbose output
+    #[facet(named, short = 'v', long, default)]
+    pub verbose: bool,
+}
+
+/// What to build and measure
+#[derive(Facet)]
+pub struct BuildArgs {
+    /// Path to the Cargo.toml of the package or workspace to measure
+    #[facet(named, long, default)]
+    pub manifest_path: Option<String>,
+
+    /// Package to measure (default: the one in the current directory)
+    #[facet(named, short = 'p', long, default)]
+    pub package: Option<String>,
+
     /// Binary name to measure (default: infer from Cargo.toml)
     #[facet(named, short = 'b', long, default)]
     pub binary: Option<String>,
@@ -18,27 +203,143 @@ pub struct Args {
     #[facet(named, long, default)]
     pub no_build: bool,
 
-    /// Show verbose output
-    #[facet(named, short = 'v', long, default)]
-    pub verbose: bool,
+    /// Cargo profile to build and measure (default: release)
+    #[facet(named, long, default)]
+    pub profile: Option<String>,
+
+    /// Target triple to build and measure for (default: host)
+    #[facet(named, long, default)]
+    pub target: Option<String>,
+}
+
+/// How dependencies are counted
+#[derive(Facet)]
+pub struct DepArgs {
+    /// Tell dependencies apart by names, or by versions so each version counts (default: names)
+    #[facet(named, long, default)]
+    pub count: Option<String>,
+
+    /// Dependencies to count: all, direct or transitive (default: all)
+    #[facet(named, long, default)]
+    pub deps: Option<String>,
+
+    /// Count build dependencies too
+    #[facet(named, long, default)]
+    pub build_deps: bool,
+
+    /// Count dev dependencies too
+    #[facet(named, long, default)]
+    pub dev_deps: bool,
+
+    /// Leave proc-macros, and what only they depend on, out of the dependency count
+    #[facet(named, long, default)]
+    pub no_proc_macros: bool,
+
+    /// Leave other workspace members out of the dependency count
+    #[facet(named, long, default)]
+    pub no_workspace_deps: bool,
+}
+
+impl DepArgs {
+    /// Which dependencies to count, as far as the flags say, leaving the rest to the config
+    pub fn dep_settings(&self) -> Result<DepSettings, ParseError> {
+        Ok(DepSettings {
+            count: parse_flag(&self.count)?,
+            depth: parse_flag(&self.deps)?,
+            build: self.build_deps.then_some(true),
+            dev: self.dev_deps.then_some(true),
+            proc_macros: self.no_proc_macros.then_some(false),
+            workspace: self.no_workspace_deps.then_some(false),
+        })
+    }
+}
+
+/// Measure the project and print the metrics
+#[derive(Facet)]
+pub struct MetricsArgs {
+    /// What to build and measure
+    #[facet(flatten)]
+    pub build: BuildArgs,
+
+    /// How to count dependencies
+    #[facet(flatten)]
+    pub deps: DepArgs,
 
-    /// Show this help message
-    #[facet(named, short = 'h', long, default)]
-    pub help: bool,
-}
-
-pub fn print_usage() {
-    println!("Usage: blazon [OPTIONS] [README]");
-    println!();
-    println!("Generate and update badge metadata in README files.");
-    println!();
-    println!("Arguments:");
-    println!("  [README]             Path to README file (default: README.md)");
-    println!();
-    println!("Options:");
-    println!("  -c, --crate-name NAME  Crate name for badges (default: infer)");
-    println!("  -b, --binary NAME      Binary name to measure (default: infer)");
-    println!("  --no-build             Skip building");
-    println!("  -v, --verbose          Show verbose output");
-    println!("  -h, --help             Show this help message");
+    /// Print the metrics as JSON
+    #[facet(named, long, default)]
+    pub json: bool,
 }
+
+/// Render a single badge from a label and value
+///
+/// Prints the SVG, or the img.shields.io URL with `--url`, unless `--output` names a file.
+#[derive(Facet)]
+pub struct BadgeArgs {
+    /// Text on the left of the badge (required)
+    #[facet(named, short = 'l', long, default)]
+    pub label: Option<String>,
+
+    /// Text on the right of the badge (required)
+    #[facet(named, long, default)]
+    pub value: Option<String>,
+
+    /// Colour of the value side, as a shields.io colour name or hex code (default: blue)
+    #[facet(named, long, default = "blue".to_string())]
+    pub color: String,
+
+    /// Badge style: flat, flat-square, plastic, for-the-badge or social (default: flat)
+    #[facet(named, long, default)]
+    pub style: Option<String>,
+
+    /// Print the img.shields.io URL instead of the SVG
+    #[facet(named, long, default)]
+    pub url: bool,
+
+    /// Write the SVG to this file instead of printing it
+    #[facet(named, short = 'o', long, default)]
+    pub output: Option<String>,
+
+    /// Exit with status 2 if the file was written
+    #[facet(named, long, default)]
+    pub exit_code: bool,
+}
+
+/// Add markers to the README and write a starter blazon.toml
+///
+/// Markers go after the badge row or first heading. Neither an existing blazon.toml nor a
+/// README that already has markers is changed.
+#[derive(Facet)]
+pub struct InitArgs {
+    /// Path to README file to add markers to (default: README.md)
+    #[facet(positional, default = "README.md".to_string())]
+    pub readme: String,
+
+    /// Only add markers, without writing blazon.toml
+    #[facet(named, long, default)]
+    pub no_config: bool,
+
+    /// Exit with status 2 if any file was written
+    #[facet(named, long, default)]
+    pub exit_code: bool,
+}
+
+/// Printed after the help of commands that take colour scales
+pub const COLOUR_SCALES: &str = "\
+Colour scales:
+  blue                       Fixed colour (shields.io name or hex)
+  green<2M,yellow<5M,red     Thresholds: first colour whose bound the value is under
+  green@2M..red@10M          Gradient between two values, clamped at either end
+";
+
+/// Printed after the overview of all commands
+pub const EXIT_STATUS: &str = "\
+Exit status:
+  0  Success
+  1  Out of date (check) or over budget
+  2  Files were updated, with --exit-code
+  3  Invalid arguments or config
+  4  README markers missing or malformed
+  5  cargo missing or failed
+  6  No binary to measure, or it is not built
+  7  A file could not be read or written
+";
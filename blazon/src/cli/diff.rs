@@ -0,0 +1,44 @@
+//! Terminal output for README diffs.
+
+use std::io::IsTerminal;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// Whether stdout should get ANSI colours: a terminal, and `NO_COLOR` unset.
+pub fn use_color() -> bool {
+    std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()) && std::io::stdout().is_terminal()
+}
+
+/// Colour a unified diff the way `git diff` does: headers bold, hunk ranges cyan, removals red
+/// and additions green.
+pub fn colorize(diff: &str) -> String {
+    diff.lines()
+        .map(|line| {
+            let color = if line.starts_with("--- ") || line.starts_with("+++ ") {
+                BOLD
+            } else if line.starts_with("@@") {
+                CYAN
+            } else if line.starts_with('-') {
+                RED
+            } else if line.starts_with('+') {
+                GREEN
+            } else {
+                return format!("{line}\n");
+            };
+            format!("{color}{line}{RESET}\n")
+        })
+        .collect()
+}
+
+/// Print a unified diff to stdout, coloured when writing to a terminal.
+pub fn print(diff: &str) {
+    if use_color() {
+        print!("{}", colorize(diff));
+    } else {
+        print!("{}", diff);
+    }
+}
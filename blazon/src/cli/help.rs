@@ -0,0 +1,155 @@
+//! Help text generated from the facet shapes of the argument definitions.
+//!
+//! Descriptions are the doc comments of the commands and their fields, and the flags come from
+//! the same `#[facet(...)]` attributes the [parser](super::parse) reads, so the help cannot
+//! drift from the arguments blazon accepts.
+
+use super::parse::{command_name, has_attr, is_switch, options, short, variants};
+use core::fmt::Write;
+use facet::{Field, FieldFlags, Shape};
+
+/// Flags and arguments are padded to this width, and longer ones get their own line
+const NAME_WIDTH: usize = 32;
+
+/// The overview of every command of `commands`, an enum with one variant per command
+pub fn overview(program: &str, commands: &'static Shape, footer: &str) -> String {
+    let mut help = String::new();
+    push_doc(&mut help, commands.doc);
+    writeln!(help, "Usage: {program} [COMMAND] [OPTIONS]").unwrap();
+    help.push('\n');
+    help.push_str("Commands:\n");
+    for variant in variants(commands) {
+        let summary = variant
+            .doc
+            .first()
+            .map_or(String::new(), |line| plain(line));
+        push_row(&mut help, &command_name(variant), &summary);
+    }
+    push_row(
+        &mut help,
+        "help [COMMAND]",
+        "Show this message, or a command's help",
+    );
+    help.push('\n');
+    writeln!(
+        help,
+        "Run '{program} COMMAND --help' for the options of a command."
+    )
+    .unwrap();
+    help.push('\n');
+    help.push_str(footer);
+    help
+}
+
+/// The help of the command called `name` among `commands`
+pub fn command(program: &str, commands: &'static Shape, name: &str, footer: &str) -> String {
+    let Some(args) = variants(commands)
+        .iter()
+        .find(|variant| command_name(variant) == name)
+        .and_then(|variant| variant.data.fields.first())
+    else {
+        return overview(program, commands, footer);
+    };
+    let (positionals, named): (Vec<&Field>, Vec<&Field>) = options(args.shape)
+        .into_iter()
+        .map(|(_, field)| field)
+        .partition(|field| has_attr(field, "positional"));
+
+    let mut help = String::new();
+    push_doc(&mut help, args.shape.doc);
+
+    write!(help, "Usage: {program} {name} [OPTIONS]").unwrap();
+    for field in named.iter().filter(|field| !is_optional(field)) {
+        write!(help, " {}", flag(field)).unwrap();
+    }
+    for field in &positionals {
+        write!(help, " {}", positional(field)).unwrap();
+    }
+    help.push('\n');
+
+    if !positionals.is_empty() {
+        help.push_str("\nArguments:\n");
+        for field in &positionals {
+            push_row(&mut help, &positional(field), &description(field));
+        }
+    }
+    help.push_str("\nOptions:\n");
+    for field in &named {
+        let short = short(field).map_or("    ".to_string(), |c| format!("-{c}, "));
+        push_row(
+            &mut help,
+            &format!("{short}{}", flag(field)),
+            &description(field),
+        );
+    }
+    // Help is handled before the options are parsed, so it is not one of them
+    push_row(&mut help, "-h, --help", "Show this help message");
+    if !footer.is_empty() {
+        help.push('\n');
+        help.push_str(footer);
+    }
+    help
+}
+
+/// Whether `field` may be left out, i.e. has a default
+fn is_optional(field: &Field) -> bool {
+    field.flags.contains(FieldFlags::DEFAULT)
+}
+
+/// `--long-name <VALUE>` for a named field, without the value for a boolean flag
+fn flag(field: &Field) -> String {
+    let long = format!("--{}", field.name.replace('_', "-"));
+    if is_switch(field) {
+        long
+    } else {
+        format!("{long} <{}>", field.name.to_uppercase())
+    }
+}
+
+/// `[NAME]` for an optional positional field, `<NAME>` for a required one
+fn positional(field: &Field) -> String {
+    let name = field.name.to_uppercase();
+    if is_optional(field) {
+        format!("[{name}]")
+    } else {
+        format!("<{name}>")
+    }
+}
+
+/// The doc comment of `field`, joined into one line
+fn description(field: &Field) -> String {
+    let lines: Vec<String> = field.doc.iter().map(|line| plain(line)).collect();
+    lines.join(" ")
+}
+
+/// A doc comment as paragraphs, followed by a blank line
+fn push_doc(help: &mut String, doc: &[&str]) {
+    if doc.is_empty() {
+        return;
+    }
+    for line in doc {
+        help.push_str(&plain(line));
+        help.push('\n');
+    }
+    help.push('\n');
+}
+
+/// A doc comment line as plain text: trimmed, unescaped, and without code spans
+///
+/// The derive stores doc comments as string literals, so quotes come escaped.
+fn plain(line: &str) -> String {
+    line.trim()
+        .replace("\\'", "'")
+        .replace("\\\"", "\"")
+        .replace('`', "")
+}
+
+/// A two-column row of a list of commands, arguments or options
+fn push_row(help: &mut String, name: &str, description: &str) {
+    if name.len() + 2 <= NAME_WIDTH {
+        writeln!(help, "  {name:NAME_WIDTH$}{description}").unwrap();
+    } else {
+        writeln!(help, "  {name}").unwrap();
+        writeln!(help, "  {:NAME_WIDTH$}{description}", "").unwrap();
+    }
+}
@@ -0,0 +1,360 @@
+//! Command-line parsing driven by the facet shapes of the argument definitions.
+//!
+//! A field marked `named` is a `--kebab-case` flag, with `-x` too if it is marked
+//! `short = 'x'`, and fields marked `positional` take the bare arguments in order. Booleans are
+//! switches, set by the flag alone or by `=true` or `=false`, and other fields take the next
+//! argument, or the part after `=`. A field marked `flatten` contributes its own fields instead,
+//! so options several commands share are defined once, and the command is the variant of an enum
+//! named by the first argument.
+//!
+//! facet-args 0.30 parses a single flat struct, with neither flattened fields nor subcommands,
+//! so it cannot read these definitions without repeating the shared options in every command.
+
+use core::fmt;
+use core::ops::Range;
+use facet::{Facet, Field, FieldAttribute, FieldFlags, Partial, ReflectError, Shape, Type};
+use facet::{UserType, Variant};
+use miette::{Diagnostic, LabeledSpan};
+
+/// The variants of an enum shape
+pub fn variants(shape: &'static Shape) -> &'static [Variant] {
+    match &shape.ty {
+        Type::User(UserType::Enum(enum_type)) => enum_type.variants,
+        _ => &[],
+    }
+}
+
+/// The name a command is typed as: its variant name in lowercase
+pub fn command_name(variant: &Variant) -> String {
+    variant.name.to_lowercase()
+}
+
+/// The options of a struct shape, with the fields of flattened structs in place of them
+///
+/// Each comes with its path of field indices from `shape`.
+pub fn options(shape: &'static Shape) -> Vec<(Vec<usize>, &'static Field)> {
+    let Type::User(UserType::Struct(struct_type)) = &shape.ty else {
+        return Vec::new();
+    };
+    let mut options = Vec::new();
+    for (index, field) in struct_type.fields.iter().enumerate() {
+        if field.flags.contains(FieldFlags::FLATTEN) {
+            for (mut path, inner) in self::options(field.shape) {
+                path.insert(0, index);
+                options.push((path, inner));
+            }
+        } else {
+            options.push((vec![index], field));
+        }
+    }
+    options
+}
+
+/// Whether `field` has the `#[facet(...)]` attribute `name`
+pub fn has_attr(field: &Field, name: &str) -> bool {
+    field
+        .attributes
+        .iter()
+        .any(|FieldAttribute::Arbitrary(attr)| *attr == name)
+}
+
+/// The `short = 'x'` letter of `field`
+pub fn short(field: &Field) -> Option<char> {
+    field
+        .attributes
+        .iter()
+        .find_map(|FieldAttribute::Arbitrary(attr)| {
+            let letter = attr.strip_prefix("short = ")?;
+            letter.trim_matches(['\'', '"']).chars().next()
+        })
+}
+
+/// Whether `field` is a switch, taking no value
+pub fn is_switch(field: &Field) -> bool {
+    field.shape.is_shape(bool::SHAPE)
+}
+
+/// Parse `args` as the options of the command `name`, a variant of the enum `T`
+///
+/// Returns `None` if `T` has no such command.
+pub fn parse_command<T: Facet<'static>>(name: &str, args: &[&str]) -> Option<Result<T, ArgsError>> {
+    let variant = variants(T::SHAPE)
+        .iter()
+        .find(|variant| command_name(variant) == name)?;
+    let options_shape = variant.data.fields.first()?.shape;
+    let input = Input::new(args);
+
+    let result = input.assign(options_shape).and_then(|values| {
+        let mut partial = Partial::alloc_shape(T::SHAPE)?;
+        partial.select_variant_named(variant.name)?;
+        partial.begin_nth_field(0)?;
+        build(&mut partial, options_shape, &[], &values)?;
+        partial.end()?;
+        Ok(partial.build()?.materialize::<T>()?)
+    });
+    Some(result.map_err(|failure| ArgsError {
+        input: input.joined.clone(),
+        span: failure.span,
+        kind: failure.kind,
+    }))
+}
+
+/// The arguments being parsed, joined for error reports
+struct Input<'a> {
+    args: &'a [&'a str],
+    /// The arguments joined by spaces
+    joined: String,
+    /// Where each argument starts in `joined`
+    starts: Vec<usize>,
+}
+
+/// A value for the option at `path`, or `None` to switch it on
+struct Value<'a> {
+    path: Vec<usize>,
+    text: Option<&'a str>,
+    span: Range<usize>,
+}
+
+/// An [`ArgsError`] before the input is attached
+struct Failure {
+    kind: ArgsErrorKind,
+    span: Range<usize>,
+}
+
+impl Failure {
+    fn new(kind: ArgsErrorKind, span: &Range<usize>) -> Self {
+        Self {
+            kind,
+            span: span.clone(),
+        }
+    }
+}
+
+impl<'a> Input<'a> {
+    fn new(args: &'a [&'a str]) -> Self {
+        let mut joined = String::new();
+        let mut starts = Vec::with_capacity(args.len());
+        for arg in args {
+            starts.push(joined.len());
+            joined.push_str(arg);
+            joined.push(' ');
+        }
+        Self {
+            args,
+            joined,
+            starts,
+        }
+    }
+
+    /// The span of the argument at `index`, or the end of the input past the last one
+    fn span(&self, index: usize) -> Range<usize> {
+        match self.args.get(index) {
+            Some(arg) => self.starts[index]..self.starts[index] + arg.len(),
+            None => self.joined.len()..self.joined.len(),
+        }
+    }
+
+    /// Match every argument to an option of `shape`
+    fn assign(&self, shape: &'static Shape) -> Result<Vec<Value<'a>>, Failure> {
+        let options = options(shape);
+        let mut positionals = options
+            .iter()
+            .filter(|(_, field)| has_attr(field, "positional"));
+        let mut values = Vec::new();
+        let mut only_positional = false;
+        let mut index = 0;
+
+        while let Some(&arg) = self.args.get(index) {
+            let span = self.span(index);
+            index += 1;
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) => (flag, Some(value)),
+                None => (arg, None),
+            };
+            let option = if only_positional {
+                None
+            } else if arg == "--" {
+                only_positional = true;
+                continue;
+            } else if let Some(long) = flag.strip_prefix("--") {
+                let name = long.replace('-', "_");
+                let option = options
+                    .iter()
+                    .find(|(_, field)| field.name == name && !has_attr(field, "positional"));
+                Some(option.ok_or_else(|| Failure::new(ArgsErrorKind::UnknownFlag, &span))?)
+            } else if let Some(letters) = flag.strip_prefix('-').filter(|s| !s.is_empty()) {
+                let mut chars = letters.chars();
+                let letter = chars.next().filter(|_| chars.next().is_none());
+                let option = options
+                    .iter()
+                    .find(|(_, field)| letter.is_some() && short(field) == letter);
+                Some(option.ok_or_else(|| Failure::new(ArgsErrorKind::UnknownFlag, &span))?)
+            } else {
+                None
+            };
+
+            let value = match option {
+                Some((path, field)) if is_switch(field) => Value {
+                    path: path.clone(),
+                    text: inline,
+                    span,
+                },
+                Some((path, _)) => {
+                    let (text, span) = match inline {
+                        Some(text) => (text, span),
+                        None => {
+                            let text = self
+                                .args
+                                .get(index)
+                                .ok_or_else(|| Failure::new(ArgsErrorKind::MissingValue, &span))?;
+                            index += 1;
+                            (*text, self.span(index - 1))
+                        }
+                    };
+                    Value {
+                        path: path.clone(),
+                        text: Some(text),
+                        span,
+                    }
+                }
+                None => {
+                    let (path, _) = positionals
+                        .next()
+                        .ok_or_else(|| Failure::new(ArgsErrorKind::UnexpectedArgument, &span))?;
+                    Value {
+                        path: path.clone(),
+                        text: Some(arg),
+                        span,
+                    }
+                }
+            };
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Fill the struct `shape` being built at `prefix` from `values`, and its other fields with
+/// their defaults
+fn build(
+    partial: &mut Partial<'static>,
+    shape: &'static Shape,
+    prefix: &[usize],
+    values: &[Value],
+) -> Result<(), Failure> {
+    let Type::User(UserType::Struct(struct_type)) = &shape.ty else {
+        return Ok(());
+    };
+    for (index, field) in struct_type.fields.iter().enumerate() {
+        let path = [prefix, &[index]].concat();
+        if field.flags.contains(FieldFlags::FLATTEN) {
+            partial.begin_nth_field(index)?;
+            build(partial, field.shape, &path, values)?;
+            partial.end()?;
+            continue;
+        }
+
+        // The last of repeated options wins
+        match values.iter().rev().find(|value| value.path == path) {
+            Some(value) => {
+                partial.begin_nth_field(index)?;
+                let set = match value.text {
+                    None => partial.set(true),
+                    Some(text) => partial.parse_from_str(text),
+                };
+                set.map_err(|e| Failure::new(ArgsErrorKind::InvalidValue(e), &value.span))?;
+                partial.end()?;
+            }
+            None if field.flags.contains(FieldFlags::DEFAULT) => {
+                partial.set_nth_field_to_default(index)?;
+            }
+            None if is_switch(field) => {
+                partial.set_nth_field(index, false)?;
+            }
+            None => {
+                let end = values.last().map_or(0, |value| value.span.end);
+                return Err(Failure::new(
+                    ArgsErrorKind::MissingArgument(field.name),
+                    &(end..end),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Arguments that do not match the options of their command
+#[derive(Debug)]
+pub struct ArgsError {
+    /// The arguments joined by spaces, which `span` points into
+    pub input: String,
+    /// The byte range of the offending argument in `input`
+    pub span: Range<usize>,
+    /// What is wrong with it
+    pub kind: ArgsErrorKind,
+}
+
+/// What is wrong with the arguments
+#[derive(Debug)]
+pub enum ArgsErrorKind {
+    /// A flag that no option of the command has
+    UnknownFlag,
+    /// A flag taking a value, given last
+    MissingValue,
+    /// A bare argument beyond those the command takes
+    UnexpectedArgument,
+    /// A required argument, named by its field, that was not given
+    MissingArgument(&'static str),
+    /// A value that does not parse as its option's type
+    InvalidValue(ReflectError),
+    /// The options could not be built, which is a bug in their definitions
+    Reflect(ReflectError),
+}
+
+impl From<ReflectError> for Failure {
+    fn from(error: ReflectError) -> Self {
+        Failure::new(ArgsErrorKind::Reflect(error), &(0..0))
+    }
+}
+
+impl fmt::Display for ArgsErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgsErrorKind::UnknownFlag => write!(f, "unknown flag"),
+            ArgsErrorKind::MissingValue => write!(f, "expected a value"),
+            ArgsErrorKind::UnexpectedArgument => write!(f, "unexpected argument"),
+            ArgsErrorKind::MissingArgument(name) => {
+                write!(f, "missing argument: {}", name.replace('_', "-"))
+            }
+            ArgsErrorKind::InvalidValue(error) => write!(f, "invalid value: {}", error),
+            ArgsErrorKind::Reflect(error) => write!(f, "reflection error: {}", error),
+        }
+    }
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Could not parse CLI arguments")
+    }
+}
+
+impl core::error::Error for ArgsError {}
+
+impl Diagnostic for ArgsError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("blazon::args"))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("run with --help to see the options"))
+    }
+
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        Some(&self.input)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let label = LabeledSpan::new_with_span(Some(self.kind.to_string()), self.span.clone());
+        Some(Box::new(core::iter::once(label)))
+    }
+}
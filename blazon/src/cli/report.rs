@@ -1,10 +1,13 @@
 //! Error reporting with miette diagnostics.
 //!
-//! This module provides utilities for displaying facet-args parsing errors
+//! This module provides utilities for displaying argument parsing errors
 //! with nice graphical diagnostics using miette's report handler.
 
-use miette::{GraphicalReportHandler, GraphicalTheme, ReportHandler};
-use std::fmt;
+use blazon_core::BlazonError;
+use blazon_core::budget::BudgetViolation;
+use blazon_core::region::MarkerErrorKind;
+use core::fmt;
+use miette::{Diagnostic, GraphicalReportHandler, GraphicalTheme, LabeledSpan, ReportHandler};
 
 /// Initializes the global miette report handler for pretty error output.
 ///
@@ -18,13 +21,13 @@ use std::fmt;
 /// # Examples
 ///
 /// ```no_run
-/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # fn main() -> Result<(), Box<dyn core::error::Error>> {
 /// blazon::cli::report::install_handler()?;
 /// // Now all diagnostics will use pretty formatting
 /// # Ok(())
 /// # }
 /// ```
-pub fn install_handler() -> Result<(), Box<dyn std::error::Error>> {
+pub fn install_handler() -> Result<(), Box<dyn core::error::Error>> {
     miette::set_hook(Box::new(|_| {
         Box::new(GraphicalReportHandler::new_themed(
             GraphicalTheme::unicode_nocolor(),
@@ -43,8 +46,7 @@ pub fn install_handler() -> Result<(), Box<dyn std::error::Error>> {
 ///
 /// ```
 /// # use blazon::cli::report::DiagnosticDisplay;
-/// # fn get_error() -> impl miette::Diagnostic { /* ... */ }
-/// let err = get_error();
+/// let err = miette::MietteDiagnostic::new("Something went wrong");
 /// eprintln!("{}", DiagnosticDisplay(&err));
 /// ```
 pub struct DiagnosticDisplay<'a>(pub &'a dyn miette::Diagnostic);
@@ -56,3 +58,117 @@ impl fmt::Display for DiagnosticDisplay<'_> {
         Ok(())
     }
 }
+
+/// Diagnostic for metrics over their budgets, with one related diagnostic per violation.
+#[derive(Debug)]
+pub struct BudgetExceeded(pub Vec<Violation>);
+
+impl BudgetExceeded {
+    /// The diagnostic for `violations`, which should not be empty
+    pub fn new(violations: Vec<BudgetViolation>) -> Self {
+        Self(violations.into_iter().map(Violation).collect())
+    }
+}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.as_slice() {
+            [violation] => write!(f, "Budget exceeded: {}", violation.0),
+            violations => write!(f, "{} budgets exceeded", violations.len()),
+        }
+    }
+}
+
+impl core::error::Error for BudgetExceeded {}
+
+impl Diagnostic for BudgetExceeded {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("blazon::budget_exceeded"))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        match self.0.as_slice() {
+            [violation] => violation.help(),
+            _ => None,
+        }
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        if self.0.len() > 1 {
+            Some(Box::new(self.0.iter().map(|v| v as &dyn Diagnostic)))
+        } else {
+            None
+        }
+    }
+}
+
+/// A single metric over its budget.
+#[derive(Debug)]
+pub struct Violation(pub BudgetViolation);
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl core::error::Error for Violation {}
+
+impl Diagnostic for Violation {
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let flag = match self.0.id {
+            "deps" => "--max-deps",
+            "size" => "--max-size",
+            _ => "--max-target-dir",
+        };
+        Some(Box::new(format!(
+            "reduce the {} or raise the budget with {} {}",
+            self.0.name,
+            flag,
+            self.0.format_value(self.0.actual)
+        )))
+    }
+}
+
+/// Diagnostic for a [`BlazonError`], with help naming the flags that fix it
+#[derive(Debug)]
+pub struct Failure<'a>(pub &'a BlazonError);
+
+impl fmt::Display for Failure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl core::error::Error for Failure<'_> {}
+
+impl Diagnostic for Failure<'_> {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.0.code()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let help = match self.0 {
+            BlazonError::AmbiguousBinary { .. } => "choose one with --binary NAME".to_string(),
+            BlazonError::BinaryNotBuilt { .. } => {
+                "build it first, or run blazon without --no-build".to_string()
+            }
+            error => match error.marker_error().map(|e| &e.kind) {
+                Some(MarkerErrorKind::NoMarkers) => format!(
+                    "{}, or pass --insert to add them automatically",
+                    error.help()?
+                ),
+                _ => return Diagnostic::help(self.0),
+            },
+        };
+        Some(Box::new(help))
+    }
+
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        self.0.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.0.labels()
+    }
+}
@@ -9,5 +9,26 @@
 //!
 //! This crate provides badge generation for dependency count and binary size, as a library or CLI.
 
+#[cfg(feature = "cli")]
+pub mod cli;
+
+/// Run the blazon CLI on the arguments of this process, then exit with its status
+#[cfg(feature = "cli")]
+#[deprecated(note = "use `blazon::cli::run`, which returns the exit status")]
+pub fn main() -> std::io::Result<()> {
+    std::process::exit(cli::run(std::env::args().skip(1).collect()))
+}
+
+/// Explain that the CLI needs the `cli` feature, then exit with status 1
+#[cfg(not(feature = "cli"))]
+#[deprecated(note = "build with the cli feature and use `blazon::cli::run`")]
+pub fn main() {
+    eprintln!("Please build with the cli feature");
+    eprintln!("Example: cargo install blazon --features cli");
+    std::process::exit(1);
+}
+
 // Re-export the core functionality
-pub use blazon_core::{Metrics, blazon_debug, collect_metrics, generate_badges};
+pub use blazon_core::{
+    BadgeOptions, BuildOptions, Metrics, blazon_debug, collect_metrics, generate_badges,
+};
@@ -2,9 +2,26 @@
 use assert_cmd::Command;
 use assert_cmd::cargo;
 use insta::assert_snapshot;
+use predicates::prelude::PredicateBooleanExt;
 use std::fs;
 use tempfile::TempDir;
 
+/// Helper to run blazon, building into the test project's own target directory
+///
+/// Cargo's target directory settings of the environment running the tests are removed, as they
+/// would point blazon at the real build output rather than the test's fake binaries.
+fn blazon_command() -> Command {
+    isolate(Command::new(cargo::cargo_bin!("blazon")))
+}
+
+/// Remove the target directory settings of the environment from `command`
+fn isolate(mut command: Command) -> Command {
+    command
+        .env_remove("CARGO_TARGET_DIR")
+        .env_remove("CARGO_BUILD_TARGET_DIR");
+    command
+}
+
 /// Helper to create a minimal Cargo.toml for testing
 fn create_test_cargo_toml(temp_dir: &TempDir) {
     let cargo_toml = r#"[package]
@@ -38,7 +55,7 @@ fn create_fake_binary(temp_dir: &TempDir, name: &str) {
 
 #[test]
 fn test_help_flag() {
-    let output = Command::new(cargo::cargo_bin!("blazon"))
+    let output = blazon_command()
         .arg("--help")
         .output()
         .expect("failed to run command");
@@ -48,6 +65,129 @@ fn test_help_flag() {
     assert_snapshot!(stdout);
 }
 
+#[test]
+fn test_command_help() {
+    let help = |args: &[&str]| {
+        let output = blazon_command().args(args).output().unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let badge_help = help(&["badge", "--help"]);
+    assert_snapshot!(badge_help);
+    assert_eq!(help(&["help", "badge"]), badge_help);
+    // Required options do not stop --help
+    assert_eq!(help(&["badge", "-h", "--value", "x"]), badge_help);
+
+    let check_help = help(&["check", "-h"]);
+    assert!(check_help.starts_with("Verify the README and SVG badges are up to date"));
+    assert!(check_help.contains("Usage: blazon check [OPTIONS] [README]"));
+    assert!(!check_help.contains("--dry-run"));
+}
+
+#[test]
+fn test_args_diagnostic() {
+    let report = |args: &[&str]| {
+        let output = blazon_command().args(args).output().unwrap();
+        assert_eq!(output.status.code(), Some(3));
+        String::from_utf8(output.stderr).unwrap()
+    };
+
+    // check shares update's options, but not the ones that write
+    let unknown = report(&["check", "--dry-run"]);
+    assert_snapshot!(unknown);
+    assert!(report(&["metrics", "--style"]).contains("unknown flag"));
+    assert!(report(&["--style"]).contains("expected a value"));
+    assert!(report(&["README.md", "other.md"]).contains("unexpected argument"));
+    assert!(report(&["badge", "-l", "a", "--value", "b", "--url=no"]).contains("invalid value"));
+}
+
+#[test]
+fn test_switch_value() {
+    let badge = |url: &str| {
+        let output = blazon_command()
+            .args(["badge", "--label", "a", "--value", "b", url])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    // A switch given `=false` stays off, rather than being switched on by its name
+    assert!(badge("--url=false").starts_with("<svg"));
+    assert!(badge("--url=true").starts_with("https://img.shields.io/"));
+}
+
+#[test]
+fn test_metrics_command() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    create_fake_binary(&temp_dir, "blazon");
+
+    let metrics = |json: bool| {
+        let mut command = blazon_command();
+        command
+            .current_dir(temp_dir.path())
+            .args(["metrics", "--no-build"]);
+        if json {
+            command.arg("--json");
+        }
+        command.assert().success()
+    };
+
+    metrics(false).stdout(predicates::str::contains("Binary size:  19B (19 bytes)"));
+    metrics(true)
+        .stdout(predicates::str::contains("\"binary_size_bytes\":19"))
+        .stdout(predicates::str::contains("\"dep_count\":"));
+}
+
+#[test]
+fn test_badge_command() {
+    let temp_dir = TempDir::new().unwrap();
+    let badge = |args: &[&str]| {
+        blazon_command()
+            .current_dir(temp_dir.path())
+            .arg("badge")
+            .args(args)
+            .assert()
+    };
+
+    badge(&[
+        "--label", "coverage", "--value", "91%", "--color", "green", "--url",
+    ])
+    .success()
+    .stdout("https://img.shields.io/badge/coverage-91%25-green\n");
+    badge(&["-l", "coverage", "--value", "91%"])
+        .success()
+        .stdout(predicates::str::starts_with("<svg"))
+        .stdout(predicates::str::contains("aria-label=\"coverage: 91%\""));
+
+    let write = || {
+        badge(&[
+            "-l",
+            "coverage",
+            "--value",
+            "91%",
+            "-o",
+            "coverage.svg",
+            "--exit-code",
+        ])
+    };
+    write().code(2).stdout("");
+    assert!(
+        fs::read_to_string(temp_dir.path().join("coverage.svg"))
+            .unwrap()
+            .starts_with("<svg")
+    );
+    write()
+        .success()
+        .stderr(predicates::str::contains("coverage.svg is unchanged"));
+
+    badge(&["--value", "91%"])
+        .code(3)
+        .stderr(predicates::str::contains("needs both --label and --value"));
+}
+
 #[test]
 fn test_missing_markers() {
     let temp_dir = TempDir::new().unwrap();
@@ -57,15 +197,19 @@ fn test_missing_markers() {
     fs::write(&readme_path, "# No markers here\n").unwrap();
     create_fake_binary(&temp_dir, "blazon");
 
-    Command::new(cargo::cargo_bin!("blazon"))
+    blazon_command()
         .current_dir(temp_dir.path())
         .arg(readme_path.to_str().unwrap())
         .arg("--no-build")
         .arg("--binary")
         .arg("blazon")
         .assert()
-        .failure()
-        .stderr(predicates::str::contains("Failed to apply patch"));
+        .code(4)
+        .stderr(predicates::str::contains("blazon::no_markers"))
+        .stderr(predicates::str::contains("No blazon markers found"))
+        .stderr(predicates::str::contains(
+            "or pass --insert to add them automatically",
+        ));
 }
 
 #[test]
@@ -76,9 +220,9 @@ fn test_update_readme_success() {
 
     let initial = r#"# Test Project
 
-<!-- auto-generated badges -->
+<!-- blazon -->
 old content
-<!-- /auto-generated badges -->
+<!-- /blazon -->
 
 More content.
 "#;
@@ -86,7 +230,7 @@ More content.
     fs::write(&readme_path, initial).unwrap();
     create_fake_binary(&temp_dir, "blazon");
 
-    Command::new(cargo::cargo_bin!("blazon"))
+    blazon_command()
         .current_dir(temp_dir.path())
         .arg(readme_path.to_str().unwrap())
         .arg("--no-build")
@@ -96,7 +240,7 @@ More content.
         .arg("test-crate")
         .assert()
         .success()
-        .stderr(predicates::str::contains("Successfully updated"));
+        .stderr(predicates::str::contains("✓ Updated"));
 
     let updated = fs::read_to_string(&readme_path).unwrap();
     assert!(updated.contains("Dependencies:"));
@@ -113,14 +257,14 @@ fn test_verbose_output() {
     fs::write(
         &readme_path,
         r#"
-<!-- auto-generated badges -->
-<!-- /auto-generated badges -->
+<!-- blazon -->
+<!-- /blazon -->
 "#,
     )
     .unwrap();
     create_fake_binary(&temp_dir, "blazon");
 
-    Command::new(cargo::cargo_bin!("blazon"))
+    blazon_command()
         .current_dir(temp_dir.path())
         .arg(readme_path.to_str().unwrap())
         .arg("--no-build")
@@ -143,15 +287,15 @@ fn test_infer_binary_name() {
     fs::write(
         &readme_path,
         r#"
-<!-- auto-generated badges -->
-<!-- /auto-generated badges -->
+<!-- blazon -->
+<!-- /blazon -->
 "#,
     )
     .unwrap();
     create_fake_binary(&temp_dir, "blazon");
     create_fake_binary(&temp_dir, "test-crate");
 
-    Command::new(cargo::cargo_bin!("blazon"))
+    blazon_command()
         .current_dir(temp_dir.path())
         .arg(readme_path.to_str().unwrap())
         .arg("--no-build")
@@ -167,13 +311,886 @@ fn test_missing_binary_error() {
 
     fs::write(&readme_path, "test").unwrap();
 
-    Command::new(cargo::cargo_bin!("blazon"))
+    blazon_command()
         .current_dir(temp_dir.path())
         .arg(readme_path.to_str().unwrap())
         .arg("--no-build")
         .arg("--binary")
         .arg("nonexistent-binary")
         .assert()
-        .failure()
+        .code(6)
         .stderr(predicates::str::contains("Failed to get size"));
 }
+
+#[test]
+fn test_custom_target_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "<!-- blazon -->\n<!-- /blazon -->\n").unwrap();
+
+    let target_dir = temp_dir.path().join("elsewhere");
+    let binary_dir = target_dir.join("debug");
+    fs::create_dir_all(&binary_dir).unwrap();
+    fs::write(binary_dir.join("blazon"), vec![0; 2048]).unwrap();
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .arg(readme_path.to_str().unwrap())
+        .arg("--no-build")
+        .arg("--binary")
+        .arg("blazon")
+        .arg("--profile")
+        .arg("dev")
+        .assert()
+        .success();
+
+    let updated = fs::read_to_string(&readme_path).unwrap();
+    assert!(updated.contains("Binary Size: 2.0K"));
+}
+
+#[test]
+fn test_infer_crate_name() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "<!-- blazon -->\n<!-- /blazon -->\n").unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg(readme_path.to_str().unwrap())
+        .arg("--no-build")
+        .assert()
+        .success()
+        .stderr(predicates::str::contains(
+            "Warning: binary 'blazon' belongs to package 'test-crate'",
+        ));
+
+    let updated = fs::read_to_string(&readme_path).unwrap();
+    assert!(updated.contains("https://crates.io/crates/test-crate"));
+    assert!(!updated.contains("https://crates.io/crates/blazon"));
+}
+
+#[test]
+fn test_svg_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "<!-- blazon -->\n<!-- /blazon -->\n").unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("README.md")
+        .arg("--no-build")
+        .arg("--binary")
+        .arg("blazon")
+        .arg("--svg-dir")
+        .arg(".github/badges")
+        .assert()
+        .success();
+
+    let updated = fs::read_to_string(&readme_path).unwrap();
+    assert!(updated.contains("(.github/badges/deps.svg)"));
+    assert!(updated.contains("(.github/badges/size.svg)"));
+    assert!(!updated.contains("img.shields.io"));
+    assert!(temp_dir.path().join(".github/badges/deps.svg").exists());
+}
+
+#[test]
+fn test_style() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "<!-- blazon -->\n<!-- /blazon -->\n").unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--no-build")
+        .arg("--binary")
+        .arg("blazon")
+        .arg("--style")
+        .arg("flat-square")
+        .assert()
+        .success();
+
+    let updated = fs::read_to_string(&readme_path).unwrap();
+    assert!(updated.contains("-blue?style=flat-square)"));
+}
+
+#[test]
+fn test_unknown_style() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--style")
+        .arg("rounded")
+        .assert()
+        .code(3)
+        .stderr(predicates::str::contains("Unknown badge style 'rounded'"));
+}
+
+#[test]
+fn test_color_scales() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "<!-- blazon -->\n<!-- /blazon -->\n").unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--no-build")
+        .arg("--binary")
+        .arg("blazon")
+        .arg("--size-color")
+        .arg("green<10,red")
+        .arg("--target-dir-color")
+        .arg("green@0..red@1")
+        .assert()
+        .success();
+
+    // The fake binary is over 10 bytes, and so is the target dir holding it
+    let updated = fs::read_to_string(&readme_path).unwrap();
+    assert!(updated.contains("/badge/build%20size-19B-red)"));
+    assert!(updated.contains("-e05d44)"));
+}
+
+#[test]
+fn test_invalid_color_scale() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--deps-color")
+        .arg("green<100")
+        .assert()
+        .code(3)
+        .stderr(predicates::str::contains(
+            "Invalid colour scale 'green<100'",
+        ));
+}
+
+#[test]
+fn test_dep_modes() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "<!-- blazon -->\n<!-- /blazon -->\n").unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--no-build")
+        .arg("--binary")
+        .arg("blazon")
+        .arg("--deps")
+        .arg("direct")
+        .arg("--build-deps")
+        .assert()
+        .success();
+
+    // The badge label says how the dependencies were counted
+    let updated = fs::read_to_string(&readme_path).unwrap();
+    assert!(updated.contains("/badge/cargo%20tree%20%28direct%2C%20%2Bbuild%29-0-"));
+
+    // The same counting set in blazon.toml leaves the README as it is without the flags
+    fs::write(
+        temp_dir.path().join("blazon.toml"),
+        "[deps]\ndepth = \"direct\"\nbuild = true\n",
+    )
+    .unwrap();
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("check")
+        .arg("--no-build")
+        .arg("--binary")
+        .arg("blazon")
+        .assert()
+        .success();
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--deps")
+        .arg("indirect")
+        .assert()
+        .code(3)
+        .stderr(predicates::str::contains(
+            "Unknown depth 'indirect' (expected one of: all, direct, transitive)",
+        ));
+}
+
+#[test]
+fn test_budget_exceeded() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "<!-- blazon -->\n<!-- /blazon -->\n").unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    let output = blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--no-build")
+        .arg("--binary")
+        .arg("blazon")
+        .arg("--max-size")
+        .arg("10B")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let report = &stderr[stderr.find("blazon::budget_exceeded").unwrap()..];
+    assert_snapshot!(report);
+
+    // The README is still updated with the measured size
+    let updated = fs::read_to_string(&readme_path).unwrap();
+    assert!(updated.contains("build%20size-19B-green"));
+}
+
+#[test]
+fn test_within_budget() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "<!-- blazon -->\n<!-- /blazon -->\n").unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--no-build")
+        .arg("--binary")
+        .arg("blazon")
+        .arg("--max-size")
+        .arg("2M")
+        .arg("--max-deps")
+        .arg("1000")
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_multiple_budgets_exceeded() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "<!-- blazon -->\n<!-- /blazon -->\n").unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--no-build")
+        .arg("--binary")
+        .arg("blazon")
+        .arg("--max-size")
+        .arg("10B")
+        .arg("--max-target-dir")
+        .arg("10B")
+        .assert()
+        .code(1)
+        .stderr(predicates::str::contains("2 budgets exceeded"))
+        .stderr(predicates::str::contains("binary size 19B exceeds"))
+        .stderr(predicates::str::contains("--max-target-dir"));
+}
+
+#[test]
+fn test_check_out_of_date() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    let original = "# Test\n<!-- blazon -->\nold badges\n<!-- /blazon -->\n";
+    fs::write(&readme_path, original).unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--no-build")
+        .arg("--binary")
+        .arg("blazon")
+        .arg("--check")
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("--- README.md"))
+        .stdout(predicates::str::contains("-old badges"))
+        .stdout(predicates::str::contains("+[![Dependencies: "))
+        .stderr(predicates::str::contains("README.md is out of date"));
+
+    // Never written in check mode
+    assert_eq!(fs::read_to_string(&readme_path).unwrap(), original);
+}
+
+#[test]
+fn test_check_command() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    let original = "# Test\n<!-- blazon -->\nold badges\n<!-- /blazon -->\n";
+    fs::write(&readme_path, original).unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    let blazon = |command: &str| {
+        blazon_command()
+            .current_dir(temp_dir.path())
+            .args([command, "--no-build", "--binary", "blazon"])
+            .assert()
+    };
+
+    blazon("check")
+        .code(1)
+        .stdout(predicates::str::contains("-old badges"))
+        .stderr(predicates::str::contains("README.md is out of date"))
+        .stderr(predicates::str::contains("Run blazon update to update"));
+    assert_eq!(fs::read_to_string(&readme_path).unwrap(), original);
+
+    blazon("update").success();
+    blazon("check")
+        .success()
+        .stderr(predicates::str::contains("README.md is up to date"));
+}
+
+#[test]
+fn test_check_up_to_date() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "<!-- blazon -->\n<!-- /blazon -->\n").unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    let blazon = |check: bool| {
+        let mut command = blazon_command();
+        command.current_dir(temp_dir.path()).args([
+            "--no-build",
+            "--binary",
+            "blazon",
+            "--svg-dir",
+            "badges",
+        ]);
+        if check {
+            command.arg("--check");
+        }
+        command.assert()
+    };
+
+    blazon(false).success();
+    blazon(true)
+        .success()
+        .stdout("")
+        .stderr(predicates::str::contains("README.md is up to date"));
+
+    // A stale SVG fails the check even when the README matches
+    fs::write(temp_dir.path().join("badges/size.svg"), "<svg/>").unwrap();
+    blazon(true)
+        .code(1)
+        .stderr(predicates::str::contains("size.svg is out of date"));
+}
+
+#[test]
+fn test_dry_run() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    let original = "# Test\n<!-- blazon -->\nold badges\n<!-- /blazon -->\n";
+    fs::write(&readme_path, original).unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .args(["--no-build", "--binary", "blazon", "--dry-run"])
+        .args(["--svg-dir", "badges"])
+        .assert()
+        .success()
+        .stdout(predicates::str::starts_with(
+            "--- README.md\n+++ README.md\n@@ -1,4 +1,6 @@\n # Test\n",
+        ))
+        .stdout(predicates::str::contains("-old badges\n"))
+        .stdout(predicates::str::contains("+[![Dependencies: "))
+        // Not a terminal, so no colour codes
+        .stdout(predicates::str::contains("\x1b[").not())
+        .stderr(predicates::str::contains("Would write badges/deps.svg"))
+        .stderr(predicates::str::contains("Would update README.md"));
+
+    assert_eq!(fs::read_to_string(&readme_path).unwrap(), original);
+    assert!(!temp_dir.path().join("badges").exists());
+}
+
+#[test]
+fn test_named_regions() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(
+        &readme_path,
+        "<!-- blazon:size -->\n<!-- /blazon:size -->\n## Performance\n<!-- blazon:table:deps,size -->\n<!-- /blazon:table:deps,size -->\n",
+    )
+    .unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .args(["--no-build", "--binary", "blazon"])
+        .assert()
+        .success();
+
+    let updated = fs::read_to_string(&readme_path).unwrap();
+    let (header, performance) = updated.split_once("## Performance").unwrap();
+    assert!(header.contains("[![Binary Size: 19B]"));
+    assert!(!header.contains("Dependencies"));
+    assert!(performance.contains("| Binary size | 19B |"));
+    assert!(performance.contains("| Dependencies |"));
+    assert!(!performance.contains("Target dir"));
+}
+
+#[test]
+fn test_unchanged_readme_not_written() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "<!-- blazon -->\n<!-- /blazon -->\n").unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    let blazon = || {
+        blazon_command()
+            .current_dir(temp_dir.path())
+            .args(["--no-build", "--binary", "blazon", "--svg-dir", "badges"])
+            .arg("--exit-code")
+            .assert()
+    };
+
+    blazon()
+        .code(2)
+        .stderr(predicates::str::contains("Wrote badges/deps.svg"))
+        .stderr(predicates::str::contains("✓ Updated README.md"));
+    let modified = |path: &str| {
+        fs::metadata(temp_dir.path().join(path))
+            .unwrap()
+            .modified()
+            .unwrap()
+    };
+    let readme_mtime = modified("README.md");
+    let svg_mtime = modified("badges/size.svg");
+
+    blazon()
+        .code(0)
+        .stderr(predicates::str::contains("Wrote").not())
+        .stderr(predicates::str::contains("✓ README.md is unchanged"));
+    assert_eq!(modified("README.md"), readme_mtime);
+    assert_eq!(modified("badges/size.svg"), svg_mtime);
+
+    // Only the stale SVG is rewritten
+    fs::write(temp_dir.path().join("badges/size.svg"), "<svg/>").unwrap();
+    blazon()
+        .code(2)
+        .stderr(predicates::str::contains("Wrote badges/size.svg"))
+        .stderr(predicates::str::contains("deps.svg").not())
+        .stderr(predicates::str::contains("✓ README.md is unchanged"));
+}
+
+#[test]
+fn test_insert() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(&readme_path, "# Test\n[![ci](ci.svg)](ci)\n\nIntro.\n").unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .args(["--no-build", "--binary", "blazon", "--insert"])
+        .assert()
+        .success()
+        .stderr(predicates::str::contains(
+            "✓ Inserted markers into README.md at the end of the badge row on line 2",
+        ));
+
+    let updated = fs::read_to_string(&readme_path).unwrap();
+    assert!(updated.starts_with("# Test\n[![ci](ci.svg)](ci)<!-- blazon -->[![Dependencies: "));
+    assert!(updated.ends_with("<!-- /blazon -->\n\nIntro.\n"));
+}
+
+#[test]
+fn test_init() {
+    let temp_dir = TempDir::new().unwrap();
+    let readme_path = temp_dir.path().join("docs.md");
+    fs::write(&readme_path, "# Test\nIntro.\n").unwrap();
+
+    let init = || {
+        blazon_command()
+            .current_dir(temp_dir.path())
+            .args(["init", "docs.md", "--exit-code"])
+            .assert()
+    };
+
+    // No Cargo.toml or binary needed, as nothing is measured
+    init().code(2).stderr(predicates::str::contains(
+        "✓ Inserted markers into docs.md on line 2, after the heading on line 1",
+    ));
+    assert_eq!(
+        fs::read_to_string(&readme_path).unwrap(),
+        "# Test\n<!-- blazon -->\n<!-- /blazon -->\n\nIntro.\n"
+    );
+    let config = fs::read_to_string(temp_dir.path().join("blazon.toml")).unwrap();
+    assert!(config.contains("# readmes = [\"README.md\"]"));
+
+    init()
+        .success()
+        .stderr(predicates::str::contains("✓ docs.md already has markers"))
+        .stderr(predicates::str::contains("✓ blazon.toml already exists"));
+}
+
+#[test]
+fn test_init_no_config() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("README.md"), "# Test\n").unwrap();
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .args(["init", "--no-config"])
+        .assert()
+        .success();
+    assert!(!temp_dir.path().join("blazon.toml").exists());
+}
+
+#[test]
+fn test_config() {
+    let temp_dir = TempDir::new().unwrap();
+    let cargo_toml = r#"[package]
+name = "test-crate"
+version = "0.1.0"
+edition = "2021"
+
+[[bin]]
+name = "blazon"
+path = "src/main.rs"
+
+[package.metadata.blazon]
+binary = "missing"
+metrics = ["size", "deps"]
+style = "plastic"
+labels = { size = "size" }
+"#;
+    fs::write(temp_dir.path().join("Cargo.toml"), cargo_toml).unwrap();
+    // blazon.toml takes precedence over Cargo.toml
+    fs::write(
+        temp_dir.path().join("blazon.toml"),
+        "binary = \"blazon\"\nreadmes = [\"README.md\", \"docs/README.md\"]\n",
+    )
+    .unwrap();
+    fs::create_dir(temp_dir.path().join("docs")).unwrap();
+    let markers = "<!-- blazon -->\n<!-- /blazon -->\n";
+    fs::write(temp_dir.path().join("README.md"), markers).unwrap();
+    fs::write(temp_dir.path().join("docs/README.md"), markers).unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .args(["--no-build", "--style", "flat-square"])
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("✓ Updated docs/README.md"));
+
+    for readme in ["README.md", "docs/README.md"] {
+        let updated = fs::read_to_string(temp_dir.path().join(readme)).unwrap();
+        let lines: Vec<&str> = updated.lines().collect();
+        assert!(lines[1].contains("/badge/size-19B-green?style=flat-square)"));
+        assert!(lines[2].starts_with("[![Dependencies:"));
+        assert!(!updated.contains("Target Dir"));
+    }
+
+    // A README argument replaces the configured ones
+    fs::write(temp_dir.path().join("docs/README.md"), markers).unwrap();
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .args(["check", "--no-build", "--style", "flat-square", "README.md"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_config_diagnostic() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    fs::write(
+        temp_dir.path().join("blazon.toml"),
+        "binary = \"blazon\"\n\n[colors]\nsize = \"green<2M\"\n",
+    )
+    .unwrap();
+
+    let output = blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--no-build")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let report = &stderr[stderr.find("blazon::config").unwrap()..];
+    assert_snapshot!(report);
+}
+
+#[test]
+fn test_insert_with_check() {
+    blazon_command()
+        .args(["--insert", "--check"])
+        .assert()
+        .code(3)
+        .stderr(predicates::str::contains("cannot be used with --check"));
+}
+
+#[test]
+fn test_marker_diagnostic() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    let original = "# Test\n[![ci](ci.svg)](ci)<!-- blazon -->\n\nIntro.\n";
+    fs::write(&readme_path, original).unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    let output = blazon_command()
+        .current_dir(temp_dir.path())
+        .args(["--no-build", "--binary", "blazon"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let report = &stderr[stderr.find("blazon::missing_end_marker").unwrap()..];
+    assert_snapshot!(report);
+    assert_eq!(fs::read_to_string(&readme_path).unwrap(), original);
+}
+
+#[test]
+fn test_duplicate_marker_diagnostic() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_cargo_toml(&temp_dir);
+    let readme_path = temp_dir.path().join("README.md");
+
+    fs::write(
+        &readme_path,
+        "<!-- blazon:size -->\n<!-- /blazon:size -->\n\n<!-- blazon:size -->\n<!-- /blazon:size -->\n",
+    )
+    .unwrap();
+    create_fake_binary(&temp_dir, "blazon");
+
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .args(["--no-build", "--binary", "blazon", "--check"])
+        .assert()
+        .code(4)
+        .stderr(predicates::str::contains(
+            "Duplicate marker '<!-- blazon:size -->'",
+        ))
+        .stderr(predicates::str::contains("repeated here"))
+        .stderr(predicates::str::contains("first here"));
+}
+
+#[test]
+fn test_cargo_blazon() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().join("ws");
+    fs::create_dir_all(&root).unwrap();
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"app\", \"tool\"]\nresolver = \"2\"\n",
+    )
+    .unwrap();
+    for name in ["app", "tool"] {
+        fs::create_dir_all(root.join(name).join("src")).unwrap();
+        fs::write(
+            root.join(name).join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n"),
+        )
+        .unwrap();
+        fs::write(root.join(name).join("src/main.rs"), "fn main() {}\n").unwrap();
+    }
+    fs::create_dir_all(root.join("target/release")).unwrap();
+    fs::write(root.join("target/release/app"), "app").unwrap();
+    fs::write(root.join("target/release/tool"), "tool binary").unwrap();
+    let manifest = root.join("Cargo.toml");
+
+    // Run from outside the workspace, the way cargo runs subcommands
+    let cargo_blazon = || {
+        let mut command = isolate(Command::new(cargo::cargo_bin!("cargo-blazon")));
+        command
+            .current_dir(temp_dir.path())
+            .args(["blazon", "metrics", "--no-build"]);
+        command
+    };
+
+    cargo_blazon()
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .args(["-p", "tool", "--json"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("\"binary_size_bytes\":11"));
+    cargo_blazon()
+        .arg(format!(
+            "--manifest-path={}",
+            root.join("app/Cargo.toml").display()
+        ))
+        .arg("--json")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("\"binary_size_bytes\":3"));
+    cargo_blazon()
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .args(["--package", "missing"])
+        .assert()
+        .code(3)
+        .stderr(predicates::str::contains(
+            "No package 'missing' in the workspace",
+        ));
+
+    // The cargo that invoked blazon is the one it runs
+    cargo_blazon()
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .env("CARGO", temp_dir.path().join("no-such-cargo"))
+        .assert()
+        .code(5)
+        .stderr(predicates::str::contains("Failed to run cargo metadata"));
+}
+
+#[test]
+fn test_manifest_path_readme() {
+    let temp_dir = TempDir::new().unwrap();
+    let project = TempDir::new_in(temp_dir.path()).unwrap();
+    create_test_cargo_toml(&project);
+    create_fake_binary(&project, "blazon");
+    let markers = "<!-- blazon -->\n<!-- /blazon -->\n";
+    fs::write(temp_dir.path().join("README.md"), markers).unwrap();
+    fs::write(project.path().join("README.md"), markers).unwrap();
+
+    // Run from outside the project, whose own README is the default
+    blazon_command()
+        .current_dir(temp_dir.path())
+        .arg("--manifest-path")
+        .arg(project.path().join("Cargo.toml"))
+        .arg("--no-build")
+        .assert()
+        .success();
+
+    let updated = fs::read_to_string(project.path().join("README.md")).unwrap();
+    assert!(updated.contains("crates.io/crates/test-crate"));
+    let untouched = fs::read_to_string(temp_dir.path().join("README.md")).unwrap();
+    assert_eq!(untouched, markers);
+}
+
+#[test]
+fn test_workspace() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"app\", \"lib-only\", \"quiet\"]\nresolver = \"2\"\n",
+    )
+    .unwrap();
+    let members = [
+        ("app", "src/main.rs", ""),
+        ("lib-only", "src/lib.rs", "readme = \"docs/README.md\"\n"),
+        ("quiet", "src/main.rs", ""),
+    ];
+    for (name, source, extra) in members {
+        fs::create_dir_all(root.join(name).join("src")).unwrap();
+        fs::write(
+            root.join(name).join("Cargo.toml"),
+            format!(
+                "[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n{extra}"
+            ),
+        )
+        .unwrap();
+        fs::write(root.join(name).join(source), "").unwrap();
+    }
+    let markers = "<!-- blazon:size -->\n<!-- /blazon:size -->\n";
+    fs::write(root.join("app/README.md"), markers).unwrap();
+    fs::create_dir_all(root.join("lib-only/docs")).unwrap();
+    let table = "<!-- blazon:table:size -->\n<!-- /blazon:table:size -->\n";
+    fs::write(
+        root.join("lib-only/docs/README.md"),
+        format!("{markers}{table}"),
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("target/release")).unwrap();
+    fs::write(root.join("target/release/app"), "app").unwrap();
+    fs::write(root.join("target/release/liblib_only.rlib"), "rlib").unwrap();
+    fs::write(root.join("target/release/quiet"), "q").unwrap();
+
+    let blazon = |args: &[&str]| {
+        let mut command = blazon_command();
+        command.current_dir(root).args(args);
+        command
+    };
+
+    let output = blazon(&["update", "--workspace", "--no-build"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let summary = String::from_utf8(output.stdout).unwrap();
+    let rows: Vec<Vec<&str>> = summary
+        .lines()
+        .map(|line| {
+            line.split("  ")
+                .map(str::trim)
+                .filter(|c| !c.is_empty())
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        [
+            vec!["Package", "Artifact", "Deps", "Size", "README", "Status"],
+            vec!["app", "app", "0", "3B", "app/README.md", "updated"],
+            vec![
+                "lib-only",
+                "liblib_only.rlib",
+                "0",
+                "4B",
+                "lib-only/docs/README.md",
+                "updated"
+            ],
+            vec!["quiet", "quiet", "0", "1B", "quiet/README.md", "no README"],
+        ]
+    );
+    let app = fs::read_to_string(root.join("app/README.md")).unwrap();
+    assert!(app.contains("/badge/build%20size-3B-green)](https://crates.io/crates/app)"));
+    let lib = fs::read_to_string(root.join("lib-only/docs/README.md")).unwrap();
+    assert!(lib.contains("[![Library Size: 4B]"));
+    assert!(lib.contains("/badge/library%20size-4B-green)](https://crates.io/crates/lib-only)"));
+    assert!(lib.contains("| Library size | 4B |"));
+
+    blazon(&["check", "--workspace", "--no-build"])
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("✓ app/README.md is up to date"));
+
+    blazon(&["--workspace", "--no-build", "-p", "app"])
+        .assert()
+        .code(3)
+        .stderr(predicates::str::contains(
+            "--workspace measures every member, so it cannot be used with --package",
+        ));
+}